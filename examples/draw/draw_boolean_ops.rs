@@ -0,0 +1,67 @@
+//! Demonstrates boolean operations on polygons and paths via `geom::boolean::Shape`.
+//!
+//! A ring, described by a path with a hole, is combined with a rotating star polygon. From the top
+//! left, the quadrants show their union, intersection, difference and xor.
+
+use nannou::geom::boolean::{self, Op, Shape};
+use nannou::geom::{self, Path};
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win_rect = app.window_rect();
+
+    // A ring described by a path with two circular sub-paths.
+    let ring = Shape::from_path(
+        &circle(90.0).merge(&circle(50.0)),
+        boolean::DEFAULT_TOLERANCE,
+    );
+
+    // A five-pointed star, whose contour intersects itself.
+    let t = app.time * 0.5;
+    let star = (0..5).map(|i| {
+        let a = t + i as f32 * 4.0 * PI / 5.0;
+        pt2(a.cos(), a.sin()) * 110.0 + vec2(40.0, 0.0)
+    });
+    let star = Shape::from_points(star).fill_rule(boolean::FillRule::NonZero);
+
+    let ops = [Op::Union, Op::Intersection, Op::Difference, Op::Xor];
+    let (w, h) = (win_rect.w() / 2.0, win_rect.h() / 2.0);
+    for (i, &op) in ops.iter().enumerate() {
+        let x = win_rect.left() + w * (i % 2) as f32 + w / 2.0;
+        let y = win_rect.top() - h * (i / 2) as f32 - h / 2.0;
+        let draw = draw.x_y(x, y).scale(w.min(h) / 260.0);
+        let path = ring.op(&star, op).to_path();
+        draw.polygon().color(STEELBLUE).events(path.iter());
+        draw.path()
+            .stroke()
+            .weight(2.0)
+            .color(BLACK)
+            .events(path.iter());
+        draw.text(&format!("{:?}", op))
+            .color(BLACK)
+            .font_size(18)
+            .y(-120.0);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn circle(radius: f32) -> Path {
+    let mut builder = geom::path();
+    for i in 0..64 {
+        let a = i as f32 / 64.0 * TAU;
+        let p = pt2(a.cos(), a.sin()) * radius;
+        builder = if i == 0 {
+            builder.begin(p)
+        } else {
+            builder.line_to(p)
+        };
+    }
+    builder.close().build()
+}
@@ -8,12 +8,10 @@ fn main() {
 }
 
 struct Model {
-    // The texture that we will draw to.
-    texture: wgpu::Texture,
+    // The offscreen target that we will draw to.
+    target: nannou::draw::RenderTarget,
     // Create a `Draw` instance for drawing to our texture.
     draw: nannou::Draw,
-    // The type used to render the `Draw` vertices to our texture.
-    renderer: nannou::draw::Renderer,
     // The type used to capture the texture.
     texture_capturer: wgpu::TextureCapturer,
     // The type used to resize our texture to the window texture.
@@ -38,37 +36,30 @@ fn model(app: &App) -> Model {
     // Retrieve the wgpu device.
     let device = window.device();
 
-    // Create our custom texture.
+    // Create our render target. Drawing is multisampled and resolved to the target's texture,
+    // which uses a spacious 16-bit linear sRGBA format suitable for high quality drawing.
     let sample_count = window.msaa_samples();
-    let texture = wgpu::TextureBuilder::new()
-        .size(texture_size)
-        // Our texture will be used as the RENDER_ATTACHMENT for our `Draw` render pass.
-        // It will also be SAMPLED by the `TextureCapturer` and `TextureResizer`.
-        .usage(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING)
-        // Use nannou's default multisampling sample count.
+    let target = nannou::draw::RenderTarget::builder()
         .sample_count(sample_count)
-        // Use a spacious 16-bit linear sRGBA format suitable for high quality drawing.
         .format(wgpu::TextureFormat::Rgba16Float)
-        // Build it!
-        .build(device);
+        .build(device, texture_size);
 
-    // Create our `Draw` instance and a renderer for it.
+    // Create our `Draw` instance.
     let draw = nannou::Draw::new();
-    let descriptor = texture.descriptor();
-    let renderer =
-        nannou::draw::RendererBuilder::new().build_from_texture_descriptor(device, descriptor);
 
     // Create the texture capturer.
     let texture_capturer = wgpu::TextureCapturer::default();
 
     // Create the texture reshaper.
-    let texture_view = texture.view().build();
-    let texture_sample_type = texture.sample_type();
+    // The target's texture has already been resolved, so only the window is multisampled.
+    let texture_view = target.texture_view();
+    let texture_sample_count = 1;
+    let texture_sample_type = target.texture().sample_type();
     let dst_format = Frame::TEXTURE_FORMAT;
     let texture_reshaper = wgpu::TextureReshaper::new(
         device,
-        &texture_view,
-        sample_count,
+        texture_view,
+        texture_sample_count,
         texture_sample_type,
         sample_count,
         dst_format,
@@ -78,9 +69,8 @@ fn model(app: &App) -> Model {
     std::fs::create_dir_all(capture_directory(app)).unwrap();
 
     Model {
-        texture,
+        target,
         draw,
-        renderer,
         texture_capturer,
         texture_reshaper,
     }
@@ -92,7 +82,7 @@ fn update(app: &App, model: &mut Model, _update: Update) {
     draw.reset();
 
     // Create a `Rect` for our texture to help with drawing.
-    let [w, h] = model.texture.size();
+    let [w, h] = model.target.size();
     let r = geom::Rect::from_w_h(w as f32, h as f32);
 
     // Use the frame number to animate, ensuring we get a constant update time.
@@ -142,18 +132,15 @@ fn update(app: &App, model: &mut Model, _update: Update) {
         label: Some("texture renderer"),
     };
     let mut encoder = device.create_command_encoder(&ce_desc);
-    model
-        .renderer
-        .render_to_texture(device, &mut encoder, draw, &model.texture);
+    draw.encode_to_texture(device, &mut encoder, &model.target);
 
     // Take a snapshot of the texture. The capturer will do the following:
     //
-    // 1. Resolve the texture to a non-multisampled texture if necessary.
-    // 2. Convert the format to non-linear 8-bit sRGBA ready for image storage.
-    // 3. Copy the result to a buffer ready to be mapped for reading.
+    // 1. Convert the format to non-linear 8-bit sRGBA ready for image storage.
+    // 2. Copy the result to a buffer ready to be mapped for reading.
     let snapshot = model
         .texture_capturer
-        .capture(device, &mut encoder, &model.texture);
+        .capture(device, &mut encoder, model.target.texture());
 
     // Submit the commands for our drawing and texture capture to the GPU.
     window.queue().submit(Some(encoder.finish()));
@@ -0,0 +1,51 @@
+//! Demonstrates clipping drawings to the shape of other primitives with `draw.clip`.
+//!
+//! On the left, stripes are clipped to a star and then again to a circle following the mouse. On
+//! the right, an inverted mask cuts text out of a rotating rectangle.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+    let t = app.time;
+
+    // Clip to a star, then clip the result to a circle following the mouse.
+    let left = draw.x(win.left() * 0.5);
+    let star = left.clip(|mask| {
+        let points = (0..10).map(|i| {
+            let radius = if i % 2 == 0 { 200.0 } else { 80.0 };
+            let angle = i as f32 / 10.0 * TAU + t * 0.2;
+            pt2(angle.cos(), angle.sin()) * radius
+        });
+        mask.polygon().points(points);
+    });
+    for i in 0..20 {
+        let y = map_range(i, 0, 20, -200.0, 200.0);
+        let hue = i as f32 / 20.0;
+        star.rect().x_y(0.0, y).w_h(400.0, 10.0).color(hsl(hue, 0.8, 0.5));
+    }
+    let mouse = app.mouse.position() - pt2(win.left() * 0.5, 0.0);
+    let spotlight = star.clip(|mask| {
+        mask.ellipse().xy(mouse).radius(90.0);
+    });
+    spotlight.rect().w_h(400.0, 400.0).color(WHITE);
+
+    // Cut text out of a rotating rectangle with an inverted mask.
+    let right = draw.x(win.right() * 0.5);
+    let cut_out = right.clip_inverted(|mask| {
+        mask.text("CLIP").font_size(96).w_h(400.0, 200.0);
+    });
+    cut_out
+        .rotate(t * 0.5)
+        .rect()
+        .w_h(250.0, 250.0)
+        .color(CORNFLOWERBLUE);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,40 @@
+//! A grid of spinning cuboids, demonstrating per-face colors and face outlines.
+
+use nannou::geom::cuboid::Face;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let win = app.window_rect();
+    let t = app.time;
+    let n = 5;
+    let step = win.w().min(win.h()) / n as f32;
+    for i in 0..n {
+        for j in 0..n {
+            let x = (i as f32 - (n - 1) as f32 * 0.5) * step;
+            let y = (j as f32 - (n - 1) as f32 * 0.5) * step;
+            let phase = (i + j) as f32 * 0.25;
+            draw.cuboid()
+                .x_y(x, y)
+                .w_h_d(step * 0.5, step * 0.5, step * 0.5)
+                .x_radians(t * 0.7 + phase)
+                .y_radians(t * 0.5 + phase)
+                .face_color(Face::Front, RED)
+                .face_color(Face::Back, GREEN)
+                .face_color(Face::Left, BLUE)
+                .face_color(Face::Right, YELLOW)
+                .face_color(Face::Top, MAGENTA)
+                .face_color(Face::Bottom, CYAN)
+                .stroke(WHITE)
+                .stroke_weight(2.0);
+        }
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,56 @@
+//! Dashed strokes along lines, polylines, arrows and the outlines of shapes.
+
+use nannou::lyon::tessellation::LineCap;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).size(800, 600).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let t = app.time;
+
+    // Marching ants around a rectangle.
+    draw.rect()
+        .x_y(-200.0, 120.0)
+        .w_h(240.0, 160.0)
+        .no_fill()
+        .stroke_color(BLACK)
+        .stroke_weight(2.0)
+        .dashes(&[8.0, 6.0])
+        .dash_offset(-t * 20.0);
+
+    // Dots along an ellipse via zero length dashes with round caps.
+    draw.ellipse()
+        .x_y(200.0, 120.0)
+        .radius(90.0)
+        .color(LIGHTSTEELBLUE)
+        .stroke_color(STEELBLUE)
+        .stroke_weight(8.0)
+        .dashes(&[0.0, 16.0])
+        .dash_cap(LineCap::Round);
+
+    // A dash-dot pattern along a curve.
+    let points = (0..=200).map(|i| {
+        let x = map_range(i, 0, 200, -350.0, 350.0);
+        pt2(x, -60.0 + (x * 0.02 + t).sin() * 40.0)
+    });
+    draw.polyline()
+        .weight(4.0)
+        .dashes(&[20.0, 8.0, 2.0, 8.0])
+        .color(DARKSLATEGRAY)
+        .points(points);
+
+    // A dashed arrow.
+    draw.arrow()
+        .start(pt2(-300.0, -220.0))
+        .end(pt2(300.0, -220.0))
+        .weight(3.0)
+        .dashes(&[12.0, 6.0])
+        .dash_cap(LineCap::Square)
+        .color(CRIMSON);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,63 @@
+//! Demonstrates triangulating a concave polygon with holes via `geom::earcut`.
+//!
+//! The outline of a gear is triangulated along with a ring of holes, and each triangle is given
+//! its own colour. The edges of the triangles are drawn on top.
+
+use nannou::geom::{earcut, Tri};
+use nannou::prelude::*;
+
+const TEETH: usize = 12;
+const HOLES: usize = 5;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let t = app.time;
+
+    // The outer ring, followed by each of the holes.
+    let mut points = gear(t);
+    let mut holes = vec![];
+    for i in 0..HOLES {
+        holes.push(points.len());
+        let angle = t * 0.2 + i as f32 / HOLES as f32 * TAU;
+        let centre = pt2(angle.cos(), angle.sin()) * 120.0;
+        points.extend((0..6).map(|j| {
+            let a = j as f32 / 6.0 * TAU;
+            centre + pt2(a.cos(), a.sin()) * 35.0
+        }));
+    }
+
+    let indices = earcut::triangle_indices(&points, &holes);
+    let tris = indices.chunks(3).enumerate().map(|(i, t)| {
+        let color = hsl(i as f32 * 0.618 % 1.0, 0.6, 0.6);
+        Tri([t[0], t[1], t[2]]).map_vertices(|ix| (points[ix].extend(0.0), color))
+    });
+    draw.mesh().tris_colored(tris);
+
+    for t in indices.chunks(3) {
+        draw.polyline()
+            .weight(1.0)
+            .color(WHITE)
+            .points_closed(t.iter().map(|&ix| points[ix]));
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
+
+// The outline of a gear, with a wobble over time.
+fn gear(t: f32) -> Vec<Point2> {
+    (0..TEETH * 4)
+        .map(|i| {
+            let a = i as f32 / (TEETH * 4) as f32 * TAU;
+            let r = match i % 4 {
+                0 | 1 => 260.0,
+                _ => 200.0 + (t + a * 3.0).sin() * 20.0,
+            };
+            pt2(a.cos(), a.sin()) * r
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+//! Demonstrates finding installed fonts via a `text::font::Database` and displaying characters
+//! missing from the primary font via a chain of fallback fonts.
+//!
+//! The top line is drawn without fallback fonts, so characters missing from the primary font are
+//! displayed as boxes. The bottom line falls back to each of the installed fonts in `FALLBACK`.
+
+use nannou::prelude::*;
+use nannou::text::font::{self, Family, Query};
+
+// Installed families to try, in order, for characters missing from the primary font.
+const FALLBACK: &[&str] = &[
+    "Noto Sans CJK JP",
+    "Noto Sans Symbols",
+    "Noto Sans Symbols 2",
+    "DejaVu Sans",
+    "Symbola",
+];
+
+const TEXT: &str = "Chess ♞ ♛, CJK 日本語, Armenian Բարեւ, arrows ⇄ ⇶";
+
+fn main() {
+    nannou::app(Box::new(model)).run();
+}
+
+struct Model {
+    font: text::Font,
+    fallback: Vec<text::Font>,
+    families: usize,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(900, 400).view(view).build().unwrap();
+
+    // Loading the metadata of every installed font may take a moment, so do so once up front.
+    let db = font::Database::system();
+    let query = Query::new().family("Noto Sans").family(Family::SansSerif);
+    let font = db.font(&query).unwrap_or_else(|_| font::default_notosans());
+    let fallback = db.fonts(FALLBACK.iter().cloned());
+    let families = db.families().len();
+    Model {
+        font,
+        fallback,
+        families,
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win_rect = app.window_rect();
+
+    let info = format!(
+        "{} installed families, {} fallback fonts found",
+        model.families,
+        model.fallback.len()
+    );
+    draw.text(&info)
+        .font_size(16)
+        .color(GRAY)
+        .xy(win_rect.mid_top() - vec2(0.0, 40.0))
+        .wh(win_rect.pad(20.0).wh());
+
+    draw.text(TEXT)
+        .font(model.font.clone())
+        .font_size(28)
+        .color(BLACK)
+        .y(60.0)
+        .wh(win_rect.pad(20.0).wh());
+
+    draw.text(TEXT)
+        .font(model.font.clone())
+        .fallback_fonts(model.fallback.iter().cloned())
+        .font_size(28)
+        .color(BLACK)
+        .y(-60.0)
+        .wh(win_rect.pad(20.0).wh());
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,51 @@
+//! Filling and stroking shapes with linear, radial and conic gradients.
+
+use nannou::draw::properties::gradient::Spread;
+use nannou::draw::Gradient;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).size(900, 600).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let t = app.time;
+
+    // A linear gradient across a rectangle, repeating beyond its stops.
+    let linear = Gradient::linear(pt2(-60.0, 0.0), pt2(60.0, 0.0))
+        .colors(vec![CRIMSON, GOLD])
+        .spread(Spread::Reflect);
+    draw.rect()
+        .x_y(-280.0, 0.0)
+        .w_h(240.0, 360.0)
+        .gradient(linear)
+        .stroke_weight(8.0)
+        .stroke_gradient(
+            Gradient::linear(pt2(0.0, -180.0), pt2(0.0, 180.0)).colors(vec![WHITE, BLACK]),
+        );
+
+    // A radial gradient within an ellipse whose center follows time.
+    let center = pt2(t.cos(), t.sin()) * 40.0;
+    let radial = Gradient::radial(center, 130.0)
+        .stop(0.0, WHITE)
+        .stop(0.4, DEEPSKYBLUE)
+        .stop(1.0, MIDNIGHTBLUE);
+    draw.ellipse().w_h(260.0, 260.0).gradient(radial);
+
+    // A rotating conic gradient, i.e. a color wheel.
+    let conic =
+        Gradient::conic(pt2(0.0, 0.0), t).colors((0..=6).map(|i| hsv(i as f32 / 6.0, 1.0, 1.0)));
+    draw.ellipse().x_y(280.0, 0.0).radius(120.0).gradient(conic);
+
+    // Paths may be painted with gradients too.
+    let points = (0..=100).map(|i| {
+        let x = map_range(i, 0, 100, -400.0, 400.0);
+        pt2(x, -250.0 + (x * 0.02 + t).sin() * 20.0)
+    });
+    let stroke = Gradient::linear(pt2(-400.0, 0.0), pt2(400.0, 0.0)).colors(vec![LIME, MAGENTA]);
+    draw.polyline().weight(6.0).gradient(stroke).points(points);
+
+    draw.to_frame(app, &frame).unwrap();
+}
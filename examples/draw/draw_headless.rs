@@ -0,0 +1,38 @@
+// Renders a short animation without opening a window and writes each frame as a PNG image file to
+// `/<path_to_nannou>/nannou/draw_headless/<frame_number>.png`.
+//
+// As no window or event loop is created, this can run on machines without a display (e.g. CI).
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view)
+        .headless([640, 480])
+        .loop_mode(LoopMode::loop_ntimes(120))
+        .run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(CORNFLOWERBLUE);
+
+    // Time advances by exactly 1/60th of a second each frame, regardless of how long rendering
+    // takes, so the output is the same on every machine.
+    let t = app.time;
+    let win = app.window_rect();
+    draw.ellipse()
+        .x_y(t.cos() * win.w() * 0.25, t.sin() * win.h() * 0.25)
+        .radius(win.h() * 0.1)
+        .color(VIOLET);
+
+    draw.to_frame(app, &frame).unwrap();
+
+    // Capture the frame!
+    let file_path = app
+        .project_path()
+        .expect("failed to locate `project_path`")
+        .join(app.exe_name().unwrap())
+        .join(format!("{:03}", frame.nth()))
+        .with_extension("png");
+    app.capture_frame(file_path);
+}
@@ -0,0 +1,39 @@
+//! Drawing tens of thousands of particles in a single draw call via instancing.
+//!
+//! The ellipse is tessellated once, then drawn once per particle with its own transform and
+//! color.
+
+use nannou::draw::primitive::Ellipse;
+use nannou::prelude::*;
+
+const PARTICLES: usize = 50_000;
+
+fn main() {
+    nannou::sketch(view).size(800, 800).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let t = app.time;
+
+    // Particles spiralling outwards from the centre, each scaled and tinted by its distance.
+    let particles = (0..PARTICLES).map(|i| {
+        let f = i as f32 / PARTICLES as f32;
+        let angle = f * 200.0 * TAU + t * 0.2;
+        let radius = f.sqrt() * 380.0;
+        let position = vec3(angle.cos() * radius, angle.sin() * radius, 0.0);
+        let scale = 0.5 + (f * 40.0 - t * 2.0).sin().abs();
+        (position, scale, f)
+    });
+    let transforms = particles
+        .clone()
+        .map(|(p, s, _)| Mat4::from_scale_rotation_translation(Vec3::splat(s), Quat::IDENTITY, p));
+    let colors = particles.map(|(_, _, f)| hsla(f * 0.6 + t * 0.05, 0.8, 0.6, 0.8));
+
+    draw.instances(Ellipse::default().radius(2.0).resolution(8.0))
+        .transforms(transforms)
+        .colors(colors);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,54 @@
+//! Demonstrates compositing groups of drawings with `draw.layer()` using each of the blend modes.
+//!
+//! Each cell contains a layer of overlapping circles blended with the striped backdrop. Move the
+//! mouse horizontally to change the opacity of the layers.
+
+use nannou::draw::BlendMode;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win = app.window_rect();
+    let t = app.time;
+
+    // A backdrop of vertical stripes.
+    let stripes = 16;
+    let stripe_w = win.w() / stripes as f32;
+    for i in 0..stripes {
+        let x = win.left() + (i as f32 + 0.5) * stripe_w;
+        let hue = i as f32 / stripes as f32;
+        draw.rect()
+            .x_y(x, 0.0)
+            .w_h(stripe_w, win.h())
+            .color(hsl(hue, 0.6, 0.5));
+    }
+
+    // One cell per blend mode.
+    let opacity = map_range(app.mouse.x, win.left(), win.right(), 0.0, 1.0);
+    let (cols, rows) = (4, 3);
+    let cell_w = win.w() / cols as f32;
+    let cell_h = win.h() / rows as f32;
+    let radius = cell_w.min(cell_h) * 0.25;
+    let colors = [rgb(0.9, 0.2, 0.2), rgb(0.2, 0.8, 0.3), rgb(0.2, 0.3, 0.9)];
+    for (i, &mode) in BlendMode::ALL.iter().enumerate() {
+        let x = win.left() + ((i % cols) as f32 + 0.5) * cell_w;
+        let y = win.top() - ((i / cols) as f32 + 0.5) * cell_h;
+        let cell = draw.x_y(x, y);
+        let layer = cell.layer().opacity(opacity).blend_mode(mode);
+        for (j, &color) in colors.iter().enumerate() {
+            let angle = j as f32 / colors.len() as f32 * TAU + t * 0.5;
+            let offset = vec2(angle.cos(), angle.sin()) * radius * 0.5;
+            layer.ellipse().xy(offset).radius(radius).color(color);
+        }
+        cell.text(&format!("{:?}", mode))
+            .y(-cell_h * 0.42)
+            .color(BLACK);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,50 @@
+//! A handful of lit solids illuminated by an ambient, a directional and an orbiting point light.
+//!
+//! Drawings are unlit by default. Drawing via `draw.lit()` or `draw.material(..)` shades them using
+//! the lights added to the scene. The small unlit sphere marks the position of the point light.
+
+use nannou::draw::Material;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).size(800, 600).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let t = app.time;
+    let win = app.window_rect();
+
+    // The lights of the scene.
+    let light_position = vec3(t.cos() * win.w() * 0.4, win.h() * 0.3, t.sin() * 300.0);
+    draw.light_ambient(rgba(1.0, 1.0, 1.0, 0.1));
+    draw.light_directional(vec3(-1.0, -1.0, -1.0), rgba(1.0, 0.9, 0.8, 0.5));
+    draw.light_point(light_position, rgb(0.4, 0.6, 1.0));
+
+    // A marker for the point light, drawn unlit.
+    draw.sphere().xyz(light_position).radius(8.0).color(WHITE);
+
+    // Lit solids with varying materials.
+    let lit = draw.lit();
+    let shiny = draw.material(Material::default().specular(1.0).shininess(128.0));
+    let matte = draw.material(Material::lambert());
+    let spin = |d: &Draw| d.x_radians(t * 0.4).y_radians(t * 0.25);
+    spin(&shiny.x(-250.0))
+        .sphere()
+        .radius(90.0)
+        .resolution(64)
+        .color(STEELBLUE);
+    spin(&lit.x(0.0))
+        .cuboid()
+        .w_h_d(140.0, 140.0, 140.0)
+        .color(ORANGE);
+    spin(&matte.x(250.0))
+        .torus()
+        .radius(70.0)
+        .tube_radius(30.0)
+        .color(SEAGREEN);
+
+    draw.to_frame(app, &frame).unwrap();
+}
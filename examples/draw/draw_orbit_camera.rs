@@ -0,0 +1,66 @@
+//! Viewing a lit scene through a perspective camera that orbits the origin.
+//!
+//! Drag with the left mouse button to orbit, drag with the right mouse button to pan and scroll to
+//! zoom.
+
+use nannou::draw::camera::Orbit;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(Box::new(model)).update(update).run();
+}
+
+struct Model {
+    orbit: Orbit,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(800, 600)
+        .view(view)
+        .mouse_wheel(mouse_wheel)
+        .build()
+        .unwrap();
+    let mut orbit = Orbit::new(app.window_rect().h());
+    orbit.pitch = 0.4;
+    orbit.yaw = 0.6;
+    Model { orbit }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    model.orbit.height = app.window_rect().h();
+    model.orbit.update(&app.mouse);
+}
+
+fn mouse_wheel(_app: &App, model: &mut Model, delta: MouseScrollDelta, _phase: TouchPhase) {
+    model.orbit.scroll(delta);
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    draw.camera(model.orbit.camera());
+    draw.light_ambient(rgba(1.0, 1.0, 1.0, 0.15));
+    draw.light_directional(vec3(-0.5, -1.0, -0.3), WHITE);
+
+    // A grid of cuboids of varying heights on a ground plane.
+    let lit = draw.lit();
+    lit.plane()
+        .x_radians(-PI / 2.0)
+        .w_h(600.0, 600.0)
+        .color(DIMGRAY);
+    for i in 0..5 {
+        for j in 0..5 {
+            let x = (i as f32 - 2.0) * 100.0;
+            let z = (j as f32 - 2.0) * 100.0;
+            let h = 40.0 + ((i * 7 + j * 3) % 5) as f32 * 30.0;
+            let hue = (i * 5 + j) as f32 / 25.0;
+            lit.cuboid()
+                .x_y_z(x, h * 0.5, z)
+                .w_h_d(50.0, h, 50.0)
+                .hsl(hue, 0.6, 0.5);
+        }
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
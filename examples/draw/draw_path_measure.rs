@@ -0,0 +1,82 @@
+//! Demonstrates measuring a path via `geom::path::Measure`.
+//!
+//! A wavy curve is revealed over time by drawing the section between its start and a growing
+//! distance along it. Offset curves are drawn on either side, and evenly spaced samples are marked
+//! with their normals.
+
+use nannou::geom::path::{self, Path};
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win_rect = app.window_rect().pad(80.0);
+
+    let curve = wave(win_rect);
+    let measure = curve.measure(path::DEFAULT_TOLERANCE);
+
+    // Offset curves on either side of the wave.
+    for i in 1..=4 {
+        let distance = i as f32 * 12.0;
+        for &d in &[distance, -distance] {
+            draw.path()
+                .stroke()
+                .weight(1.0)
+                .color(LIGHTGRAY)
+                .events(measure.offset(d).iter());
+        }
+    }
+
+    // Reveal the wave over time.
+    let t = (app.time * 0.25).fract();
+    let revealed = measure.section(0.0, t * measure.length());
+    draw.path()
+        .stroke()
+        .weight(4.0)
+        .color(STEELBLUE)
+        .events(revealed.iter());
+
+    // Mark evenly spaced samples with their normals.
+    for sample in measure.samples(24) {
+        let end = sample.position + sample.normal * 20.0;
+        draw.line()
+            .start(sample.position)
+            .end(end)
+            .weight(1.5)
+            .color(ORANGERED);
+        draw.ellipse().xy(sample.position).radius(3.0).color(BLACK);
+    }
+
+    // The tip of the revealed section.
+    if let Some(tip) = measure.sample_normalized(t) {
+        draw.tri()
+            .points(pt2(10.0, 0.0), pt2(-6.0, 6.0), pt2(-6.0, -6.0))
+            .xy(tip.position)
+            .rotate(tip.angle())
+            .color(STEELBLUE);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
+
+// A wave of cubic bezier curves spanning the given rect.
+fn wave(rect: Rect) -> Path {
+    let n = 4;
+    let step = rect.w() / n as f32;
+    let amp = rect.h() / 2.0;
+    let mut builder = path::path().begin(rect.mid_left());
+    for i in 0..n {
+        let x = rect.left() + i as f32 * step;
+        let dir = if i % 2 == 0 { 1.0 } else { -1.0 };
+        builder = builder.cubic_bezier_to(
+            pt2(x + step / 3.0, amp * dir),
+            pt2(x + step * 2.0 / 3.0, amp * dir),
+            pt2(x + step, 0.0),
+        );
+    }
+    builder.end().build()
+}
@@ -0,0 +1,62 @@
+//! Demonstrates finding the drawing beneath the mouse via `draw.pick()`.
+//!
+//! A field of shapes drifts around the window. The topmost shape beneath the mouse is highlighted
+//! and its tag is displayed in the corner of the window.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let win = app.window_rect();
+    let t = app.time * 0.2;
+
+    // Draws each shape tagged by its index, highlighting the given tag.
+    let shapes = |draw: &Draw, highlight: Option<u64>| {
+        for i in 0..24u64 {
+            let f = i as f32;
+            let x = (t + f * 1.3).sin() * win.w() * 0.4;
+            let y = (t * 0.7 + f * 2.1).cos() * win.h() * 0.4;
+            let hue = f / 24.0;
+            let color = match Some(i) == highlight {
+                true => hsla(hue, 1.0, 0.8, 1.0),
+                false => hsla(hue, 0.6, 0.4, 0.8),
+            };
+            let draw = draw.tag(i).x_y(x, y).rotate(t + f);
+            match i % 3 {
+                0 => {
+                    draw.ellipse().radius(40.0).color(color);
+                }
+                1 => {
+                    draw.rect().w_h(80.0, 50.0).color(color);
+                }
+                _ => {
+                    draw.tri().w_h(90.0, 90.0).color(color);
+                }
+            }
+        }
+    };
+
+    // Pick the shape beneath the mouse from a first pass, then draw the shapes with the
+    // highlight.
+    let picking = Draw::new();
+    shapes(&picking, None);
+    let picked = picking
+        .pick(app.mouse.position(), win.wh())
+        .and_then(|pick| pick.tag);
+    shapes(&draw, picked);
+
+    let text = match picked {
+        Some(tag) => format!("shape {}", tag),
+        None => "nothing".to_string(),
+    };
+    draw.text(&text)
+        .xy(win.top_left() + vec2(60.0, -20.0))
+        .color(WHITE);
+
+    draw.to_frame(app, &frame).unwrap();
+}
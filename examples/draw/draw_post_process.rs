@@ -0,0 +1,66 @@
+//! Applying a chain of post-processing effects to the frame.
+//!
+//! Includes a custom WGSL effect that shifts the red and blue channels apart, along with the
+//! built-in feedback, bloom and CRT effects. Move the mouse horizontally to adjust the shift.
+
+use nannou::frame::post::{Chain, Effect};
+use nannou::prelude::*;
+
+// A custom effect with a single `shift` parameter, given in pixels.
+const CHROMATIC_ABERRATION: &str = r#"
+[[stage(fragment)]]
+fn main([[location(0)]] tex_coords: vec2<f32>) -> [[location(0)]] vec4<f32> {
+    let offset = vec2<f32>(uniforms.shift / uniforms.resolution.x, 0.0);
+    let r = textureSample(src_texture, tex_sampler, tex_coords + offset).r;
+    let ga = textureSample(src_texture, tex_sampler, tex_coords).ga;
+    let b = textureSample(src_texture, tex_sampler, tex_coords - offset).b;
+    return vec4<f32>(r, ga.x, b, ga.y);
+}
+"#;
+
+struct Model {
+    chain: Chain,
+}
+
+fn main() {
+    nannou::app(Box::new(model)).update(update).run();
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 540).view(view).build().unwrap();
+    let chain = Chain::new()
+        .effect(Effect::feedback(0.92))
+        .effect(Effect::new(CHROMATIC_ABERRATION).param("shift", 0.0))
+        .effect(Effect::bloom(0.6, 1.2, 12.0))
+        .effect(Effect::crt(0.08, 0.4, 0.25));
+    Model { chain }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    let shift = map_range(
+        app.mouse.x,
+        app.window_rect().left(),
+        app.window_rect().right(),
+        0.0,
+        12.0,
+    );
+    model.chain.effects_mut()[1].set_param("shift", shift);
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+    let t = app.time;
+    for i in 0..6 {
+        let phase = t + i as f32 * TAU / 6.0;
+        draw.ellipse()
+            .x_y(phase.cos() * 180.0, (phase * 2.0).sin() * 120.0)
+            .radius(18.0)
+            .color(hsl(i as f32 / 6.0, 0.9, 0.6));
+    }
+    draw.text("nannou").font_size(64).color(WHITE);
+    draw.to_frame(app, &frame).unwrap();
+
+    // Apply the effects once all drawing is complete.
+    frame.post_process(&model.chain);
+}
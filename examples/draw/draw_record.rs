@@ -0,0 +1,58 @@
+// Records the first 300 frames of a simple animation to a sequence of 16-bit PNG images in
+// `/<path_to_nannou>/nannou/draw_record/`.
+//
+// Unlike `capture_frame`, recordings never drop frames. Instead, the application loop is throttled
+// to the rate at which frames can be written to disk.
+
+use nannou::frame::record;
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(Box::new(model)).run();
+}
+
+struct Model;
+
+fn model(app: &App) -> Model {
+    app.new_window().size(720, 720).view(view).build().unwrap();
+
+    // Record to a directory named after the example.
+    let directory = app
+        .project_path()
+        .expect("failed to locate `project_path`")
+        .join(app.exe_name().unwrap());
+    app.main_window()
+        .record()
+        .directory(directory)
+        .name_pattern("frame_#####")
+        .format(record::Format::Png16)
+        .frame_count(300)
+        .start()
+        .expect("failed to start recording");
+
+    Model
+}
+
+fn view(app: &App, _model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let t = frame.nth() as f32 / 60.0;
+    let win = app.window_rect();
+    for i in 0..12 {
+        let phase = i as f32 / 12.0;
+        let angle = (t + phase) * TAU;
+        let radius = win.w() * 0.3 * (t * 0.5 + phase).sin();
+        draw.ellipse()
+            .x_y(angle.cos() * radius, angle.sin() * radius)
+            .radius(20.0)
+            .hsv(phase, 0.8, 1.0);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+
+    if app.main_window().is_recording() {
+        app.main_window()
+            .set_title(&format!("recording frame {}", frame.nth()));
+    }
+}
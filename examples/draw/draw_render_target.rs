@@ -0,0 +1,68 @@
+//! A feedback effect using two offscreen `RenderTarget`s.
+//!
+//! Each update, the previous result is drawn slightly zoomed and rotated into the other target
+//! along with a new shape, producing trails that spiral outwards. The two targets swap roles
+//! every frame, as a texture may not be sampled while it is being rendered to.
+
+use nannou::draw::RenderTarget;
+use nannou::prelude::*;
+
+struct Model {
+    draw: nannou::Draw,
+    targets: [RenderTarget; 2],
+    current: usize,
+}
+
+fn main() {
+    nannou::app(Box::new(model)).update(update).run();
+}
+
+fn model(app: &App) -> Model {
+    let w_id = app.new_window().size(600, 600).view(view).build().unwrap();
+    let window = app.window(w_id).unwrap();
+    let device = window.device();
+    let size = [600, 600];
+    let targets = [
+        RenderTarget::new(device, size),
+        RenderTarget::new(device, size),
+    ];
+    Model {
+        draw: nannou::Draw::new(),
+        targets,
+        current: 0,
+    }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    let prev = model.current;
+    let next = (prev + 1) % model.targets.len();
+    let [w, h] = model.targets[next].size();
+    let t = app.time;
+
+    // Draw the previous result, then fade it out slightly and add a new shape on top.
+    let draw = &model.draw;
+    draw.reset();
+    draw.background().color(BLACK);
+    draw.texture(model.targets[prev].texture())
+        .w_h(w as f32 * 1.01, h as f32 * 1.01)
+        .rotate(0.004);
+    draw.rect()
+        .w_h(w as f32, h as f32)
+        .color(srgba(0.0, 0.0, 0.0, 0.03));
+    draw.ellipse()
+        .x_y((t * 1.3).sin() * 160.0, (t * 1.7).cos() * 160.0)
+        .radius(12.0)
+        .color(hsl(t * 0.1 % 1.0, 0.8, 0.6));
+
+    // Render to the next target.
+    let window = app.main_window();
+    draw.to_texture(window.device_queue_pair(), &model.targets[next]);
+    model.current = next;
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.texture(model.targets[model.current].texture())
+        .wh(app.window_rect().wh());
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,61 @@
+//! Demonstrates laying out text with mixed font sizes, colors, weights and slants in a single
+//! wrapped block via `draw.text(..).span(..)`.
+//!
+//! Resize the window to see the styled spans wrap along with the rest of the text.
+
+use nannou::prelude::*;
+use nannou::text::Span;
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win_rect = app.window_rect().pad(40.0);
+
+    // A label with a bold, larger value.
+    let temp = (app.time * 0.5).sin() * 10.0 + 20.0;
+    let label = format!("Temp: {:.0}°C", temp);
+    let value_start = "Temp: ".len();
+    let value_color = if temp > 20.0 { ORANGERED } else { STEELBLUE };
+    draw.text(&label)
+        .color(BLACK)
+        .font_size(24)
+        .left_justify()
+        .align_text_top()
+        .x_y(win_rect.left() + 200.0, win_rect.top() - 50.0)
+        .w_h(400.0, 100.0)
+        .span(
+            Span::new(value_start..)
+                .bold()
+                .font_size(48)
+                .color(value_color),
+        );
+
+    // A paragraph with styled words.
+    let text = "Spans style ranges of text with their own font size, color, weight or slant. \
+        Each line is as tall as its LARGEST span, and styled words wrap along with the rest \
+        of the paragraph.";
+    let span = |word: &str| {
+        let start = text.find(word).unwrap();
+        Span::new(start..start + word.len())
+    };
+    draw.text(text)
+        .color(DIMGRAY)
+        .font_size(20)
+        .line_spacing(6.0)
+        .left_justify()
+        .xy(win_rect.xy() - vec2(0.0, 60.0))
+        .wh(win_rect.wh() - vec2(0.0, 120.0))
+        .spans(vec![
+            span("font size").font_size(32),
+            span("color").color(MEDIUMVIOLETRED),
+            span("weight").bold().color(BLACK),
+            span("slant").italic().color(BLACK),
+            span("LARGEST").font_size(40).color(DARKGREEN),
+        ]);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,58 @@
+//! Demonstrates text shaping and bidirectional ordering.
+//!
+//! Each line of text is laid out as a sequence of runs via `Text::runs`. Runs are outlined in blue
+//! for left-to-right text and in red for right-to-left text, and the start of each shaped glyph is
+//! marked along the baseline. Note the kerning between pairs like "AV" and "Wa".
+//!
+//! The default font has no Hebrew or Arabic glyphs, so these are displayed as boxes. Load a font
+//! covering these scripts via `text::font::from_file` and pass it to `.font(..)` to display them.
+
+use nannou::prelude::*;
+use nannou::text::{self, shape::Direction};
+
+fn main() {
+    nannou::sketch(view).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win_rect = app.window_rect();
+
+    let lines = [
+        ("AVATAR Wave Typography", 48),
+        ("Hello שלום world", 36),
+        ("The number مرحبا 123 بالعالم", 36),
+    ];
+    let line_h = win_rect.h() / lines.len() as f32;
+    for (i, &(s, font_size)) in lines.iter().enumerate() {
+        let y = win_rect.top() - line_h * (i as f32 + 0.5);
+        let rect = Rect::from_x_y_w_h(0.0, y, win_rect.w() - 80.0, line_h);
+        let layout = text::text(s).font_size(font_size).build(rect);
+
+        for run in layout.runs() {
+            let color = match run.direction {
+                Direction::LeftToRight => STEELBLUE,
+                Direction::RightToLeft => ORANGERED,
+            };
+            draw.rect()
+                .xy(run.rect.xy())
+                .wh(run.rect.wh())
+                .no_fill()
+                .stroke(color)
+                .stroke_weight(1.0);
+            for (_, _, glyph_rect) in layout.run_glyphs(&run) {
+                let start = pt2(glyph_rect.left(), run.rect.bottom());
+                draw.ellipse().xy(start).radius(2.0).color(color);
+            }
+        }
+
+        draw.text(s)
+            .font_size(font_size)
+            .xy(rect.xy())
+            .wh(rect.wh())
+            .color(BLACK);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
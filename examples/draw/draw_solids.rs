@@ -0,0 +1,72 @@
+//! Each of the 3D solid primitives, slowly rotating.
+//!
+//! The solids are colored by their texture coordinates via a small generated texture, showing how
+//! textures are mapped onto each surface.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::app(Box::new(model)).run();
+}
+
+struct Model {
+    texture: wgpu::Texture,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(900, 300).view(view).build().unwrap();
+
+    // Generate a checkerboard texture.
+    let image = nannou::image::ImageBuffer::from_fn(256, 256, |x, y| {
+        let checker = ((x / 32) + (y / 32)) % 2 == 0;
+        let r = x as u8;
+        let g = y as u8;
+        let b = if checker { 255 } else { 64 };
+        nannou::image::Rgba([r, g, b, 255])
+    });
+    let image = nannou::image::DynamicImage::ImageRgba8(image);
+    let texture = wgpu::Texture::from_image(app, &image);
+    Model { texture }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(BLACK);
+
+    let win = app.window_rect();
+    let t = app.time;
+    let step = win.w() / 5.0;
+    let x = |i: usize| win.left() + step * (i as f32 + 0.5);
+    let size = step * 0.6;
+
+    // Rotate all solids about the same axes.
+    let rotate = |d: &Draw| d.x_radians(t * 0.5).y_radians(t * 0.3);
+
+    rotate(&draw.x(x(0)))
+        .sphere()
+        .radius(size * 0.5)
+        .resolution(48)
+        .texture(&model.texture);
+    rotate(&draw.x(x(1)))
+        .cylinder()
+        .radius(size * 0.4)
+        .h(size)
+        .texture(&model.texture);
+    rotate(&draw.x(x(2)))
+        .cone()
+        .radius(size * 0.5)
+        .h(size)
+        .texture(&model.texture);
+    rotate(&draw.x(x(3)))
+        .torus()
+        .radius(size * 0.35)
+        .tube_radius(size * 0.15)
+        .texture(&model.texture);
+    rotate(&draw.x(x(4)))
+        .plane()
+        .w_h(size, size)
+        .resolution(8)
+        .texture(&model.texture);
+
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -0,0 +1,109 @@
+//! Demonstrates spatial indexing via `geom::spatial`.
+//!
+//! Particles drift around the window, steering away from neighbours found via a `GridHash` that
+//! is updated as they move. Each frame the particles are also collected into a `QuadTree`, whose
+//! leaf nodes are drawn in the background. Particles within the radius of the mouse are
+//! highlighted and lines are drawn to the nearest few.
+
+use nannou::geom::spatial::{GridHash, Key, QuadTree};
+use nannou::prelude::*;
+
+const PARTICLES: usize = 600;
+const NEIGHBOUR_RADIUS: f32 = 20.0;
+const MOUSE_RADIUS: f32 = 80.0;
+const NEAREST: usize = 6;
+
+fn main() {
+    nannou::app(Box::new(model)).update(update).run();
+}
+
+struct Model {
+    grid: GridHash<Point2, Vec2>,
+    keys: Vec<Key>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window().size(800, 800).view(view).build().unwrap();
+    let rect = app.window_rect();
+    let mut grid = GridHash::new(NEIGHBOUR_RADIUS);
+    let keys = (0..PARTICLES)
+        .map(|_| {
+            let p = pt2(
+                random_range(rect.left(), rect.right()),
+                random_range(rect.bottom(), rect.top()),
+            );
+            let v = vec2(random_f32() - 0.5, random_f32() - 0.5);
+            grid.insert(p, v)
+        })
+        .collect();
+    Model { grid, keys }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    let rect = app.window_rect();
+    for &key in &model.keys {
+        let (p, &v) = model.grid.get(key).unwrap();
+
+        // Steer away from nearby particles.
+        let mut push = Vec2::ZERO;
+        for other in model.grid.within_radius(p, NEIGHBOUR_RADIUS) {
+            let q = model.grid.get(other).unwrap().0;
+            let d = p.distance(q);
+            if other != key && d > 0.0 {
+                push += (p - q) / d * (1.0 - d / NEIGHBOUR_RADIUS);
+            }
+        }
+        let v = (v + push * 0.1).clamp_length_max(1.5);
+
+        // Wrap around the edges of the window.
+        let mut p = p + v;
+        if !rect.contains(p) {
+            p.x = (p.x - rect.left()).rem_euclid(rect.w()) + rect.left();
+            p.y = (p.y - rect.bottom()).rem_euclid(rect.h()) + rect.bottom();
+        }
+        *model.grid.get_mut(key).unwrap() = v;
+        model.grid.set_point(key, p);
+    }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+
+    let mut quadtree = QuadTree::new(app.window_rect());
+    for (_, p, _) in model.grid.iter() {
+        quadtree.insert(p, ());
+    }
+    for rect in quadtree.leaf_rects() {
+        draw.rect()
+            .xy(rect.xy())
+            .wh(rect.wh())
+            .no_fill()
+            .stroke(rgba(0.0, 0.0, 0.0, 0.15))
+            .stroke_weight(1.0);
+    }
+
+    let mouse = app.mouse.position();
+    draw.ellipse()
+        .xy(mouse)
+        .radius(MOUSE_RADIUS)
+        .no_fill()
+        .stroke(STEELBLUE)
+        .stroke_weight(1.0);
+    for key in quadtree.nearest(mouse, NEAREST) {
+        let p = quadtree.get(key).unwrap().0;
+        draw.line().start(mouse).end(p).weight(1.0).color(STEELBLUE);
+    }
+    let highlighted = quadtree.within_radius(mouse, MOUSE_RADIUS);
+
+    for (key, p, _) in quadtree.iter() {
+        let color = if highlighted.contains(&key) {
+            CRIMSON
+        } else {
+            BLACK
+        };
+        draw.ellipse().xy(p).radius(2.5).color(color);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
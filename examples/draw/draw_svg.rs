@@ -0,0 +1,45 @@
+//! Loading a vector graphic from SVG and drawing it with `draw.svg`.
+//!
+//! Documents may also be loaded from file via `geom::svg::Document::from_path`.
+
+use nannou::geom::svg::Document;
+use nannou::prelude::*;
+
+const LOGO: &str = r##"
+<svg xmlns="http://www.w3.org/2000/svg" width="240" height="240" viewBox="0 0 120 120">
+  <rect x="4" y="4" width="112" height="112" rx="16" fill="#1d2b53" />
+  <g transform="translate(60 60)" stroke="#ffec27" stroke-width="3" stroke-linecap="round">
+    <circle r="36" fill="none" stroke-dasharray="6 4" />
+    <path d="M-24 16 L-24 -16 L24 16 L24 -16" fill="none" stroke="#ff004d" stroke-width="6"
+          stroke-linejoin="round" />
+    <ellipse rx="8" ry="4" transform="rotate(-30)" fill="#29adff" style="stroke: none" />
+  </g>
+</svg>
+"##;
+
+struct Model {
+    logo: Document,
+}
+
+fn main() {
+    nannou::app(Box::new(model)).simple_window(view).run();
+}
+
+fn model(_app: &App) -> Model {
+    let logo = Document::parse(LOGO).expect("failed to parse SVG");
+    Model { logo }
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+
+    // Draw the logo at its own size, then a spinning copy scaled to 100x100.
+    draw.svg(&model.logo).x(-100.0);
+    draw.svg(&model.logo)
+        .x_y(140.0, 0.0)
+        .w_h(100.0, 100.0)
+        .rotate(app.time * 0.5);
+
+    draw.to_frame(app, &frame).unwrap();
+}
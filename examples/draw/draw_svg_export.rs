@@ -0,0 +1,48 @@
+//! Rendering a drawing to the window while also exporting it as an SVG document.
+//!
+//! The first frame is written to `/<path_to_nannou>/nannou/draw_svg_export.svg`.
+
+use nannou::prelude::*;
+
+fn main() {
+    nannou::sketch(view).size(600, 600).run()
+}
+
+fn view(app: &App, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let t = app.time;
+
+    // Concentric rings of dashed circles, suitable for a pen plotter.
+    for i in 1..12 {
+        let radius = i as f32 * 22.0;
+        draw.ellipse()
+            .radius(radius)
+            .no_fill()
+            .stroke(BLACK)
+            .stroke_weight(1.5)
+            .dashes(&[radius * 0.2, radius * 0.1])
+            .dash_offset(t * 10.0 * i as f32);
+    }
+
+    // A curve through the rings.
+    let points = (0..=100).map(|i| {
+        let x = map_range(i, 0, 100, -250.0, 250.0);
+        pt2(x, (x * 0.03 + t).sin() * 80.0)
+    });
+    draw.polyline().weight(3.0).color(CRIMSON).points(points);
+
+    draw.text("nannou").font_size(48).y(-200.0).color(STEELBLUE);
+
+    if frame.nth() == 0 {
+        let path = app
+            .project_path()
+            .expect("failed to locate `project_path`")
+            .join(app.exe_name().unwrap())
+            .with_extension("svg");
+        draw.to_svg(path, app.window_rect().wh())
+            .expect("failed to write SVG");
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
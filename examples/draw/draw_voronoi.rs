@@ -0,0 +1,86 @@
+//! Demonstrates Voronoi diagrams, Delaunay triangulation and Lloyd relaxation via
+//! `geom::voronoi` and `geom::delaunay`.
+//!
+//! Random points are gradually relaxed toward an even distribution, one iteration per frame. Each
+//! cell is shaded by its area, with the Delaunay triangulation of the points drawn on top. Click to
+//! scatter a new set of points.
+
+use nannou::geom::voronoi::{self, Voronoi};
+use nannou::prelude::*;
+
+const POINTS: usize = 120;
+
+fn main() {
+    nannou::app(Box::new(model)).update(update).run();
+}
+
+struct Model {
+    points: Vec<Point2>,
+}
+
+fn model(app: &App) -> Model {
+    app.new_window()
+        .size(800, 800)
+        .view(view)
+        .mouse_pressed(mouse_pressed)
+        .build()
+        .unwrap();
+    let points = scatter(app.window_rect());
+    Model { points }
+}
+
+fn update(app: &App, model: &mut Model, _update: Update) {
+    voronoi::relax(&mut model.points, app.window_rect(), 1);
+}
+
+fn mouse_pressed(app: &App, model: &mut Model, _button: MouseButton) {
+    model.points = scatter(app.window_rect());
+}
+
+fn view(app: &App, model: &Model, frame: Frame) {
+    let draw = app.draw();
+    draw.background().color(WHITE);
+    let win_rect = app.window_rect();
+
+    let voronoi = Voronoi::new(&model.points, win_rect);
+    let mean_area = win_rect.w() * win_rect.h() / POINTS as f32;
+    for cell in voronoi.cells() {
+        let lightness = map_range(area(cell) / mean_area, 0.5, 1.5, 0.45, 0.9);
+        draw.polygon()
+            .hsl(0.58, 0.6, lightness.clamp(0.45, 0.9))
+            .stroke(WHITE)
+            .stroke_weight(2.0)
+            .points(cell.iter().cloned());
+    }
+
+    for tri in voronoi.triangulation().triangles() {
+        let [a, b, c] = tri.0;
+        draw.polyline()
+            .weight(1.0)
+            .color(rgba(0.0, 0.0, 0.0, 0.25))
+            .points_closed(vec![a, b, c]);
+    }
+    for &p in voronoi.points() {
+        draw.ellipse().xy(p).radius(3.0).color(BLACK);
+    }
+
+    draw.to_frame(app, &frame).unwrap();
+}
+
+fn scatter(rect: Rect) -> Vec<Point2> {
+    (0..POINTS)
+        .map(|_| {
+            let x = random_range(rect.left(), rect.right());
+            let y = random_range(rect.bottom(), rect.top());
+            pt2(x, y)
+        })
+        .collect()
+}
+
+fn area(cell: &[Point2]) -> f32 {
+    let n = cell.len();
+    (0..n)
+        .map(|i| cell[i].perp_dot(cell[(i + 1) % n]))
+        .sum::<f32>()
+        * 0.5
+}
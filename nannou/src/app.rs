@@ -10,7 +10,7 @@
 
 use crate::draw;
 use crate::event::{self, Event, Key, LoopEvent, Update};
-use crate::frame::{Frame, RawFrame};
+use crate::frame::{self, Frame, RawFrame};
 use crate::geom;
 use crate::state;
 use crate::time::DurationF64;
@@ -18,10 +18,10 @@ use crate::wgpu;
 use crate::window::{self, Window};
 use find_folder;
 use instant::Instant;
-use std::cell::{RefCell, RefMut};
+use std::cell::{Cell, RefCell, RefMut};
 use std::collections::HashMap;
 use std::future::Future;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::atomic::{self, AtomicBool};
 use std::sync::Arc;
@@ -69,6 +69,7 @@ pub struct Builder<'a, M: 'a = (), E = Event> {
     capture_frame_timeout: Option<Option<Duration>>,
     max_capture_frame_jobs: Option<u32>,
     backends: wgpu::Backends,
+    headless_size: Option<[u32; 2]>,
 }
 
 /// A nannou `Sketch` builder.
@@ -110,6 +111,8 @@ pub struct App {
     pub(crate) event_loop_window_target: Option<EventLoopWindowTarget>,
     pub(crate) event_loop_proxy: Proxy,
     pub(crate) windows: RefCell<HashMap<window::Id, Window>>,
+    /// The offscreen render target used in place of windows when running headless.
+    pub(crate) headless: Option<Headless>,
     /// The wgpu backends to choose between.
     backends: wgpu::Backends,
     /// The main wgpu instance.
@@ -155,6 +158,8 @@ pub struct App {
     /// the number becomes higher. Instead, we recommend using `app.duration.since_start` or
     /// `app.duration.since_prev_update` to access a more precise form of app time.
     pub time: f32,
+    // The fraction of a fixed step of real time yet to be simulated in `LoopMode::Fixed`.
+    fixed_update_alpha: f32,
 }
 
 /// Miscellaneous app configuration parameters.
@@ -172,11 +177,28 @@ struct DrawState {
     renderers: RefCell<HashMap<window::Id, RefCell<draw::Renderer>>>,
 }
 
+// The offscreen render target that stands in for a window when the **App** runs headless.
+#[derive(Debug)]
+pub(crate) struct Headless {
+    // The ID reported by `Frame::window_id` for frames targeting this texture.
+    pub(crate) id: window::Id,
+    pub(crate) device_queue_pair: Arc<wgpu::DeviceQueuePair>,
+    pub(crate) size: [u32; 2],
+    pub(crate) msaa_samples: u32,
+    // The texture that takes the place of the window's surface texture.
+    texture: wgpu::Texture,
+    pub(crate) frame_data: window::FrameData,
+    pub(crate) frame_count: u64,
+    // Set by `App::quit` as there are no windows whose removal would end the loop.
+    quit: Cell<bool>,
+}
+
 /// A handle to the **App** that can be shared across threads. This may be used to "wake up" the
 /// **App**'s inner event loop.
 #[derive(Clone)]
 pub struct Proxy {
-    event_loop_proxy: winit::event_loop::EventLoopProxy<()>,
+    // `None` when running headless, in which case there is no event loop to wake up.
+    event_loop_proxy: Option<winit::event_loop::EventLoopProxy<()>>,
     // Whether or not a wakeup is already queued.
     //
     // Used to avoid spuriously calling `EventLoopProxy::send_event` as this can be expensive on
@@ -190,6 +212,10 @@ struct LoopState {
     loop_start: Instant,
     last_update: Instant,
     total_updates: u64,
+    // Real time that has elapsed but is yet to be simulated in `LoopMode::Fixed`.
+    fixed_time_accumulated: Duration,
+    // The moment at which real time was last added to `fixed_time_accumulated`.
+    fixed_time_last_checked: Instant,
 }
 
 /// The mode in which the **App** is currently running the event loop and emitting `Update` events.
@@ -231,6 +257,29 @@ pub enum LoopMode {
         /// The number of updates that must be emited regardless of non-update events
         number_of_updates: usize,
     },
+
+    /// Emits `Update`s with a fixed time step, advancing a simulated clock rather than following
+    /// the wall clock.
+    ///
+    /// Each `Update`'s `since_last` is exactly `1 / update_hz` seconds and `App::time` and
+    /// `App::duration` advance by exactly this amount per update. This allows for generative work
+    /// to produce the same results on every machine and while capturing frames.
+    ///
+    /// If `updates_per_frame` is `Some`, exactly that many updates are applied before each frame,
+    /// entirely decoupling the simulation from real time. This is ideal for rendering frames
+    /// offline.
+    ///
+    /// If `updates_per_frame` is `None`, the simulation keeps pace with real time by applying as
+    /// many updates as fit into the real time elapsed since the last frame (up to
+    /// `LoopMode::MAX_FIXED_UPDATES_PER_FRAME`). The remaining fraction of a step is available
+    /// during `view` via `App::fixed_update_alpha` for interpolating between the previous and
+    /// current state.
+    Fixed {
+        /// The number of updates per second of simulated time.
+        update_hz: f64,
+        /// The exact number of updates to apply per frame, or `None` to follow real time.
+        updates_per_frame: Option<u32>,
+    },
 }
 
 pub trait Captures<T> {}
@@ -273,6 +322,7 @@ where
             max_capture_frame_jobs: None,
             capture_frame_timeout: None,
             backends: Self::DEFAULT_BACKENDS,
+            headless_size: None,
         }
     }
 
@@ -299,6 +349,7 @@ where
             max_capture_frame_jobs,
             capture_frame_timeout,
             backends,
+            headless_size,
             ..
         } = self;
         Builder {
@@ -312,7 +363,8 @@ where
             default_window_size,
             max_capture_frame_jobs,
             capture_frame_timeout,
-            backends
+            backends,
+            headless_size,
         }
     }
 }
@@ -446,6 +498,26 @@ where
         self
     }
 
+    /// Run the app without any windows, rendering each frame to an offscreen texture with the
+    /// given size in pixels.
+    ///
+    /// No winit event loop is created, so headless apps can run on machines without a display
+    /// (e.g. CI or render farms). The `view` function receives a regular `Frame` whose window ID
+    /// refers to the offscreen target, so the `Draw` API works as usual. Frames can be written to
+    /// disk via `App::capture_frame`.
+    ///
+    /// Rather than following the wall clock, each update advances `App::time` by a fixed step.
+    /// This step is the `update_interval` in `LoopMode::Rate`, `1 / update_hz` in
+    /// `LoopMode::Fixed` and `1 / LoopMode::DEFAULT_RATE_FPS` otherwise. As there are no input
+    /// events in headless mode, the app runs until either `LoopMode::NTimes` completes,
+    /// `App::quit` is called or, in `LoopMode::Wait`, after the first update.
+    ///
+    /// Windows cannot be created while running headless.
+    pub fn headless(mut self, size: [u32; 2]) -> Self {
+        self.headless_size = Some(size);
+        self
+    }
+
     /// Build and run an `App` with the specified parameters.
     ///
     /// This function will not return until the application has exited.
@@ -458,11 +530,14 @@ where
     }
 
     pub async fn run_async(self) {
-        // Start the winit window event loop.
-        let event_loop = winit::event_loop::EventLoop::new();
+        // Start the winit window event loop, unless running headless where there is no display.
+        let event_loop = match self.headless_size {
+            None => Some(winit::event_loop::EventLoop::new()),
+            Some(_) => None,
+        };
 
         // Create the proxy used to awaken the event loop.
-        let event_loop_proxy = event_loop.create_proxy();
+        let event_loop_proxy = event_loop.as_ref().map(|el| el.create_proxy());
         let wakeup_queued = Arc::new(AtomicBool::new(false));
         let event_loop_proxy = Proxy {
             event_loop_proxy,
@@ -476,8 +551,8 @@ where
         let capture_frame_timeout = self
             .capture_frame_timeout
             .unwrap_or(Some(Self::DEFAULT_CAPTURE_FRAME_TIMEOUT));
-        let event_loop_window_target = Some(EventLoopWindowTarget::Owned(event_loop));
-        let app = App::new(
+        let event_loop_window_target = event_loop.map(EventLoopWindowTarget::Owned);
+        let mut app = App::new(
             self.config,
             event_loop_proxy,
             event_loop_window_target,
@@ -487,8 +562,16 @@ where
             self.backends,
         );
 
+        // Create the offscreen render target in place of any windows if running headless.
+        if let Some(size) = self.headless_size {
+            let headless = Headless::new(&app, size)
+                .await
+                .expect("could not build headless render target");
+            app.headless = Some(headless);
+        }
+
         // Create the default window if necessary
-        if self.create_default_window {
+        if self.create_default_window && app.headless.is_none() {
             let window_id = app
                 .new_window()
                 .build_async()
@@ -507,14 +590,25 @@ where
             }
         }
 
-        run_loop(
-            app,
-            model,
-            self.event,
-            self.update,
-            self.default_view,
-            self.exit,
-        );
+        if app.headless.is_some() {
+            run_headless_loop(
+                app,
+                model,
+                self.event,
+                self.update,
+                self.default_view,
+                self.exit,
+            );
+        } else {
+            run_loop(
+                app,
+                model,
+                self.event,
+                self.update,
+                self.default_view,
+                self.exit,
+            );
+        }
     }
 }
 
@@ -536,6 +630,14 @@ where
         self
     }
 
+    /// Run the sketch headless, rendering to an offscreen texture of the given size in pixels.
+    ///
+    /// This method delegates to `Builder::headless`.
+    pub fn headless(mut self, size: [u32; 2]) -> Self {
+        self.builder = self.builder.headless(size);
+        self
+    }
+
     /// Build and run a `Sketch` with the specified parameters.
     ///
     /// This calls `App::run` internally. See that method for details!
@@ -573,6 +675,11 @@ impl LoopMode {
     /// The minimum number of updates that will be emitted after an event is triggered in Wait
     /// mode.
     pub const UPDATES_PER_WAIT_EVENT: u32 = 3;
+    /// The maximum number of updates applied per frame in the real-time `Fixed` mode.
+    ///
+    /// If the simulation falls further behind than this, the remaining time is dropped so that
+    /// slow updates cannot cause the loop to fall further and further behind.
+    pub const MAX_FIXED_UPDATES_PER_FRAME: u32 = 8;
 
     /// A simplified constructor for the default `RefreshSync` loop mode.
     ///
@@ -604,6 +711,27 @@ impl LoopMode {
     pub fn loop_once() -> Self {
         Self::loop_ntimes(1)
     }
+
+    /// Specify the **Fixed** mode with the given update rate, keeping pace with real time.
+    pub fn fixed(update_hz: f64) -> Self {
+        assert!(update_hz > 0.0);
+        let updates_per_frame = None;
+        LoopMode::Fixed {
+            update_hz,
+            updates_per_frame,
+        }
+    }
+
+    /// Specify the **Fixed** mode with the given update rate, applying exactly
+    /// `updates_per_frame` updates before each frame regardless of real time.
+    pub fn fixed_simulated(update_hz: f64, updates_per_frame: u32) -> Self {
+        assert!(update_hz > 0.0);
+        let updates_per_frame = Some(updates_per_frame);
+        LoopMode::Fixed {
+            update_hz,
+            updates_per_frame,
+        }
+    }
 }
 
 impl Default for LoopMode {
@@ -663,12 +791,14 @@ impl App {
             instance,
             adapters,
             windows,
+            headless: None,
             config,
             draw_state,
             mouse,
             keys,
             duration,
             time,
+            fixed_update_alpha: 0.0,
         };
         app
     }
@@ -778,8 +908,13 @@ impl App {
     ///
     /// The **Rect** coords are described in "points" (pixels divided by the hidpi factor).
     ///
+    /// When running headless, this is the **Rect** of the offscreen render target.
+    ///
     /// **Panics** if there are no windows or if no window is in focus.
     pub fn window_rect(&self) -> geom::Rect<f32> {
+        if let Some(headless) = self.headless.as_ref() {
+            return headless.rect();
+        }
         self.main_window().rect()
     }
 
@@ -893,10 +1028,28 @@ impl App {
 
     /// The number of times the focused window's **view** function has been called since the start
     /// of the program.
+    ///
+    /// When running headless, this is the number of frames rendered to the offscreen target.
     pub fn elapsed_frames(&self) -> u64 {
+        if let Some(headless) = self.headless.as_ref() {
+            return headless.frame_count;
+        }
         self.main_window().frame_count
     }
 
+    /// The fraction of a fixed update step that has elapsed in real time but has not yet been
+    /// simulated, in the range `0.0..1.0`.
+    ///
+    /// When using `LoopMode::Fixed` without a fixed number of `updates_per_frame`, this can be used
+    /// within `view` to interpolate between the previous and current state of the model for
+    /// smooth motion. In all other cases this is `0.0`.
+    pub fn fixed_update_alpha(&self) -> f32 {
+        match self.loop_mode() {
+            LoopMode::Fixed { .. } => self.fixed_update_alpha,
+            _ => 0.0,
+        }
+    }
+
     /// The number of frames that can currently be displayed a second
     pub fn fps(&self) -> f32 {
         self.duration.updates_per_second()
@@ -914,8 +1067,54 @@ impl App {
 
     /// Quits the currently running application.
     pub fn quit(&self) {
+        if let Some(headless) = self.headless.as_ref() {
+            headless.quit.set(true);
+        }
         self.windows.borrow_mut().clear();
     }
+
+    /// Whether or not the **App** is running headless, rendering to an offscreen texture rather
+    /// than to windows.
+    ///
+    /// See `app::Builder::headless` for details.
+    pub fn is_headless(&self) -> bool {
+        self.headless.is_some()
+    }
+
+    /// Capture the next frame rendered to the offscreen target of a headless app and write it to
+    /// an image file at the given path.
+    ///
+    /// This behaves the same as `Window::capture_frame`. The destination image file type will be
+    /// inferred from the extension given in the path.
+    ///
+    /// **Panics** if the **App** is not running headless.
+    pub fn capture_frame<P>(&self, path: P)
+    where
+        P: AsRef<Path>,
+    {
+        let headless = self
+            .headless
+            .as_ref()
+            .expect("`App::capture_frame` requires a headless app, see `Window::capture_frame`");
+        window::capture_frame_to_path(&headless.frame_data, path.as_ref());
+    }
+
+    /// Block and wait for all active capture frame jobs of a headless app to complete.
+    ///
+    /// This is called implicitly when a headless app exits. Does nothing if the **App** is not
+    /// running headless.
+    pub fn await_capture_frame_jobs(
+        &self,
+    ) -> Result<(), wgpu::TextureCapturerAwaitWorkerTimeout<()>> {
+        match self.headless.as_ref() {
+            None => Ok(()),
+            Some(headless) => {
+                let capture_data = &headless.frame_data.capture;
+                let device = headless.device_queue_pair.device();
+                capture_data.texture_capturer.await_active_snapshots(device)
+            }
+        }
+    }
 }
 
 impl Proxy {
@@ -930,58 +1129,149 @@ impl Proxy {
     /// immediately set the flag to false afterwards. This makes it safe to call the `wakeup`
     /// method as frequently as necessary across methods without causing any underlying OS methods
     /// to be called more than necessary.
+    ///
+    /// This does nothing when the **App** is running headless as there is no event loop to wake.
     pub fn wakeup(&self) -> Result<(), winit::event_loop::EventLoopClosed<()>> {
+        let event_loop_proxy = match self.event_loop_proxy {
+            Some(ref proxy) => proxy,
+            None => return Ok(()),
+        };
         if !self.wakeup_queued.load(atomic::Ordering::SeqCst) {
-            self.event_loop_proxy.send_event(())?;
+            event_loop_proxy.send_event(())?;
             self.wakeup_queued.store(true, atomic::Ordering::SeqCst);
         }
         Ok(())
     }
 }
 
+impl Headless {
+    // The format of the texture standing in for the window's surface texture.
+    const TEXTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+    // Request a device and create the offscreen textures.
+    //
+    // Returns `None` if no suitable adapter is available.
+    async fn new(app: &App, size: [u32; 2]) -> Option<Self> {
+        let request_adapter_opts = wgpu::RequestAdapterOptions {
+            power_preference: wgpu::DEFAULT_POWER_PREFERENCE,
+            compatible_surface: None,
+            force_fallback_adapter: false,
+        };
+        let adapter = app
+            .wgpu_adapters()
+            .get_or_request_async(request_adapter_opts, app.instance())
+            .await?;
+        let device_desc = wgpu::default_device_descriptor();
+        let device_queue_pair = adapter.get_or_request_device_async(device_desc).await;
+        let device = device_queue_pair.device();
+
+        let texture = wgpu::TextureBuilder::new()
+            .size(size)
+            .format(Self::TEXTURE_FORMAT)
+            .usage(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC)
+            .build(device);
+
+        let msaa_samples = Frame::DEFAULT_MSAA_SAMPLES;
+        let render = frame::RenderData::new(device, size, Self::TEXTURE_FORMAT, msaa_samples);
+        let capture =
+            frame::CaptureData::new(app.max_capture_frame_jobs, app.capture_frame_timeout);
+        let frame_data = window::FrameData { render, capture };
+
+        // The target is never passed to winit, so a dummy ID is sufficient.
+        let id = unsafe { window::Id::dummy() };
+
+        Some(Headless {
+            id,
+            device_queue_pair,
+            size,
+            msaa_samples,
+            texture,
+            frame_data,
+            frame_count: 0,
+            quit: Cell::new(false),
+        })
+    }
+
+    // The rect of the offscreen target, where one point is always equal to one pixel.
+    fn rect(&self) -> geom::Rect {
+        let [w, h] = self.size;
+        geom::Rect::from_w_h(w as f32, h as f32)
+    }
+}
+
 impl draw::Draw {
     /// Render the **Draw**'s inner list of commands to the texture associated with the **Frame**.
     ///
     /// The **App** stores a unique render.
     pub fn to_frame(&self, app: &App, frame: &Frame) -> Result<(), draw::renderer::DrawError> {
+        // Headless apps have no window, so use the parameters of the offscreen target instead.
+        if let Some(headless) = app.headless.as_ref() {
+            let device = headless.device_queue_pair.device();
+            let msaa_samples = headless.msaa_samples;
+            render_draw_to_frame(app, self, frame, device, headless.size, 1.0, msaa_samples);
+            return Ok(());
+        }
+
         let window_id = frame.window_id();
         let window = app
             .window(window_id)
             .expect("no window to draw to for `Draw`'s window_id");
-
-        // Retrieve a renderer for this window.
-        let renderers = app.draw_state.renderers.borrow_mut();
-        let renderer = RefMut::map(renderers, |renderers| {
-            renderers.entry(window_id).or_insert_with(|| {
-                let device = window.device();
-                let frame_dims: [u32; 2] = window.tracked_state.physical_size.into();
-                let scale_factor = window.tracked_state.scale_factor as f32;
-                let msaa_samples = window.msaa_samples();
-                let target_format = crate::frame::Frame::TEXTURE_FORMAT;
-                let renderer = draw::RendererBuilder::new().build(
-                    device,
-                    frame_dims,
-                    scale_factor,
-                    msaa_samples,
-                    target_format,
-                );
-                RefCell::new(renderer)
-            })
-        });
-
-        let scale_factor = window.tracked_state.scale_factor as _;
-        let mut renderer = renderer.borrow_mut();
-        renderer.render_to_frame(window.device(), self, scale_factor, frame);
+        let frame_dims: [u32; 2] = window.tracked_state.physical_size.into();
+        let scale_factor = window.tracked_state.scale_factor as f32;
+        let msaa_samples = window.msaa_samples();
+        render_draw_to_frame(
+            app,
+            self,
+            frame,
+            window.device(),
+            frame_dims,
+            scale_factor,
+            msaa_samples,
+        );
         Ok(())
     }
 }
 
+// Render the `draw` to the `frame` using the renderer associated with the frame's window ID,
+// creating the renderer first if necessary.
+fn render_draw_to_frame(
+    app: &App,
+    draw: &draw::Draw,
+    frame: &Frame,
+    device: &wgpu::Device,
+    frame_dims: [u32; 2],
+    scale_factor: f32,
+    msaa_samples: u32,
+) {
+    // Retrieve a renderer for this window.
+    let renderers = app.draw_state.renderers.borrow_mut();
+    let renderer = RefMut::map(renderers, |renderers| {
+        renderers.entry(frame.window_id()).or_insert_with(|| {
+            let target_format = crate::frame::Frame::TEXTURE_FORMAT;
+            let renderer = draw::RendererBuilder::new().build(
+                device,
+                frame_dims,
+                scale_factor,
+                msaa_samples,
+                target_format,
+            );
+            RefCell::new(renderer)
+        })
+    });
+
+    let mut renderer = renderer.borrow_mut();
+    renderer.render_to_frame(device, draw, scale_factor, frame);
+}
+
 impl<'a> wgpu::WithDeviceQueuePair for &'a crate::app::App {
     fn with_device_queue_pair<F, O>(self, f: F) -> O
     where
         F: FnOnce(&wgpu::Device, &wgpu::Queue) -> O,
     {
-        self.main_window().with_device_queue_pair(f)
+        match self.headless.as_ref() {
+            Some(headless) => headless.device_queue_pair.with_device_queue_pair(f),
+            None => self.main_window().with_device_queue_pair(f),
+        }
     }
 }
 
@@ -1086,6 +1376,8 @@ fn run_loop<M, E>(
         loop_start,
         last_update: loop_start,
         total_updates: 0,
+        fixed_time_accumulated: Duration::default(),
+        fixed_time_last_checked: loop_start,
     };
 
     // Run the event loop.
@@ -1116,6 +1408,20 @@ fn run_loop<M, E>(
                         // TODO: Consider allowing for a custom number of updates like so:
                         // LoopMode::Wait { updates_before_waiting } =>
                         //     if loop_state.updates_since_event > updates_before_waiting as u64 => {}
+                        LoopMode::Fixed {
+                            update_hz,
+                            updates_per_frame,
+                        } => {
+                            apply_fixed_updates(
+                                &mut app,
+                                model,
+                                event_fn,
+                                update_fn,
+                                &mut loop_state,
+                                update_hz,
+                                updates_per_frame,
+                            );
+                        }
                         _ => {
                             do_update(&mut loop_state);
                         },
@@ -1370,6 +1676,190 @@ fn run_loop<M, E>(
     }
 }
 
+// The application loop used when running headless.
+//
+// There is no event loop or window, so each iteration simply applies an update with a fixed time
+// step and renders a frame to the offscreen target.
+fn run_headless_loop<M, E>(
+    mut app: App,
+    mut model: M,
+    event_fn: Option<EventFn<M, E>>,
+    update_fn: Option<UpdateFn<M>>,
+    default_view: Option<View<M>>,
+    exit_fn: Option<ExitFn<M>>,
+) where
+    M: 'static,
+    E: LoopEvent,
+{
+    let loop_start = Instant::now();
+    let mut loop_state = LoopState {
+        updates_since_event: 0,
+        loop_start,
+        last_update: loop_start,
+        total_updates: 0,
+        fixed_time_accumulated: Duration::default(),
+        fixed_time_last_checked: loop_start,
+    };
+
+    loop {
+        let loop_mode = app.loop_mode();
+        let headless = app
+            .headless
+            .as_ref()
+            .expect("headless loop requires a headless app");
+        if headless.quit.get() {
+            break;
+        }
+        let (step, updates) = match loop_mode {
+            // No events can occur to wake the loop again.
+            LoopMode::Wait if loop_state.updates_since_event > 0 => break,
+            LoopMode::NTimes { number_of_updates }
+                if loop_state.total_updates >= number_of_updates as u64 =>
+            {
+                break
+            }
+            LoopMode::Rate { update_interval } => (update_interval, 1),
+            LoopMode::Fixed {
+                update_hz,
+                updates_per_frame,
+            } => (
+                self::update_interval(update_hz),
+                updates_per_frame.unwrap_or(1),
+            ),
+            _ => (self::update_interval(LoopMode::DEFAULT_RATE_FPS), 1),
+        };
+
+        // Step time by exact increments rather than following the wall clock.
+        for _ in 0..updates {
+            let now = loop_state.last_update + step;
+            apply_update(
+                &mut app,
+                &mut model,
+                event_fn,
+                update_fn,
+                &mut loop_state,
+                now,
+            );
+        }
+        view_headless(&app, &model, default_view.as_ref());
+
+        let headless = app
+            .headless
+            .as_mut()
+            .expect("headless loop requires a headless app");
+        headless.frame_count += 1;
+
+        // Poll the device so that the callbacks of any frame captures may run.
+        headless
+            .device_queue_pair
+            .device()
+            .poll(wgpu::Maintain::Poll);
+    }
+
+    if let Some(exit_fn) = exit_fn {
+        exit_fn(&app, model);
+    }
+
+    if app.await_capture_frame_jobs().is_err() {
+        // TODO: Replace eprintlns with proper logging.
+        eprintln!("timed out while waiting for capture jobs to complete");
+    }
+}
+
+// Construct a frame targeting the headless app's offscreen texture and pass it to the `view`.
+fn view_headless<M>(app: &App, model: &M, view: Option<&View<M>>) {
+    let headless = app
+        .headless
+        .as_ref()
+        .expect("headless view requires a headless app");
+    let texture_view = headless.texture.view().build();
+    let raw_frame = RawFrame::new_empty(
+        headless.device_queue_pair.clone(),
+        headless.id,
+        headless.frame_count,
+        &texture_view,
+        Headless::TEXTURE_FORMAT,
+        headless.rect(),
+    );
+
+    // The contents of the intermediary texture are undefined until first cleared.
+    let render_data = &headless.frame_data.render;
+    if headless.frame_count == 0 {
+        raw_frame.clear(render_data.texture_view(), wgpu::Color::BLACK);
+    }
+
+    let capture_data = &headless.frame_data.capture;
+    match view {
+        Some(View::Sketch(view)) => {
+            let frame = Frame::new_empty(raw_frame, render_data, capture_data);
+            view(app, frame);
+        }
+        Some(View::WithModel(view)) => {
+            let frame = Frame::new_empty(raw_frame, render_data, capture_data);
+            view(app, model, frame);
+        }
+        None => raw_frame.submit(),
+    }
+}
+
+// Apply the updates due before the next frame in `LoopMode::Fixed`.
+//
+// The app's clock is advanced by exactly one step of simulated time per update. When following
+// real time, the number of updates depends on the real time accumulated since the last frame.
+fn apply_fixed_updates<M, E>(
+    app: &mut App,
+    model: &mut M,
+    event_fn: Option<EventFn<M, E>>,
+    update_fn: Option<UpdateFn<M>>,
+    loop_state: &mut LoopState,
+    update_hz: f64,
+    updates_per_frame: Option<u32>,
+) where
+    M: 'static,
+    E: LoopEvent,
+{
+    let step = update_interval(update_hz);
+    let updates = match updates_per_frame {
+        Some(updates) => {
+            app.fixed_update_alpha = 0.0;
+            updates
+        }
+        None => {
+            let now = Instant::now();
+            let elapsed = now.duration_since(loop_state.fixed_time_last_checked);
+            loop_state.fixed_time_last_checked = now;
+            loop_state.fixed_time_accumulated += elapsed;
+            let mut updates = 0;
+            while loop_state.fixed_time_accumulated >= step
+                && updates < LoopMode::MAX_FIXED_UPDATES_PER_FRAME
+            {
+                loop_state.fixed_time_accumulated -= step;
+                updates += 1;
+            }
+            // Drop any time that we could not catch up on this frame.
+            if loop_state.fixed_time_accumulated >= step {
+                loop_state.fixed_time_accumulated = Duration::default();
+            }
+            let alpha = loop_state.fixed_time_accumulated.secs() / step.secs();
+            app.fixed_update_alpha = alpha as f32;
+            updates
+        }
+    };
+
+    for _ in 0..updates {
+        let now = loop_state.last_update + step;
+        apply_update(app, model, event_fn, update_fn, loop_state, now);
+    }
+
+    // Still request a frame so that `view` may interpolate using the new alpha.
+    if updates == 0 {
+        let windows = app.windows.borrow();
+        for window in windows.values() {
+            window.window.request_redraw();
+        }
+    }
+}
+
 // Apply an update to the model via the user's function and update the app and loop state
 // accordingly.
 fn apply_update<M, E>(
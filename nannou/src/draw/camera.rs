@@ -0,0 +1,273 @@
+//! Items related to the camera through which a **Draw**ing is viewed.
+//!
+//! By default, a **Draw**ing is viewed through an orthographic projection where one unit is one
+//! logical pixel and the origin is at the centre of the output. A **Camera** may be specified via
+//! `draw.camera(..)` in order to view the scene from a different position or with perspective.
+
+use crate::event::MouseScrollDelta;
+use crate::geom::Point2;
+use crate::glam::{Mat4, Vec3};
+use crate::state;
+use std::f32::consts::FRAC_PI_2;
+
+/// The position, orientation and projection of the viewer of a **Draw**ing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Camera {
+    /// The position of the viewer.
+    pub eye: Vec3,
+    /// The point at which the viewer is looking.
+    pub target: Vec3,
+    /// The direction considered to be "up" for the viewer.
+    pub up: Vec3,
+    /// The way in which the scene is projected onto the output.
+    pub projection: Projection,
+}
+
+/// The way in which a **Camera** projects the scene onto the output.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Projection {
+    /// Parallel lines remain parallel, and one unit at any depth is one logical pixel divided by
+    /// `zoom`.
+    Orthographic {
+        /// Greater values magnify the scene.
+        zoom: f32,
+        /// The distance from the eye to the near clipping plane. May be negative.
+        near: f32,
+        /// The distance from the eye to the far clipping plane.
+        far: f32,
+    },
+    /// Objects become smaller with distance from the eye.
+    Perspective {
+        /// The vertical field of view in radians.
+        fov_y: f32,
+        /// The distance from the eye to the near clipping plane. Must be greater than zero.
+        near: f32,
+        /// The distance from the eye to the far clipping plane.
+        far: f32,
+    },
+}
+
+/// A camera that orbits around a target, driven by the mouse.
+///
+/// Call `update` with the **App**'s `mouse` state once per update to orbit while the left mouse
+/// button is dragged and pan while the right mouse button is dragged. Forward mouse wheel events
+/// to `scroll` to zoom towards and away from the target.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Orbit {
+    /// The point around which the camera orbits.
+    pub target: Vec3,
+    /// The distance from the eye to the target.
+    pub distance: f32,
+    /// The rotation around the *y* axis in radians. At `0.0` the eye lies along the positive *z*
+    /// axis from the target.
+    pub yaw: f32,
+    /// The rotation above or below the *xz* plane in radians.
+    pub pitch: f32,
+    /// The vertical field of view in radians.
+    pub fov_y: f32,
+    /// The radians rotated per logical pixel dragged.
+    pub sensitivity: f32,
+    /// The logical height of the output, used to pan the target along with the mouse.
+    pub height: f32,
+    /// The position of the mouse during the previous update while dragging.
+    drag: Option<Point2>,
+}
+
+impl Camera {
+    /// The default vertical field of view for a perspective projection: 45 degrees.
+    pub const DEFAULT_FOV_Y: f32 = std::f32::consts::FRAC_PI_4;
+    /// The default distance to the near clipping plane for a perspective projection.
+    pub const DEFAULT_NEAR: f32 = 1.0;
+    /// The default distance to the far clipping plane.
+    pub const DEFAULT_FAR: f32 = 10_000.0;
+
+    /// A camera at the given `eye` position looking at the given `target` with the default
+    /// perspective projection and the positive *y* axis as "up".
+    pub fn look_at(eye: Vec3, target: Vec3) -> Self {
+        Camera {
+            eye,
+            target,
+            up: Vec3::Y,
+            projection: Projection::perspective(Self::DEFAULT_FOV_Y),
+        }
+    }
+
+    /// A perspective camera looking at the origin along the negative *z* axis from the distance
+    /// at which the *xy* plane appears at the same scale as the default orthographic view.
+    ///
+    /// `height` is the logical height of the output, e.g. `app.window_rect().h()`.
+    pub fn perspective(fov_y: f32, height: f32) -> Self {
+        let eye = Vec3::Z * fit_distance(fov_y, height);
+        Camera {
+            projection: Projection::perspective(fov_y),
+            ..Self::look_at(eye, Vec3::ZERO)
+        }
+    }
+
+    /// Specify the projection.
+    pub fn projection(mut self, projection: Projection) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Specify the direction considered to be "up" for the viewer.
+    pub fn up(mut self, up: Vec3) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// The matrix transforming from world space to view space.
+    pub fn view_matrix(&self) -> Mat4 {
+        Mat4::look_at_rh(self.eye, self.target, self.up)
+    }
+
+    /// The matrix transforming from world space to clip space for an output of the given logical
+    /// size.
+    pub fn view_projection_matrix(&self, [w, h]: [f32; 2]) -> Mat4 {
+        self.projection.matrix([w, h]) * self.view_matrix()
+    }
+}
+
+impl Projection {
+    /// An orthographic projection in logical pixels with the default clipping planes.
+    pub fn orthographic() -> Self {
+        Projection::Orthographic {
+            zoom: 1.0,
+            near: -Camera::DEFAULT_FAR,
+            far: Camera::DEFAULT_FAR,
+        }
+    }
+
+    /// A perspective projection with the given vertical field of view in radians and the default
+    /// clipping planes.
+    pub fn perspective(fov_y: f32) -> Self {
+        Projection::Perspective {
+            fov_y,
+            near: Camera::DEFAULT_NEAR,
+            far: Camera::DEFAULT_FAR,
+        }
+    }
+
+    /// The matrix transforming from view space to clip space for an output of the given logical
+    /// size.
+    ///
+    /// Depth is mapped to the range `0.0` (near) to `1.0` (far).
+    pub fn matrix(&self, [w, h]: [f32; 2]) -> Mat4 {
+        match *self {
+            Projection::Orthographic { zoom, near, far } => {
+                let (hw, hh) = (w * 0.5 / zoom, h * 0.5 / zoom);
+                Mat4::orthographic_rh(-hw, hw, -hh, hh, near, far)
+            }
+            Projection::Perspective { fov_y, near, far } => {
+                let aspect = if h > 0.0 { w / h } else { 1.0 };
+                Mat4::perspective_rh(fov_y, aspect, near, far)
+            }
+        }
+    }
+}
+
+impl Orbit {
+    /// The default radians rotated per logical pixel dragged.
+    pub const DEFAULT_SENSITIVITY: f32 = 0.01;
+    /// The maximum magnitude of the pitch, just short of looking straight up or down.
+    pub const MAX_PITCH: f32 = FRAC_PI_2 - 0.001;
+    /// The factor by which the distance changes per line scrolled.
+    pub const ZOOM_PER_LINE: f32 = 1.1;
+
+    /// An orbit camera around the origin, initially matching the view of `Camera::perspective`
+    /// for an output of the given logical height.
+    pub fn new(height: f32) -> Self {
+        let fov_y = Camera::DEFAULT_FOV_Y;
+        Orbit {
+            target: Vec3::ZERO,
+            distance: fit_distance(fov_y, height),
+            yaw: 0.0,
+            pitch: 0.0,
+            fov_y,
+            sensitivity: Self::DEFAULT_SENSITIVITY,
+            height,
+            drag: None,
+        }
+    }
+
+    /// The position of the eye.
+    pub fn eye(&self) -> Vec3 {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+        let dir = Vec3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw);
+        self.target + dir * self.distance
+    }
+
+    /// The **Camera** describing the current view.
+    pub fn camera(&self) -> Camera {
+        Camera::look_at(self.eye(), self.target).projection(Projection::perspective(self.fov_y))
+    }
+
+    /// Rotate the eye around the target by the given yaw and pitch in radians.
+    pub fn rotate(&mut self, yaw: f32, pitch: f32) {
+        self.yaw += yaw;
+        self.pitch = (self.pitch + pitch).clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+    }
+
+    /// Move the target and eye across the view plane by the given amount in world units.
+    pub fn pan(&mut self, x: f32, y: f32) {
+        let forward = (self.target - self.eye()).normalize_or_zero();
+        let right = forward.cross(Vec3::Y).normalize_or_zero();
+        let up = right.cross(forward);
+        self.target += right * x + up * y;
+    }
+
+    /// Multiply the distance from the eye to the target by the given factor.
+    pub fn zoom(&mut self, factor: f32) {
+        self.distance = (self.distance * factor).max(f32::EPSILON);
+    }
+
+    /// Zoom in or out in response to a mouse wheel event.
+    pub fn scroll(&mut self, delta: MouseScrollDelta) {
+        let lines = match delta {
+            MouseScrollDelta::LineDelta(_, y) => y,
+            MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 20.0,
+        };
+        self.zoom(Self::ZOOM_PER_LINE.powf(-lines));
+    }
+
+    /// Orbit while the left mouse button is dragged and pan while the right is dragged.
+    pub fn update(&mut self, mouse: &state::Mouse) {
+        let left = mouse.buttons.left().is_down();
+        let right = mouse.buttons.right().is_down();
+        if !left && !right {
+            self.drag = None;
+            return;
+        }
+        let position = mouse.position();
+        if let Some(last) = self.drag {
+            let delta = position - last;
+            if left {
+                self.rotate(-delta.x * self.sensitivity, -delta.y * self.sensitivity);
+            } else {
+                // Pan such that the target follows the mouse at the target's depth.
+                let view_h = 2.0 * self.distance * (self.fov_y * 0.5).tan();
+                let scale = view_h / self.height.max(1.0);
+                self.pan(-delta.x * scale, -delta.y * scale);
+            }
+        }
+        self.drag = Some(position);
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Camera {
+            eye: Vec3::ZERO,
+            target: -Vec3::Z,
+            up: Vec3::Y,
+            projection: Projection::orthographic(),
+        }
+    }
+}
+
+// The distance from which the xy plane of the given height exactly fills the vertical field of
+// view.
+fn fit_distance(fov_y: f32, height: f32) -> f32 {
+    height * 0.5 / (fov_y * 0.5).tan()
+}
@@ -2,7 +2,8 @@ use crate::color::IntoLinSrgba;
 use crate::draw::mesh::vertex::{Color, TexCoords};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::{
-    ColorScalar, SetColor, SetDimensions, SetFill, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Gradient, SetColor, SetDashes, SetDimensions, SetFill, SetGradient,
+    SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Draw};
 use crate::geom::{Point2, Point3};
@@ -298,6 +299,21 @@ where
     }
 }
 
+// SetGradient implementations.
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetGradient + Into<Primitive>,
+    Primitive: Into<Option<T>>,
+{
+    /// Paint with the given gradient in place of the color.
+    ///
+    /// A gradient without any stops is ignored.
+    pub fn gradient(self, gradient: Gradient) -> Self {
+        self.map_ty(|ty| SetGradient::gradient(ty, gradient))
+    }
+}
+
 // SetDimensions implementations.
 
 impl<'a, T> Drawing<'a, T>
@@ -706,3 +722,28 @@ where
         self.map_ty(|ty| ty.stroke_opts(opts))
     }
 }
+
+// SetDashes methods
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetDashes + Into<Primitive>,
+    Primitive: Into<Option<T>>,
+{
+    /// Dash the stroke with the given alternating lengths of dashes and gaps.
+    ///
+    /// As in SVG, a pattern with an odd number of lengths is repeated to yield an even number.
+    pub fn dashes(self, pattern: &[f32]) -> Self {
+        self.map_ty(|ty| ty.dashes(pattern))
+    }
+
+    /// The distance into the dash pattern at which each sub-path begins.
+    pub fn dash_offset(self, offset: f32) -> Self {
+        self.map_ty(|ty| ty.dash_offset(offset))
+    }
+
+    /// The cap applied to both ends of every dash, in place of the stroke's start and end caps.
+    pub fn dash_cap(self, cap: LineCap) -> Self {
+        self.map_ty(|ty| ty.dash_cap(cap))
+    }
+}
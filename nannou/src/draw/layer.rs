@@ -0,0 +1,155 @@
+//! Items related to compositing groups of drawings via blend modes and opacity.
+
+use crate::draw::Draw;
+use std::cell::Cell;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// A group of drawings that is rendered to an intermediary texture before being composited with
+/// everything drawn before it.
+///
+/// A **Layer** is produced via `Draw::layer` and dereferences to the **Draw** used to draw its
+/// contents, e.g. `layer.ellipse()`. The layer is composited at the point at which it was created,
+/// so drawings made via the parent **Draw** after `layer` was called appear above it regardless of
+/// when the layer's contents are drawn. Depth testing does not apply between a layer and the
+/// drawings around it.
+///
+/// Unlike `Draw::blend`, which describes how each individual vertex is blended by the GPU, a
+/// layer is blended as a whole using one of the shader-based **BlendMode**s.
+#[derive(Clone, Debug)]
+pub struct Layer {
+    draw: Draw,
+    properties: Rc<Cell<Properties>>,
+}
+
+/// Describes how a **Layer** is composited with its backdrop.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Properties {
+    /// The opacity by which the layer's alpha is multiplied, in the range `0.0..=1.0`.
+    pub opacity: f32,
+    /// The mode with which the layer's colors are blended with those of the backdrop.
+    pub blend_mode: BlendMode,
+}
+
+/// The blend modes with which a **Layer** may be composited with its backdrop.
+///
+/// These are the separable blend modes of the W3C "Compositing and Blending" specification and
+/// behave like their namesakes in most image editors. Colors are blended in linear space.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(u32)]
+pub enum BlendMode {
+    /// The layer's colors are drawn over the backdrop.
+    #[default]
+    Normal = 0,
+    /// Multiplies the colors, always resulting in a color at least as dark.
+    Multiply = 1,
+    /// Multiplies the complements of the colors, always resulting in a color at least as light.
+    Screen = 2,
+    /// Multiplies or screens the colors depending on the backdrop color.
+    Overlay = 3,
+    /// Selects the darker of the colors.
+    Darken = 4,
+    /// Selects the lighter of the colors.
+    Lighten = 5,
+    /// Brightens the backdrop color to reflect the layer color.
+    ColorDodge = 6,
+    /// Darkens the backdrop color to reflect the layer color.
+    ColorBurn = 7,
+    /// Multiplies or screens the colors depending on the layer color.
+    HardLight = 8,
+    /// Darkens or lightens the colors depending on the layer color.
+    SoftLight = 9,
+    /// Subtracts the darker of the colors from the lighter.
+    Difference = 10,
+    /// Like `Difference`, but with lower contrast.
+    Exclusion = 11,
+}
+
+impl Layer {
+    /// Produce a new layer whose contents are drawn via the given **Draw**.
+    pub(crate) fn new(draw: Draw) -> Self {
+        let properties = Rc::new(Cell::new(Properties::default()));
+        Layer { draw, properties }
+    }
+
+    /// The opacity by which the layer's alpha is multiplied when composited.
+    ///
+    /// Values are clamped to the range `0.0..=1.0`. The default is `1.0`.
+    pub fn opacity(self, opacity: f32) -> Self {
+        let mut properties = self.properties.get();
+        properties.opacity = opacity.clamp(0.0, 1.0);
+        self.properties.set(properties);
+        self
+    }
+
+    /// The mode with which the layer is blended with its backdrop. The default is
+    /// `BlendMode::Normal`.
+    pub fn blend_mode(self, blend_mode: BlendMode) -> Self {
+        let mut properties = self.properties.get();
+        properties.blend_mode = blend_mode;
+        self.properties.set(properties);
+        self
+    }
+
+    /// The properties describing how the layer is composited.
+    pub fn properties(&self) -> Properties {
+        self.properties.get()
+    }
+
+    /// The **Draw** used to draw the contents of the layer.
+    pub fn draw(&self) -> &Draw {
+        &self.draw
+    }
+}
+
+impl BlendMode {
+    /// All blend modes in order of their discriminant.
+    pub const ALL: [BlendMode; 12] = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Overlay,
+        BlendMode::Darken,
+        BlendMode::Lighten,
+        BlendMode::ColorDodge,
+        BlendMode::ColorBurn,
+        BlendMode::HardLight,
+        BlendMode::SoftLight,
+        BlendMode::Difference,
+        BlendMode::Exclusion,
+    ];
+
+    /// The name of the equivalent CSS `mix-blend-mode`, as used when exporting to SVG.
+    pub fn css_name(&self) -> &'static str {
+        match *self {
+            BlendMode::Normal => "normal",
+            BlendMode::Multiply => "multiply",
+            BlendMode::Screen => "screen",
+            BlendMode::Overlay => "overlay",
+            BlendMode::Darken => "darken",
+            BlendMode::Lighten => "lighten",
+            BlendMode::ColorDodge => "color-dodge",
+            BlendMode::ColorBurn => "color-burn",
+            BlendMode::HardLight => "hard-light",
+            BlendMode::SoftLight => "soft-light",
+            BlendMode::Difference => "difference",
+            BlendMode::Exclusion => "exclusion",
+        }
+    }
+}
+
+impl Deref for Layer {
+    type Target = Draw;
+    fn deref(&self) -> &Self::Target {
+        &self.draw
+    }
+}
+
+impl Default for Properties {
+    fn default() -> Self {
+        Properties {
+            opacity: 1.0,
+            blend_mode: BlendMode::default(),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Items related to lighting drawings with the **Draw** API.
+//!
+//! Drawing is unlit by default. Lights are added to the scene via the `draw.light_*` methods and
+//! only affect drawings made via a **Draw** instance with a **Material**, e.g. `draw.lit()`.
+
+use crate::color::LinSrgba;
+use crate::glam::Vec3;
+
+/// A light within the scene of a **Draw**.
+///
+/// Lights are positioned in "world space", i.e. the transform of the **Draw** instance used to
+/// add the light has already been applied.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Light {
+    /// Light that reaches all surfaces equally, regardless of their orientation.
+    Ambient { color: LinSrgba },
+    /// Light travelling in a single direction from infinitely far away, e.g. sunlight.
+    Directional { direction: Vec3, color: LinSrgba },
+    /// Light emitted in all directions from a single point.
+    Point { position: Vec3, color: LinSrgba },
+}
+
+/// Describes how a lit surface reflects the light that reaches it.
+///
+/// Surfaces are shaded using the Blinn-Phong reflection model. A `specular` of `0.0` results in
+/// purely diffuse, Lambertian shading.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Material {
+    /// The amount of light reflected diffusely, scaling the surface color.
+    pub diffuse: f32,
+    /// The amount of light reflected as a specular highlight.
+    pub specular: f32,
+    /// The Blinn-Phong exponent. Greater values produce smaller, sharper highlights.
+    pub shininess: f32,
+}
+
+impl Light {
+    /// The color of the light, scaled by its alpha channel which acts as the intensity.
+    pub fn intensity(&self) -> Vec3 {
+        let color = match *self {
+            Light::Ambient { color }
+            | Light::Directional { color, .. }
+            | Light::Point { color, .. } => color,
+        };
+        Vec3::new(color.red, color.green, color.blue) * color.alpha
+    }
+}
+
+impl Material {
+    /// The default amount of diffuse reflection.
+    pub const DEFAULT_DIFFUSE: f32 = 1.0;
+    /// The default amount of specular reflection.
+    pub const DEFAULT_SPECULAR: f32 = 0.5;
+    /// The default Blinn-Phong exponent.
+    pub const DEFAULT_SHININESS: f32 = 32.0;
+
+    /// A material with purely diffuse, Lambertian shading.
+    pub fn lambert() -> Self {
+        Material {
+            specular: 0.0,
+            ..Default::default()
+        }
+    }
+
+    /// Specify the amount of diffuse reflection.
+    pub fn diffuse(mut self, diffuse: f32) -> Self {
+        self.diffuse = diffuse;
+        self
+    }
+
+    /// Specify the amount of specular reflection.
+    pub fn specular(mut self, specular: f32) -> Self {
+        self.specular = specular;
+        self
+    }
+
+    /// Specify the Blinn-Phong exponent.
+    pub fn shininess(mut self, shininess: f32) -> Self {
+        self.shininess = shininess;
+        self
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material {
+            diffuse: Self::DEFAULT_DIFFUSE,
+            specular: Self::DEFAULT_SPECULAR,
+            shininess: Self::DEFAULT_SHININESS,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Items related to the custom mesh type used by the `Draw` API.
 
 use crate::geom;
-use crate::mesh::{self, MeshPoints, WithColors, WithIndices, WithTexCoords};
+use crate::mesh::{self, MeshPoints, WithColors, WithIndices, WithNormals, WithTexCoords};
 use std::ops::{Deref, DerefMut};
 
 pub mod builder;
@@ -14,10 +14,13 @@ pub type Points = Vec<vertex::Point>;
 pub type Indices = Vec<u32>;
 pub type Colors = Vec<vertex::Color>;
 pub type TexCoords = Vec<vertex::TexCoords>;
+pub type Normals = Vec<vertex::Normal>;
 
 /// The inner mesh type used by the **draw::Mesh**.
-pub type MeshType =
-    WithTexCoords<WithColors<WithIndices<MeshPoints<Points>, Indices>, Colors>, TexCoords>;
+pub type MeshType = WithNormals<
+    WithTexCoords<WithColors<WithIndices<MeshPoints<Points>, Indices>, Colors>, TexCoords>,
+    Normals,
+>;
 
 /// The custom mesh type used internally by the **Draw** API.
 #[derive(Clone, Debug)]
@@ -61,6 +64,11 @@ impl Mesh {
         mesh::TexCoords::tex_coords(self)
     }
 
+    /// The **Mesh**'s vertex normals channel.
+    pub fn normals(&self) -> &[vertex::Normal] {
+        mesh::Normals::normals(self)
+    }
+
     /// Push the given vertex onto the inner channels.
     pub fn push_vertex(&mut self, v: Vertex) {
         mesh::push_vertex(self, v);
@@ -126,6 +134,8 @@ impl Mesh {
     ///
     /// This is faster than `extend` which uses iteration internally.
     ///
+    /// Each vertex is given the `vertex::DEFAULT_NORMAL`.
+    ///
     /// **Panic!**s if the length of the given points, colors and tex_coords slices do not match.
     pub fn extend_from_slices(
         &mut self,
@@ -136,7 +146,8 @@ impl Mesh {
     ) {
         assert_eq!(points.len(), colors.len());
         assert_eq!(points.len(), tex_coords.len());
-        let slices = (tex_coords, (colors, (indices, points)));
+        let normals = vec![vertex::DEFAULT_NORMAL; points.len()];
+        let slices = (&normals[..], (tex_coords, (colors, (indices, points))));
         mesh::ExtendFromSlice::extend_from_slice(&mut self.mesh, slices);
     }
 
@@ -199,7 +210,7 @@ impl DerefMut for Mesh {
 impl mesh::GetVertex<u32> for Mesh {
     type Vertex = Vertex;
     fn get_vertex(&self, index: u32) -> Option<Self::Vertex> {
-        mesh::WithTexCoords::get_vertex(&self.mesh, index)
+        mesh::WithNormals::get_vertex(&self.mesh, index)
     }
 }
 
@@ -235,6 +246,14 @@ impl mesh::TexCoords for Mesh {
     }
 }
 
+impl mesh::Normals for Mesh {
+    type Normal = vertex::Normal;
+    type Normals = Normals;
+    fn normals(&self) -> &Self::Normals {
+        self.mesh.normals()
+    }
+}
+
 impl mesh::PushVertex<Vertex> for Mesh {
     fn push_vertex(&mut self, v: Vertex) {
         self.mesh.push_vertex(v);
@@ -276,4 +295,5 @@ fn test_method_access() {
     mesh::Indices::indices(&mesh);
     mesh::Colors::colors(&mesh);
     mesh::TexCoords::tex_coords(&mesh);
+    mesh::Normals::normals(&mesh);
 }
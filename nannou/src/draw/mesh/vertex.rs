@@ -1,6 +1,6 @@
 use crate::color;
 use crate::geom::{Point2, Point3, Vec3};
-use crate::mesh::vertex::{WithColor, WithTexCoords};
+use crate::mesh::vertex::{WithColor, WithNormal, WithTexCoords};
 
 pub type Point = Point3;
 pub type Color = color::LinSrgba;
@@ -10,7 +10,7 @@ pub type ColoredPoint = WithColor<Point, Color>;
 pub type ColoredPoint2 = WithColor<Point2, Color>;
 
 /// The vertex type produced by the **draw::Mesh**'s inner **MeshType**.
-pub type Vertex = WithTexCoords<WithColor<Point, Color>, TexCoords>;
+pub type Vertex = WithNormal<WithTexCoords<WithColor<Point, Color>, TexCoords>, Normal>;
 
 /// The number of channels in the color type.
 pub const COLOR_CHANNEL_COUNT: usize = 4;
@@ -25,13 +25,24 @@ pub const DEFAULT_VERTEX_COLOR: Color = color::Alpha {
     alpha: 1.0,
 };
 
-/// Simplified constructor for a **draw::mesh::Vertex**.
+/// The normal assumed for vertices that were not given one, facing towards the viewer.
+pub const DEFAULT_NORMAL: Normal = Vec3::Z;
+
+/// Simplified constructor for a **draw::mesh::Vertex** with the `DEFAULT_NORMAL`.
 pub fn new(point: Point, color: Color, tex_coords: TexCoords) -> Vertex {
-    WithTexCoords {
-        tex_coords,
-        vertex: WithColor {
-            color,
-            vertex: point,
+    with_normal(point, color, tex_coords, DEFAULT_NORMAL)
+}
+
+/// Simplified constructor for a **draw::mesh::Vertex** with the given normal.
+pub fn with_normal(point: Point, color: Color, tex_coords: TexCoords, normal: Normal) -> Vertex {
+    WithNormal {
+        normal,
+        vertex: WithTexCoords {
+            tex_coords,
+            vertex: WithColor {
+                color,
+                vertex: point,
+            },
         },
     }
 }
@@ -73,7 +84,8 @@ where
         self.colored_points.next().map(|vertex| {
             let tex_coords = default_tex_coords();
             let vertex = WithTexCoords { tex_coords, vertex };
-            vertex
+            let normal = DEFAULT_NORMAL;
+            WithNormal { vertex, normal }
         })
     }
 }
@@ -154,7 +166,8 @@ where
             let vertex = WithColor { vertex, color };
             let tex_coords = default_tex_coords();
             let vertex = WithTexCoords { vertex, tex_coords };
-            vertex
+            let normal = DEFAULT_NORMAL;
+            WithNormal { vertex, normal }
         })
     }
 }
@@ -171,7 +184,8 @@ where
             let vertex = WithColor { vertex, color };
             let tex_coords = default_tex_coords();
             let vertex = WithTexCoords { vertex, tex_coords };
-            vertex
+            let normal = DEFAULT_NORMAL;
+            WithNormal { vertex, normal }
         })
     }
 }
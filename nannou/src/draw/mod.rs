@@ -2,6 +2,7 @@
 //!
 //! See the [**Draw** type](./struct.Draw.html) for more details.
 
+use crate::color::IntoLinSrgba;
 use crate::geom::{self, Point2};
 use crate::glam::{vec3, EulerRot, Mat4, Quat, Vec2, Vec3};
 use crate::math::{deg_to_rad, turns_to_rad};
@@ -13,19 +14,31 @@ use std::mem;
 use std::rc::Rc;
 
 pub use self::background::Background;
+pub use self::camera::{Camera, Projection};
 pub use self::drawing::{Drawing, DrawingContext};
+pub use self::layer::{BlendMode, Layer};
+pub use self::light::{Light, Material};
 use self::mesh::vertex::{Color, TexCoords};
 pub use self::mesh::Mesh;
+pub use self::pick::Pick;
 use self::primitive::Primitive;
+pub use self::properties::Gradient;
+pub use self::render_target::{Builder as RenderTargetBuilder, RenderTarget};
 pub use self::renderer::{Builder as RendererBuilder, Renderer};
 pub use self::theme::Theme;
 
 pub mod background;
+pub mod camera;
 mod drawing;
+pub mod layer;
+pub mod light;
 pub mod mesh;
+pub mod pick;
 pub mod primitive;
 pub mod properties;
+pub mod render_target;
 pub mod renderer;
+pub mod svg;
 pub mod theme;
 
 /// A simple API for drawing 2D and 3D graphics.
@@ -58,16 +71,23 @@ pub struct Draw {
     context: Context,
 }
 
-/// The current **Transform**, alpha **BlendState** and **Scissor** of a **Draw** instance.
+/// The current **Transform**, alpha **BlendState**, **Scissor** and clip **Mask**s of a **Draw**
+/// instance.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Context {
     pub transform: Mat4,
     pub blend: wgpu::BlendState,
     pub scissor: Scissor,
+    /// The stack of masks to which drawing is clipped, outermost first.
+    pub clip: Vec<Mask>,
     // TODO: Consider changing `PolygonMode` (added as of wgpu 0.7) rather than `PrimitiveTopology`
     // here.
     pub topology: wgpu::PrimitiveTopology,
     pub sampler: wgpu::SamplerDescriptor<'static>,
+    /// If `Some`, drawings are shaded by the scene's lights using the given material.
+    pub material: Option<Material>,
+    /// Identifies drawings found via `Draw::pick`.
+    pub tag: Option<u64>,
 }
 
 /// Commands generated by drawings.
@@ -80,6 +100,8 @@ pub enum DrawCommand {
     Primitive(Primitive),
     /// A change in the rendering context occurred.
     Context(Context),
+    /// Composite a layer with everything drawn before it.
+    Layer(Layer),
 }
 
 /// The scissor for a **Draw**'s render context.
@@ -93,6 +115,18 @@ pub enum Scissor {
     NoOverlap,
 }
 
+/// A mask limiting drawing to the filled region of a set of primitives.
+///
+/// Masks are produced via `Draw::clip` and `Draw::clip_inverted` and are implemented with the
+/// renderer's stencil buffer. Two masks are equal only if they were produced by the same call.
+#[derive(Clone, Debug)]
+pub struct Mask {
+    /// The commands drawing the primitives that make up the mask.
+    pub commands: Rc<Vec<DrawCommand>>,
+    /// Whether drawing is limited to the region *outside* of the primitives.
+    pub inverted: bool,
+}
+
 /// The inner state of the **Draw** type.
 ///
 /// The **Draw** type stores its **State** behind a **RefCell** - a type used for moving mutability
@@ -106,6 +140,11 @@ pub struct State {
     last_draw_context: Option<Context>,
     /// If `Some`, the **Draw** should first clear the frame's texture with the given color.
     background_color: Option<properties::LinSrgba>,
+    /// The lights illuminating drawings made with a **Material**.
+    lights: Vec<Light>,
+    /// If `Some`, the scene is viewed through the given camera rather than the default
+    /// orthographic view.
+    camera: Option<Camera>,
     /// Primitives that are in the process of being drawn.
     ///
     /// Keys are indices into the `draw_commands` Vec.
@@ -149,6 +188,8 @@ impl State {
     // Resets all state within the `Draw` instance.
     fn reset(&mut self) {
         self.background_color = None;
+        self.lights.clear();
+        self.camera = None;
         self.last_draw_context = None;
         self.drawing.clear();
         self.draw_commands.clear();
@@ -417,6 +458,75 @@ impl Draw {
         self.context(context)
     }
 
+    /// Produce a new **Draw** instance that will be clipped to the filled region of the
+    /// primitives drawn by the given function.
+    ///
+    /// The mask is drawn with the current context, so `draw.x(100.0).clip(..)` clips to a mask
+    /// translated along the *x* axis. Colors and textures of the mask are ignored, as is any
+    /// primitive outside of the mask's region.
+    ///
+    /// If the current **Draw** instance is already clipped, the result will be clipped to the
+    /// overlap of the original mask and the new one.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// # fn view(app: &App, frame: Frame) {
+    /// let draw = app.draw();
+    /// let clipped = draw.clip(|mask| {
+    ///     mask.ellipse().w_h(200.0, 200.0);
+    /// });
+    /// clipped.rect().w_h(300.0, 100.0).color(PLUM);
+    /// # }
+    /// # fn main() {}
+    /// ```
+    ///
+    /// Clipping requires a renderer whose depth format has a stencil aspect, as is the case for
+    /// the default `Depth24PlusStencil8`. Masks are ignored when exporting to SVG.
+    pub fn clip<F>(&self, mask: F) -> Self
+    where
+        F: FnOnce(&Draw),
+    {
+        self.clip_mask(false, mask)
+    }
+
+    /// Produce a new **Draw** instance that will be clipped to the region *outside* of the
+    /// primitives drawn by the given function.
+    ///
+    /// See `clip` for details.
+    pub fn clip_inverted<F>(&self, mask: F) -> Self
+    where
+        F: FnOnce(&Draw),
+    {
+        self.clip_mask(true, mask)
+    }
+
+    /// Shared between the `clip` and `clip_inverted` methods.
+    fn clip_mask<F>(&self, inverted: bool, mask: F) -> Self
+    where
+        F: FnOnce(&Draw),
+    {
+        // Ensure the first primitive of the mask is preceded by its context.
+        let start = {
+            let mut state = self.state.borrow_mut();
+            state.last_draw_context = None;
+            state.draw_commands.len()
+        };
+        mask(self);
+        // Take the commands of the mask out of the list of draw commands.
+        let commands = {
+            let mut state = self.state.borrow_mut();
+            for index in start..state.draw_commands.len() {
+                state.finish_drawing(index);
+            }
+            state.last_draw_context = None;
+            state.draw_commands.split_off(start)
+        };
+        let commands = Rc::new(commands.into_iter().flatten().collect());
+        let mut context = self.context.clone();
+        context.clip.push(Mask { commands, inverted });
+        self.context(context)
+    }
+
     /// Produce a new **Draw** instance.
     ///
     /// All drawing that occurs on the new instance will be rendered as a "wireframe" between all
@@ -461,6 +571,43 @@ impl Draw {
         self.context(context)
     }
 
+    /// Produce a new **Draw** instance where all drawings are shaded by the scene's lights using
+    /// the given material.
+    ///
+    /// Drawings are unlit by default, in which case their color is used as is.
+    pub fn material(&self, material: Material) -> Self {
+        let mut context = self.context.clone();
+        context.material = Some(material);
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance where all drawings are shaded by the scene's lights using
+    /// the default **Material**.
+    ///
+    /// This is short-hand for `draw.material(Default::default())`.
+    pub fn lit(&self) -> Self {
+        self.material(Default::default())
+    }
+
+    /// Produce a new **Draw** instance where all drawings ignore the scene's lights.
+    ///
+    /// This is the default.
+    pub fn unlit(&self) -> Self {
+        let mut context = self.context.clone();
+        context.material = None;
+        self.context(context)
+    }
+
+    /// Produce a new **Draw** instance whose drawings are identified by the given tag when found
+    /// via `Draw::pick`.
+    ///
+    /// Tags have no effect on rendering.
+    pub fn tag(&self, tag: u64) -> Self {
+        let mut context = self.context.clone();
+        context.tag = Some(tag);
+        self.context(context)
+    }
+
     /// Specify the primitive topology to use within the render pipeline.
     ///
     /// This method is shared between the `line_mode`, `point_mode` and `triangle_mode` methods.
@@ -483,6 +630,106 @@ impl Draw {
         background::new(self)
     }
 
+    /// View the scene through the given **Camera**.
+    ///
+    /// By default, the scene is viewed through an orthographic projection where one unit is one
+    /// logical pixel and the origin is at the centre of the output. Like the background color, the
+    /// camera applies to the whole **Draw**ing and is reset along with it.
+    pub fn camera(&self, camera: Camera) {
+        if let Ok(mut state) = self.state.try_borrow_mut() {
+            state.camera = Some(camera);
+        }
+    }
+
+    /// Add the given light to the scene.
+    ///
+    /// Lights only affect drawings made with a **Material**, e.g. via `draw.lit()`, and apply to
+    /// all such drawings regardless of the order in which they are drawn. The renderer supports up
+    /// to `draw::renderer::MAX_LIGHTS` lights, beyond which lights are ignored.
+    pub fn light(&self, light: Light) {
+        if let Ok(mut state) = self.state.try_borrow_mut() {
+            state.lights.push(light);
+        }
+    }
+
+    /// Add an ambient light of the given color, illuminating all lit surfaces equally.
+    ///
+    /// The alpha channel of the color scales the intensity of the light.
+    pub fn light_ambient<C>(&self, color: C)
+    where
+        C: IntoLinSrgba<properties::ColorScalar>,
+    {
+        let color = color.into_lin_srgba();
+        self.light(Light::Ambient { color });
+    }
+
+    /// Add a light of the given color travelling in the given direction, e.g. sunlight.
+    ///
+    /// The direction is transformed by the transform of this **Draw** instance. The alpha channel
+    /// of the color scales the intensity of the light.
+    pub fn light_directional<C>(&self, direction: Vec3, color: C)
+    where
+        C: IntoLinSrgba<properties::ColorScalar>,
+    {
+        let direction = self
+            .context
+            .transform
+            .transform_vector3(direction)
+            .normalize_or_zero();
+        let color = color.into_lin_srgba();
+        self.light(Light::Directional { direction, color });
+    }
+
+    /// Add a light of the given color emitted in all directions from the given position.
+    ///
+    /// The position is transformed by the transform of this **Draw** instance. The alpha channel
+    /// of the color scales the intensity of the light.
+    pub fn light_point<C>(&self, position: Vec3, color: C)
+    where
+        C: IntoLinSrgba<properties::ColorScalar>,
+    {
+        let position = self.context.transform.transform_point3(position);
+        let color = color.into_lin_srgba();
+        self.light(Light::Point { position, color });
+    }
+
+    /// Begin a new **Layer**, a group of drawings that is composited with everything drawn before
+    /// it via a shader-based **BlendMode** and opacity.
+    ///
+    /// The layer inherits the current context, e.g. its transform, other than any clip masks.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// # use nannou::draw::BlendMode;
+    /// # fn view(app: &App, frame: Frame) {
+    /// let draw = app.draw();
+    /// let layer = draw.layer().opacity(0.5).blend_mode(BlendMode::Overlay);
+    /// layer.ellipse().w_h(200.0, 200.0).color(ORANGE);
+    /// # }
+    /// # fn main() {}
+    /// ```
+    ///
+    /// When rendering a layer to a single-sampled texture, the texture must have the
+    /// `TEXTURE_BINDING` usage so that it can be read while compositing.
+    pub fn layer(&self) -> Layer {
+        let mut state = self.state.borrow_mut();
+        let layer_state = State {
+            theme: state.theme.clone(),
+            ..Default::default()
+        };
+        let mut context = self.context.clone();
+        context.clip.clear();
+        let draw = Draw {
+            state: Rc::new(RefCell::new(layer_state)),
+            context,
+        };
+        let layer = Layer::new(draw);
+        state
+            .draw_commands
+            .push(Some(DrawCommand::Layer(layer.clone())));
+        layer
+    }
+
     /// Add the given type to be drawn.
     pub fn a<T>(&self, primitive: T) -> Drawing<T>
     where
@@ -543,6 +790,47 @@ impl Draw {
         self.a(Default::default())
     }
 
+    /// Begin drawing a **Cuboid**.
+    pub fn cuboid(&self) -> Drawing<primitive::Cuboid> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Sphere**.
+    pub fn sphere(&self) -> Drawing<primitive::Sphere> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Cylinder**.
+    pub fn cylinder(&self) -> Drawing<primitive::Cylinder> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Cone**.
+    pub fn cone(&self) -> Drawing<primitive::Cone> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Torus**.
+    pub fn torus(&self) -> Drawing<primitive::Torus> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing a **Plane**.
+    pub fn plane(&self) -> Drawing<primitive::Plane> {
+        self.a(Default::default())
+    }
+
+    /// Begin drawing many instances of the given primitive in a single draw call.
+    ///
+    /// The primitive is tessellated once, then drawn once per transform given to the
+    /// `transforms` or `positions` builder methods, optionally tinted per instance via `colors`.
+    pub fn instances<T>(&self, primitive: T) -> Drawing<primitive::Instances>
+    where
+        T: Into<Primitive>,
+    {
+        self.a(primitive::Instances::new(primitive))
+    }
+
     /// Begin drawing a **Polygon**.
     pub fn polygon(&self) -> Drawing<primitive::PolygonInit> {
         self.a(Default::default())
@@ -571,6 +859,19 @@ impl Draw {
         self.a(text)
     }
 
+    /// Begin drawing the shapes of an SVG **Document**.
+    ///
+    /// The document is drawn at its own size unless dimensions are specified.
+    pub fn svg(&self, document: &geom::svg::Document) -> Drawing<primitive::Svg> {
+        let svg = {
+            let state = self.state.borrow();
+            let mut intermediary_state = state.intermediary_state.borrow_mut();
+            let ctxt = DrawingContext::from_intermediary_state(&mut *intermediary_state);
+            primitive::svg::Svg::new(ctxt, document)
+        };
+        self.a(svg)
+    }
+
     /// Begin drawing a **Texture**.
     pub fn texture(&self, view: &dyn wgpu::ToTextureView) -> Drawing<primitive::Texture> {
         self.a(primitive::Texture::new(view))
@@ -615,6 +916,8 @@ impl Default for State {
     fn default() -> Self {
         let last_draw_context = None;
         let background_color = Default::default();
+        let lights = Default::default();
+        let camera = None;
         let draw_commands = Default::default();
         let drawing = Default::default();
         let intermediary_state = RefCell::new(Default::default());
@@ -626,6 +929,8 @@ impl Default for State {
             intermediary_state,
             theme,
             background_color,
+            lights,
+            camera,
         }
     }
 }
@@ -638,6 +943,12 @@ impl Default for Draw {
     }
 }
 
+impl PartialEq for Mask {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.commands, &other.commands) && self.inverted == other.inverted
+    }
+}
+
 impl Default for Context {
     fn default() -> Self {
         Self {
@@ -647,8 +958,11 @@ impl Default for Context {
                 alpha: wgpu::RenderPipelineBuilder::DEFAULT_ALPHA_BLEND,
             },
             scissor: Scissor::Full,
+            clip: Vec::new(),
             topology: wgpu::RenderPipelineBuilder::DEFAULT_PRIMITIVE_TOPOLOGY,
             sampler: wgpu::SamplerBuilder::new().into_descriptor(),
+            material: None,
+            tag: None,
         }
     }
 }
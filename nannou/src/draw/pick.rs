@@ -0,0 +1,259 @@
+//! Items related to finding the drawings beneath a point, e.g. the mouse position.
+//!
+//! Primitives are tessellated via the same `RenderPrimitive` implementations used by the
+//! **Renderer**, and the resulting triangles are tested for the point via
+//! `geom::tri::iter_contains`. Scissors, clip masks, layers and the camera are taken into account.
+
+use crate::draw::renderer::{GlyphCache, RenderContext, RenderPrimitive, Renderer};
+use crate::draw::{self, Draw, DrawCommand, Mask, Scissor};
+use crate::geom::{self, Point2, Tri};
+use crate::glam::{Mat4, Vec2};
+use lyon::tessellation::{FillTessellator, StrokeTessellator};
+
+/// A drawing found beneath a point via `Draw::pick`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Pick {
+    /// The index of the primitive in the order in which primitives are rendered.
+    ///
+    /// Primitives drawn via a **Layer** are counted at the point at which the layer was created.
+    pub index: usize,
+    /// The tag of the **Draw** used to draw the primitive, if any. See `Draw::tag`.
+    pub tag: Option<u64>,
+}
+
+// A primitive along with the context in which it was drawn.
+struct Entry {
+    draw: Draw,
+    command: usize,
+    context: draw::Context,
+}
+
+// Tessellates primitives in order to test whether they contain the point.
+struct Picker {
+    // The point in logical window coordinates.
+    point: Point2,
+    size: Vec2,
+    // Transforms from world space to clip space.
+    view: Mat4,
+    fill_tessellator: FillTessellator,
+    stroke_tessellator: StrokeTessellator,
+    // Only text requires a full size glyph cache, so it is created upon picking the first text.
+    glyph_cache: Option<GlyphCache>,
+    // Stands in for the glyph cache while tessellating all other primitives.
+    empty_glyph_cache: GlyphCache,
+    mesh: draw::Mesh,
+}
+
+impl Draw {
+    /// The topmost drawing whose tessellated fill or stroke contains the given point.
+    ///
+    /// The `point` is in logical window coordinates, e.g. `app.mouse.position()`, and `size` is
+    /// the logical size of the output to which the **Draw** is rendered, e.g.
+    /// `app.window_rect().wh()`. The commands of the **Draw** are left in place, so picking may
+    /// occur at any point before the **Draw** is rendered.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// # fn view(app: &App, frame: Frame) {
+    /// let draw = app.draw();
+    /// draw.tag(1).ellipse().x(-100.0).color(RED);
+    /// draw.tag(2).rect().x(100.0).color(BLUE);
+    /// if let Some(pick) = draw.pick(app.mouse.position(), app.window_rect().wh()) {
+    ///     println!("the mouse is over {:?}", pick.tag);
+    /// }
+    /// # }
+    /// # fn main() {}
+    /// ```
+    pub fn pick(&self, point: Point2, size: Vec2) -> Option<Pick> {
+        let entries = entries(self);
+        let mut picker = Picker::new(self, point, size);
+        entries
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, entry)| picker.entry_contains(entry))
+            .map(|(index, entry)| entry.pick(index))
+    }
+
+    /// All drawings whose tessellated fill or stroke contains the given point, topmost first.
+    ///
+    /// See `pick` for details on the `point` and `size`.
+    pub fn pick_all(&self, point: Point2, size: Vec2) -> Vec<Pick> {
+        let entries = entries(self);
+        let mut picker = Picker::new(self, point, size);
+        entries
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, entry)| picker.entry_contains(entry))
+            .map(|(index, entry)| entry.pick(index))
+            .collect()
+    }
+}
+
+impl Entry {
+    fn pick(&self, index: usize) -> Pick {
+        let tag = self.context.tag;
+        Pick { index, tag }
+    }
+}
+
+impl Picker {
+    fn new(draw: &Draw, point: Point2, size: Vec2) -> Self {
+        let camera = draw.state.borrow().camera.unwrap_or_default();
+        let view = camera.view_projection_matrix(size.into());
+        Picker {
+            point,
+            size,
+            view,
+            fill_tessellator: FillTessellator::new(),
+            stroke_tessellator: StrokeTessellator::new(),
+            glyph_cache: None,
+            empty_glyph_cache: GlyphCache::new([1, 1], 1.0, 1.0),
+            mesh: draw::Mesh::default(),
+        }
+    }
+
+    // Whether or not the primitive of the entry contains the point, respecting its scissor and
+    // clip masks.
+    fn entry_contains(&mut self, entry: &Entry) -> bool {
+        let in_scissor = match entry.context.scissor {
+            Scissor::Full => true,
+            Scissor::Rect(rect) => rect.contains(self.point),
+            Scissor::NoOverlap => false,
+        };
+        if !in_scissor {
+            return false;
+        }
+        for mask in &entry.context.clip {
+            if !self.mask_contains(&entry.draw, mask) {
+                return false;
+            }
+        }
+        let state = entry.draw.state.borrow();
+        let prim = match state.draw_commands[entry.command] {
+            Some(DrawCommand::Primitive(ref prim)) => prim.clone(),
+            _ => return false,
+        };
+        drop(state);
+        self.primitive_contains(&entry.draw, prim, entry.context.transform)
+    }
+
+    // Whether or not the given mask permits drawing at the point.
+    fn mask_contains(&mut self, draw: &Draw, mask: &Mask) -> bool {
+        let mut transform = Mat4::IDENTITY;
+        let mut contains = false;
+        for cmd in mask.commands.iter() {
+            match cmd {
+                DrawCommand::Context(ctxt) => transform = ctxt.transform,
+                DrawCommand::Primitive(prim) => {
+                    if self.primitive_contains(draw, prim.clone(), transform) {
+                        contains = true;
+                        break;
+                    }
+                }
+                DrawCommand::Layer(_) => (),
+            }
+        }
+        contains != mask.inverted
+    }
+
+    // Whether or not the tessellated primitive contains the point.
+    fn primitive_contains(&mut self, draw: &Draw, prim: draw::Primitive, transform: Mat4) -> bool {
+        let prims = match prim {
+            draw::Primitive::Instances(instances) => instances
+                .transforms
+                .iter()
+                .map(|t| ((*instances.primitive).clone(), transform * *t))
+                .collect(),
+            prim => vec![(prim, transform)],
+        };
+        let state = draw.state.borrow();
+        let intermediary_state = state.intermediary_state.borrow();
+        for (prim, transform) in prims {
+            self.mesh.clear();
+            let glyph_cache = match prim {
+                draw::Primitive::Text(_) => self.glyph_cache.get_or_insert_with(|| {
+                    GlyphCache::new(
+                        Renderer::DEFAULT_GLYPH_CACHE_SIZE,
+                        Renderer::DEFAULT_GLYPH_CACHE_SCALE_TOLERANCE,
+                        Renderer::DEFAULT_GLYPH_CACHE_POSITION_TOLERANCE,
+                    )
+                }),
+                _ => &mut self.empty_glyph_cache,
+            };
+            let ctxt = RenderContext {
+                intermediary_mesh: &intermediary_state.intermediary_mesh,
+                path_event_buffer: &intermediary_state.path_event_buffer,
+                path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
+                path_points_textured_buffer: &intermediary_state.path_points_textured_buffer,
+                text_buffer: &intermediary_state.text_buffer,
+                theme: &state.theme,
+                transform: &transform,
+                fill_tessellator: &mut self.fill_tessellator,
+                stroke_tessellator: &mut self.stroke_tessellator,
+                glyph_cache,
+                output_attachment_size: self.size,
+                output_attachment_scale_factor: 1.0,
+            };
+            prim.render_primitive(ctxt, &mut self.mesh);
+
+            // Project the tessellated points into logical window coordinates.
+            let (view, half_size) = (self.view, self.size * 0.5);
+            let points: Vec<Point2> = self
+                .mesh
+                .points()
+                .iter()
+                .map(|&p| view.project_point3(p).truncate() * half_size)
+                .collect();
+            let tris = self.mesh.indices().chunks_exact(3).map(|ix| {
+                let [a, b, c] = [ix[0], ix[1], ix[2]].map(|i| points[i as usize]);
+                Tri([a, b, c])
+            });
+            if geom::tri::iter_contains(tris, &self.point).is_some() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+// Collect the primitives of the given **Draw** and those of its layers in the order in which they
+// are rendered.
+fn entries(draw: &Draw) -> Vec<Entry> {
+    fn collect(draw: &Draw, entries: &mut Vec<Entry>) {
+        draw.finish_remaining_drawings();
+        let layers: Vec<_> = {
+            let state = draw.state.borrow();
+            let mut context = draw::Context::default();
+            let mut layers = vec![];
+            for (command, cmd) in state.draw_commands.iter().enumerate() {
+                match cmd {
+                    Some(DrawCommand::Context(ctxt)) => context = ctxt.clone(),
+                    Some(DrawCommand::Primitive(_)) => {
+                        let draw = draw.clone();
+                        let context = context.clone();
+                        entries.push(Entry {
+                            draw,
+                            command,
+                            context,
+                        });
+                    }
+                    Some(DrawCommand::Layer(layer)) => layers.push((entries.len(), layer.clone())),
+                    None => (),
+                }
+            }
+            layers
+        };
+        // Insert the contents of each layer at the point at which it was created, last first so
+        // that the preceding insertion points remain valid.
+        for (position, layer) in layers.into_iter().rev() {
+            let mut layer_entries = vec![];
+            collect(layer.draw(), &mut layer_entries);
+            entries.splice(position..position, layer_entries);
+        }
+    }
+    let mut entries = vec![];
+    collect(draw, &mut entries);
+    entries
+}
@@ -3,7 +3,10 @@ use crate::draw::primitive::path;
 use crate::draw::primitive::Line;
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{ColorScalar, SetColor, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    ColorScalar, Dashes, Gradient, SetColor, SetDashes, SetGradient, SetOrientation, SetPosition,
+    SetStroke,
+};
 use crate::draw::{self, Drawing};
 use crate::geom::{pt2, Point2};
 use crate::glam::vec2;
@@ -146,6 +149,18 @@ impl SetPosition for Arrow {
     }
 }
 
+impl SetGradient for Arrow {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.line)
+    }
+}
+
+impl SetDashes for Arrow {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.line)
+    }
+}
+
 impl SetColor<ColorScalar> for Arrow {
     fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
         SetColor::rgba_mut(&mut self.line)
@@ -173,36 +188,11 @@ impl draw::renderer::RenderPrimitive for Arrow {
         mut ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Arrow {
-            line,
-            head_length,
-            head_width,
-        } = self;
-        let start = line.start.unwrap_or(pt2(0.0, 0.0));
-        let end = line.end.unwrap_or(pt2(0.0, 0.0));
-        if start == end {
-            return draw::renderer::PrimitiveRender::default();
-        }
-
-        // Calculate the arrow head points.
-        let line_w_2 = line.path.opts.line_width * 2.0;
-        let line_w_4 = line_w_2 * 2.0;
-        let head_width = head_width.unwrap_or(line_w_2);
-        let head_length = head_length.unwrap_or(line_w_4);
-        let line_dir = end - start;
-        let line_dir_len = line_dir.length();
-        let tri_len = head_length.min(line_dir_len);
-        let tri_dir_norm = line_dir.normalize() * tri_len;
-        let tri_start = end - tri_dir_norm;
-        let tri_end = end;
-        let line_start = start;
-        let line_end = tri_start;
-        let tri_a = tri_end;
-        let tri_w_dir = vec2(-tri_dir_norm.y, tri_dir_norm.x).normalize() * head_width;
-        let tri_b = tri_start + tri_w_dir;
-        let tri_c = tri_start - tri_w_dir;
-        // The line should only be drawn if there is space after drawing the triangle.
-        let draw_line = line_dir_len > tri_len;
+        let (tri_points, line_points) = match self.geometry() {
+            None => return draw::renderer::PrimitiveRender::default(),
+            Some(points) => points,
+        };
+        let line = self.line;
 
         // Determine the transform to apply to all points.
         let global_transform = *ctxt.transform;
@@ -210,13 +200,14 @@ impl draw::renderer::RenderPrimitive for Arrow {
         let transform = global_transform * local_transform;
 
         // Draw the tri.
-        let tri_points = [tri_a, tri_b, tri_c];
         let tri_points = tri_points.iter().cloned().map(|p| p.to_array().into());
         let close_tri = true;
         let tri_events = lyon::path::iterator::FromPolyline::new(close_tri, tri_points);
         path::render_path_events(
             tri_events,
             line.path.color,
+            line.path.gradient.as_ref(),
+            None,
             transform,
             path::Options::Fill(Default::default()),
             &ctxt.theme,
@@ -227,14 +218,15 @@ impl draw::renderer::RenderPrimitive for Arrow {
         );
 
         // Draw the line.
-        if draw_line {
-            let line_points = [line_start, line_end];
+        if let Some(line_points) = line_points {
             let line_points = line_points.iter().cloned().map(|p| p.to_array().into());
             let close_line = false;
             let line_events = lyon::path::iterator::FromPolyline::new(close_line, line_points);
             path::render_path_events(
                 line_events,
                 line.path.color,
+                line.path.gradient.as_ref(),
+                line.path.dashes.as_ref(),
                 transform,
                 path::Options::Stroke(line.path.opts),
                 &ctxt.theme,
@@ -249,6 +241,88 @@ impl draw::renderer::RenderPrimitive for Arrow {
     }
 }
 
+impl draw::svg::WritePrimitive for Arrow {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        let (tri_points, line_points) = match self.geometry() {
+            None => return true,
+            Some(points) => points,
+        };
+        let path = &self.line.path;
+        let gradient = path.gradient.as_ref();
+        if !draw::svg::supports_gradient(gradient) {
+            return false;
+        }
+
+        // Determine the transform to apply to all points.
+        let local_transform = path.position.transform() * path.orientation.transform();
+        let transform = ctxt.transform * local_transform;
+        let theme_prim = &draw::theme::Primitive::Arrow;
+
+        // Write the tri.
+        let tri_points = tri_points.iter().cloned().map(|p| p.to_array().into());
+        let tri_events = lyon::path::iterator::FromPolyline::closed(tri_points);
+        let options = path::Options::Fill(Default::default());
+        let color = ctxt.color(path.color, &options, theme_prim);
+        svg.path(tri_events, color, gradient, None, transform, &options);
+
+        // Write the line.
+        if let Some(line_points) = line_points {
+            let line_points = line_points.iter().cloned().map(|p| p.to_array().into());
+            let line_events = lyon::path::iterator::FromPolyline::new(false, line_points);
+            let options = path::Options::Stroke(path.opts);
+            let color = ctxt.color(path.color, &options, theme_prim);
+            let dashes = path.dashes.as_ref();
+            svg.path(line_events, color, gradient, dashes, transform, &options);
+        }
+        true
+    }
+}
+
+impl Arrow {
+    // The points of the arrow head along with those of the line, if there is space for the line
+    // after the head.
+    //
+    // Returns `None` if the start and end of the arrow are the same.
+    fn geometry(&self) -> Option<([Point2; 3], Option<[Point2; 2]>)> {
+        let Arrow {
+            ref line,
+            head_length,
+            head_width,
+        } = *self;
+        let start = line.start.unwrap_or(pt2(0.0, 0.0));
+        let end = line.end.unwrap_or(pt2(0.0, 0.0));
+        if start == end {
+            return None;
+        }
+
+        // Calculate the arrow head points.
+        let line_w_2 = line.path.opts.line_width * 2.0;
+        let line_w_4 = line_w_2 * 2.0;
+        let head_width = head_width.unwrap_or(line_w_2);
+        let head_length = head_length.unwrap_or(line_w_4);
+        let line_dir = end - start;
+        let line_dir_len = line_dir.length();
+        let tri_len = head_length.min(line_dir_len);
+        let tri_dir_norm = line_dir.normalize() * tri_len;
+        let tri_start = end - tri_dir_norm;
+        let tri_end = end;
+        let line_start = start;
+        let line_end = tri_start;
+        let tri_a = tri_end;
+        let tri_w_dir = vec2(-tri_dir_norm.y, tri_dir_norm.x).normalize() * head_width;
+        let tri_b = tri_start + tri_w_dir;
+        let tri_c = tri_start - tri_w_dir;
+        // The line should only be drawn if there is space after drawing the triangle.
+        let draw_line = line_dir_len > tri_len;
+        let line_points = if draw_line {
+            Some([line_start, line_end])
+        } else {
+            None
+        };
+        Some(([tri_a, tri_b, tri_c], line_points))
+    }
+}
+
 impl Default for Arrow {
     fn default() -> Self {
         let line = Default::default();
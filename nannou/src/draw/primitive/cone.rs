@@ -0,0 +1,143 @@
+use crate::draw::primitive::solid::{self, Geometry, SetSolid, SolidOptions};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::glam::Vec3;
+
+/// Properties related to drawing a **Cone**.
+#[derive(Clone, Debug)]
+pub struct Cone {
+    solid: SolidOptions,
+    segments: u32,
+}
+
+/// The drawing context for a Cone.
+pub type DrawingCone<'a> = Drawing<'a, Cone>;
+
+// Trait implementations.
+
+impl Cone {
+    /// The number of divisions around the axis used if none is specified.
+    pub const DEFAULT_SEGMENTS: u32 = 32;
+
+    /// Specify the radius of the cone.
+    ///
+    /// This is short-hand for setting the width and depth to twice the radius.
+    pub fn radius(self, radius: f32) -> Self {
+        let d = radius * 2.0;
+        self.w(d).d(d)
+    }
+
+    /// The number of divisions around the *y* axis of the cone.
+    pub fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Specify the resolution of the cone.
+    ///
+    /// This is short-hand for `segments(resolution)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.segments(resolution)
+    }
+}
+
+impl<'a> DrawingCone<'a> {
+    /// Specify the radius of the cone.
+    ///
+    /// This is short-hand for setting the width and depth to twice the radius.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+
+    /// The number of divisions around the *y* axis of the cone.
+    pub fn segments(self, segments: u32) -> Self {
+        self.map_ty(|ty| ty.segments(segments))
+    }
+
+    /// Specify the resolution of the cone.
+    ///
+    /// This is short-hand for `segments(resolution)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.map_ty(|ty| ty.resolution(resolution))
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Cone {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Cone { solid, segments } = self;
+        let scale = solid::scale(&solid.spatial.dimensions, Vec3::splat(100.0));
+        let geometry = Geometry::cone(segments);
+        solid::render_solid(
+            solid,
+            &geometry,
+            scale,
+            ctxt,
+            &draw::theme::Primitive::Cone,
+            mesh,
+        )
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone {
+            solid: Default::default(),
+            segments: Self::DEFAULT_SEGMENTS,
+        }
+    }
+}
+
+impl SetOrientation for Cone {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.solid)
+    }
+}
+
+impl SetPosition for Cone {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.solid)
+    }
+}
+
+impl SetDimensions for Cone {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.solid)
+    }
+}
+
+impl SetColor<ColorScalar> for Cone {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.solid)
+    }
+}
+
+impl SetSolid for Cone {
+    fn solid_options_mut(&mut self) -> &mut SolidOptions {
+        SetSolid::solid_options_mut(&mut self.solid)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Cone> for Primitive {
+    fn from(prim: Cone) -> Self {
+        Primitive::Cone(prim)
+    }
+}
+
+impl From<Primitive> for Option<Cone> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Cone(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
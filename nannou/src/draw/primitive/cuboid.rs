@@ -0,0 +1,350 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::mesh::vertex;
+use crate::draw::primitive::path;
+use crate::draw::primitive::polygon::{PolygonInit, PolygonOptions, SetPolygon};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, Dashes, LinSrgba, SetColor, SetDashes, SetDimensions, SetOrientation, SetPosition,
+    SetStroke,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{self, cuboid::Face};
+use crate::glam::{Mat3, Mat4, Vec2, Vec3};
+use crate::wgpu;
+use lyon::tessellation::StrokeOptions;
+
+/// The number of faces on a cuboid.
+const NUM_FACES: usize = geom::cuboid::NUM_FACES as usize;
+
+/// Each face in the order in which they are tessellated.
+const FACES: [Face; NUM_FACES] = [
+    Face::Back,
+    Face::Right,
+    Face::Top,
+    Face::Front,
+    Face::Bottom,
+    Face::Left,
+];
+
+/// Properties related to drawing a **Cuboid**.
+#[derive(Clone, Debug)]
+pub struct Cuboid {
+    dimensions: dimension::Properties,
+    polygon: PolygonInit,
+    area: geom::Rect,
+    // Per-face overrides, where later entries take precedence.
+    faces: Vec<(Face, FaceOverride)>,
+    texture_view: Option<wgpu::TextureView>,
+}
+
+// A property of a single face, overriding that of the cuboid as a whole.
+#[derive(Clone, Debug)]
+enum FaceOverride {
+    Color(LinSrgba),
+    Area(geom::Rect),
+}
+
+/// The drawing context for a Cuboid.
+pub type DrawingCuboid<'a> = Drawing<'a, Cuboid>;
+
+// Trait implementations.
+
+impl Cuboid {
+    /// Stroke the outline of each face with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.stroke_color(color)
+    }
+
+    /// Specify the fill color of a single face, overriding the color of the cuboid as a whole.
+    pub fn face_color<C>(mut self, face: Face, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        let color = color.into_lin_srgba();
+        self.faces.push((face, FaceOverride::Color(color)));
+        self
+    }
+
+    /// Texture each face of the cuboid with the given texture.
+    ///
+    /// When textured, the fill and stroke colors are ignored in favour of the texture.
+    pub fn texture(mut self, view: &dyn wgpu::ToTextureView) -> Self {
+        self.texture_view = Some(view.to_texture_view());
+        self
+    }
+
+    /// Specify the area of the texture to draw on every face.
+    ///
+    /// The bounds of the rectangle should represent the desired area as texture coordinates of the
+    /// underlying texture, as with the **Texture** primitive. Each face is mapped as though viewed
+    /// from outside the cuboid, with the top and bottom faces viewed with the back face upwards.
+    ///
+    /// By default, the area represents the full extent of the texture.
+    pub fn area(mut self, rect: geom::Rect) -> Self {
+        self.area = rect;
+        self.faces
+            .retain(|(_, o)| !matches!(o, FaceOverride::Area(_)));
+        self
+    }
+
+    /// Specify the area of the texture to draw on a single face.
+    ///
+    /// See the `area` method for details.
+    pub fn face_area(mut self, face: Face, rect: geom::Rect) -> Self {
+        self.faces.push((face, FaceOverride::Area(rect)));
+        self
+    }
+}
+
+impl<'a> DrawingCuboid<'a> {
+    /// Stroke the outline of each face with the given color.
+    pub fn stroke<C>(self, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.stroke(color))
+    }
+
+    /// Specify the fill color of a single face, overriding the color of the cuboid as a whole.
+    pub fn face_color<C>(self, face: Face, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.face_color(face, color))
+    }
+
+    /// Texture each face of the cuboid with the given texture.
+    ///
+    /// When textured, the fill and stroke colors are ignored in favour of the texture.
+    pub fn texture(self, view: &dyn wgpu::ToTextureView) -> Self {
+        self.map_ty(|ty| ty.texture(view))
+    }
+
+    /// Specify the area of the texture to draw on every face.
+    ///
+    /// The bounds of the rectangle should represent the desired area as texture coordinates of the
+    /// underlying texture, as with the **Texture** primitive. Each face is mapped as though viewed
+    /// from outside the cuboid, with the top and bottom faces viewed with the back face upwards.
+    ///
+    /// By default, the area represents the full extent of the texture.
+    pub fn area(self, rect: geom::Rect) -> Self {
+        self.map_ty(|ty| ty.area(rect))
+    }
+
+    /// Specify the area of the texture to draw on a single face.
+    ///
+    /// See the `area` method for details.
+    pub fn face_area(self, face: Face, rect: geom::Rect) -> Self {
+        self.map_ty(|ty| ty.face_area(face, rect))
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Cuboid {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Cuboid {
+            dimensions,
+            polygon,
+            area,
+            faces,
+            texture_view,
+        } = self;
+        let PolygonOptions {
+            position,
+            orientation,
+            no_fill,
+            stroke_color,
+            color,
+            stroke,
+            stroke_gradient,
+            dashes,
+            ..
+        } = polygon.opts;
+
+        // If dimensions were specified, scale the cuboid to those dimensions.
+        let w = dimensions.x.unwrap_or(100.0);
+        let h = dimensions.y.unwrap_or(100.0);
+        let d = dimensions.z.unwrap_or(100.0);
+        let half = Vec3::new(w, h, d) * 0.5;
+
+        // Determine the transform to apply to all points.
+        let global_transform = *ctxt.transform;
+        let local_transform = position.transform() * orientation.transform();
+        let transform = global_transform * local_transform;
+        let theme_prim = draw::theme::Primitive::Cuboid;
+
+        // Fill each face with a quad of four vertices so that faces may be colored and textured
+        // independently.
+        if !no_fill {
+            let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme_prim));
+            let start = mesh.raw_vertex_count() as u32;
+            let normal_transform = Mat3::from_mat4(transform).inverse().transpose();
+            let mut vertices = Vec::with_capacity(NUM_FACES * 4);
+            for &face in FACES.iter() {
+                let (mut color, mut area) = (color, area);
+                for (_, o) in faces.iter().filter(|&&(f, _)| f == face) {
+                    match *o {
+                        FaceOverride::Color(c) => color = c,
+                        FaceOverride::Area(r) => area = r,
+                    }
+                }
+                let (right, up, normal) = face_axes(face);
+                let center = normal * half;
+                let (hw, hh) = ((right * half).length(), (up * half).length());
+                let face_normal = (normal_transform * normal).normalize_or_zero();
+                for &(su, sv) in &[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+                    let p = center + right * hw * (su * 2.0 - 1.0) + up * hh * (sv * 2.0 - 1.0);
+                    let point = transform.transform_point3(p);
+                    let tex_x = area.x.start + su * (area.x.end - area.x.start);
+                    let tex_y = area.y.end - sv * (area.y.end - area.y.start);
+                    let tex_coords = Vec2::new(tex_x, tex_y);
+                    vertices.push(vertex::with_normal(point, color, tex_coords, face_normal));
+                }
+            }
+            let indices = (0..NUM_FACES as u32).flat_map(|i| {
+                let v = start + i * 4;
+                [v, v + 1, v + 2, v, v + 2, v + 3]
+            });
+            mesh.extend(vertices, indices);
+        }
+
+        // Stroke the outline of each face within the plane of that face.
+        if let Some(stroke_opts) = stroke {
+            for &face in FACES.iter() {
+                let (right, up, normal) = face_axes(face);
+                let center = normal * half;
+                let face_transform = Mat4::from_cols(
+                    right.extend(0.0),
+                    up.extend(0.0),
+                    normal.extend(0.0),
+                    center.extend(1.0),
+                );
+                let rect =
+                    geom::Rect::from_w_h((right * half).length() * 2.0, (up * half).length() * 2.0);
+                let points = rect.corners().vertices().map(|p| p.into());
+                let events = lyon::path::iterator::FromPolyline::closed(points);
+                path::render_path_events(
+                    events,
+                    stroke_color,
+                    stroke_gradient.as_ref(),
+                    dashes.as_ref(),
+                    transform * face_transform,
+                    path::Options::Stroke(stroke_opts),
+                    ctxt.theme,
+                    &theme_prim,
+                    ctxt.fill_tessellator,
+                    ctxt.stroke_tessellator,
+                    mesh,
+                );
+            }
+        }
+
+        match texture_view {
+            None => draw::renderer::PrimitiveRender::default(),
+            Some(texture_view) => draw::renderer::PrimitiveRender::texture(texture_view),
+        }
+    }
+}
+
+// The `(right, up, normal)` axes of the face as seen from outside the cuboid.
+fn face_axes(face: Face) -> (Vec3, Vec3, Vec3) {
+    let (up, normal) = match face {
+        Face::Back => (Vec3::Y, Vec3::Z),
+        Face::Front => (Vec3::Y, -Vec3::Z),
+        Face::Right => (Vec3::Y, Vec3::X),
+        Face::Left => (Vec3::Y, -Vec3::X),
+        Face::Top => (-Vec3::Z, Vec3::Y),
+        Face::Bottom => (Vec3::Z, -Vec3::Y),
+    };
+    (up.cross(normal), up, normal)
+}
+
+impl From<geom::Cuboid<f32>> for Cuboid {
+    fn from(c: geom::Cuboid<f32>) -> Self {
+        let (x, y, z, w, h, d) = c.x_y_z_w_h_d();
+        Self::default().x_y_z(x, y, z).w_h_d(w, h, d)
+    }
+}
+
+impl Default for Cuboid {
+    fn default() -> Self {
+        let dimensions = <_>::default();
+        let polygon = <_>::default();
+        let area = geom::Rect::from_x_y_w_h(0.5, 0.5, 1.0, 1.0);
+        let faces = Vec::new();
+        let texture_view = None;
+        Cuboid {
+            dimensions,
+            polygon,
+            area,
+            faces,
+            texture_view,
+        }
+    }
+}
+
+impl SetOrientation for Cuboid {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.polygon)
+    }
+}
+
+impl SetPosition for Cuboid {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.polygon)
+    }
+}
+
+impl SetDimensions for Cuboid {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.dimensions)
+    }
+}
+
+impl SetColor<ColorScalar> for Cuboid {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.polygon)
+    }
+}
+
+impl SetStroke for Cuboid {
+    fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
+        SetStroke::stroke_options_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Cuboid {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
+impl SetPolygon for Cuboid {
+    fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
+        SetPolygon::polygon_options_mut(&mut self.polygon)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Cuboid> for Primitive {
+    fn from(prim: Cuboid) -> Self {
+        Primitive::Cuboid(prim)
+    }
+}
+
+impl From<Primitive> for Option<Cuboid> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Cuboid(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::draw::primitive::solid::{self, Geometry, SetSolid, SolidOptions};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::glam::Vec3;
+
+/// Properties related to drawing a **Cylinder**.
+#[derive(Clone, Debug)]
+pub struct Cylinder {
+    solid: SolidOptions,
+    segments: u32,
+}
+
+/// The drawing context for a Cylinder.
+pub type DrawingCylinder<'a> = Drawing<'a, Cylinder>;
+
+// Trait implementations.
+
+impl Cylinder {
+    /// The number of divisions around the axis used if none is specified.
+    pub const DEFAULT_SEGMENTS: u32 = 32;
+
+    /// Specify the radius of the cylinder.
+    ///
+    /// This is short-hand for setting the width and depth to twice the radius.
+    pub fn radius(self, radius: f32) -> Self {
+        let d = radius * 2.0;
+        self.w(d).d(d)
+    }
+
+    /// The number of divisions around the *y* axis of the cylinder.
+    pub fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Specify the resolution of the cylinder.
+    ///
+    /// This is short-hand for `segments(resolution)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.segments(resolution)
+    }
+}
+
+impl<'a> DrawingCylinder<'a> {
+    /// Specify the radius of the cylinder.
+    ///
+    /// This is short-hand for setting the width and depth to twice the radius.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+
+    /// The number of divisions around the *y* axis of the cylinder.
+    pub fn segments(self, segments: u32) -> Self {
+        self.map_ty(|ty| ty.segments(segments))
+    }
+
+    /// Specify the resolution of the cylinder.
+    ///
+    /// This is short-hand for `segments(resolution)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.map_ty(|ty| ty.resolution(resolution))
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Cylinder {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Cylinder { solid, segments } = self;
+        let scale = solid::scale(&solid.spatial.dimensions, Vec3::splat(100.0));
+        let geometry = Geometry::cylinder(segments);
+        solid::render_solid(
+            solid,
+            &geometry,
+            scale,
+            ctxt,
+            &draw::theme::Primitive::Cylinder,
+            mesh,
+        )
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Cylinder {
+            solid: Default::default(),
+            segments: Self::DEFAULT_SEGMENTS,
+        }
+    }
+}
+
+impl SetOrientation for Cylinder {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.solid)
+    }
+}
+
+impl SetPosition for Cylinder {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.solid)
+    }
+}
+
+impl SetDimensions for Cylinder {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.solid)
+    }
+}
+
+impl SetColor<ColorScalar> for Cylinder {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.solid)
+    }
+}
+
+impl SetSolid for Cylinder {
+    fn solid_options_mut(&mut self) -> &mut SolidOptions {
+        SetSolid::solid_options_mut(&mut self.solid)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Cylinder> for Primitive {
+    fn from(prim: Cylinder) -> Self {
+        Primitive::Cylinder(prim)
+    }
+}
+
+impl From<Primitive> for Option<Cylinder> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Cylinder(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
@@ -4,7 +4,8 @@ use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPoly
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    spatial, ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+    spatial, ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetDimensions,
+    SetGradient, SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::Drawing;
 use crate::geom;
@@ -57,13 +58,42 @@ impl draw::renderer::RenderPrimitive for Ellipse {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Ellipse {
-            dimensions,
-            polygon,
-            resolution,
-        } = self;
+        if let Some(path) = self.outline() {
+            polygon::render_events_themed(
+                self.polygon.opts,
+                || (&path).into_iter(),
+                ctxt,
+                &draw::theme::Primitive::Ellipse,
+                mesh,
+            );
+        }
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+impl draw::svg::WritePrimitive for Ellipse {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        match self.outline() {
+            None => true,
+            Some(path) => polygon::write_events_themed(
+                &self.polygon.opts,
+                || (&path).into_iter(),
+                ctxt,
+                &draw::theme::Primitive::Ellipse,
+                svg,
+            ),
+        }
+    }
+}
 
+impl Ellipse {
+    // The outline of the ellipse, or `None` if it has no area.
+    //
+    // Without a resolution, the outline is described by arcs.
+    fn outline(&self) -> Option<lyon::path::Path> {
         // First get the dimensions of the ellipse.
+        let dimensions = self.dimensions;
         let (maybe_x, maybe_y, maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
         assert!(
             maybe_z.is_none(),
@@ -72,43 +102,30 @@ impl draw::renderer::RenderPrimitive for Ellipse {
 
         let w = maybe_x.map(f32::abs).unwrap_or(100.0);
         let h = maybe_y.map(f32::abs).unwrap_or(100.0);
-        match resolution {
+        match self.resolution {
             None => {
-                // Determine the transform to apply to all points.
                 let radii = lyon::math::vector(w * 0.5, h * 0.5);
-                if radii.square_length() > 0.0 {
-                    let centre = lyon::math::point(0.0, 0.0);
-                    let mut builder = lyon::path::Path::svg_builder();
-                    let sweep_angle = lyon::math::Angle::radians(std::f32::consts::PI * 2.0);
-                    let x_rotation = lyon::math::Angle::radians(0.0);
-                    let start = lyon::math::point(w * 0.5, 0.0);
-                    builder.move_to(start);
-                    builder.arc(centre, radii, sweep_angle, x_rotation);
-                    let path = builder.build();
-                    polygon::render_events_themed(
-                        polygon.opts,
-                        || (&path).into_iter(),
-                        ctxt,
-                        &draw::theme::Primitive::Ellipse,
-                        mesh,
-                    );
+                if radii.square_length() <= 0.0 {
+                    return None;
                 }
+                let centre = lyon::math::point(0.0, 0.0);
+                let mut builder = lyon::path::Path::svg_builder();
+                let sweep_angle = lyon::math::Angle::radians(std::f32::consts::PI * 2.0);
+                let x_rotation = lyon::math::Angle::radians(0.0);
+                let start = lyon::math::point(w * 0.5, 0.0);
+                builder.move_to(start);
+                builder.arc(centre, radii, sweep_angle, x_rotation);
+                Some(builder.build())
             }
             Some(resolution) => {
                 let rect = geom::Rect::from_w_h(w, h);
                 let ellipse = geom::Ellipse::new(rect, resolution);
-                let points = ellipse.circumference().map(Vec2::from);
-                polygon::render_points_themed(
-                    polygon.opts,
-                    points,
-                    ctxt,
-                    &draw::theme::Primitive::Ellipse,
-                    mesh,
-                );
+                let points = ellipse
+                    .circumference()
+                    .map(|p| Vec2::from(p).to_array().into());
+                Some(lyon::path::iterator::FromPolyline::closed(points).collect())
             }
         }
-
-        draw::renderer::PrimitiveRender::default()
     }
 }
 
@@ -142,6 +159,18 @@ impl SetStroke for Ellipse {
     }
 }
 
+impl SetGradient for Ellipse {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Ellipse {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Ellipse {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
@@ -0,0 +1,142 @@
+use crate::color::conv::IntoLinSrgba;
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::{ColorScalar, LinSrgba};
+use crate::draw::Drawing;
+use crate::glam::{Mat4, Vec3};
+
+/// Properties related to drawing many instances of a single primitive.
+///
+/// The primitive is tessellated once and drawn in a single draw call with a transform and color
+/// per instance, making it suitable for drawing thousands of identical shapes, e.g. particles.
+///
+/// Each instance's transform is applied within the local coordinate space of the primitive,
+/// prior to the transform of the **Draw** instance. Each instance's color multiplies the colors
+/// of the primitive, so primitives that are to be colored per instance are best left white.
+#[derive(Clone, Debug)]
+pub struct Instances {
+    pub(crate) primitive: Box<Primitive>,
+    pub(crate) transforms: Vec<Mat4>,
+    pub(crate) colors: Vec<LinSrgba>,
+}
+
+/// The drawing context for a set of instances.
+pub type DrawingInstances<'a> = Drawing<'a, Instances>;
+
+// Instances-specific methods.
+
+impl Instances {
+    /// Instances of the given primitive.
+    ///
+    /// Nothing is drawn until at least one transform has been specified.
+    pub fn new<T>(primitive: T) -> Self
+    where
+        T: Into<Primitive>,
+    {
+        Instances {
+            primitive: Box::new(primitive.into()),
+            transforms: vec![],
+            colors: vec![],
+        }
+    }
+
+    /// Specify the transform of each instance, replacing any previous transforms.
+    ///
+    /// One instance is drawn per transform.
+    pub fn transforms<I>(mut self, transforms: I) -> Self
+    where
+        I: IntoIterator<Item = Mat4>,
+    {
+        self.transforms.clear();
+        self.transforms.extend(transforms);
+        self
+    }
+
+    /// Specify the position of each instance, replacing any previous transforms.
+    ///
+    /// Short-hand for `transforms` with a translation per instance.
+    pub fn positions<I>(self, positions: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec3>,
+    {
+        let transforms = positions
+            .into_iter()
+            .map(|p| Mat4::from_translation(p.into()));
+        self.transforms(transforms)
+    }
+
+    /// Specify the color of each instance, replacing any previous colors.
+    ///
+    /// Instances without a color are drawn with the colors of the primitive.
+    pub fn colors<I>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoLinSrgba<ColorScalar>,
+    {
+        self.colors.clear();
+        self.colors
+            .extend(colors.into_iter().map(IntoLinSrgba::into_lin_srgba));
+        self
+    }
+
+    /// The number of instances to be drawn.
+    pub fn len(&self) -> usize {
+        self.transforms.len()
+    }
+
+    /// Whether or not there are no instances to be drawn.
+    pub fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+}
+
+// Drawing methods.
+
+impl<'a> DrawingInstances<'a> {
+    /// Specify the transform of each instance, replacing any previous transforms.
+    ///
+    /// One instance is drawn per transform.
+    pub fn transforms<I>(self, transforms: I) -> Self
+    where
+        I: IntoIterator<Item = Mat4>,
+    {
+        self.map_ty(|ty| ty.transforms(transforms))
+    }
+
+    /// Specify the position of each instance, replacing any previous transforms.
+    ///
+    /// Short-hand for `transforms` with a translation per instance.
+    pub fn positions<I>(self, positions: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Vec3>,
+    {
+        self.map_ty(|ty| ty.positions(positions))
+    }
+
+    /// Specify the color of each instance, replacing any previous colors.
+    ///
+    /// Instances without a color are drawn with the colors of the primitive.
+    pub fn colors<I>(self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoLinSrgba<ColorScalar>,
+    {
+        self.map_ty(|ty| ty.colors(colors))
+    }
+}
+
+impl From<Instances> for Primitive {
+    fn from(prim: Instances) -> Self {
+        Primitive::Instances(prim)
+    }
+}
+
+impl From<Primitive> for Option<Instances> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Instances(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
@@ -2,7 +2,10 @@ use crate::color::LinSrgba;
 use crate::draw::primitive::path;
 use crate::draw::primitive::{PathStroke, Primitive};
 use crate::draw::properties::spatial::{orientation, position};
-use crate::draw::properties::{ColorScalar, SetColor, SetOrientation, SetPosition, SetStroke};
+use crate::draw::properties::{
+    ColorScalar, Dashes, Gradient, SetColor, SetDashes, SetGradient, SetOrientation, SetPosition,
+    SetStroke,
+};
 use crate::draw::{self, Drawing};
 use crate::geom::{pt2, Point2};
 use lyon::tessellation::StrokeOptions;
@@ -105,6 +108,18 @@ impl SetPosition for Line {
     }
 }
 
+impl SetGradient for Line {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.path)
+    }
+}
+
+impl SetDashes for Line {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.path)
+    }
+}
+
 impl SetColor<ColorScalar> for Line {
     fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
         SetColor::rgba_mut(&mut self.path)
@@ -151,6 +166,8 @@ impl draw::renderer::RenderPrimitive for Line {
         path::render_path_events(
             events,
             path.color,
+            path.gradient.as_ref(),
+            path.dashes.as_ref(),
             transform,
             path::Options::Stroke(path.opts),
             &ctxt.theme,
@@ -163,3 +180,35 @@ impl draw::renderer::RenderPrimitive for Line {
         draw::renderer::PrimitiveRender::default()
     }
 }
+
+impl draw::svg::WritePrimitive for Line {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        let Line {
+            ref path,
+            start,
+            end,
+        } = *self;
+        let start = start.unwrap_or(pt2(0.0, 0.0));
+        let end = end.unwrap_or(pt2(0.0, 0.0));
+        if start == end {
+            return true;
+        }
+        if !draw::svg::supports_gradient(path.gradient.as_ref()) {
+            return false;
+        }
+        let close = false;
+        let points = [start, end];
+        let points = points.iter().cloned().map(|p| p.to_array().into());
+        let events = lyon::path::iterator::FromPolyline::new(close, points);
+
+        // Determine the transform to apply to all points.
+        let local_transform = path.position.transform() * path.orientation.transform();
+        let transform = ctxt.transform * local_transform;
+
+        let options = path::Options::Stroke(path.opts);
+        let color = ctxt.color(path.color, &options, &draw::theme::Primitive::Line);
+        let (gradient, dashes) = (path.gradient.as_ref(), path.dashes.as_ref());
+        svg.path(events, color, gradient, dashes, transform, &options);
+        true
+    }
+}
@@ -1,10 +1,11 @@
 use crate::color::conv::IntoLinSrgba;
-use crate::draw::mesh::vertex::{self, Point, TexCoords, Vertex};
+use crate::draw::mesh::vertex::{self, Normal, Point, TexCoords, Vertex};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition};
 use crate::draw::{self, Drawing};
 use crate::geom;
+use crate::glam::Mat3;
 use crate::wgpu;
 use std::ops;
 
@@ -59,7 +60,7 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = t.into();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Texture;
         self.points_inner(
@@ -85,7 +86,7 @@ impl Vertexless {
             let point = p.into();
             let color = c.into_lin_srgba();
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         self.points_inner(inner_mesh, vertices, vertex_mode, None)
@@ -107,7 +108,35 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
+        });
+        let vertex_mode = draw::renderer::VertexMode::Color;
+        let mut mesh = self.points_inner(inner_mesh, vertices, vertex_mode, None);
+        mesh.fill_color = Some(FillColor(None));
+        mesh
+    }
+
+    /// Describe the mesh with a sequence of points along with their normals.
+    ///
+    /// Each of the vertices must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`. Normals are
+    /// used to light the mesh and are transformed along with its points.
+    ///
+    /// This method assumes that the entire mesh should be coloured with a single colour. If a
+    /// colour is not specified via one of the builder methods, a default colour will be retrieved
+    /// from the inner `Theme`.
+    pub fn points_with_normals<I, P, N>(self, inner_mesh: &mut draw::Mesh, points: I) -> Mesh
+    where
+        I: IntoIterator<Item = (P, N)>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        let vertices = points.into_iter().map(|(p, n)| {
+            let point = p.into();
+            let color = vertex::DEFAULT_VERTEX_COLOR;
+            let tex_coords = vertex::default_tex_coords();
+            vertex::with_normal(point, color, tex_coords, n.into())
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         let mut mesh = self.points_inner(inner_mesh, vertices, vertex_mode, None);
@@ -223,7 +252,7 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = t.into();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Texture;
         self.indexed_inner(
@@ -258,7 +287,7 @@ impl Vertexless {
             let point = p.into();
             let color = c.into_lin_srgba();
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         self.indexed_inner(inner_mesh, vertices, indices, vertex_mode, None)
@@ -279,7 +308,38 @@ impl Vertexless {
             let point = p.into();
             let color = vertex::DEFAULT_VERTEX_COLOR;
             let tex_coords = vertex::default_tex_coords();
-            ((point, color), tex_coords).into()
+            vertex::new(point, color, tex_coords)
+        });
+        let vertex_mode = draw::renderer::VertexMode::Color;
+        let mut mesh = self.indexed_inner(inner_mesh, vertices, indices, vertex_mode, None);
+        mesh.fill_color = Some(FillColor(None));
+        mesh
+    }
+
+    /// Describe the mesh with the given indexed points along with their normals.
+    ///
+    /// Each trio of `indices` describes a single triangle made up of `points`.
+    ///
+    /// Each of the `points` must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    pub fn indexed_with_normals<V, I, P, N>(
+        self,
+        inner_mesh: &mut draw::Mesh,
+        points: V,
+        indices: I,
+    ) -> Mesh
+    where
+        V: IntoIterator<Item = (P, N)>,
+        I: IntoIterator<Item = usize>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        let vertices = points.into_iter().map(|(p, n)| {
+            let point = p.into();
+            let color = vertex::DEFAULT_VERTEX_COLOR;
+            let tex_coords = vertex::default_tex_coords();
+            vertex::with_normal(point, color, tex_coords, n.into())
         });
         let vertex_mode = draw::renderer::VertexMode::Color;
         let mut mesh = self.indexed_inner(inner_mesh, vertices, indices, vertex_mode, None);
@@ -381,6 +441,25 @@ impl<'a> Drawing<'a, Vertexless> {
         self.map_ty_with_context(|ty, ctxt| ty.points_textured(ctxt.mesh, view, points))
     }
 
+    /// Describe the mesh with a sequence of points along with their normals.
+    ///
+    /// Each of the vertices must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`. Normals are
+    /// used to light the mesh and are transformed along with its points.
+    ///
+    /// This method assumes that the entire mesh should be coloured with a single colour. If a
+    /// colour is not specified via one of the builder methods, a default colour will be retrieved
+    /// from the inner `Theme`.
+    pub fn points_with_normals<I, P, N>(self, points: I) -> DrawingMesh<'a>
+    where
+        I: IntoIterator<Item = (P, N)>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.points_with_normals(ctxt.mesh, points))
+    }
+
     /// Describe the mesh with a sequence of triangles.
     ///
     /// Each triangle may be composed of any vertex type that may be converted directly into
@@ -457,6 +536,23 @@ impl<'a> Drawing<'a, Vertexless> {
         self.map_ty_with_context(|ty, ctxt| ty.indexed_colored(ctxt.mesh, points, indices))
     }
 
+    /// Describe the mesh with the given indexed points along with their normals.
+    ///
+    /// Each trio of `indices` describes a single triangle made up of `points`.
+    ///
+    /// Each of the `points` must be represented as a tuple containing the point and the normal in
+    /// that order, e.g. `(point, normal)`. `point` may be of any type that implements
+    /// `Into<Point3>` and `normal` may be of any type that implements `Into<Vec3>`.
+    pub fn indexed_with_normals<V, I, P, N>(self, points: V, indices: I) -> DrawingMesh<'a>
+    where
+        V: IntoIterator<Item = (P, N)>,
+        I: IntoIterator<Item = usize>,
+        P: Into<Point>,
+        N: Into<Normal>,
+    {
+        self.map_ty_with_context(|ty, ctxt| ty.indexed_with_normals(ctxt.mesh, points, indices))
+    }
+
     /// Describe the mesh with the given indexed, textured points.
     ///
     /// Each trio of `indices` describes a single triangle made up of colored `points`.
@@ -512,6 +608,11 @@ impl draw::renderer::RenderPrimitive for Mesh {
         // A small function for transforming a point via the transform matrix.
         let transform_point = |p: geom::Point3| -> geom::Point3 { transform.transform_point3(p) };
 
+        // Normals are transformed by the inverse transpose so that they remain perpendicular to
+        // the surface under non-uniform scaling.
+        let normal_transform = Mat3::from_mat4(transform).inverse().transpose();
+        let transform_normal = |n: vertex::Normal| (normal_transform * n).normalize_or_zero();
+
         // Color the vertices based on whether or not we should fill, then extend the mesh!
         match fill_color {
             Some(fill) => {
@@ -522,7 +623,8 @@ impl draw::renderer::RenderPrimitive for Mesh {
                 let vertices = vertex_range.map(|i| {
                     let point = transform_point(ctxt.intermediary_mesh.points()[i]);
                     let tex_coords = ctxt.intermediary_mesh.tex_coords()[i];
-                    ((point, color), tex_coords).into()
+                    let normal = transform_normal(ctxt.intermediary_mesh.normals()[i]);
+                    vertex::with_normal(point, color, tex_coords, normal)
                 });
                 mesh.extend(vertices, indices);
             }
@@ -531,7 +633,8 @@ impl draw::renderer::RenderPrimitive for Mesh {
                     let point = transform_point(ctxt.intermediary_mesh.points()[i]);
                     let color = ctxt.intermediary_mesh.colors()[i];
                     let tex_coords = ctxt.intermediary_mesh.tex_coords()[i];
-                    ((point, color), tex_coords).into()
+                    let normal = transform_normal(ctxt.intermediary_mesh.normals()[i]);
+                    vertex::with_normal(point, color, tex_coords, normal)
                 });
                 mesh.extend(vertices, indices);
             }
@@ -1,25 +1,43 @@
 pub mod arrow;
+pub mod cone;
+pub mod cuboid;
+pub mod cylinder;
 pub mod ellipse;
+pub mod instances;
 pub mod line;
 pub mod mesh;
 pub mod path;
+pub mod plane;
 pub mod polygon;
 pub mod quad;
 pub mod rect;
+pub mod solid;
+pub mod sphere;
+pub mod svg;
 pub mod text;
 pub mod texture;
+pub mod torus;
 pub mod tri;
 
 pub use self::arrow::Arrow;
+pub use self::cone::Cone;
+pub use self::cuboid::Cuboid;
+pub use self::cylinder::Cylinder;
 pub use self::ellipse::Ellipse;
+pub use self::instances::Instances;
 pub use self::line::Line;
 pub use self::mesh::Mesh;
 pub use self::path::{Path, PathFill, PathInit, PathStroke};
+pub use self::plane::Plane;
 pub use self::polygon::{Polygon, PolygonInit};
 pub use self::quad::Quad;
 pub use self::rect::Rect;
+pub use self::solid::{SetSolid, SolidOptions};
+pub use self::sphere::Sphere;
+pub use self::svg::Svg;
 pub use self::text::Text;
 pub use self::texture::Texture;
+pub use self::torus::Torus;
 pub use self::tri::Tri;
 
 /// A wrapper around all primitive sets of properties so that they may be stored within the
@@ -30,7 +48,11 @@ pub use self::tri::Tri;
 #[derive(Clone, Debug)]
 pub enum Primitive {
     Arrow(Arrow),
+    Cone(Cone),
+    Cuboid(Cuboid),
+    Cylinder(Cylinder),
     Ellipse(Ellipse),
+    Instances(Instances),
     Line(Line),
     MeshVertexless(mesh::Vertexless),
     Mesh(Mesh),
@@ -38,11 +60,15 @@ pub enum Primitive {
     PathFill(PathFill),
     PathStroke(PathStroke),
     Path(Path),
+    Plane(Plane),
     PolygonInit(PolygonInit),
     Polygon(Polygon),
     Quad(Quad),
     Rect(Rect),
+    Sphere(Sphere),
+    Svg(Svg),
     Text(Text),
     Texture(Texture),
+    Torus(Torus),
     Tri(Tri),
 }
@@ -4,14 +4,18 @@ use crate::draw::mesh::vertex::{Color, TexCoords};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{
-    ColorScalar, SetColor, SetFill, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Dashes, Gradient, SetColor, SetDashes, SetFill, SetGradient, SetOrientation,
+    SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing, DrawingContext};
 use crate::geom::Point2;
 use crate::glam::Mat4;
 use crate::wgpu;
 use lyon::path::PathEvent;
-use lyon::tessellation::{FillOptions, FillTessellator, StrokeOptions, StrokeTessellator};
+use lyon::tessellation::{
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, StrokeOptions, StrokeTessellator,
+    StrokeVertex, VertexBuffers,
+};
 
 /// A set of path tessellation options (FillOptions or StrokeOptions).
 pub trait TessellationOptions {
@@ -59,6 +63,8 @@ pub struct PathInit;
 pub struct PathOptions<T> {
     pub(crate) opts: T,
     pub(crate) color: Option<LinSrgba>,
+    pub(crate) gradient: Option<Gradient>,
+    pub(crate) dashes: Option<Dashes>,
     pub(crate) position: position::Properties,
     pub(crate) orientation: orientation::Properties,
 }
@@ -79,6 +85,8 @@ pub type PathStroke = PathOptions<StrokeOptions>;
 #[derive(Clone, Debug)]
 pub struct Path {
     color: Option<LinSrgba>,
+    gradient: Option<Gradient>,
+    dashes: Option<Dashes>,
     position: position::Properties,
     orientation: orientation::Properties,
     path_event_src: PathEventSource,
@@ -133,11 +141,15 @@ impl<T> PathOptions<T> {
         let orientation = Default::default();
         let position = Default::default();
         let color = Default::default();
+        let gradient = Default::default();
+        let dashes = Default::default();
         PathOptions {
             opts,
             orientation,
             position,
             color,
+            gradient,
+            dashes,
         }
     }
 }
@@ -191,6 +203,8 @@ where
             self.position,
             self.orientation,
             self.color,
+            self.gradient,
+            self.dashes,
             PathEventSource::Buffered(start..end),
             self.opts.into_options(),
             draw::renderer::VertexMode::Color,
@@ -307,6 +321,8 @@ where
             self.position,
             self.orientation,
             self.color,
+            self.gradient,
+            self.dashes,
             path_event_src,
             self.opts.into_options(),
             draw::renderer::VertexMode::Color,
@@ -343,6 +359,8 @@ where
             self.position,
             self.orientation,
             self.color,
+            self.gradient,
+            self.dashes,
             path_event_src,
             self.opts.into_options(),
             draw::renderer::VertexMode::Texture,
@@ -351,9 +369,12 @@ where
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn render_path_events<I>(
     events: I,
     color: Option<LinSrgba>,
+    gradient: Option<&Gradient>,
+    dashes: Option<&Dashes>,
     transform: Mat4,
     options: Options,
     theme: &draw::Theme,
@@ -364,6 +385,37 @@ pub(crate) fn render_path_events<I>(
 ) where
     I: IntoIterator<Item = lyon::path::PathEvent>,
 {
+    // Split dashed strokes into a sub-path per dash prior to tessellation.
+    if let (Options::Stroke(stroke_options), Some(dashes)) = (&options, dashes) {
+        let events = dashes.dash(events, stroke_options.tolerance);
+        let options = Options::Stroke(dashes.stroke_options(*stroke_options));
+        render_path_events(
+            events,
+            color,
+            gradient,
+            None,
+            transform,
+            options,
+            theme,
+            theme_prim,
+            fill_tessellator,
+            stroke_tessellator,
+            mesh,
+        );
+        return;
+    }
+    if let Some(gradient) = gradient.filter(|g| !g.stops.is_empty()) {
+        render_path_events_gradient(
+            events,
+            gradient,
+            transform,
+            options,
+            fill_tessellator,
+            stroke_tessellator,
+            mesh,
+        );
+        return;
+    }
     let res = match options {
         Options::Fill(options) => {
             let color = color.unwrap_or_else(|| theme.fill_lin_srgba(theme_prim));
@@ -381,6 +433,56 @@ pub(crate) fn render_path_events<I>(
     }
 }
 
+// Tessellate the path in local space, subdivide it to fit the gradient and extend the mesh with
+// the result.
+fn render_path_events_gradient<I>(
+    events: I,
+    gradient: &Gradient,
+    transform: Mat4,
+    options: Options,
+    fill_tessellator: &mut lyon::tessellation::FillTessellator,
+    stroke_tessellator: &mut lyon::tessellation::StrokeTessellator,
+    mesh: &mut draw::Mesh,
+) where
+    I: IntoIterator<Item = lyon::path::PathEvent>,
+{
+    let mut buffers: VertexBuffers<Point2, u32> = VertexBuffers::new();
+    let res = match options {
+        Options::Fill(options) => {
+            let mut builder = BuffersBuilder::new(&mut buffers, |v: FillVertex| {
+                Point2::new(v.position().x, v.position().y)
+            });
+            fill_tessellator.tessellate(events, &options, &mut builder)
+        }
+        Options::Stroke(options) => {
+            let mut builder = BuffersBuilder::new(&mut buffers, |v: StrokeVertex| {
+                Point2::new(v.position().x, v.position().y)
+            });
+            stroke_tessellator.tessellate(events, &options, &mut builder)
+        }
+    };
+    if let Err(err) = res {
+        eprintln!("failed to tessellate path: {:?}", err);
+        return;
+    }
+
+    let VertexBuffers {
+        mut vertices,
+        mut indices,
+    } = buffers;
+    gradient.subdivide(&mut vertices, &mut indices);
+
+    // Extend the mesh, coloring each vertex by the gradient at its local position.
+    let offset = mesh.points().len() as u32;
+    let tex_coords = draw::mesh::vertex::default_tex_coords();
+    for p in vertices {
+        let point = transform.transform_point3(p.extend(0.0));
+        let vertex = draw::mesh::vertex::new(point, gradient.color(p), tex_coords);
+        mesh.push_vertex(vertex);
+    }
+    mesh.extend_indices(indices.into_iter().map(|i| i + offset));
+}
+
 pub(crate) fn render_path_points_colored<I>(
     points_colored: I,
     close: bool,
@@ -459,10 +561,13 @@ pub(crate) fn render_path_points_textured<I>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn render_path_source(
     // TODO:
     path_src: PathEventSourceIter,
     color: Option<LinSrgba>,
+    gradient: Option<&Gradient>,
+    dashes: Option<&Dashes>,
     transform: Mat4,
     options: Options,
     theme: &draw::Theme,
@@ -475,6 +580,8 @@ pub(crate) fn render_path_source(
         PathEventSourceIter::Events(events) => render_path_events(
             events,
             color,
+            gradient,
+            dashes,
             transform,
             options,
             theme,
@@ -483,6 +590,27 @@ pub(crate) fn render_path_source(
             stroke_tessellator,
             mesh,
         ),
+        // A gradient or dashed stroke takes precedence over the colors of the points.
+        PathEventSourceIter::ColoredPoints { points, close }
+            if gradient.map(|g| !g.stops.is_empty()).unwrap_or(false)
+                || (dashes.is_some() && matches!(options, Options::Stroke(_))) =>
+        {
+            let points = points.map(|(p, _)| lyon::math::point(p.x, p.y));
+            let events = lyon::path::iterator::FromPolyline::new(close, points);
+            render_path_events(
+                events,
+                color,
+                gradient,
+                dashes,
+                transform,
+                options,
+                theme,
+                theme_prim,
+                fill_tessellator,
+                stroke_tessellator,
+                mesh,
+            )
+        }
         PathEventSourceIter::ColoredPoints { points, close } => render_path_points_colored(
             points,
             close,
@@ -512,6 +640,8 @@ impl draw::renderer::RenderPrimitive for Path {
     ) -> draw::renderer::PrimitiveRender {
         let Path {
             color,
+            gradient,
+            dashes,
             position,
             orientation,
             path_event_src,
@@ -534,6 +664,8 @@ impl draw::renderer::RenderPrimitive for Path {
                 render_path_source(
                     src,
                     color,
+                    gradient.as_ref(),
+                    dashes.as_ref(),
                     transform,
                     options,
                     theme,
@@ -590,6 +722,42 @@ impl draw::renderer::RenderPrimitive for Path {
     }
 }
 
+impl draw::svg::WritePrimitive for Path {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        let gradient = self.gradient.as_ref();
+        if self.texture_view.is_some() || !draw::svg::supports_gradient(gradient) {
+            return false;
+        }
+
+        // Determine the transform to apply to all points.
+        let local_transform = self.position.transform() * self.orientation.transform();
+        let transform = ctxt.transform * local_transform;
+
+        let color = ctxt.color(self.color, &self.options, &draw::theme::Primitive::Path);
+        let dashes = self.dashes.as_ref();
+        match self.path_event_src {
+            PathEventSource::Buffered(ref range) => {
+                let events = ctxt.path_event_buffer[range.clone()].iter().cloned();
+                svg.path(events, color, gradient, dashes, transform, &self.options);
+                true
+            }
+            // A gradient or dashed stroke takes precedence over the colors of the points,
+            // otherwise the path is tessellated with its per-vertex colors.
+            PathEventSource::ColoredPoints { ref range, close }
+                if gradient.map(|g| !g.stops.is_empty()).unwrap_or(false)
+                    || (dashes.is_some() && matches!(self.options, Options::Stroke(_))) =>
+            {
+                let points = ctxt.path_points_colored_buffer[range.clone()].iter();
+                let points = points.map(|&(p, _)| lyon::math::point(p.x, p.y));
+                let events = lyon::path::iterator::FromPolyline::new(close, points);
+                svg.path(events, color, gradient, dashes, transform, &self.options);
+                true
+            }
+            PathEventSource::ColoredPoints { .. } | PathEventSource::TexturedPoints { .. } => false,
+        }
+    }
+}
+
 /// Create a lyon path for the given iterator of colored points.
 pub fn points_colored_to_lyon_path<I>(points_colored: I, close: bool) -> Option<lyon::path::Path>
 where
@@ -656,6 +824,8 @@ impl Path {
         position: position::Properties,
         orientation: orientation::Properties,
         color: Option<LinSrgba>,
+        gradient: Option<Gradient>,
+        dashes: Option<Dashes>,
         path_event_src: PathEventSource,
         options: Options,
         vertex_mode: draw::renderer::VertexMode,
@@ -663,6 +833,8 @@ impl Path {
     ) -> Self {
         Path {
             color,
+            gradient,
+            dashes,
             orientation,
             position,
             path_event_src,
@@ -846,6 +1018,18 @@ impl<T> SetColor<ColorScalar> for PathOptions<T> {
     }
 }
 
+impl<T> SetGradient for PathOptions<T> {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        &mut self.gradient
+    }
+}
+
+impl SetDashes for PathStroke {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.dashes)
+    }
+}
+
 impl SetOrientation for Path {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.orientation)
@@ -864,6 +1048,18 @@ impl SetColor<ColorScalar> for Path {
     }
 }
 
+impl SetGradient for Path {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        &mut self.gradient
+    }
+}
+
+impl SetDashes for Path {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.dashes)
+    }
+}
+
 impl From<PathInit> for Primitive {
     fn from(prim: PathInit) -> Self {
         Primitive::PathInit(prim)
@@ -0,0 +1,148 @@
+use crate::draw::primitive::solid::{self, Geometry, SetSolid, SolidOptions};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::glam::Vec3;
+
+/// Properties related to drawing a **Plane**.
+///
+/// Unlike the **Rect**, the plane is a subdivided grid with normals, suitable for use within 3D
+/// scenes.
+#[derive(Clone, Debug)]
+pub struct Plane {
+    solid: SolidOptions,
+    columns: u32,
+    rows: u32,
+}
+
+/// The drawing context for a Plane.
+pub type DrawingPlane<'a> = Drawing<'a, Plane>;
+
+// Trait implementations.
+
+impl Plane {
+    /// The number of columns and rows used if none are specified.
+    pub const DEFAULT_SUBDIVISIONS: u32 = 1;
+
+    /// The number of divisions along the *x* axis.
+    pub fn columns(mut self, columns: u32) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// The number of divisions along the *y* axis.
+    pub fn rows(mut self, rows: u32) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Specify the resolution of the plane.
+    ///
+    /// This is short-hand for `columns(resolution)` and `rows(resolution)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.columns(resolution).rows(resolution)
+    }
+}
+
+impl<'a> DrawingPlane<'a> {
+    /// The number of divisions along the *x* axis.
+    pub fn columns(self, columns: u32) -> Self {
+        self.map_ty(|ty| ty.columns(columns))
+    }
+
+    /// The number of divisions along the *y* axis.
+    pub fn rows(self, rows: u32) -> Self {
+        self.map_ty(|ty| ty.rows(rows))
+    }
+
+    /// Specify the resolution of the plane.
+    ///
+    /// This is short-hand for `columns(resolution)` and `rows(resolution)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.map_ty(|ty| ty.resolution(resolution))
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Plane {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Plane {
+            solid,
+            columns,
+            rows,
+        } = self;
+        let scale = solid::scale(&solid.spatial.dimensions, Vec3::new(100.0, 100.0, 1.0));
+        let geometry = Geometry::plane(columns, rows);
+        solid::render_solid(
+            solid,
+            &geometry,
+            scale,
+            ctxt,
+            &draw::theme::Primitive::Plane,
+            mesh,
+        )
+    }
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Plane {
+            solid: Default::default(),
+            columns: Self::DEFAULT_SUBDIVISIONS,
+            rows: Self::DEFAULT_SUBDIVISIONS,
+        }
+    }
+}
+
+impl SetOrientation for Plane {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.solid)
+    }
+}
+
+impl SetPosition for Plane {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.solid)
+    }
+}
+
+impl SetDimensions for Plane {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.solid)
+    }
+}
+
+impl SetColor<ColorScalar> for Plane {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.solid)
+    }
+}
+
+impl SetSolid for Plane {
+    fn solid_options_mut(&mut self) -> &mut SolidOptions {
+        SetSolid::solid_options_mut(&mut self.solid)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Plane> for Primitive {
+    fn from(prim: Plane) -> Self {
+        Primitive::Plane(prim)
+    }
+}
+
+impl From<Primitive> for Option<Plane> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Plane(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
@@ -5,7 +5,8 @@ use crate::draw::primitive::path::{self, PathEventSource};
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{orientation, position};
 use crate::draw::properties::{
-    ColorScalar, LinSrgba, SetColor, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetGradient, SetOrientation,
+    SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
 use crate::geom::Point2;
@@ -36,6 +37,14 @@ pub trait SetPolygon: Sized {
         self
     }
 
+    /// Specify a gradient to use for stroke tessellation in place of the stroke color.
+    ///
+    /// As with `stroke_color`, the stroke is only tessellated once a `SetStroke` method is called.
+    fn stroke_gradient(mut self, gradient: Gradient) -> Self {
+        self.polygon_options_mut().stroke_gradient = Some(gradient);
+        self
+    }
+
     /// Specify the whole set of polygon options.
     fn polygon_options(mut self, opts: PolygonOptions) -> Self {
         *self.polygon_options_mut() = opts;
@@ -58,6 +67,9 @@ pub struct PolygonOptions {
     pub stroke_color: Option<LinSrgba>,
     pub color: Option<LinSrgba>,
     pub stroke: Option<StrokeOptions>,
+    pub gradient: Option<Gradient>,
+    pub stroke_gradient: Option<Gradient>,
+    pub dashes: Option<Dashes>,
 }
 
 /// A polygon with vertices already submitted.
@@ -191,6 +203,9 @@ pub fn render_events_themed<F, I>(
         stroke_color,
         color,
         stroke,
+        gradient,
+        stroke_gradient,
+        dashes,
     } = opts;
 
     // Determine the transform to apply to all points.
@@ -202,12 +217,15 @@ pub fn render_events_themed<F, I>(
     let mut render =
         |opts: path::Options,
          color: Option<LinSrgba>,
+         gradient: Option<&Gradient>,
          theme: &draw::Theme,
          fill_tessellator: &mut lyon::tessellation::FillTessellator,
          stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
             path::render_path_events(
                 events(),
                 color,
+                gradient,
+                dashes.as_ref(),
                 transform,
                 opts,
                 theme,
@@ -224,6 +242,7 @@ pub fn render_events_themed<F, I>(
         render(
             opts,
             color,
+            gradient.as_ref(),
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
@@ -237,6 +256,7 @@ pub fn render_events_themed<F, I>(
         render(
             opts,
             color,
+            stroke_gradient.as_ref(),
             &ctxt.theme,
             &mut ctxt.fill_tessellator,
             &mut ctxt.stroke_tessellator,
@@ -262,6 +282,77 @@ pub fn render_points_themed<I>(
     );
 }
 
+/// Write the fill and stroke of the given path events to an SVG document, falling back to the
+/// theme's colors for the given primitive.
+///
+/// Returns `false` without writing anything if either paint has no SVG equivalent.
+pub(crate) fn write_events_themed<F, I>(
+    opts: &PolygonOptions,
+    events: F,
+    ctxt: &draw::svg::Context,
+    theme_primitive: &draw::theme::Primitive,
+    svg: &mut draw::svg::Writer,
+) -> bool
+where
+    F: Fn() -> I,
+    I: Iterator<Item = lyon::path::PathEvent>,
+{
+    let PolygonOptions {
+        ref position,
+        ref orientation,
+        no_fill,
+        stroke_color,
+        color,
+        stroke,
+        ref gradient,
+        ref stroke_gradient,
+        ref dashes,
+    } = *opts;
+    if !draw::svg::supports_gradient(gradient.as_ref())
+        || !draw::svg::supports_gradient(stroke_gradient.as_ref())
+    {
+        return false;
+    }
+
+    // Determine the transform to apply to all points.
+    let transform = ctxt.transform * position.transform() * orientation.transform();
+
+    // Write the fill first, then the stroke on top.
+    if !no_fill {
+        let opts = path::Options::Fill(lyon::tessellation::FillOptions::default());
+        let color = ctxt.color(color, &opts, theme_primitive);
+        svg.path(events(), color, gradient.as_ref(), None, transform, &opts);
+    }
+    if let Some(stroke_opts) = stroke {
+        let opts = path::Options::Stroke(stroke_opts);
+        let color = ctxt.color(stroke_color, &opts, theme_primitive);
+        let gradient = stroke_gradient.as_ref();
+        svg.path(events(), color, gradient, dashes.as_ref(), transform, &opts);
+    }
+    true
+}
+
+/// Write the fill and stroke of the closed polygon described by the given points to an SVG
+/// document.
+pub(crate) fn write_points_themed<I>(
+    opts: &PolygonOptions,
+    points: I,
+    ctxt: &draw::svg::Context,
+    theme_primitive: &draw::theme::Primitive,
+    svg: &mut draw::svg::Writer,
+) -> bool
+where
+    I: Clone + Iterator<Item = Point2>,
+{
+    write_events_themed(
+        opts,
+        || lyon::path::iterator::FromPolyline::closed(points.clone().map(|p| p.to_array().into())),
+        ctxt,
+        theme_primitive,
+        svg,
+    )
+}
+
 impl Polygon {
     pub(crate) fn render_themed(
         self,
@@ -279,6 +370,9 @@ impl Polygon {
                     stroke_color,
                     color,
                     stroke,
+                    gradient,
+                    stroke_gradient,
+                    dashes,
                 },
             texture_view,
         } = self;
@@ -303,12 +397,15 @@ impl Polygon {
             |src: path::PathEventSourceIter,
              opts: path::Options,
              color: Option<LinSrgba>,
+             gradient: Option<&Gradient>,
              theme: &draw::Theme,
              fill_tessellator: &mut lyon::tessellation::FillTessellator,
              stroke_tessellator: &mut lyon::tessellation::StrokeTessellator| {
                 path::render_path_source(
                     src,
                     color,
+                    gradient,
+                    dashes.as_ref(),
                     transform,
                     opts,
                     theme,
@@ -330,6 +427,7 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -346,6 +444,7 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -362,6 +461,7 @@ impl Polygon {
                         src,
                         opts,
                         color,
+                        gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -381,6 +481,7 @@ impl Polygon {
                         src,
                         opts,
                         stroke_color,
+                        stroke_gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -401,6 +502,7 @@ impl Polygon {
                         src,
                         opts,
                         stroke_color,
+                        stroke_gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -416,6 +518,7 @@ impl Polygon {
                         src,
                         opts,
                         stroke_color,
+                        stroke_gradient.as_ref(),
                         theme,
                         fill_tessellator,
                         stroke_tessellator,
@@ -444,6 +547,41 @@ impl draw::renderer::RenderPrimitive for Polygon {
     }
 }
 
+impl draw::svg::WritePrimitive for Polygon {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        let theme_primitive = &draw::theme::Primitive::Polygon;
+        if self.texture_view.is_some() {
+            return false;
+        }
+        match self.path_event_src {
+            PathEventSource::Buffered(ref range) => {
+                let events = &ctxt.path_event_buffer[range.clone()];
+                write_events_themed(
+                    &self.opts,
+                    || events.iter().cloned(),
+                    ctxt,
+                    theme_primitive,
+                    svg,
+                )
+            }
+            // The fill takes the colors of the points unless painted with a gradient.
+            PathEventSource::ColoredPoints { ref range, close } => {
+                let gradient = self.opts.gradient.as_ref();
+                if !self.opts.no_fill && gradient.map(|g| g.stops.is_empty()).unwrap_or(true) {
+                    return false;
+                }
+                let points = &ctxt.path_points_colored_buffer[range.clone()];
+                let events = || {
+                    let points = points.iter().map(|&(p, _)| p.to_array().into());
+                    lyon::path::iterator::FromPolyline::new(close, points)
+                };
+                write_events_themed(&self.opts, events, ctxt, theme_primitive, svg)
+            }
+            PathEventSource::TexturedPoints { .. } => false,
+        }
+    }
+}
+
 impl<'a, T> Drawing<'a, T>
 where
     T: SetPolygon + Into<Primitive>,
@@ -465,6 +603,13 @@ where
         self.map_ty(|ty| ty.stroke_color(color))
     }
 
+    /// Specify a gradient to use for stroke tessellation in place of the stroke color.
+    ///
+    /// As with `stroke_color`, the stroke is only tessellated once a `SetStroke` method is called.
+    pub fn stroke_gradient(self, gradient: Gradient) -> Self {
+        self.map_ty(|ty| ty.stroke_gradient(gradient))
+    }
+
     /// Specify the whole set of polygon options.
     pub fn polygon_options(self, opts: PolygonOptions) -> Self {
         self.map_ty(|ty| ty.polygon_options(opts))
@@ -552,6 +697,18 @@ impl SetPolygon for PolygonInit {
     }
 }
 
+impl SetGradient for PolygonInit {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        &mut self.opts.gradient
+    }
+}
+
+impl SetDashes for PolygonInit {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.opts.dashes)
+    }
+}
+
 impl SetStroke for PolygonInit {
     fn stroke_options_mut(&mut self) -> &mut StrokeOptions {
         SetStroke::stroke_options_mut(&mut self.opts.stroke)
@@ -576,6 +733,18 @@ impl SetColor<ColorScalar> for Polygon {
     }
 }
 
+impl SetGradient for Polygon {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        &mut self.opts.gradient
+    }
+}
+
+impl SetDashes for Polygon {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.opts.dashes)
+    }
+}
+
 impl From<PolygonInit> for Primitive {
     fn from(prim: PolygonInit) -> Self {
         Primitive::PolygonInit(prim)
@@ -3,7 +3,8 @@ use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPoly
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    spatial, ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+    spatial, ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetDimensions,
+    SetGradient, SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
 use crate::geom::{self, pt2, Point2};
@@ -53,32 +54,9 @@ impl draw::renderer::RenderPrimitive for Quad {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Quad {
-            mut quad,
-            polygon,
-            dimensions,
-        } = self;
-
-        // If dimensions were specified, scale the points to those dimensions.
-        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
-        if maybe_x.is_some() || maybe_y.is_some() {
-            let cuboid = quad.bounding_rect();
-            let centroid = quad.centroid();
-            let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
-            let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
-            let scale = vec2(x_scale, y_scale);
-            let (a, b, c, d) = quad.into();
-            let translate = |v: Point2| centroid + ((v - centroid) * scale);
-            let new_a = translate(a);
-            let new_b = translate(b);
-            let new_c = translate(c);
-            let new_d = translate(d);
-            quad = geom::Quad([new_a, new_b, new_c, new_d]);
-        }
-
-        let points = quad.vertices();
+        let points = self.scaled_quad().vertices();
         polygon::render_points_themed(
-            polygon.opts,
+            self.polygon.opts,
             points,
             ctxt,
             &draw::theme::Primitive::Quad,
@@ -89,6 +67,39 @@ impl draw::renderer::RenderPrimitive for Quad {
     }
 }
 
+impl draw::svg::WritePrimitive for Quad {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        polygon::write_points_themed(
+            &self.polygon.opts,
+            self.scaled_quad().vertices(),
+            ctxt,
+            &draw::theme::Primitive::Quad,
+            svg,
+        )
+    }
+}
+
+impl Quad {
+    // The quad, scaled to its dimensions if any were specified.
+    fn scaled_quad(&self) -> geom::Quad<Point2> {
+        let Quad {
+            quad, dimensions, ..
+        } = *self;
+        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
+        if maybe_x.is_none() && maybe_y.is_none() {
+            return quad;
+        }
+        let cuboid = quad.bounding_rect();
+        let centroid = quad.centroid();
+        let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
+        let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
+        let scale = vec2(x_scale, y_scale);
+        let (a, b, c, d) = quad.into();
+        let translate = |v: Point2| centroid + ((v - centroid) * scale);
+        geom::Quad([translate(a), translate(b), translate(c), translate(d)])
+    }
+}
+
 impl From<geom::Quad<Point2>> for Quad {
     fn from(quad: geom::Quad<Point2>) -> Self {
         let polygon = Default::default();
@@ -147,6 +158,18 @@ impl SetStroke for Quad {
     }
 }
 
+impl SetGradient for Quad {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Quad {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Quad {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
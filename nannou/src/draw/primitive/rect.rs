@@ -3,7 +3,8 @@ use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPoly
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetDimensions, SetGradient,
+    SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
 use crate::geom;
@@ -48,23 +49,9 @@ impl draw::renderer::RenderPrimitive for Rect {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Rect {
-            polygon,
-            dimensions,
-        } = self;
-
-        // If dimensions were specified, scale the points to those dimensions.
-        let (maybe_x, maybe_y, maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
-        assert!(
-            maybe_z.is_none(),
-            "z dimension support for rect is unimplemented"
-        );
-        let w = maybe_x.unwrap_or(100.0);
-        let h = maybe_y.unwrap_or(100.0);
-        let rect = geom::Rect::from_wh([w, h].into());
-        let points = rect.corners().vertices().map(Vec2::from);
+        let points = self.points();
         polygon::render_points_themed(
-            polygon.opts,
+            self.polygon.opts,
             points,
             ctxt,
             &draw::theme::Primitive::Rect,
@@ -75,6 +62,33 @@ impl draw::renderer::RenderPrimitive for Rect {
     }
 }
 
+impl draw::svg::WritePrimitive for Rect {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        polygon::write_points_themed(
+            &self.polygon.opts,
+            self.points(),
+            ctxt,
+            &draw::theme::Primitive::Rect,
+            svg,
+        )
+    }
+}
+
+impl Rect {
+    // The corners of the rect, scaled to its dimensions.
+    fn points(&self) -> impl Clone + Iterator<Item = Vec2> {
+        let (maybe_x, maybe_y, maybe_z) = (self.dimensions.x, self.dimensions.y, self.dimensions.z);
+        assert!(
+            maybe_z.is_none(),
+            "z dimension support for rect is unimplemented"
+        );
+        let w = maybe_x.unwrap_or(100.0);
+        let h = maybe_y.unwrap_or(100.0);
+        let rect = geom::Rect::from_wh([w, h].into());
+        rect.corners().vertices().map(Vec2::from)
+    }
+}
+
 impl From<geom::Rect<f32>> for Rect {
     fn from(r: geom::Rect<f32>) -> Self {
         let (x, y, w, h) = r.x_y_w_h();
@@ -123,6 +137,18 @@ impl SetStroke for Rect {
     }
 }
 
+impl SetGradient for Rect {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Rect {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Rect {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
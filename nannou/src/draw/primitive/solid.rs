@@ -0,0 +1,366 @@
+//! Items shared between the 3D solid primitives: **Sphere**, **Cylinder**, **Cone**, **Torus** and
+//! **Plane**.
+//!
+//! Each solid is generated as a triangulated surface in its own local space, with a normal and
+//! texture coordinates for every vertex. The surface is then scaled, oriented and positioned via
+//! the usual spatial builder methods.
+
+use crate::draw::mesh::vertex::{self, TexCoords};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::geom::{Point3, Vec3};
+use crate::glam::{Mat3, Mat4};
+use crate::wgpu;
+use std::f32::consts::{PI, TAU};
+
+/// A trait implemented for all solid draw primitives.
+pub trait SetSolid: Sized {
+    /// Access to the solid builder parameters.
+    fn solid_options_mut(&mut self) -> &mut SolidOptions;
+
+    /// Texture the surface of the solid with the given texture.
+    ///
+    /// The texture is mapped using the texture coordinates generated for the solid. When textured,
+    /// the fill color is ignored in favour of the texture.
+    fn texture(mut self, view: &dyn wgpu::ToTextureView) -> Self {
+        self.solid_options_mut().texture_view = Some(view.to_texture_view());
+        self
+    }
+
+    /// Specify the whole set of solid options.
+    fn solid_options(mut self, opts: SolidOptions) -> Self {
+        *self.solid_options_mut() = opts;
+        self
+    }
+}
+
+/// The set of options shared by all solid types.
+#[derive(Clone, Debug, Default)]
+pub struct SolidOptions {
+    pub spatial: spatial::Properties,
+    pub color: Option<LinSrgba>,
+    pub texture_view: Option<wgpu::TextureView>,
+}
+
+/// A single vertex of a solid's surface, described in the solid's local space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Vertex {
+    /// The position of the vertex.
+    pub point: Point3,
+    /// The unit length normal of the surface at the vertex, facing outwards.
+    pub normal: Vec3,
+    /// The texture coordinates of the vertex.
+    pub tex_coords: TexCoords,
+}
+
+/// The triangulated surface of a solid.
+///
+/// Triangles are wound counter-clockwise when viewed from outside the solid.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Geometry {
+    /// All vertices of the surface.
+    pub vertices: Vec<Vertex>,
+    /// Each trio of indices describes a single triangle.
+    pub indices: Vec<u32>,
+}
+
+impl Geometry {
+    /// A sphere of unit diameter centered on the origin with the poles along the *y* axis.
+    ///
+    /// `segments` is the number of divisions around the *y* axis and `rings` is the number of
+    /// divisions from pole to pole.
+    pub fn sphere(segments: u32, rings: u32) -> Self {
+        let segments = segments.max(3);
+        let rings = rings.max(2);
+        let mut geometry = Geometry::default();
+        for i in 0..=rings {
+            let v = i as f32 / rings as f32;
+            let phi = v * PI;
+            for j in 0..=segments {
+                let u = j as f32 / segments as f32;
+                let theta = u * TAU;
+                let normal = Vec3::new(phi.sin() * theta.sin(), phi.cos(), phi.sin() * theta.cos());
+                geometry.push_vertex(normal * 0.5, normal, [u, v]);
+            }
+        }
+        let stride = segments + 1;
+        for i in 0..rings {
+            for j in 0..segments {
+                let a = i * stride + j;
+                let b = a + stride;
+                let c = b + 1;
+                let d = a + 1;
+                // Skip the degenerate triangles at the poles.
+                if i != rings - 1 {
+                    geometry.indices.extend_from_slice(&[a, b, c]);
+                }
+                if i != 0 {
+                    geometry.indices.extend_from_slice(&[a, c, d]);
+                }
+            }
+        }
+        geometry
+    }
+
+    /// A capped cylinder of unit height and diameter centered on the origin with its axis along
+    /// the *y* axis.
+    ///
+    /// `segments` is the number of divisions around the *y* axis.
+    pub fn cylinder(segments: u32) -> Self {
+        let segments = segments.max(3);
+        let mut geometry = Geometry::default();
+        for j in 0..=segments {
+            let u = j as f32 / segments as f32;
+            let theta = u * TAU;
+            let normal = Vec3::new(theta.sin(), 0.0, theta.cos());
+            let point = normal * 0.5;
+            geometry.push_vertex(point - Vec3::Y * 0.5, normal, [u, 1.0]);
+            geometry.push_vertex(point + Vec3::Y * 0.5, normal, [u, 0.0]);
+        }
+        for j in 0..segments {
+            let bottom = j * 2;
+            let top = bottom + 1;
+            geometry.indices.extend_from_slice(&[
+                top,
+                bottom,
+                bottom + 2,
+                top,
+                bottom + 2,
+                top + 2,
+            ]);
+        }
+        geometry.push_cap(segments, 0.5, Vec3::Y);
+        geometry.push_cap(segments, -0.5, -Vec3::Y);
+        geometry
+    }
+
+    /// A cone of unit height and base diameter centered on the origin with its apex pointing
+    /// along the positive *y* axis.
+    ///
+    /// `segments` is the number of divisions around the *y* axis.
+    pub fn cone(segments: u32) -> Self {
+        let segments = segments.max(3);
+        let mut geometry = Geometry::default();
+        // The side normals tilt upwards by the ratio of the radius to the height.
+        let side_normal = |theta: f32| Vec3::new(theta.sin(), 0.5, theta.cos()).normalize();
+        for j in 0..=segments {
+            let u = j as f32 / segments as f32;
+            let theta = u * TAU;
+            let point = Vec3::new(theta.sin() * 0.5, -0.5, theta.cos() * 0.5);
+            geometry.push_vertex(point, side_normal(theta), [u, 1.0]);
+        }
+        // Give each segment its own apex so that the normals are smooth around the side.
+        let apex_start = geometry.vertices.len() as u32;
+        for j in 0..segments {
+            let u = (j as f32 + 0.5) / segments as f32;
+            let point = Vec3::Y * 0.5;
+            geometry.push_vertex(point, side_normal(u * TAU), [u, 0.0]);
+        }
+        for j in 0..segments {
+            geometry
+                .indices
+                .extend_from_slice(&[apex_start + j, j, j + 1]);
+        }
+        geometry.push_cap(segments, -0.5, -Vec3::Y);
+        geometry
+    }
+
+    /// A torus centered on the origin, lying within the *xy* plane.
+    ///
+    /// `radius` is the distance from the center to the middle of the tube and `tube_radius` is
+    /// the radius of the tube itself. `segments` is the number of divisions around the center and
+    /// `sides` is the number of divisions around the tube.
+    pub fn torus(radius: f32, tube_radius: f32, segments: u32, sides: u32) -> Self {
+        let segments = segments.max(3);
+        let sides = sides.max(3);
+        let mut geometry = Geometry::default();
+        for i in 0..=sides {
+            let v = i as f32 / sides as f32;
+            let psi = v * TAU;
+            for j in 0..=segments {
+                let u = j as f32 / segments as f32;
+                let phi = u * TAU;
+                let center = Vec3::new(phi.cos(), phi.sin(), 0.0) * radius;
+                let normal = Vec3::new(phi.cos() * psi.cos(), phi.sin() * psi.cos(), psi.sin());
+                geometry.push_vertex(center + normal * tube_radius, normal, [u, v]);
+            }
+        }
+        let stride = segments + 1;
+        for i in 0..sides {
+            for j in 0..segments {
+                let a = i * stride + j;
+                let b = a + stride;
+                let c = b + 1;
+                let d = a + 1;
+                geometry.indices.extend_from_slice(&[a, c, b, a, d, c]);
+            }
+        }
+        geometry
+    }
+
+    /// A square plane of unit size centered on the origin within the *xy* plane, facing along the
+    /// positive *z* axis.
+    ///
+    /// The plane is subdivided into a grid of `columns` by `rows` quads.
+    pub fn plane(columns: u32, rows: u32) -> Self {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+        let mut geometry = Geometry::default();
+        for i in 0..=rows {
+            let v = i as f32 / rows as f32;
+            for j in 0..=columns {
+                let u = j as f32 / columns as f32;
+                let point = Vec3::new(u - 0.5, v - 0.5, 0.0);
+                geometry.push_vertex(point, Vec3::Z, [u, 1.0 - v]);
+            }
+        }
+        let stride = columns + 1;
+        for i in 0..rows {
+            for j in 0..columns {
+                let a = i * stride + j;
+                let b = a + 1;
+                let c = b + stride;
+                let d = a + stride;
+                geometry.indices.extend_from_slice(&[a, b, c, a, c, d]);
+            }
+        }
+        geometry
+    }
+
+    fn push_vertex(&mut self, point: Vec3, normal: Vec3, tex_coords: [f32; 2]) {
+        let tex_coords = tex_coords.into();
+        self.vertices.push(Vertex {
+            point,
+            normal,
+            tex_coords,
+        });
+    }
+
+    // A disc of unit diameter at the given height, facing along the given normal.
+    //
+    // Texture coordinates are mapped as though viewing the disc from outside, with the negative
+    // *z* axis upwards for the top cap and the positive *z* axis upwards for the bottom cap.
+    fn push_cap(&mut self, segments: u32, y: f32, normal: Vec3) {
+        let start = self.vertices.len() as u32;
+        let facing_up = normal.y > 0.0;
+        let tex_y = |z: f32| if facing_up { 0.5 + z } else { 0.5 - z };
+        self.push_vertex(Vec3::Y * y, normal, [0.5, 0.5]);
+        for j in 0..=segments {
+            let theta = j as f32 / segments as f32 * TAU;
+            let (x, z) = (theta.sin() * 0.5, theta.cos() * 0.5);
+            self.push_vertex(Vec3::new(x, y, z), normal, [0.5 + x, tex_y(z)]);
+        }
+        for j in 0..segments {
+            let (a, b) = (start + 1 + j, start + 2 + j);
+            let tri = if facing_up {
+                [start, a, b]
+            } else {
+                [start, b, a]
+            };
+            self.indices.extend_from_slice(&tri);
+        }
+    }
+}
+
+/// Render the given solid geometry to the mesh.
+///
+/// The geometry is first scaled by `scale`, then positioned and oriented by the solid's options.
+pub(crate) fn render_solid(
+    opts: SolidOptions,
+    geometry: &Geometry,
+    scale: Vec3,
+    ctxt: draw::renderer::RenderContext,
+    theme_primitive: &draw::theme::Primitive,
+    mesh: &mut draw::Mesh,
+) -> draw::renderer::PrimitiveRender {
+    let SolidOptions {
+        spatial,
+        color,
+        texture_view,
+    } = opts;
+
+    // Determine the transform to apply to all points.
+    let global_transform = *ctxt.transform;
+    let local_transform = spatial.position.transform() * spatial.orientation.transform();
+    let transform = global_transform * local_transform * Mat4::from_scale(scale);
+
+    let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(theme_primitive));
+    // Normals are transformed by the inverse transpose so that they remain perpendicular to the
+    // surface under non-uniform scaling.
+    let normal_transform = Mat3::from_mat4(transform).inverse().transpose();
+    let start = mesh.raw_vertex_count() as u32;
+    let vertices = geometry.vertices.iter().map(|v| {
+        let point = transform.transform_point3(v.point);
+        let normal = (normal_transform * v.normal).normalize_or_zero();
+        vertex::with_normal(point, color, v.tex_coords, normal)
+    });
+    let indices = geometry.indices.iter().map(|&i| start + i);
+    mesh.extend(vertices, indices);
+
+    match texture_view {
+        None => draw::renderer::PrimitiveRender::default(),
+        Some(texture_view) => draw::renderer::PrimitiveRender::texture(texture_view),
+    }
+}
+
+// Resolve the scale of a solid from its dimensions, falling back to the given natural size.
+pub(crate) fn scale(dimensions: &dimension::Properties, natural: Vec3) -> Vec3 {
+    Vec3::new(
+        dimensions.x.unwrap_or(natural.x),
+        dimensions.y.unwrap_or(natural.y),
+        dimensions.z.unwrap_or(natural.z),
+    )
+}
+
+impl<'a, T> Drawing<'a, T>
+where
+    T: SetSolid + Into<Primitive>,
+    Primitive: Into<Option<T>>,
+{
+    /// Texture the surface of the solid with the given texture.
+    ///
+    /// The texture is mapped using the texture coordinates generated for the solid. When textured,
+    /// the fill color is ignored in favour of the texture.
+    pub fn texture(self, view: &dyn wgpu::ToTextureView) -> Self {
+        self.map_ty(|ty| ty.texture(view))
+    }
+
+    /// Specify the whole set of solid options.
+    pub fn solid_options(self, opts: SolidOptions) -> Self {
+        self.map_ty(|ty| ty.solid_options(opts))
+    }
+}
+
+impl SetSolid for SolidOptions {
+    fn solid_options_mut(&mut self) -> &mut SolidOptions {
+        self
+    }
+}
+
+impl SetOrientation for SolidOptions {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for SolidOptions {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for SolidOptions {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+impl SetColor<ColorScalar> for SolidOptions {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.color)
+    }
+}
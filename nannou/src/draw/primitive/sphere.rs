@@ -0,0 +1,162 @@
+use crate::draw::primitive::solid::{self, Geometry, SetSolid, SolidOptions};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::glam::Vec3;
+
+/// Properties related to drawing a **Sphere**.
+#[derive(Clone, Debug)]
+pub struct Sphere {
+    solid: SolidOptions,
+    segments: u32,
+    rings: u32,
+}
+
+/// The drawing context for a Sphere.
+pub type DrawingSphere<'a> = Drawing<'a, Sphere>;
+
+// Trait implementations.
+
+impl Sphere {
+    /// The number of divisions around the axis used if none is specified.
+    pub const DEFAULT_SEGMENTS: u32 = 32;
+    /// The number of divisions from pole to pole used if none is specified.
+    pub const DEFAULT_RINGS: u32 = 16;
+
+    /// Specify the radius of the sphere.
+    ///
+    /// This is short-hand for setting the width, height and depth to twice the radius.
+    pub fn radius(self, radius: f32) -> Self {
+        let d = radius * 2.0;
+        self.w_h_d(d, d, d)
+    }
+
+    /// The number of divisions around the *y* axis of the sphere.
+    pub fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// The number of divisions from pole to pole.
+    pub fn rings(mut self, rings: u32) -> Self {
+        self.rings = rings;
+        self
+    }
+
+    /// Specify the resolution of the sphere.
+    ///
+    /// This is short-hand for `segments(resolution)` and `rings(resolution / 2)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.segments(resolution).rings(resolution / 2)
+    }
+}
+
+impl<'a> DrawingSphere<'a> {
+    /// Specify the radius of the sphere.
+    ///
+    /// This is short-hand for setting the width, height and depth to twice the radius.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+
+    /// The number of divisions around the *y* axis of the sphere.
+    pub fn segments(self, segments: u32) -> Self {
+        self.map_ty(|ty| ty.segments(segments))
+    }
+
+    /// The number of divisions from pole to pole.
+    pub fn rings(self, rings: u32) -> Self {
+        self.map_ty(|ty| ty.rings(rings))
+    }
+
+    /// Specify the resolution of the sphere.
+    ///
+    /// This is short-hand for `segments(resolution)` and `rings(resolution / 2)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.map_ty(|ty| ty.resolution(resolution))
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Sphere {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Sphere {
+            solid,
+            segments,
+            rings,
+        } = self;
+        let scale = solid::scale(&solid.spatial.dimensions, Vec3::splat(100.0));
+        let geometry = Geometry::sphere(segments, rings);
+        solid::render_solid(
+            solid,
+            &geometry,
+            scale,
+            ctxt,
+            &draw::theme::Primitive::Sphere,
+            mesh,
+        )
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Sphere {
+            solid: Default::default(),
+            segments: Self::DEFAULT_SEGMENTS,
+            rings: Self::DEFAULT_RINGS,
+        }
+    }
+}
+
+impl SetOrientation for Sphere {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.solid)
+    }
+}
+
+impl SetPosition for Sphere {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.solid)
+    }
+}
+
+impl SetDimensions for Sphere {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.solid)
+    }
+}
+
+impl SetColor<ColorScalar> for Sphere {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.solid)
+    }
+}
+
+impl SetSolid for Sphere {
+    fn solid_options_mut(&mut self) -> &mut SolidOptions {
+        SetSolid::solid_options_mut(&mut self.solid)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Sphere> for Primitive {
+    fn from(prim: Sphere) -> Self {
+        Primitive::Sphere(prim)
+    }
+}
+
+impl From<Primitive> for Option<Sphere> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Sphere(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
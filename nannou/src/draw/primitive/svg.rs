@@ -0,0 +1,222 @@
+use crate::draw::drawing::DrawingContext;
+use crate::draw::primitive::path::{self, Options};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{self, dimension, orientation, position};
+use crate::draw::properties::{Dashes, SetDimensions, SetOrientation, SetPosition};
+use crate::draw::{self, theme, Drawing};
+use crate::geom::svg::{Document, Fill, Stroke};
+use crate::glam::{Mat4, Vec2, Vec3};
+use lyon::tessellation::FillOptions;
+
+/// Properties related to drawing the shapes of an SVG **Document**.
+///
+/// The document's view box is centred on the position of the primitive and scaled to fit the
+/// size of the document, or the primitive's dimensions if they are specified.
+#[derive(Clone, Debug)]
+pub struct Svg {
+    spatial: spatial::Properties,
+    size: Vec2,
+    view_transform: Mat4,
+    shapes: Vec<Shape>,
+}
+
+// A shape whose path events are stored within the `Draw` context's path event buffer.
+#[derive(Clone, Debug)]
+struct Shape {
+    events: std::ops::Range<usize>,
+    transform: Mat4,
+    fill: Option<Fill>,
+    stroke: Option<Stroke>,
+}
+
+/// The drawing context for an SVG document.
+pub type DrawingSvg<'a> = Drawing<'a, Svg>;
+
+impl Svg {
+    /// Begin drawing the shapes of the given document.
+    pub fn new(ctxt: DrawingContext, document: &Document) -> Self {
+        let DrawingContext {
+            path_event_buffer, ..
+        } = ctxt;
+        let shapes = document
+            .shapes
+            .iter()
+            .map(|shape| {
+                let start = path_event_buffer.len();
+                path_event_buffer.extend(shape.path.iter());
+                let end = path_event_buffer.len();
+                Shape {
+                    events: start..end,
+                    transform: shape.transform,
+                    fill: shape.fill,
+                    stroke: shape.stroke.clone(),
+                }
+            })
+            .collect();
+        Svg {
+            spatial: Default::default(),
+            size: document.size,
+            view_transform: document.transform(),
+            shapes,
+        }
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Svg {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        // Determine the transform to apply to all points.
+        let transform = *ctxt.transform * self.local_transform();
+
+        for shape in self.shapes {
+            let transform = transform * shape.transform;
+            let events = &ctxt.path_event_buffer[shape.events];
+            if let Some(fill) = shape.fill {
+                let options = FillOptions::default().with_fill_rule(fill.rule);
+                path::render_path_events(
+                    events.iter().cloned(),
+                    Some(fill.color),
+                    None,
+                    None,
+                    transform,
+                    Options::Fill(options),
+                    ctxt.theme,
+                    &theme::Primitive::Path,
+                    ctxt.fill_tessellator,
+                    ctxt.stroke_tessellator,
+                    mesh,
+                );
+            }
+            if let Some(stroke) = shape.stroke {
+                let dashes = dashes(&stroke);
+                path::render_path_events(
+                    events.iter().cloned(),
+                    Some(stroke.color),
+                    None,
+                    dashes.as_ref(),
+                    transform,
+                    Options::Stroke(stroke.options),
+                    ctxt.theme,
+                    &theme::Primitive::Path,
+                    ctxt.fill_tessellator,
+                    ctxt.stroke_tessellator,
+                    mesh,
+                );
+            }
+        }
+
+        draw::renderer::PrimitiveRender::default()
+    }
+}
+
+impl draw::svg::WritePrimitive for Svg {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        let transform = ctxt.transform * self.local_transform();
+        for shape in &self.shapes {
+            let transform = transform * shape.transform;
+            let events = &ctxt.path_event_buffer[shape.events.clone()];
+            if let Some(ref fill) = shape.fill {
+                let options = Options::Fill(FillOptions::default().with_fill_rule(fill.rule));
+                svg.path(
+                    events.iter().cloned(),
+                    fill.color,
+                    None,
+                    None,
+                    transform,
+                    &options,
+                );
+            }
+            if let Some(ref stroke) = shape.stroke {
+                let dashes = dashes(stroke);
+                let options = Options::Stroke(stroke.options);
+                let events = events.iter().cloned();
+                svg.path(
+                    events,
+                    stroke.color,
+                    None,
+                    dashes.as_ref(),
+                    transform,
+                    &options,
+                );
+            }
+        }
+        true
+    }
+}
+
+impl Svg {
+    // The transform from the document's coordinates to those of the `Draw` context.
+    //
+    // The document is scaled to the dimensions of the primitive, if any.
+    fn local_transform(&self) -> Mat4 {
+        let Svg {
+            ref spatial,
+            size,
+            view_transform,
+            ..
+        } = *self;
+        let scale = |dim: Option<f32>, len: f32| match dim {
+            Some(dim) if len != 0.0 => dim / len,
+            _ => 1.0,
+        };
+        let scale = Vec3::new(
+            scale(spatial.dimensions.x, size.x),
+            scale(spatial.dimensions.y, size.y),
+            1.0,
+        );
+        spatial.position.transform()
+            * spatial.orientation.transform()
+            * Mat4::from_scale(scale)
+            * view_transform
+    }
+}
+
+// The dashes of the given stroke, if it has a dash array.
+fn dashes(stroke: &Stroke) -> Option<Dashes> {
+    if stroke.dash_array.is_empty() {
+        return None;
+    }
+    Some(Dashes {
+        pattern: stroke.dash_array.clone(),
+        offset: stroke.dash_offset,
+        cap: None,
+    })
+}
+
+impl SetOrientation for Svg {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.spatial)
+    }
+}
+
+impl SetPosition for Svg {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.spatial)
+    }
+}
+
+impl SetDimensions for Svg {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.spatial)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Svg> for Primitive {
+    fn from(prim: Svg) -> Self {
+        Primitive::Svg(prim)
+    }
+}
+
+impl From<Primitive> for Option<Svg> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Svg(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use crate::color::conv::IntoLinSrgba;
 use crate::draw::drawing::DrawingContext;
-use crate::draw::primitive::Primitive;
+use crate::draw::primitive::{path, Primitive};
 use crate::draw::properties::spatial::{self, dimension, orientation, position};
 use crate::draw::properties::{
     ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
 };
 use crate::draw::{self, theme, Drawing};
 use crate::geom::{self, Point2};
+use crate::glam::Mat4;
 use crate::text::{self, Align, Font, FontSize, Justify, Layout, Scalar, Wrap};
 
 /// Properties related to drawing the **Text** primitive.
@@ -24,6 +25,8 @@ pub struct Style {
     pub color: Option<LinSrgba>,
     pub glyph_colors: Vec<LinSrgba>, // Overrides `color` if non-empty.
     pub layout: text::layout::Builder,
+    /// Ranges of the text styled with their own font, font size, color, weight or slant.
+    pub spans: Vec<text::Span>,
 }
 
 /// The drawing context for the **Text** primitive.
@@ -92,6 +95,21 @@ impl Text {
         self.map_layout(|l| l.font(font))
     }
 
+    /// Append a font used to display the characters for which the preceding fonts have no glyph,
+    /// e.g. emoji or CJK characters.
+    pub fn fallback_font(self, font: Font) -> Self {
+        self.map_layout(|l| l.fallback_font(font))
+    }
+
+    /// Specify the fonts used to display the characters for which the font has no glyph, in order
+    /// of preference.
+    pub fn fallback_fonts<I>(self, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = Font>,
+    {
+        self.map_layout(|l| l.fallback_fonts(fonts))
+    }
+
     /// Describe the end along the *x* axis to which the text should be aligned.
     pub fn justify(self, justify: Justify) -> Self {
         self.map_layout(|l| l.justify(justify))
@@ -156,6 +174,21 @@ impl Text {
         self.style.glyph_colors = colors;
         self
     }
+
+    /// Style a range of the text with its own font, font size, color, weight or slant.
+    pub fn span(mut self, span: text::Span) -> Self {
+        self.style.spans.push(span);
+        self
+    }
+
+    /// Style ranges of the text via each of the given spans in order.
+    pub fn spans<I>(mut self, spans: I) -> Self
+    where
+        I: IntoIterator<Item = text::Span>,
+    {
+        self.style.spans.extend(spans);
+        self
+    }
 }
 
 impl<'a> DrawingText<'a> {
@@ -184,6 +217,21 @@ impl<'a> DrawingText<'a> {
         self.map_ty(|ty| ty.font(font))
     }
 
+    /// Append a font used to display the characters for which the preceding fonts have no glyph,
+    /// e.g. emoji or CJK characters.
+    pub fn fallback_font(self, font: text::Font) -> Self {
+        self.map_ty(|ty| ty.fallback_font(font))
+    }
+
+    /// Specify the fonts used to display the characters for which the font has no glyph, in order
+    /// of preference.
+    pub fn fallback_fonts<I>(self, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = text::Font>,
+    {
+        self.map_ty(|ty| ty.fallback_fonts(fonts))
+    }
+
     /// Build the **Text** with the given **Style**.
     pub fn with_style(self, style: Style) -> Self {
         self.map_ty(|ty| ty.with_style(style))
@@ -257,6 +305,35 @@ impl<'a> DrawingText<'a> {
 
         self.map_ty(|ty| ty.glyph_colors(glyph_colors))
     }
+
+    /// Style a range of the text with its own font, font size, color, weight or slant.
+    ///
+    /// The range is a byte range into the text. Spans are applied in order, with later spans
+    /// taking precedence where they overlap. Each line is as tall as the greatest font size
+    /// within it.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// # use nannou::text::Span;
+    /// # fn view(app: &App, frame: Frame) {
+    /// # let draw = app.draw();
+    /// draw.text("Temp: 23°C")
+    ///     .color(BLACK)
+    ///     .span(Span::new(6..).bold().font_size(24).color(RED));
+    /// # }
+    /// # fn main() {}
+    /// ```
+    pub fn span(self, span: text::Span) -> Self {
+        self.map_ty(|ty| ty.span(span))
+    }
+
+    /// Style ranges of the text via each of the given spans in order.
+    pub fn spans<I>(self, spans: I) -> Self
+    where
+        I: IntoIterator<Item = text::Span>,
+    {
+        self.map_ty(|ty| ty.spans(spans))
+    }
 }
 
 impl draw::renderer::RenderPrimitive for Text {
@@ -265,44 +342,28 @@ impl draw::renderer::RenderPrimitive for Text {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Text {
-            spatial,
-            style,
-            text,
-        } = self;
-        let Style {
-            color,
-            glyph_colors,
-            layout,
-        } = style;
-        let layout = layout.build();
-        let (maybe_x, maybe_y, maybe_z) = (
-            spatial.dimensions.x,
-            spatial.dimensions.y,
-            spatial.dimensions.z,
-        );
-        assert!(
-            maybe_z.is_none(),
-            "z dimension support for text is unimplemented"
-        );
-        let w = maybe_x.unwrap_or(200.0);
-        let h = maybe_y.unwrap_or(200.0);
-        let rect: geom::Rect = geom::Rect::from_wh([w, h].into());
-        let color = color.unwrap_or_else(|| ctxt.theme.fill_lin_srgba(&theme::Primitive::Text));
+        let text = self.layout_text(ctxt.text_buffer);
+        let runs = text.runs();
+
+        // Determine the transform to apply to all points.
+        let transform = *ctxt.transform * self.local_transform();
 
-        let text_str = &ctxt.text_buffer[text.clone()];
-        let text = text::text(text_str).layout(&layout).build(rect);
+        let color = self.text_color(ctxt.theme);
+        let glyph_color = |run: &text::Run, char_ix: usize| self.glyph_color(run, char_ix, color);
 
-        // Queue the glyphs to be cached
-        let font_id = text::font::id(text.font());
-        let positioned_glyphs: Vec<_> = text
-            .rt_glyphs(
+        // Queue the glyphs of each run to be cached with the run's font.
+        let mut positioned_glyphs = vec![];
+        for (run_ix, run) in runs.iter().enumerate() {
+            let font_id = text::font::id(&run.font);
+            let glyphs = text.rt_run_glyphs(
+                run,
                 ctxt.output_attachment_size,
                 ctxt.output_attachment_scale_factor,
-            )
-            .collect();
-        for glyph in positioned_glyphs.iter() {
-            ctxt.glyph_cache.queue_glyph(font_id.index(), glyph.clone());
+            );
+            for (char_ix, glyph) in glyphs {
+                ctxt.glyph_cache.queue_glyph(font_id.index(), glyph.clone());
+                positioned_glyphs.push((run_ix, font_id, char_ix, glyph));
+            }
         }
 
         // Cache the enqueued glyphs within the pixel buffer.
@@ -340,11 +401,6 @@ impl draw::renderer::RenderPrimitive for Text {
             }
         }
 
-        // Determine the transform to apply to all points.
-        let global_transform = *ctxt.transform;
-        let local_transform = spatial.position.transform() * spatial.orientation.transform();
-        let transform = global_transform * local_transform;
-
         // A function for converting RustType rects to nannou rects.
         let scale_factor = ctxt.output_attachment_scale_factor;
         let (out_w, out_h) = ctxt.output_attachment_size.into();
@@ -357,58 +413,52 @@ impl draw::renderer::RenderPrimitive for Text {
             geom::Rect::from_corners([l, b].into(), [r, t].into())
         };
 
-        // Skips non-rendered colors (e.g. due to line breaks),
-        //   assuming LineInfos are ordered by ascending character position.
-        let glyph_colors_iter = text
-            .line_infos()
-            .iter()
-            .flat_map(|li| li.char_range())
-            .take_while(|&i| i < glyph_colors.len())
-            .map(|i| &glyph_colors[i])
-            // Repeat `color` if more glyphs than glyph_colors
-            .chain(std::iter::repeat(&color));
-
-        // Extend the mesh with a rect for each displayed glyph.
-        for (g, g_color) in positioned_glyphs.iter().zip(glyph_colors_iter) {
+        // Extend the mesh with a rect for each displayed glyph, once per pass of its run.
+        for (run_ix, font_id, char_ix, g) in positioned_glyphs {
+            let run = &runs[run_ix];
+            let g_color = glyph_color(run, char_ix);
             if let Ok(Some((uv_rect, screen_rect))) = ctxt.glyph_cache.rect_for(font_id.index(), &g)
             {
                 let rect = to_nannou_rect(screen_rect);
 
-                // Create a mesh-compatible vertex from the position and tex_coords.
-                let v = |p: Point2, tex_coords: [f32; 2]| -> draw::mesh::Vertex {
-                    let p = transform.transform_point3([p.x, p.y, 0.0].into());
-                    let point = draw::mesh::vertex::Point::from(p);
-                    draw::mesh::vertex::new(point, g_color.to_owned(), tex_coords.into())
-                };
-
                 // The sides of the UV rect.
                 let uv_l = uv_rect.min.x;
                 let uv_t = uv_rect.min.y;
                 let uv_r = uv_rect.max.x;
                 let uv_b = uv_rect.max.y;
 
-                // Insert the vertices.
-                let bottom_left = v(rect.bottom_left(), [uv_l, uv_b]);
-                let bottom_right = v(rect.bottom_right(), [uv_r, uv_b]);
-                let top_left = v(rect.top_left(), [uv_l, uv_t]);
-                let top_right = v(rect.top_right(), [uv_r, uv_t]);
-                let start_ix = mesh.points().len() as u32;
-                mesh.push_vertex(top_left);
-                mesh.push_vertex(bottom_left);
-                mesh.push_vertex(bottom_right);
-                mesh.push_vertex(top_right);
-
-                // Now the indices.
-                let tl_ix = start_ix;
-                let bl_ix = start_ix + 1;
-                let br_ix = start_ix + 2;
-                let tr_ix = start_ix + 3;
-                mesh.push_index(tl_ix);
-                mesh.push_index(bl_ix);
-                mesh.push_index(br_ix);
-                mesh.push_index(tl_ix);
-                mesh.push_index(br_ix);
-                mesh.push_index(tr_ix);
+                for pass_offset in run.passes() {
+                    // Create a mesh-compatible vertex from the position and tex_coords.
+                    let v = |p: Point2, tex_coords: [f32; 2]| -> draw::mesh::Vertex {
+                        let p = run.transform_point(p, pass_offset);
+                        let p = transform.transform_point3([p.x, p.y, 0.0].into());
+                        let point = draw::mesh::vertex::Point::from(p);
+                        draw::mesh::vertex::new(point, g_color, tex_coords.into())
+                    };
+
+                    // Insert the vertices.
+                    let bottom_left = v(rect.bottom_left(), [uv_l, uv_b]);
+                    let bottom_right = v(rect.bottom_right(), [uv_r, uv_b]);
+                    let top_left = v(rect.top_left(), [uv_l, uv_t]);
+                    let top_right = v(rect.top_right(), [uv_r, uv_t]);
+                    let start_ix = mesh.points().len() as u32;
+                    mesh.push_vertex(top_left);
+                    mesh.push_vertex(bottom_left);
+                    mesh.push_vertex(bottom_right);
+                    mesh.push_vertex(top_right);
+
+                    // Now the indices.
+                    let tl_ix = start_ix;
+                    let bl_ix = start_ix + 1;
+                    let br_ix = start_ix + 2;
+                    let tr_ix = start_ix + 3;
+                    mesh.push_index(tl_ix);
+                    mesh.push_index(bl_ix);
+                    mesh.push_index(br_ix);
+                    mesh.push_index(tl_ix);
+                    mesh.push_index(br_ix);
+                    mesh.push_index(tr_ix);
+                }
             }
         }
 
@@ -416,6 +466,87 @@ impl draw::renderer::RenderPrimitive for Text {
     }
 }
 
+impl draw::svg::WritePrimitive for Text {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        let text = self.layout_text(ctxt.text_buffer);
+        let transform = ctxt.transform * self.local_transform();
+        let color = self.text_color(ctxt.theme);
+
+        // Write the outline of each glyph, combining consecutive glyphs of the same color into a
+        // single path.
+        let fill = path::Options::Fill(lyon::tessellation::FillOptions::non_zero());
+        let mut path = vec![];
+        let mut path_color = color;
+        for run in &text.runs() {
+            for (char_ix, glyph, rect) in text.run_glyphs(run) {
+                let g_color = self.glyph_color(run, char_ix, color);
+                if g_color != path_color && !path.is_empty() {
+                    svg.path(path.drain(..), path_color, None, None, transform, &fill);
+                }
+                path_color = g_color;
+                let events: Vec<_> = match text::glyph::path_events(glyph) {
+                    None => continue,
+                    Some(events) => events.collect(),
+                };
+                for t in run.glyph_transforms(rect) {
+                    path.extend(events.iter().map(|e| e.transformed(&t)));
+                }
+            }
+        }
+        if !path.is_empty() {
+            svg.path(path, path_color, None, None, transform, &fill);
+        }
+        true
+    }
+}
+
+impl Text {
+    // Lay out the text within the primitive's dimensions.
+    fn layout_text<'a>(&self, text_buffer: &'a str) -> text::Text<'a> {
+        let (maybe_x, maybe_y, maybe_z) = (
+            self.spatial.dimensions.x,
+            self.spatial.dimensions.y,
+            self.spatial.dimensions.z,
+        );
+        assert!(
+            maybe_z.is_none(),
+            "z dimension support for text is unimplemented"
+        );
+        let w = maybe_x.unwrap_or(200.0);
+        let h = maybe_y.unwrap_or(200.0);
+        let rect: geom::Rect = geom::Rect::from_wh([w, h].into());
+        let layout = self.style.layout.clone().build();
+        let text_str = &text_buffer[self.text.clone()];
+        text::text(text_str)
+            .layout(&layout)
+            .spans(self.style.spans.iter().cloned())
+            .build(rect)
+    }
+
+    // The transform from the text's local space to that of the `Draw` context.
+    fn local_transform(&self) -> Mat4 {
+        self.spatial.position.transform() * self.spatial.orientation.transform()
+    }
+
+    // The color of the text, falling back to that of the theme.
+    fn text_color(&self, theme: &draw::Theme) -> LinSrgba {
+        self.style
+            .color
+            .unwrap_or_else(|| theme.fill_lin_srgba(&theme::Primitive::Text))
+    }
+
+    // The color of the glyph at the given char index within the given run. Glyph colors take
+    // precedence, followed by the color of the run's span and then the text color.
+    fn glyph_color(&self, run: &text::Run, char_ix: usize, color: LinSrgba) -> LinSrgba {
+        self.style
+            .glyph_colors
+            .get(char_ix)
+            .cloned()
+            .or(run.color)
+            .unwrap_or(color)
+    }
+}
+
 impl SetOrientation for Text {
     fn properties(&mut self) -> &mut orientation::Properties {
         SetOrientation::properties(&mut self.spatial)
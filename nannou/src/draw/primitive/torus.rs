@@ -0,0 +1,182 @@
+use crate::draw::primitive::solid::{self, Geometry, SetSolid, SolidOptions};
+use crate::draw::primitive::Primitive;
+use crate::draw::properties::spatial::{dimension, orientation, position};
+use crate::draw::properties::{
+    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition,
+};
+use crate::draw::{self, Drawing};
+use crate::glam::Vec3;
+
+/// Properties related to drawing a **Torus**.
+#[derive(Clone, Debug)]
+pub struct Torus {
+    solid: SolidOptions,
+    radius: f32,
+    tube_radius: f32,
+    segments: u32,
+    sides: u32,
+}
+
+/// The drawing context for a Torus.
+pub type DrawingTorus<'a> = Drawing<'a, Torus>;
+
+// Trait implementations.
+
+impl Torus {
+    /// The radius used if none is specified.
+    pub const DEFAULT_RADIUS: f32 = 50.0;
+    /// The tube radius used if none is specified.
+    pub const DEFAULT_TUBE_RADIUS: f32 = 15.0;
+    /// The number of divisions around the center used if none is specified.
+    pub const DEFAULT_SEGMENTS: u32 = 48;
+    /// The number of divisions around the tube used if none is specified.
+    pub const DEFAULT_SIDES: u32 = 24;
+
+    /// The distance from the center of the torus to the middle of the tube.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// The radius of the tube.
+    pub fn tube_radius(mut self, tube_radius: f32) -> Self {
+        self.tube_radius = tube_radius;
+        self
+    }
+
+    /// The number of divisions around the center of the torus.
+    pub fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// The number of divisions around the tube.
+    pub fn sides(mut self, sides: u32) -> Self {
+        self.sides = sides;
+        self
+    }
+
+    /// Specify the resolution of the torus.
+    ///
+    /// This is short-hand for `segments(resolution)` and `sides(resolution / 2)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.segments(resolution).sides(resolution / 2)
+    }
+}
+
+impl<'a> DrawingTorus<'a> {
+    /// The distance from the center of the torus to the middle of the tube.
+    pub fn radius(self, radius: f32) -> Self {
+        self.map_ty(|ty| ty.radius(radius))
+    }
+
+    /// The radius of the tube.
+    pub fn tube_radius(self, tube_radius: f32) -> Self {
+        self.map_ty(|ty| ty.tube_radius(tube_radius))
+    }
+
+    /// The number of divisions around the center of the torus.
+    pub fn segments(self, segments: u32) -> Self {
+        self.map_ty(|ty| ty.segments(segments))
+    }
+
+    /// The number of divisions around the tube.
+    pub fn sides(self, sides: u32) -> Self {
+        self.map_ty(|ty| ty.sides(sides))
+    }
+
+    /// Specify the resolution of the torus.
+    ///
+    /// This is short-hand for `segments(resolution)` and `sides(resolution / 2)`.
+    pub fn resolution(self, resolution: u32) -> Self {
+        self.map_ty(|ty| ty.resolution(resolution))
+    }
+}
+
+impl draw::renderer::RenderPrimitive for Torus {
+    fn render_primitive(
+        self,
+        ctxt: draw::renderer::RenderContext,
+        mesh: &mut draw::Mesh,
+    ) -> draw::renderer::PrimitiveRender {
+        let Torus {
+            solid,
+            radius,
+            tube_radius,
+            segments,
+            sides,
+        } = self;
+        // If dimensions were specified, stretch the torus from its natural size to fit them.
+        let outer = (radius + tube_radius) * 2.0;
+        let natural = Vec3::new(outer, outer, tube_radius * 2.0);
+        let scale = solid::scale(&solid.spatial.dimensions, natural) / natural;
+        let geometry = Geometry::torus(radius, tube_radius, segments, sides);
+        solid::render_solid(
+            solid,
+            &geometry,
+            scale,
+            ctxt,
+            &draw::theme::Primitive::Torus,
+            mesh,
+        )
+    }
+}
+
+impl Default for Torus {
+    fn default() -> Self {
+        Torus {
+            solid: Default::default(),
+            radius: Self::DEFAULT_RADIUS,
+            tube_radius: Self::DEFAULT_TUBE_RADIUS,
+            segments: Self::DEFAULT_SEGMENTS,
+            sides: Self::DEFAULT_SIDES,
+        }
+    }
+}
+
+impl SetOrientation for Torus {
+    fn properties(&mut self) -> &mut orientation::Properties {
+        SetOrientation::properties(&mut self.solid)
+    }
+}
+
+impl SetPosition for Torus {
+    fn properties(&mut self) -> &mut position::Properties {
+        SetPosition::properties(&mut self.solid)
+    }
+}
+
+impl SetDimensions for Torus {
+    fn properties(&mut self) -> &mut dimension::Properties {
+        SetDimensions::properties(&mut self.solid)
+    }
+}
+
+impl SetColor<ColorScalar> for Torus {
+    fn rgba_mut(&mut self) -> &mut Option<LinSrgba> {
+        SetColor::rgba_mut(&mut self.solid)
+    }
+}
+
+impl SetSolid for Torus {
+    fn solid_options_mut(&mut self) -> &mut SolidOptions {
+        SetSolid::solid_options_mut(&mut self.solid)
+    }
+}
+
+// Primitive conversions.
+
+impl From<Torus> for Primitive {
+    fn from(prim: Torus) -> Self {
+        Primitive::Torus(prim)
+    }
+}
+
+impl From<Primitive> for Option<Torus> {
+    fn from(prim: Primitive) -> Self {
+        match prim {
+            Primitive::Torus(prim) => Some(prim),
+            _ => None,
+        }
+    }
+}
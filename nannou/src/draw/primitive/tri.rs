@@ -3,7 +3,8 @@ use crate::draw::primitive::polygon::{self, PolygonInit, PolygonOptions, SetPoly
 use crate::draw::primitive::Primitive;
 use crate::draw::properties::spatial::{dimension, orientation, position};
 use crate::draw::properties::{
-    ColorScalar, LinSrgba, SetColor, SetDimensions, SetOrientation, SetPosition, SetStroke,
+    ColorScalar, Dashes, Gradient, LinSrgba, SetColor, SetDashes, SetDimensions, SetGradient,
+    SetOrientation, SetPosition, SetStroke,
 };
 use crate::draw::{self, Drawing};
 use crate::geom::{self, pt2, Point2};
@@ -73,29 +74,9 @@ impl draw::renderer::RenderPrimitive for Tri {
         ctxt: draw::renderer::RenderContext,
         mesh: &mut draw::Mesh,
     ) -> draw::renderer::PrimitiveRender {
-        let Tri {
-            mut tri,
-            dimensions,
-            polygon,
-        } = self;
-        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
-        // If dimensions were specified, scale the points to those dimensions.
-        if maybe_x.is_some() || maybe_y.is_some() {
-            let cuboid = tri.bounding_rect();
-            let centroid = tri.centroid();
-            let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
-            let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
-            let scale = vec2(x_scale, y_scale);
-            let (a, b, c) = tri.into();
-            let translate = |v: Point2| centroid + ((v - centroid) * scale);
-            let new_a = translate(a);
-            let new_b = translate(b);
-            let new_c = translate(c);
-            tri = geom::Tri([new_a, new_b, new_c]);
-        }
-        let points = tri.vertices();
+        let points = self.scaled_tri().vertices();
         polygon::render_points_themed(
-            polygon.opts,
+            self.polygon.opts,
             points,
             ctxt,
             &draw::theme::Primitive::Tri,
@@ -106,6 +87,39 @@ impl draw::renderer::RenderPrimitive for Tri {
     }
 }
 
+impl draw::svg::WritePrimitive for Tri {
+    fn write_primitive(&self, ctxt: &draw::svg::Context, svg: &mut draw::svg::Writer) -> bool {
+        polygon::write_points_themed(
+            &self.polygon.opts,
+            self.scaled_tri().vertices(),
+            ctxt,
+            &draw::theme::Primitive::Tri,
+            svg,
+        )
+    }
+}
+
+impl Tri {
+    // The triangle, scaled to its dimensions if any were specified.
+    fn scaled_tri(&self) -> geom::Tri<Point2> {
+        let Tri {
+            tri, dimensions, ..
+        } = *self;
+        let (maybe_x, maybe_y, _maybe_z) = (dimensions.x, dimensions.y, dimensions.z);
+        if maybe_x.is_none() && maybe_y.is_none() {
+            return tri;
+        }
+        let cuboid = tri.bounding_rect();
+        let centroid = tri.centroid();
+        let x_scale = maybe_x.map(|x| x / cuboid.w()).unwrap_or(1.0);
+        let y_scale = maybe_y.map(|y| y / cuboid.h()).unwrap_or(1.0);
+        let scale = vec2(x_scale, y_scale);
+        let (a, b, c) = tri.into();
+        let translate = |v: Point2| centroid + ((v - centroid) * scale);
+        geom::Tri([translate(a), translate(b), translate(c)])
+    }
+}
+
 impl From<geom::Tri<Point2>> for Tri {
     fn from(tri: geom::Tri<Point2>) -> Self {
         let dimensions = <_>::default();
@@ -160,6 +174,18 @@ impl SetStroke for Tri {
     }
 }
 
+impl SetGradient for Tri {
+    fn gradient_mut(&mut self) -> &mut Option<Gradient> {
+        SetGradient::gradient_mut(&mut self.polygon)
+    }
+}
+
+impl SetDashes for Tri {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        SetDashes::dashes_mut(&mut self.polygon)
+    }
+}
+
 impl SetPolygon for Tri {
     fn polygon_options_mut(&mut self) -> &mut PolygonOptions {
         SetPolygon::polygon_options_mut(&mut self.polygon)
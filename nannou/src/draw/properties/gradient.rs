@@ -0,0 +1,282 @@
+//! Items related to painting primitives with color gradients.
+//!
+//! Gradients are applied to the vertex colors of a primitive after tessellation. Triangles are
+//! subdivided where necessary so that the linear interpolation of vertex colors closely
+//! approximates the gradient, e.g. around color stops and along the curves of radial gradients.
+
+use crate::color::{IntoLinSrgba, LinSrgba};
+use crate::draw::properties::ColorScalar;
+use crate::geom::Point2;
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+
+/// Edges shorter than this are never subdivided.
+const MIN_EDGE_LENGTH: f32 = 1.0;
+/// The maximum difference in any color channel between the interpolated vertex color and the
+/// gradient before an edge is subdivided.
+const COLOR_TOLERANCE: f32 = 1.0 / 255.0;
+/// A limit on the number of vertices produced by subdivision, protecting against degenerate cases.
+const MAX_VERTICES: usize = 1 << 16;
+
+/// A gradient of colors across the plane of a primitive.
+///
+/// Gradients are described within the local coordinate space of the primitive, i.e. prior to
+/// applying its position and orientation. Stops are interpolated within linear sRGB space. Colors
+/// may be specified in any color space that can be converted into linear sRGBA.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    /// The shape of the gradient.
+    pub kind: Kind,
+    /// How the gradient is extended beyond the first and last stops.
+    pub spread: Spread,
+    /// The color stops, sorted by offset.
+    pub stops: Vec<Stop>,
+}
+
+/// The shape of a **Gradient**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Kind {
+    /// Colors vary along the line from `start` (offset `0.0`) to `end` (offset `1.0`).
+    Linear { start: Point2, end: Point2 },
+    /// Colors vary with distance from `center`, from offset `0.0` at the center to `1.0` at
+    /// `radius`.
+    Radial { center: Point2, radius: f32 },
+    /// Colors vary counter-clockwise around `center`, beginning at `angle` radians from the
+    /// positive *x* axis.
+    Conic { center: Point2, angle: f32 },
+}
+
+/// How a **Gradient** is extended beyond the range `0.0` to `1.0`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Spread {
+    /// Extend the colors of the first and last stops.
+    Pad,
+    /// Repeat the gradient.
+    Repeat,
+    /// Repeat the gradient, reversing every other repetition.
+    Reflect,
+}
+
+/// A color at an offset along a **Gradient**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Stop {
+    /// The offset, typically within the range `0.0` to `1.0`.
+    pub offset: f32,
+    /// The color at the offset.
+    pub color: LinSrgba,
+}
+
+/// Nodes that may be painted with a gradient.
+pub trait SetGradient: Sized {
+    /// Provide a mutable reference to the gradient field.
+    fn gradient_mut(&mut self) -> &mut Option<Gradient>;
+
+    /// Paint with the given gradient in place of the color.
+    ///
+    /// A gradient without any stops is ignored.
+    fn gradient(mut self, gradient: Gradient) -> Self {
+        *self.gradient_mut() = Some(gradient);
+        self
+    }
+}
+
+impl Gradient {
+    /// A gradient of the given kind without any stops.
+    pub fn new(kind: Kind) -> Self {
+        Gradient {
+            kind,
+            spread: Spread::Pad,
+            stops: vec![],
+        }
+    }
+
+    /// A linear gradient along the line from `start` to `end`.
+    pub fn linear(start: Point2, end: Point2) -> Self {
+        Self::new(Kind::Linear { start, end })
+    }
+
+    /// A radial gradient around `center` reaching offset `1.0` at `radius`.
+    pub fn radial(center: Point2, radius: f32) -> Self {
+        Self::new(Kind::Radial { center, radius })
+    }
+
+    /// A conic gradient sweeping counter-clockwise around `center` from `angle` radians.
+    pub fn conic(center: Point2, angle: f32) -> Self {
+        Self::new(Kind::Conic { center, angle })
+    }
+
+    /// Add a color stop at the given offset.
+    ///
+    /// Stops may be added in any order. Stops at equal offsets produce a hard edge, with the most
+    /// recently added stop taking effect beyond the offset.
+    pub fn stop<C>(mut self, offset: f32, color: C) -> Self
+    where
+        C: IntoLinSrgba<ColorScalar>,
+    {
+        let color = color.into_lin_srgba();
+        let ix = self.stops.partition_point(|s| s.offset <= offset);
+        self.stops.insert(ix, Stop { offset, color });
+        self
+    }
+
+    /// Add the given colors as stops, evenly spaced from offset `0.0` to `1.0`.
+    pub fn colors<I>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoLinSrgba<ColorScalar>,
+    {
+        let colors: Vec<_> = colors.into_iter().collect();
+        let step = 1.0 / (colors.len().max(2) - 1) as f32;
+        for (i, color) in colors.into_iter().enumerate() {
+            self = self.stop(i as f32 * step, color);
+        }
+        self
+    }
+
+    /// Specify how the gradient is extended beyond the range `0.0` to `1.0`.
+    pub fn spread(mut self, spread: Spread) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// The offset along the gradient at the given point, prior to applying the spread.
+    pub fn offset(&self, p: Point2) -> f32 {
+        match self.kind {
+            Kind::Linear { start, end } => {
+                let d = end - start;
+                let len_sq = d.length_squared();
+                if len_sq > 0.0 {
+                    (p - start).dot(d) / len_sq
+                } else {
+                    0.0
+                }
+            }
+            Kind::Radial { center, radius } => {
+                if radius > 0.0 {
+                    (p - center).length() / radius
+                } else {
+                    0.0
+                }
+            }
+            Kind::Conic { center, angle } => {
+                let d = p - center;
+                ((d.y.atan2(d.x) - angle) / TAU).rem_euclid(1.0)
+            }
+        }
+    }
+
+    /// The color at the given offset along the gradient.
+    ///
+    /// Returns fully transparent black if the gradient has no stops.
+    pub fn color_at(&self, offset: f32) -> LinSrgba {
+        let t = match self.spread {
+            Spread::Pad => offset,
+            Spread::Repeat => offset.rem_euclid(1.0),
+            Spread::Reflect => 1.0 - (offset.rem_euclid(2.0) - 1.0).abs(),
+        };
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return LinSrgba::new(0.0, 0.0, 0.0, 0.0),
+        };
+        if t < first.offset {
+            return first.color;
+        }
+        if t >= last.offset {
+            return last.color;
+        }
+        let ix = self.stops.partition_point(|s| s.offset <= t);
+        let (a, b) = (self.stops[ix - 1], self.stops[ix]);
+        let f = (t - a.offset) / (b.offset - a.offset);
+        lerp_color(a.color, b.color, f)
+    }
+
+    /// The color of the gradient at the given point.
+    pub fn color(&self, p: Point2) -> LinSrgba {
+        self.color_at(self.offset(p))
+    }
+
+    /// Subdivide the given triangles so that interpolating the gradient's colors at their vertices
+    /// closely approximates the gradient.
+    ///
+    /// New vertices are appended to `points` and `indices` is replaced with the subdivided
+    /// triangles. Edges are subdivided consistently between neighbouring triangles so that no
+    /// cracks are introduced.
+    pub fn subdivide(&self, points: &mut Vec<Point2>, indices: &mut Vec<u32>) {
+        let mut midpoints: HashMap<(u32, u32), Option<u32>> = HashMap::new();
+        let mut stack: Vec<[u32; 3]> = indices
+            .chunks_exact(3)
+            .map(|t| [t[0], t[1], t[2]])
+            .collect();
+        indices.clear();
+        while let Some([a, b, c]) = stack.pop() {
+            let mut split = |i: u32, j: u32| {
+                let key = (i.min(j), i.max(j));
+                if let Some(&mid) = midpoints.get(&key) {
+                    return mid;
+                }
+                let (p, q) = (points[i as usize], points[j as usize]);
+                let mid = if points.len() < MAX_VERTICES && self.needs_split(p, q) {
+                    points.push((p + q) * 0.5);
+                    Some(points.len() as u32 - 1)
+                } else {
+                    None
+                };
+                midpoints.insert(key, mid);
+                mid
+            };
+            let tris: &[[u32; 3]] = match (split(a, b), split(b, c), split(c, a)) {
+                (None, None, None) => {
+                    indices.extend_from_slice(&[a, b, c]);
+                    continue;
+                }
+                (Some(ab), None, None) => &[[a, ab, c], [ab, b, c]],
+                (None, Some(bc), None) => &[[a, b, bc], [a, bc, c]],
+                (None, None, Some(ca)) => &[[a, b, ca], [ca, b, c]],
+                (Some(ab), Some(bc), None) => &[[ab, b, bc], [a, ab, bc], [a, bc, c]],
+                (None, Some(bc), Some(ca)) => &[[bc, c, ca], [a, b, bc], [a, bc, ca]],
+                (Some(ab), None, Some(ca)) => &[[a, ab, ca], [ab, b, c], [ab, c, ca]],
+                (Some(ab), Some(bc), Some(ca)) => {
+                    &[[a, ab, ca], [ab, b, bc], [ca, bc, c], [ab, bc, ca]]
+                }
+            };
+            stack.extend_from_slice(tris);
+        }
+    }
+
+    // Whether or not the edge between the given points should be subdivided.
+    fn needs_split(&self, p: Point2, q: Point2) -> bool {
+        if p.distance(q) <= MIN_EDGE_LENGTH {
+            return false;
+        }
+        // Split edges crossing a stop as the gradient is not linear across them.
+        let (tp, tq) = (self.offset(p), self.offset(q));
+        let (lo, hi) = (tp.min(tq), tp.max(tq));
+        if self.spread == Spread::Pad && self.stops.iter().any(|s| lo < s.offset && s.offset < hi) {
+            return true;
+        }
+        // Split edges along which the gradient differs from the interpolated vertex colors.
+        let (cp, cq) = (self.color_at(tp), self.color_at(tq));
+        [0.25, 0.5, 0.75].iter().any(|&f| {
+            let expected = self.color(p.lerp(q, f));
+            let actual = lerp_color(cp, cq, f);
+            color_distance(expected, actual) > COLOR_TOLERANCE
+        })
+    }
+}
+
+fn lerp_color(a: LinSrgba, b: LinSrgba, f: f32) -> LinSrgba {
+    LinSrgba::new(
+        a.red + (b.red - a.red) * f,
+        a.green + (b.green - a.green) * f,
+        a.blue + (b.blue - a.blue) * f,
+        a.alpha + (b.alpha - a.alpha) * f,
+    )
+}
+
+fn color_distance(a: LinSrgba, b: LinSrgba) -> f32 {
+    (a.red - b.red)
+        .abs()
+        .max((a.green - b.green).abs())
+        .max((a.blue - b.blue).abs())
+        .max((a.alpha - b.alpha).abs())
+}
@@ -1,5 +1,56 @@
+use lyon::math::Point;
+use lyon::path::iterator::PathIterator;
+use lyon::path::PathEvent;
 use lyon::tessellation::{LineCap, LineJoin, StrokeOptions};
 
+/// Distances along a path within which dash boundaries are considered to coincide.
+const DASH_EPSILON: f32 = 1e-4;
+
+/// A dash pattern applied to a stroke.
+///
+/// The pattern describes alternating lengths of "on" and "off" distances along the stroke,
+/// beginning with "on". As in SVG, a pattern with an odd number of lengths is repeated to yield an
+/// even number, and the pattern restarts at the beginning of each sub-path. A pattern that is
+/// empty, contains negative lengths or sums to zero results in a solid stroke.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dashes {
+    /// The alternating lengths of dashes and gaps.
+    pub pattern: Vec<f32>,
+    /// The distance into the pattern at which each sub-path begins.
+    pub offset: f32,
+    /// The cap applied to both ends of every dash. If `None`, the start and end caps of the stroke
+    /// are used.
+    pub cap: Option<LineCap>,
+}
+
+/// Nodes whose stroke may be dashed.
+///
+/// Dashing is applied to the flattened path prior to stroke tessellation, with each dash stroked
+/// as a separate sub-path. Strokes of colored points are dashed using a single stroke color, while
+/// strokes of textured points are never dashed.
+pub trait SetDashes: Sized {
+    /// Provide a mutable reference to the dash properties.
+    fn dashes_mut(&mut self) -> &mut Dashes;
+
+    /// Dash the stroke with the given alternating lengths of dashes and gaps.
+    fn dashes(mut self, pattern: &[f32]) -> Self {
+        self.dashes_mut().pattern = pattern.to_vec();
+        self
+    }
+
+    /// The distance into the dash pattern at which each sub-path begins.
+    fn dash_offset(mut self, offset: f32) -> Self {
+        self.dashes_mut().offset = offset;
+        self
+    }
+
+    /// The cap applied to both ends of every dash, in place of the stroke's start and end caps.
+    fn dash_cap(mut self, cap: LineCap) -> Self {
+        self.dashes_mut().cap = Some(cap);
+        self
+    }
+}
+
 /// Nodes that support stroke tessellation.
 ///
 /// This trait allows the `Drawing` context to automatically provide an implementation of the
@@ -156,3 +207,182 @@ impl SetStroke for Option<StrokeOptions> {
         self.get_or_insert_with(Default::default)
     }
 }
+
+impl SetDashes for Option<Dashes> {
+    fn dashes_mut(&mut self) -> &mut Dashes {
+        self.get_or_insert_with(Default::default)
+    }
+}
+
+impl Dashes {
+    /// The pattern with an even number of lengths, or `None` if the stroke should be solid.
+    fn normalized_pattern(&self) -> Option<Vec<f32>> {
+        let valid = self.pattern.iter().all(|&l| l.is_finite() && l >= 0.0);
+        let total: f32 = self.pattern.iter().sum();
+        if !valid || total <= 0.0 {
+            return None;
+        }
+        let mut pattern = self.pattern.clone();
+        if pattern.len() % 2 == 1 {
+            pattern.extend(self.pattern.iter().cloned());
+        }
+        Some(pattern)
+    }
+
+    /// The stroke options with which to tessellate the dashes.
+    pub fn stroke_options(&self, mut opts: StrokeOptions) -> StrokeOptions {
+        if let Some(cap) = self.cap {
+            opts.start_cap = cap;
+            opts.end_cap = cap;
+        }
+        opts
+    }
+
+    /// Split the given path events into a separate open sub-path for each dash.
+    ///
+    /// Curves are first flattened into line segments with the given tolerance. If the pattern
+    /// would produce a solid stroke, the events are returned unchanged.
+    pub fn dash<I>(&self, events: I, tolerance: f32) -> Vec<PathEvent>
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let pattern = match self.normalized_pattern() {
+            Some(pattern) => pattern,
+            None => return events.into_iter().collect(),
+        };
+        let mut dasher = Dasher::new(&pattern, self.offset);
+        let mut output = vec![];
+        for event in events.into_iter().flattened(tolerance) {
+            match event {
+                PathEvent::Begin { at } => {
+                    dasher = Dasher::new(&pattern, self.offset);
+                    dasher.begin(at, &mut output);
+                }
+                PathEvent::Line { from, to } => dasher.line(from, to, &mut output),
+                PathEvent::End { last, first, close } => {
+                    if close {
+                        dasher.line(last, first, &mut output);
+                    }
+                    dasher.end(&mut output);
+                }
+                // Flattening yields only lines.
+                PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => (),
+            }
+        }
+        output
+    }
+}
+
+// Walks the segments of a sub-path, tracking the position within the dash pattern.
+struct Dasher<'a> {
+    pattern: &'a [f32],
+    // The index of the current dash or gap.
+    index: usize,
+    // The distance remaining within the current dash or gap.
+    remaining: f32,
+    // The start of the current dash, if one is in progress.
+    dash_start: Option<Point>,
+    // The position reached along the sub-path.
+    position: Point,
+}
+
+impl<'a> Dasher<'a> {
+    fn new(pattern: &'a [f32], offset: f32) -> Self {
+        let total: f32 = pattern.iter().sum();
+        let mut phase = offset.rem_euclid(total);
+        let mut index = 0;
+        for _ in 0..pattern.len() {
+            // Zero-length dashes are only skipped while there is phase left to consume.
+            if phase == 0.0 || phase < pattern[index] {
+                break;
+            }
+            phase -= pattern[index];
+            index = (index + 1) % pattern.len();
+        }
+        Dasher {
+            pattern,
+            index,
+            remaining: pattern[index] - phase,
+            dash_start: None,
+            position: Point::new(0.0, 0.0),
+        }
+    }
+
+    // Even indices are dashes while odd indices are gaps.
+    fn is_dash(&self) -> bool {
+        self.index & 1 == 0
+    }
+
+    fn begin(&mut self, at: Point, output: &mut Vec<PathEvent>) {
+        self.position = at;
+        if self.is_dash() {
+            output.push(PathEvent::Begin { at });
+            self.dash_start = Some(at);
+        }
+        self.advance(output);
+    }
+
+    fn line(&mut self, from: Point, to: Point, output: &mut Vec<PathEvent>) {
+        let len = (to - from).length();
+        // Zero-length segments, e.g. between repeated points, have no direction to dash along.
+        if len <= 0.0 {
+            return;
+        }
+        let mut travelled = 0.0;
+        while self.remaining <= len - travelled + DASH_EPSILON {
+            travelled += self.remaining;
+            let point = from.lerp(to, travelled / len);
+            self.line_to(point, output);
+            self.remaining = 0.0;
+            self.advance(output);
+        }
+        self.remaining -= len - travelled;
+        if travelled < len {
+            self.line_to(to, output);
+        }
+    }
+
+    // Finish the sub-path, ending any dash in progress.
+    fn end(&mut self, output: &mut Vec<PathEvent>) {
+        // A dash begun exactly at the end of the sub-path has no extent, so discard its beginning.
+        let remaining = self.pattern[self.index];
+        if self.dash_start.is_some() && remaining > 0.0 && self.remaining == remaining {
+            self.dash_start = None;
+            output.pop();
+        }
+        self.end_dash(output);
+    }
+
+    fn end_dash(&mut self, output: &mut Vec<PathEvent>) {
+        if let Some(first) = self.dash_start.take() {
+            let last = self.position;
+            output.push(PathEvent::End {
+                last,
+                first,
+                close: false,
+            });
+        }
+    }
+
+    fn line_to(&mut self, to: Point, output: &mut Vec<PathEvent>) {
+        let from = self.position;
+        if self.dash_start.is_some() && from != to {
+            output.push(PathEvent::Line { from, to });
+        }
+        self.position = to;
+    }
+
+    // Step to the next dash or gap for as long as the current one is exhausted.
+    fn advance(&mut self, output: &mut Vec<PathEvent>) {
+        while self.remaining <= 0.0 {
+            self.end_dash(output);
+            self.index = (self.index + 1) % self.pattern.len();
+            self.remaining = self.pattern[self.index];
+            if self.is_dash() {
+                let at = self.position;
+                output.push(PathEvent::Begin { at });
+                self.dash_start = Some(at);
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Items related to rendering a **Draw** to an offscreen texture.
+
+use crate::draw::{self, Draw};
+use crate::frame::Frame;
+use crate::wgpu;
+use std::cell::RefCell;
+
+/// An offscreen texture to which a **Draw** may be rendered via `Draw::to_texture`.
+///
+/// When multisampling is enabled, drawing occurs within an inner multisampled texture that is
+/// resolved to the target's `texture` at the end of each render pass. The renderer, along with
+/// its depth buffer and glyph cache, is created once and re-used for every render.
+///
+/// The resolved texture may be sampled like any other, e.g. via `draw.texture(target.texture())`,
+/// making render targets useful for feedback effects and multi-pass compositions. Note that a
+/// target may not be sampled while it is being rendered to, so feedback effects require two
+/// targets that swap roles each frame.
+#[derive(Debug)]
+pub struct RenderTarget {
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    multisampled_texture_view: Option<wgpu::TextureView>,
+    sample_count: u32,
+    scale_factor: f32,
+    renderer: RefCell<draw::Renderer>,
+}
+
+/// A type used for building a **RenderTarget**.
+#[derive(Debug)]
+pub struct Builder {
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    scale_factor: f32,
+    usage: wgpu::TextureUsages,
+    renderer: draw::RendererBuilder,
+}
+
+impl RenderTarget {
+    /// Begin building a new **RenderTarget**.
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    /// Create a render target of the given size with the default format and sample count.
+    pub fn new(device: &wgpu::Device, size: [u32; 2]) -> Self {
+        Builder::new().build(device, size)
+    }
+
+    /// The resolved, single-sampled texture containing the result of the last render.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// A view of the resolved texture.
+    pub fn texture_view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+
+    /// The size of the target's texture in pixels.
+    pub fn size(&self) -> [u32; 2] {
+        self.texture.size()
+    }
+
+    /// The number of samples used when drawing to the target.
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// The scale factor used to map points to pixels.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Encode the commands necessary to render the given **Draw** to the target.
+    ///
+    /// The target's texture contains the result once the `encoder` has been submitted.
+    pub fn render(&self, device: &wgpu::Device, encoder: &mut wgpu::CommandEncoder, draw: &Draw) {
+        let size = self.texture.size();
+        let mut renderer = self.renderer.borrow_mut();
+        match self.multisampled_texture_view {
+            Some(ref multisampled) => renderer.encode_render_pass(
+                device,
+                encoder,
+                draw,
+                self.scale_factor,
+                size,
+                multisampled,
+                Some(&self.texture_view),
+            ),
+            None => renderer.encode_render_pass(
+                device,
+                encoder,
+                draw,
+                self.scale_factor,
+                size,
+                &self.texture_view,
+                None,
+            ),
+        }
+    }
+}
+
+impl Builder {
+    /// The default number of samples used for multisample anti-aliasing.
+    pub const DEFAULT_SAMPLE_COUNT: u32 = Frame::DEFAULT_MSAA_SAMPLES;
+    /// The default texture format, matching that of the window **Frame**.
+    pub const DEFAULT_FORMAT: wgpu::TextureFormat = Frame::TEXTURE_FORMAT;
+
+    /// Begin building a **RenderTarget** with the default parameters.
+    pub fn new() -> Self {
+        Builder {
+            format: Self::DEFAULT_FORMAT,
+            sample_count: Self::DEFAULT_SAMPLE_COUNT,
+            scale_factor: 1.0,
+            usage: wgpu::TextureUsages::empty(),
+            renderer: Default::default(),
+        }
+    }
+
+    /// The format of the target's texture.
+    pub fn format(mut self, format: wgpu::TextureFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// The number of samples used for multisample anti-aliasing. A count of `1` disables
+    /// multisampling, as does `0`, which is treated as `1`.
+    pub fn sample_count(mut self, sample_count: u32) -> Self {
+        self.sample_count = sample_count.max(1);
+        self
+    }
+
+    /// The number of pixels per point, e.g. `2.0` to render at twice the resolution of the
+    /// target's size in points.
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Usages of the target's texture in addition to `RENDER_ATTACHMENT`, `TEXTURE_BINDING` and
+    /// `COPY_SRC`, which are always enabled.
+    pub fn usage(mut self, usage: wgpu::TextureUsages) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// The builder used to create the target's renderer, e.g. to specify the depth format or the
+    /// size of the glyph cache.
+    pub fn renderer(mut self, renderer: draw::RendererBuilder) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    /// The descriptor of the resolved texture of a target of the given size in pixels.
+    pub fn texture_descriptor(&self, size: [u32; 2]) -> wgpu::TextureDescriptor<'static> {
+        wgpu::TextureBuilder::new()
+            .size(size)
+            .format(self.format)
+            .usage(
+                wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_SRC
+                    | self.usage,
+            )
+            .into_descriptor()
+    }
+
+    /// The descriptor of the multisampled texture of a target of the given size in pixels, or
+    /// `None` if multisampling is disabled.
+    pub fn multisampled_texture_descriptor(
+        &self,
+        size: [u32; 2],
+    ) -> Option<wgpu::TextureDescriptor<'static>> {
+        match self.sample_count {
+            1 => None,
+            sample_count => Some(
+                wgpu::TextureBuilder::new()
+                    .size(size)
+                    .format(self.format)
+                    .sample_count(sample_count)
+                    .usage(wgpu::TextureUsages::RENDER_ATTACHMENT)
+                    .into_descriptor(),
+            ),
+        }
+    }
+
+    /// Build the **RenderTarget** with a texture of the given size in pixels.
+    pub fn build(self, device: &wgpu::Device, size: [u32; 2]) -> RenderTarget {
+        let texture = wgpu::TextureBuilder::from(self.texture_descriptor(size)).build(device);
+        let texture_view = texture.view().build();
+        let multisampled_texture_view = self.multisampled_texture_descriptor(size).map(|desc| {
+            let multisampled_texture = wgpu::TextureBuilder::from(desc).build(device);
+            multisampled_texture.view().build()
+        });
+        let Builder {
+            format,
+            sample_count,
+            scale_factor,
+            renderer,
+            ..
+        } = self;
+        let renderer = renderer.build(device, size, scale_factor, sample_count, format);
+        RenderTarget {
+            texture,
+            texture_view,
+            multisampled_texture_view,
+            sample_count,
+            scale_factor,
+            renderer: RefCell::new(renderer),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Draw {
+    /// Render the **Draw**'s inner list of commands to the given **RenderTarget**.
+    ///
+    /// The commands are encoded via a new command encoder that is submitted to the given queue
+    /// before returning, after which the target's texture may be sampled or read, e.g. by drawing
+    /// it via `draw.texture(target.texture())`.
+    pub fn to_texture(&self, device: &wgpu::DeviceQueuePair, target: &RenderTarget) {
+        let desc = wgpu::CommandEncoderDescriptor {
+            label: Some("nannou Draw to_texture"),
+        };
+        let mut encoder = device.device().create_command_encoder(&desc);
+        target.render(device.device(), &mut encoder, self);
+        device.queue().submit(Some(encoder.finish()));
+    }
+
+    /// Encode the commands necessary to render the **Draw**'s inner list of commands to the given
+    /// **RenderTarget**.
+    ///
+    /// The `encoder` must be submitted before the target's texture is sampled or read. This is
+    /// useful for encoding further commands that depend on the result, e.g. a texture capture,
+    /// within the same submission.
+    pub fn encode_to_texture(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &RenderTarget,
+    ) {
+        target.render(device, encoder, self);
+    }
+}
@@ -0,0 +1,211 @@
+//! Compositing of **Layer**s with the contents of the output attachment.
+
+use crate::draw::layer::Properties;
+use crate::wgpu;
+use wgpu::util::DeviceExt;
+
+/// The pipelines and textures used to composite layers, created when the first layer is drawn.
+#[derive(Debug)]
+pub(crate) struct Compositor {
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    composite_bind_group_layout: wgpu::BindGroupLayout,
+    composite_pipeline: wgpu::RenderPipeline,
+    blit_bind_group_layout: wgpu::BindGroupLayout,
+    blit_pipeline: wgpu::RenderPipeline,
+    // Textures for each level of layer nesting.
+    targets: Vec<Targets>,
+}
+
+/// The textures used to render and composite a layer.
+#[derive(Clone, Debug)]
+pub(crate) struct Targets {
+    /// The multisampled texture to which the layer's contents are drawn, if multisampling.
+    pub layer_msaa: Option<wgpu::TextureView>,
+    /// The texture containing the layer's contents.
+    pub layer: wgpu::TextureView,
+    /// When multisampling, the texture to which the output is resolved so that it may be read
+    /// while compositing. Otherwise, the texture to which the composited result is written before
+    /// being copied to the output.
+    pub backdrop: wgpu::TextureView,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct Uniforms {
+    blend_mode: u32,
+    opacity: f32,
+    _padding: [u32; 2],
+}
+
+impl Compositor {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, sample_count: u32) -> Self {
+        let vs_desc = wgpu::include_wgsl!("shaders/fullscreen_vs.wgsl");
+        let composite_fs_desc = wgpu::include_wgsl!("shaders/layer_fs.wgsl");
+        let blit_fs_desc = wgpu::include_wgsl!("shaders/blit_fs.wgsl");
+        let vs_mod = device.create_shader_module(&vs_desc);
+        let composite_fs_mod = device.create_shader_module(&composite_fs_desc);
+        let blit_fs_mod = device.create_shader_module(&blit_fs_desc);
+
+        let sample_type = format.describe().sample_type;
+        let composite_bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+            .uniform_buffer(wgpu::ShaderStages::FRAGMENT, false)
+            .texture(
+                wgpu::ShaderStages::FRAGMENT,
+                false,
+                wgpu::TextureViewDimension::D2,
+                sample_type,
+            )
+            .texture(
+                wgpu::ShaderStages::FRAGMENT,
+                false,
+                wgpu::TextureViewDimension::D2,
+                sample_type,
+            )
+            .build(device);
+        let blit_bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+            .texture(
+                wgpu::ShaderStages::FRAGMENT,
+                false,
+                wgpu::TextureViewDimension::D2,
+                sample_type,
+            )
+            .build(device);
+
+        // Both pipelines replace every pixel of the target.
+        let composite_pipeline = wgpu::RenderPipelineBuilder::from_layout_descriptor(
+            &[&composite_bind_group_layout][..],
+            &vs_mod,
+        )
+        .fragment_shader(&composite_fs_mod)
+        .color_format(format)
+        .color_blend(wgpu::BlendComponent::REPLACE)
+        .alpha_blend(wgpu::BlendComponent::REPLACE)
+        .sample_count(sample_count)
+        .build(device);
+        let blit_pipeline = wgpu::RenderPipelineBuilder::from_layout_descriptor(
+            &[&blit_bind_group_layout][..],
+            &vs_mod,
+        )
+        .fragment_shader(&blit_fs_mod)
+        .color_format(format)
+        .color_blend(wgpu::BlendComponent::REPLACE)
+        .alpha_blend(wgpu::BlendComponent::REPLACE)
+        .sample_count(sample_count)
+        .build(device);
+
+        Compositor {
+            format,
+            sample_count,
+            composite_bind_group_layout,
+            composite_pipeline,
+            blit_bind_group_layout,
+            blit_pipeline,
+            targets: vec![],
+        }
+    }
+
+    /// The textures for layers at the given level of nesting, resized if necessary.
+    pub fn targets(&mut self, device: &wgpu::Device, depth: usize, size: [u32; 2]) -> Targets {
+        while self.targets.len() <= depth {
+            let targets = create_targets(device, size, self.format, self.sample_count);
+            self.targets.push(targets);
+        }
+        if self.targets[depth].layer.extent() != extent(size) {
+            self.targets[depth] = create_targets(device, size, self.format, self.sample_count);
+        }
+        self.targets[depth].clone()
+    }
+
+    /// Composite the layer within the given targets with the output attachment.
+    ///
+    /// When multisampling, the output must have been resolved to the `backdrop` of the targets,
+    /// and the composited result is written to every sample of the output. Otherwise the output
+    /// is read directly, requiring the `TEXTURE_BINDING` usage.
+    pub fn composite(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        properties: Properties,
+        output_attachment: &wgpu::TextureView,
+        targets: &Targets,
+    ) {
+        let uniforms = Uniforms {
+            blend_mode: properties.blend_mode as u32,
+            opacity: properties.opacity,
+            _padding: [0; 2],
+        };
+        let uniforms_bytes = unsafe { wgpu::bytes::from(&uniforms) };
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("nannou Renderer layer uniform_buffer"),
+            contents: uniforms_bytes,
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+
+        let (backdrop, dst) = match self.sample_count {
+            1 => (output_attachment, &targets.backdrop),
+            _ => (&targets.backdrop, output_attachment),
+        };
+        let bind_group = wgpu::BindGroupBuilder::new()
+            .buffer::<Uniforms>(&uniform_buffer, 0..1)
+            .texture_view(backdrop)
+            .texture_view(&targets.layer)
+            .build(device, &self.composite_bind_group_layout);
+        {
+            let mut render_pass = wgpu::RenderPassBuilder::new()
+                .color_attachment(dst, |color| color.load_op(wgpu::LoadOp::Load))
+                .begin(encoder);
+            render_pass.set_pipeline(&self.composite_pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+
+        // Without multisampling, copy the result back to the output.
+        if self.sample_count == 1 {
+            let bind_group = wgpu::BindGroupBuilder::new()
+                .texture_view(&targets.backdrop)
+                .build(device, &self.blit_bind_group_layout);
+            let mut render_pass = wgpu::RenderPassBuilder::new()
+                .color_attachment(output_attachment, |color| color.load_op(wgpu::LoadOp::Load))
+                .begin(encoder);
+            render_pass.set_pipeline(&self.blit_pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+    }
+}
+
+fn extent([width, height]: [u32; 2]) -> wgpu::Extent3d {
+    wgpu::Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    }
+}
+
+fn create_targets(
+    device: &wgpu::Device,
+    size: [u32; 2],
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> Targets {
+    let texture = |sample_count: u32, usage: wgpu::TextureUsages| {
+        wgpu::TextureBuilder::new()
+            .size(size)
+            .format(format)
+            .sample_count(sample_count)
+            .usage(wgpu::TextureUsages::RENDER_ATTACHMENT | usage)
+            .build(device)
+            .view()
+            .build()
+    };
+    let layer_msaa = match sample_count {
+        1 => None,
+        _ => Some(texture(sample_count, wgpu::TextureUsages::empty())),
+    };
+    Targets {
+        layer_msaa,
+        layer: texture(1, wgpu::TextureUsages::TEXTURE_BINDING),
+        backdrop: texture(1, wgpu::TextureUsages::TEXTURE_BINDING),
+    }
+}
@@ -1,5 +1,6 @@
 use crate::draw;
 use crate::draw::mesh::vertex::{Color, TexCoords};
+use crate::draw::{Camera, Light, Material, Projection};
 use crate::frame::Frame;
 use crate::geom::{self, Point2, Rect};
 use crate::glam::{Mat4, Vec2, Vec3};
@@ -14,6 +15,13 @@ use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 
+mod layer;
+
+/// The maximum number of lights that may illuminate a single **Draw**ing.
+///
+/// Lights added beyond this limit are ignored.
+pub const MAX_LIGHTS: usize = 8;
+
 /// Draw API primitives that may be rendered via the **Renderer** type.
 pub trait RenderPrimitive {
     /// Render self into the given mesh.
@@ -79,6 +87,13 @@ pub struct Renderer {
     fs_mod: wgpu::ShaderModule,
     // One pipeline per unique Pipeline ID (combination of blend, topology and component type).
     pipelines: HashMap<PipelineId, wgpu::RenderPipeline>,
+    mask_fs_mod: wgpu::ShaderModule,
+    // Pipelines for writing clip masks to the stencil buffer, created on first use.
+    mask_pipelines: HashMap<MaskOp, wgpu::RenderPipeline>,
+    // Composites layers with the output, created when the first layer is drawn.
+    compositor: Option<layer::Compositor>,
+    // The nesting depth of the layer currently being rendered.
+    layer_depth: usize,
     glyph_cache_texture: wgpu::Texture,
     depth_texture: wgpu::Texture,
     depth_texture_view: wgpu::TextureView,
@@ -94,10 +109,14 @@ pub struct Renderer {
     output_color_format: wgpu::TextureFormat,
     sample_count: u32,
     scale_factor: f32,
+    camera: Option<Camera>,
     render_commands: Vec<RenderCommand>,
     mesh: draw::Mesh,
     vertex_mode_buffer: Vec<VertexMode>,
+    vertex_material_buffer: Vec<VertexMaterial>,
+    instance_buffer: Vec<Instance>,
     uniform_buffer: wgpu::Buffer,
+    light_uniform_buffer: wgpu::Buffer,
 }
 
 /// A type aimed at simplifying construction of a `draw::Renderer`.
@@ -118,10 +137,15 @@ enum RenderCommand {
     SetBindGroup(BindGroupId),
     /// Set the rectangular scissor.
     SetScissor(Scissor),
-    /// Draw the given vertex range.
+    /// Change to the pipeline for writing clip masks with the given stencil operation.
+    SetMaskPipeline(MaskOp),
+    /// Set the stencil value against which clip masks and clipped drawings are compared.
+    SetStencilReference(u32),
+    /// Draw the given vertex range once per instance within the given instance range.
     DrawIndexed {
         start_vertex: i32,
         index_range: std::ops::Range<u32>,
+        instance_range: std::ops::Range<u32>,
     },
 }
 
@@ -150,12 +174,58 @@ struct Uniforms {
     proj: Mat4,
 }
 
+/// The lights illuminating the scene, read by the fragment shader for lit vertices.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct LightUniforms {
+    /// The sum of all ambient lights. The `w` component is unused.
+    ambient: [f32; 4],
+    /// The direction towards the viewer if `w` is `0.0`, otherwise the position of the viewer.
+    eye: [f32; 4],
+    /// The number of lights in use.
+    light_count: u32,
+    _padding: [u32; 3],
+    lights: [LightUniform; MAX_LIGHTS],
+}
+
+/// A single directional or point light.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+struct LightUniform {
+    /// The direction in which the light travels if `w` is `0.0`, otherwise the light's position.
+    position: [f32; 4],
+    /// The color of the light scaled by its intensity. The `w` component is unused.
+    color: [f32; 4],
+}
+
+/// The per-vertex material: diffuse, specular, shininess and whether or not the vertex is lit.
+type VertexMaterial = [f32; 4];
+
+/// The transform and color applied to each instance of a primitive.
+///
+/// Primitives that are not instanced are drawn via the identity instance at index `0`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+struct Instance {
+    transform: [[f32; 4]; 4],
+    color: [f32; 4],
+}
+
 type SamplerId = u64;
 type BindGroupId = (SamplerId, wgpu::TextureViewId);
 type BlendId = u64;
 type ColorId = BlendId;
 type AlphaId = BlendId;
 
+/// The operation applied to the stencil value of each pixel covered by a clip mask.
+///
+/// The operation is only applied where the stencil value equals the current reference.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+enum MaskOp {
+    Increment,
+    Decrement,
+}
+
 /// Each of the properties that indicate a unique pipeline.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 struct PipelineId {
@@ -175,6 +245,12 @@ impl RenderPrimitive for draw::Primitive {
     fn render_primitive(self, ctxt: RenderContext, mesh: &mut draw::Mesh) -> PrimitiveRender {
         match self {
             draw::Primitive::Arrow(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cuboid(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Sphere(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cylinder(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Cone(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Torus(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Plane(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Mesh(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Path(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Polygon(prim) => prim.render_primitive(ctxt, mesh),
@@ -183,6 +259,7 @@ impl RenderPrimitive for draw::Primitive {
             draw::Primitive::Quad(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Rect(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Line(prim) => prim.render_primitive(ctxt, mesh),
+            draw::Primitive::Svg(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Text(prim) => prim.render_primitive(ctxt, mesh),
             draw::Primitive::Texture(prim) => prim.render_primitive(ctxt, mesh),
             _ => PrimitiveRender::default(),
@@ -200,6 +277,29 @@ impl fmt::Debug for GlyphCache {
     }
 }
 
+impl Instance {
+    /// The instance applied to all primitives that are not instanced.
+    const IDENTITY: Self = Instance {
+        transform: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+        color: [1.0; 4],
+    };
+    /// The columns of the transform followed by the color.
+    const ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+        6 => Float32x4,
+        7 => Float32x4,
+        8 => Float32x4,
+        9 => Float32x4,
+        10 => Float32x4
+    ];
+    /// The instance range for drawing primitives that are not instanced.
+    const DEFAULT_RANGE: std::ops::Range<u32> = 0..1;
+}
+
 impl PrimitiveRender {
     /// Specify a vertex mode for the primitive render.
     pub fn vertex_mode(vertex_mode: VertexMode) -> Self {
@@ -324,7 +424,7 @@ impl Builder {
 }
 
 impl GlyphCache {
-    fn new(size: [u32; 2], scale_tolerance: f32, position_tolerance: f32) -> Self {
+    pub(crate) fn new(size: [u32; 2], scale_tolerance: f32, position_tolerance: f32) -> Self {
         let [w, h] = size;
         let cache = text::GlyphCache::builder()
             .dimensions(w, h)
@@ -343,8 +443,10 @@ impl GlyphCache {
 }
 
 impl Renderer {
-    /// The default depth format
-    pub const DEFAULT_DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+    /// The default depth format.
+    ///
+    /// Includes a stencil aspect, as required for clipping via `Draw::clip`.
+    pub const DEFAULT_DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24PlusStencil8;
     /// The default size for the inner glyph cache.
     pub const DEFAULT_GLYPH_CACHE_SIZE: [u32; 2] = [1024; 2];
     /// The default scale tolerance for the glyph cache.
@@ -362,7 +464,8 @@ impl Renderer {
     /// See the **RendererBuilder** type for a simplified approach to building a renderer that will
     /// fall back to a set of reasonable defaults.
     ///
-    /// The `depth_format` will be used to construct a depth texture for depth testing.
+    /// The `depth_format` will be used to construct a depth texture for depth testing. Clip masks
+    /// are only applied if the format has a stencil aspect.
     ///
     /// The `glyph_cache_size` will be used to create a texture on which glyphs will be stored for
     /// efficient look-up.
@@ -386,9 +489,15 @@ impl Renderer {
 
         // Load shader modules.
         let vs_desc = wgpu::include_wgsl!("shaders/vs.wgsl");
-        let fs_desc = wgpu::include_wgsl!("shaders/fs.wgsl");
+        let fs_src = include_str!("shaders/fs.wgsl").replace("MAX_LIGHTS", &MAX_LIGHTS.to_string());
+        let fs_desc = wgpu::ShaderModuleDescriptor {
+            label: Some("shaders/fs.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(fs_src.into()),
+        };
         let vs_mod = device.create_shader_module(&vs_desc);
         let fs_mod = device.create_shader_module(&fs_desc);
+        let mask_fs_desc = wgpu::include_wgsl!("shaders/fs_mask.wgsl");
+        let mask_fs_mod = device.create_shader_module(&mask_fs_desc);
 
         // Create the glyph cache texture.
         let text_sampler_desc = wgpu::SamplerBuilder::new().into_descriptor();
@@ -415,7 +524,7 @@ impl Renderer {
         let default_texture_view = default_texture.view().build();
 
         // Initial uniform buffer values. These will be overridden on draw.
-        let uniforms = create_uniforms(output_attachment_size, output_scale_factor);
+        let uniforms = create_uniforms(output_attachment_size, output_scale_factor, None);
         let contents = uniforms_as_bytes(&uniforms);
         let usage = wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST;
         let uniform_buffer = device.create_buffer_init(&wgpu::BufferInitDescriptor {
@@ -424,10 +533,23 @@ impl Renderer {
             usage,
         });
 
+        // Initial light uniform values. These will be overridden on draw.
+        let light_uniforms = create_light_uniforms(&[], None);
+        let contents = light_uniforms_as_bytes(&light_uniforms);
+        let light_uniform_buffer = device.create_buffer_init(&wgpu::BufferInitDescriptor {
+            label: Some("nannou Renderer light_uniform_buffer"),
+            contents,
+            usage,
+        });
+
         // Bind group for uniforms.
         let uniform_bind_group_layout = create_uniform_bind_group_layout(device);
-        let uniform_bind_group =
-            create_uniform_bind_group(device, &uniform_bind_group_layout, &uniform_buffer);
+        let uniform_bind_group = create_uniform_bind_group(
+            device,
+            &uniform_bind_group_layout,
+            &uniform_buffer,
+            &light_uniform_buffer,
+        );
 
         // Bind group for text.
         let text_bind_group_layout = create_text_bind_group_layout(device, text_sampler_filtering);
@@ -449,11 +571,14 @@ impl Renderer {
 
         // Pipeline per unique pipelin ID.
         let pipelines = HashMap::default();
+        let mask_pipelines = HashMap::default();
 
         let texture_samplers = Some((sampler_id, texture_sampler)).into_iter().collect();
         let render_commands = vec![];
         let mesh = Default::default();
         let vertex_mode_buffer = vec![];
+        let vertex_material_buffer = vec![];
+        let instance_buffer = vec![Instance::IDENTITY];
 
         Self {
             vs_mod,
@@ -472,13 +597,21 @@ impl Renderer {
             texture_bind_group_layouts,
             texture_bind_groups,
             pipelines,
+            mask_fs_mod,
+            mask_pipelines,
+            compositor: None,
+            layer_depth: 0,
             output_color_format,
             sample_count,
             scale_factor: output_scale_factor,
+            camera: None,
             render_commands,
             mesh,
             vertex_mode_buffer,
+            vertex_material_buffer,
+            instance_buffer,
             uniform_buffer,
+            light_uniform_buffer,
         }
     }
 
@@ -487,18 +620,41 @@ impl Renderer {
         self.render_commands.clear();
         self.mesh.clear();
         self.vertex_mode_buffer.clear();
+        self.vertex_material_buffer.clear();
+        self.instance_buffer.clear();
+        self.instance_buffer.push(Instance::IDENTITY);
     }
 
     /// Generate a list of `RenderCommand`s from the given **Draw** instance and prepare any
     /// necessary vertex data.
     ///
-    /// Note that the given **Draw** instance will be *drained* of its commands.
+    /// Note that the given **Draw** instance will be *drained* of its commands. **Layer**s are
+    /// ignored, as they are composited in separate render passes by `encode_render_pass`.
     pub fn fill(
         &mut self,
         device: &wgpu::Device,
         draw: &draw::Draw,
         scale_factor: f32,
         output_attachment_size: [u32; 2],
+    ) {
+        let draw_cmds = draw.drain_commands().collect();
+        self.fill_commands(
+            device,
+            draw,
+            draw_cmds,
+            scale_factor,
+            output_attachment_size,
+        );
+    }
+
+    // Generate the `RenderCommand`s and vertex data for the given commands of the **Draw**.
+    fn fill_commands(
+        &mut self,
+        device: &wgpu::Device,
+        draw: &draw::Draw,
+        draw_cmds: Vec<draw::DrawCommand>,
+        scale_factor: f32,
+        output_attachment_size: [u32; 2],
     ) {
         // Pushes a draw command and updates the `curr_start_index`.
         //
@@ -507,6 +663,7 @@ impl Renderer {
         fn push_draw_cmd(
             curr_start_index: &mut u32,
             end_index: u32,
+            instance_range: std::ops::Range<u32>,
             render_commands: &mut Vec<RenderCommand>,
         ) -> bool {
             let index_range = *curr_start_index..end_index;
@@ -516,6 +673,7 @@ impl Renderer {
                 let cmd = RenderCommand::DrawIndexed {
                     start_vertex,
                     index_range,
+                    instance_range,
                 };
                 render_commands.push(cmd);
                 true
@@ -552,14 +710,207 @@ impl Renderer {
         let mut curr_scissor = None;
         let mut curr_tex_sampler_id = None;
 
-        // Collect all draw commands to avoid borrow errors.
-        let draw_cmds: Vec<_> = draw.drain_commands().collect();
+        // Clip masks are only applied if the depth texture has a stencil aspect.
+        let stencil = has_stencil_aspect(self.depth_texture.format());
+        // The masks currently written to the stencil buffer along with their index ranges.
+        let mut curr_masks: Vec<(draw::Mask, std::ops::Range<u32>)> = vec![];
+        // The index and instance ranges of a quad covering the output, created on first use.
+        let mut cover: Option<(std::ops::Range<u32>, std::ops::Range<u32>)> = None;
+        let full_scissor = Scissor {
+            left: 0,
+            bottom: 0,
+            width: w_px,
+            height: h_px,
+        };
+
         let draw_state = draw.state.borrow_mut();
         let intermediary_state = draw_state.intermediary_state.borrow();
         for cmd in draw_cmds {
             match cmd {
                 draw::DrawCommand::Context(ctxt) => curr_ctxt = ctxt,
+                draw::DrawCommand::Layer(_) => (),
                 draw::DrawCommand::Primitive(prim) => {
+                    // If the clip masks have changed, update the stencil buffer before drawing.
+                    let common = curr_masks
+                        .iter()
+                        .zip(&curr_ctxt.clip)
+                        .take_while(|((a, _), b)| a == *b)
+                        .count();
+                    if stencil && (common < curr_masks.len() || common < curr_ctxt.clip.len()) {
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            self.mesh.indices().len() as u32,
+                            Instance::DEFAULT_RANGE,
+                            &mut self.render_commands,
+                        );
+
+                        // Masks are written to the whole stencil buffer regardless of scissor.
+                        self.render_commands
+                            .push(RenderCommand::SetScissor(full_scissor));
+                        curr_scissor = None;
+                        curr_pipeline_id = None;
+
+                        // Inverted masks first mark the whole output via a quad covering it.
+                        let (cover_indices, cover_instances) = match cover {
+                            Some(ref cover) => cover.clone(),
+                            None => {
+                                let camera = draw_state.camera;
+                                let uniforms = create_uniforms(
+                                    output_attachment_size,
+                                    scale_factor,
+                                    camera.as_ref(),
+                                );
+                                let instance = self.instance_buffer.len() as u32;
+                                self.instance_buffer.push(Instance {
+                                    transform: uniforms.proj.inverse().to_cols_array_2d(),
+                                    color: [1.0; 4],
+                                });
+                                let start_vertex = self.mesh.points().len() as u32;
+                                let start_index = self.mesh.indices().len() as u32;
+                                let white = Color::new(1.0, 1.0, 1.0, 1.0);
+                                for &(x, y) in &[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                                {
+                                    let point = Vec3::new(x, y, 0.0);
+                                    let vertex = draw::mesh::vertex::new(point, white, Vec2::ZERO);
+                                    self.mesh.push_vertex(vertex);
+                                }
+                                let indices = [0, 1, 2, 0, 2, 3].iter().map(|i| start_vertex + i);
+                                self.mesh.extend_indices(indices);
+                                self.vertex_mode_buffer
+                                    .extend((0..4).map(|_| VertexMode::Color));
+                                let end_index = self.mesh.indices().len() as u32;
+                                let new_cover = (start_index..end_index, instance..instance + 1);
+                                cover = Some(new_cover.clone());
+                                new_cover
+                            }
+                        };
+
+                        // Pop the masks that no longer apply, innermost first.
+                        while curr_masks.len() > common {
+                            let depth = curr_masks.len() as u32;
+                            let (mask, index_range) = curr_masks.pop().expect("no mask to pop");
+                            let (index_range, instance_range) = match mask.inverted {
+                                true => (cover_indices.clone(), cover_instances.clone()),
+                                false => (index_range, Instance::DEFAULT_RANGE),
+                            };
+                            self.render_commands.extend(mask_commands(
+                                MaskOp::Decrement,
+                                depth,
+                                index_range,
+                                instance_range,
+                            ));
+                        }
+
+                        // Tessellate and push the new masks, outermost first.
+                        for mask in &curr_ctxt.clip[common..] {
+                            let depth = curr_masks.len() as u32;
+                            let start_index = self.mesh.indices().len() as u32;
+                            let mut transform = Mat4::IDENTITY;
+                            for cmd in mask.commands.iter() {
+                                let prim = match cmd {
+                                    draw::DrawCommand::Context(ctxt) => {
+                                        transform = ctxt.transform;
+                                        continue;
+                                    }
+                                    draw::DrawCommand::Primitive(prim) => prim.clone(),
+                                    draw::DrawCommand::Layer(_) => continue,
+                                };
+                                let prims = match prim {
+                                    draw::Primitive::Instances(instances) => instances
+                                        .transforms
+                                        .iter()
+                                        .map(|t| ((*instances.primitive).clone(), transform * *t))
+                                        .collect(),
+                                    prim => vec![(prim, transform)],
+                                };
+                                for (prim, transform) in prims {
+                                    let ctxt = RenderContext {
+                                        intermediary_mesh: &intermediary_state.intermediary_mesh,
+                                        path_event_buffer: &intermediary_state.path_event_buffer,
+                                        path_points_colored_buffer: &intermediary_state
+                                            .path_points_colored_buffer,
+                                        path_points_textured_buffer: &intermediary_state
+                                            .path_points_textured_buffer,
+                                        text_buffer: &intermediary_state.text_buffer,
+                                        theme: &draw_state.theme,
+                                        transform: &transform,
+                                        fill_tessellator: &mut fill_tessellator,
+                                        stroke_tessellator: &mut stroke_tessellator,
+                                        glyph_cache: &mut self.glyph_cache,
+                                        output_attachment_size: Vec2::new(
+                                            px_to_pt(w_px),
+                                            px_to_pt(h_px),
+                                        ),
+                                        output_attachment_scale_factor: scale_factor,
+                                    };
+                                    let render = prim.render_primitive(ctxt, &mut self.mesh);
+                                    let new_vs =
+                                        self.mesh.points().len() - self.vertex_mode_buffer.len();
+                                    let mode = match render.vertex_mode {
+                                        VertexMode::Text => VertexMode::Text,
+                                        _ => VertexMode::Color,
+                                    };
+                                    self.vertex_mode_buffer.extend((0..new_vs).map(|_| mode));
+                                }
+                            }
+                            let index_range = start_index..self.mesh.indices().len() as u32;
+                            if mask.inverted {
+                                self.render_commands.extend(mask_commands(
+                                    MaskOp::Increment,
+                                    depth,
+                                    cover_indices.clone(),
+                                    cover_instances.clone(),
+                                ));
+                                self.render_commands.extend(mask_commands(
+                                    MaskOp::Decrement,
+                                    depth + 1,
+                                    index_range.clone(),
+                                    Instance::DEFAULT_RANGE,
+                                ));
+                            } else {
+                                self.render_commands.extend(mask_commands(
+                                    MaskOp::Increment,
+                                    depth,
+                                    index_range.clone(),
+                                    Instance::DEFAULT_RANGE,
+                                ));
+                            }
+                            curr_masks.push((mask.clone(), index_range));
+                        }
+
+                        // Give the mask vertices the default material.
+                        let new_vs = self.mesh.points().len() - self.vertex_material_buffer.len();
+                        self.vertex_material_buffer
+                            .extend((0..new_vs).map(|_| vertex_material(None)));
+
+                        // Following drawings are limited to where the stencil matches the depth.
+                        let depth = curr_masks.len() as u32;
+                        self.render_commands
+                            .push(RenderCommand::SetStencilReference(depth));
+                        curr_start_index = self.mesh.indices().len() as u32;
+                    }
+
+                    // Instanced primitives are tessellated once within their local space, with
+                    // the context transform applied per instance instead.
+                    let (prim, instances) = match prim {
+                        draw::Primitive::Instances(instances) => {
+                            if instances.is_empty() {
+                                continue;
+                            }
+                            let draw::primitive::Instances {
+                                primitive,
+                                transforms,
+                                colors,
+                            } = instances;
+                            (*primitive, Some((transforms, colors)))
+                        }
+                        prim => (prim, None),
+                    };
+                    let transform = match instances {
+                        Some(_) => Mat4::IDENTITY,
+                        None => curr_ctxt.transform,
+                    };
+
                     // Track the prev index and vertex counts.
                     let prev_index_count = self.mesh.indices().len() as u32;
                     let prev_vert_count = self.mesh.vertex_count();
@@ -573,7 +924,7 @@ impl Renderer {
                             .path_points_textured_buffer,
                         text_buffer: &intermediary_state.text_buffer,
                         theme: &draw_state.theme,
-                        transform: &curr_ctxt.transform,
+                        transform: &transform,
                         fill_tessellator: &mut fill_tessellator,
                         stroke_tessellator: &mut stroke_tessellator,
                         glyph_cache: &mut self.glyph_cache,
@@ -637,6 +988,7 @@ impl Renderer {
                         push_draw_cmd(
                             &mut curr_start_index,
                             prev_index_count,
+                            Instance::DEFAULT_RANGE,
                             &mut self.render_commands,
                         );
                     }
@@ -690,6 +1042,37 @@ impl Renderer {
                     let mode = render.vertex_mode;
                     let new_vs = self.mesh.points().len() - self.vertex_mode_buffer.len();
                     self.vertex_mode_buffer.extend((0..new_vs).map(|_| mode));
+
+                    // Extend the material channel.
+                    let material = vertex_material(curr_ctxt.material.as_ref());
+                    self.vertex_material_buffer
+                        .extend((0..new_vs).map(|_| material));
+
+                    // Draw instances separately from the surrounding non-instanced vertices.
+                    if let Some((transforms, colors)) = instances {
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            prev_index_count,
+                            Instance::DEFAULT_RANGE,
+                            &mut self.render_commands,
+                        );
+                        let start = self.instance_buffer.len() as u32;
+                        let white = Color::new(1.0, 1.0, 1.0, 1.0);
+                        let colors = colors.into_iter().chain(std::iter::repeat(white));
+                        let new_instances = transforms.iter().zip(colors).map(|(t, c)| {
+                            let transform = (curr_ctxt.transform * *t).to_cols_array_2d();
+                            let color = [c.red, c.green, c.blue, c.alpha];
+                            Instance { transform, color }
+                        });
+                        self.instance_buffer.extend(new_instances);
+                        let end = self.instance_buffer.len() as u32;
+                        push_draw_cmd(
+                            &mut curr_start_index,
+                            self.mesh.indices().len() as u32,
+                            start..end,
+                            &mut self.render_commands,
+                        );
+                    }
                 }
             }
         }
@@ -698,9 +1081,32 @@ impl Renderer {
         push_draw_cmd(
             &mut curr_start_index,
             self.mesh.indices().len() as u32,
+            Instance::DEFAULT_RANGE,
             &mut self.render_commands,
         );
 
+        // Create the clip mask pipelines if they are required.
+        let mask_ops = self.render_commands.iter().filter_map(|cmd| match *cmd {
+            RenderCommand::SetMaskPipeline(op) => Some(op),
+            _ => None,
+        });
+        for op in mask_ops {
+            if !self.mask_pipelines.contains_key(&op) {
+                let pipeline = create_mask_render_pipeline(
+                    device,
+                    &self.uniform_bind_group_layout,
+                    &self.text_bind_group_layout,
+                    &self.vs_mod,
+                    &self.mask_fs_mod,
+                    self.output_color_format,
+                    self.depth_texture.format(),
+                    self.sample_count,
+                    op,
+                );
+                self.mask_pipelines.insert(op, pipeline);
+            }
+        }
+
         // Clear out unnecessary pipelines.
         self.pipelines
             .retain(|id, _| new_pipeline_ids.contains_key(id));
@@ -770,6 +1176,9 @@ impl Renderer {
     ///
     /// If the `output_attachment` is multisampled and should be resolved to another texture,
     /// include the `resolve_target`.
+    ///
+    /// Each **Layer** of the **Draw**ing is rendered to an intermediary texture and composited
+    /// with the output via additional render passes.
     pub fn encode_render_pass(
         &mut self,
         device: &wgpu::Device,
@@ -779,12 +1188,157 @@ impl Renderer {
         output_attachment_size: [u32; 2],
         output_attachment: &wgpu::TextureView,
         resolve_target: Option<&wgpu::TextureView>,
+    ) {
+        // Split the commands into those drawn before and after each layer.
+        let mut segments = vec![vec![]];
+        let mut layers = vec![];
+        for cmd in draw.drain_commands() {
+            match cmd {
+                draw::DrawCommand::Layer(layer) => {
+                    layers.push(layer);
+                    segments.push(vec![]);
+                }
+                cmd => segments.last_mut().expect("no segment").push(cmd),
+            }
+        }
+
+        // Retrieve the clear values based on the bg color.
+        let bg_color = draw.state.borrow().background_color;
+        let mut load_op = match bg_color {
+            None => wgpu::LoadOp::Load,
+            Some(color) => {
+                let (r, g, b, a) = color.into();
+                let (r, g, b, a) = (r as f64, g as f64, b as f64, a as f64);
+                let clear_color = wgpu::Color { r, g, b, a };
+                wgpu::LoadOp::Clear(clear_color)
+            }
+        };
+
+        // Retrieve the textures used for compositing the layers at this level of nesting.
+        let targets = match layers.is_empty() {
+            true => None,
+            false => {
+                let (format, sample_count) = (self.output_color_format, self.sample_count);
+                let compositor = self
+                    .compositor
+                    .get_or_insert_with(|| layer::Compositor::new(device, format, sample_count));
+                Some(compositor.targets(device, self.layer_depth, output_attachment_size))
+            }
+        };
+
+        let last = segments.len() - 1;
+        for (i, draw_cmds) in segments.into_iter().enumerate() {
+            // When multisampling, resolve to the backdrop of the following layer.
+            let resolve = match targets {
+                Some(ref targets) if i < last && self.sample_count > 1 => Some(&targets.backdrop),
+                _ => resolve_target,
+            };
+            self.encode_commands(
+                device,
+                encoder,
+                draw,
+                draw_cmds,
+                scale_factor,
+                output_attachment_size,
+                output_attachment,
+                resolve,
+                load_op,
+            );
+            load_op = wgpu::LoadOp::Load;
+            if let (Some(layer), Some(ref targets)) = (layers.get(i), &targets) {
+                self.encode_layer(
+                    device,
+                    encoder,
+                    draw,
+                    layer,
+                    scale_factor,
+                    output_attachment,
+                    targets,
+                );
+            }
+        }
+    }
+
+    // Render the contents of the layer and composite them with the output attachment.
+    #[allow(clippy::too_many_arguments)]
+    fn encode_layer(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        draw: &draw::Draw,
+        layer: &draw::Layer,
+        scale_factor: f32,
+        output_attachment: &wgpu::TextureView,
+        targets: &layer::Targets,
+    ) {
+        // The layer is viewed and lit like its parent and starts out transparent.
+        {
+            let parent_state = draw.state.borrow();
+            let mut state = layer.state.borrow_mut();
+            state.camera = parent_state.camera;
+            state.lights = parent_state.lights.clone();
+            state.background_color = Some(draw::properties::LinSrgba::new(0.0, 0.0, 0.0, 0.0));
+        }
+        let size = targets.layer.extent();
+        let size = [size.width, size.height];
+        self.layer_depth += 1;
+        match targets.layer_msaa {
+            Some(ref msaa) => self.encode_render_pass(
+                device,
+                encoder,
+                layer,
+                scale_factor,
+                size,
+                msaa,
+                Some(&targets.layer),
+            ),
+            None => self.encode_render_pass(
+                device,
+                encoder,
+                layer,
+                scale_factor,
+                size,
+                &targets.layer,
+                None,
+            ),
+        }
+        self.layer_depth -= 1;
+        let compositor = self.compositor.as_ref().expect("no layer compositor");
+        compositor.composite(
+            device,
+            encoder,
+            layer.properties(),
+            output_attachment,
+            targets,
+        );
+    }
+
+    // Encode a render pass drawing the given commands of the **Draw**.
+    #[allow(clippy::too_many_arguments)]
+    fn encode_commands(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        draw: &draw::Draw,
+        draw_cmds: Vec<draw::DrawCommand>,
+        scale_factor: f32,
+        output_attachment_size: [u32; 2],
+        output_attachment: &wgpu::TextureView,
+        resolve_target: Option<&wgpu::TextureView>,
+        load_op: wgpu::LoadOp<wgpu::Color>,
     ) {
         self.clear();
-        self.fill(device, draw, scale_factor, output_attachment_size);
+        self.fill_commands(
+            device,
+            draw,
+            draw_cmds,
+            scale_factor,
+            output_attachment_size,
+        );
 
         let Renderer {
             ref pipelines,
+            ref mask_pipelines,
             ref glyph_cache,
             ref glyph_cache_texture,
             ref mut depth_texture,
@@ -794,9 +1348,13 @@ impl Renderer {
             ref texture_bind_groups,
             ref mesh,
             ref vertex_mode_buffer,
+            ref vertex_material_buffer,
+            ref instance_buffer,
             ref mut render_commands,
             ref uniform_buffer,
+            ref light_uniform_buffer,
             scale_factor: ref mut old_scale_factor,
+            camera: ref mut old_camera,
             ..
         } = *self;
 
@@ -815,18 +1373,6 @@ impl Renderer {
             *depth_texture_view = depth_texture.view().build();
         }
 
-        // Retrieve the clear values based on the bg color.
-        let bg_color = draw.state.borrow().background_color;
-        let load_op = match bg_color {
-            None => wgpu::LoadOp::Load,
-            Some(color) => {
-                let (r, g, b, a) = color.into();
-                let (r, g, b, a) = (r as f64, g as f64, b as f64, a as f64);
-                let clear_color = wgpu::Color { r, g, b, a };
-                wgpu::LoadOp::Clear(clear_color)
-            }
-        };
-
         // Create render pass builder.
         let render_pass_builder = wgpu::RenderPassBuilder::new()
             .color_attachment(output_attachment, |color| {
@@ -847,6 +1393,9 @@ impl Renderer {
         let colors_bytes = colors_as_bytes(mesh.colors());
         let tex_coords_bytes = tex_coords_as_bytes(mesh.tex_coords());
         let modes_bytes = vertex_modes_as_bytes(vertex_mode_buffer);
+        let normals_bytes = normals_as_bytes(mesh.normals());
+        let materials_bytes = vertex_materials_as_bytes(vertex_material_buffer);
+        let instances_bytes = instances_as_bytes(instance_buffer);
         let indices_bytes = indices_as_bytes(mesh.indices());
         let point_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("nannou Renderer point_buffer"),
@@ -868,17 +1417,38 @@ impl Renderer {
             contents: modes_bytes,
             usage: vertex_usage,
         });
+        let normal_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("nannou Renderer normal_buffer"),
+            contents: normals_bytes,
+            usage: vertex_usage,
+        });
+        let material_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("nannou Renderer material_buffer"),
+            contents: materials_bytes,
+            usage: vertex_usage,
+        });
+        let instance_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("nannou Renderer instance_buffer"),
+            contents: instances_bytes,
+            usage: vertex_usage,
+        });
         let index_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("nannou Renderer index_buffer"),
             contents: indices_bytes,
             usage: wgpu::BufferUsages::INDEX,
         });
 
-        // If the scale factor or window size has changed, update the uniforms for vertex scaling.
-        if *old_scale_factor != scale_factor || output_attachment_size != depth_size {
+        // If the scale factor, window size or camera has changed, update the uniforms for vertex
+        // scaling and projection.
+        let camera = draw.state.borrow().camera;
+        if *old_scale_factor != scale_factor
+            || output_attachment_size != depth_size
+            || *old_camera != camera
+        {
             *old_scale_factor = scale_factor;
+            *old_camera = camera;
             // Upload uniform data for vertex scaling.
-            let uniforms = create_uniforms(output_attachment_size, scale_factor);
+            let uniforms = create_uniforms(output_attachment_size, scale_factor, camera.as_ref());
             let uniforms_size = std::mem::size_of::<Uniforms>() as wgpu::BufferAddress;
             let uniforms_bytes = uniforms_as_bytes(&uniforms);
             let usage = wgpu::BufferUsages::COPY_SRC;
@@ -891,6 +1461,22 @@ impl Renderer {
             encoder.copy_buffer_to_buffer(&new_uniform_buffer, 0, uniform_buffer, 0, uniforms_size);
         }
 
+        // Lights may change between every drawing, so always upload them.
+        let light_uniforms = create_light_uniforms(&draw.state.borrow().lights, camera.as_ref());
+        let light_uniforms_size = std::mem::size_of::<LightUniforms>() as wgpu::BufferAddress;
+        let new_light_uniform_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("nannou Renderer light_uniform_buffer"),
+            contents: light_uniforms_as_bytes(&light_uniforms),
+            usage: wgpu::BufferUsages::COPY_SRC,
+        });
+        encoder.copy_buffer_to_buffer(
+            &new_light_uniform_buffer,
+            0,
+            light_uniform_buffer,
+            0,
+            light_uniforms_size,
+        );
+
         // Encode the render pass.
         let mut render_pass = render_pass_builder.begin(encoder);
 
@@ -900,6 +1486,9 @@ impl Renderer {
         render_pass.set_vertex_buffer(1, color_buffer.slice(..));
         render_pass.set_vertex_buffer(2, tex_coords_buffer.slice(..));
         render_pass.set_vertex_buffer(3, mode_buffer.slice(..));
+        render_pass.set_vertex_buffer(4, normal_buffer.slice(..));
+        render_pass.set_vertex_buffer(5, material_buffer.slice(..));
+        render_pass.set_vertex_buffer(6, instance_buffer.slice(..));
 
         // Set the uniform and text bind groups here.
         render_pass.set_bind_group(0, uniform_bind_group, &[]);
@@ -927,11 +1516,20 @@ impl Renderer {
                     render_pass.set_scissor_rect(left, bottom, width, height);
                 }
 
+                RenderCommand::SetMaskPipeline(op) => {
+                    let pipeline = &mask_pipelines[&op];
+                    render_pass.set_pipeline(pipeline);
+                }
+
+                RenderCommand::SetStencilReference(reference) => {
+                    render_pass.set_stencil_reference(reference);
+                }
+
                 RenderCommand::DrawIndexed {
                     start_vertex,
                     index_range,
+                    instance_range,
                 } => {
-                    let instance_range = 0..1u32;
                     render_pass.draw_indexed(index_range, start_vertex, instance_range);
                 }
             }
@@ -1021,7 +1619,16 @@ fn create_depth_texture(
         .build(device)
 }
 
-fn create_uniforms([img_w, img_h]: [u32; 2], scale_factor: f32) -> Uniforms {
+fn create_uniforms(
+    [img_w, img_h]: [u32; 2],
+    scale_factor: f32,
+    camera: Option<&Camera>,
+) -> Uniforms {
+    if let Some(camera) = camera {
+        let size = [img_w as f32 / scale_factor, img_h as f32 / scale_factor];
+        let proj = camera.view_projection_matrix(size);
+        return Uniforms { proj };
+    }
     let right = img_w as f32 * 0.5 / scale_factor;
     let left = -right;
     let top = img_h as f32 * 0.5 / scale_factor;
@@ -1039,9 +1646,59 @@ fn create_uniforms([img_w, img_h]: [u32; 2], scale_factor: f32) -> Uniforms {
     Uniforms { proj }
 }
 
+fn create_light_uniforms(lights: &[Light], camera: Option<&Camera>) -> LightUniforms {
+    let mut ambient = Vec3::ZERO;
+    let mut uniforms = [LightUniform::default(); MAX_LIGHTS];
+    let mut light_count = 0;
+    for light in lights {
+        let intensity = light.intensity();
+        let position = match *light {
+            Light::Ambient { .. } => {
+                ambient += intensity;
+                continue;
+            }
+            Light::Directional { direction, .. } => direction.extend(0.0),
+            Light::Point { position, .. } => position.extend(1.0),
+        };
+        if light_count == MAX_LIGHTS {
+            continue;
+        }
+        uniforms[light_count] = LightUniform {
+            position: position.into(),
+            color: intensity.extend(0.0).into(),
+        };
+        light_count += 1;
+    }
+    let eye = match camera {
+        // Looking down the negative z axis via an orthographic projection.
+        None => Vec3::Z.extend(0.0),
+        Some(camera) => match camera.projection {
+            Projection::Orthographic { .. } => {
+                (camera.eye - camera.target).normalize_or_zero().extend(0.0)
+            }
+            Projection::Perspective { .. } => camera.eye.extend(1.0),
+        },
+    };
+    LightUniforms {
+        ambient: ambient.extend(0.0).into(),
+        eye: eye.into(),
+        light_count: light_count as u32,
+        _padding: [0; 3],
+        lights: uniforms,
+    }
+}
+
+fn vertex_material(material: Option<&Material>) -> VertexMaterial {
+    match material {
+        None => [0.0; 4],
+        Some(m) => [m.diffuse, m.specular, m.shininess, 1.0],
+    }
+}
+
 fn create_uniform_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
     wgpu::BindGroupLayoutBuilder::new()
         .uniform_buffer(wgpu::ShaderStages::VERTEX, false)
+        .uniform_buffer(wgpu::ShaderStages::FRAGMENT, false)
         .build(device)
 }
 
@@ -1077,9 +1734,11 @@ fn create_uniform_bind_group(
     device: &wgpu::Device,
     layout: &wgpu::BindGroupLayout,
     uniform_buffer: &wgpu::Buffer,
+    light_uniform_buffer: &wgpu::Buffer,
 ) -> wgpu::BindGroup {
     wgpu::BindGroupBuilder::new()
         .buffer::<Uniforms>(uniform_buffer, 0..1)
+        .buffer::<LightUniforms>(light_uniform_buffer, 0..1)
         .build(device, layout)
 }
 
@@ -1122,6 +1781,25 @@ fn create_render_pipeline(
     topology: wgpu::PrimitiveTopology,
 ) -> wgpu::RenderPipeline {
     let bind_group_layouts = &[uniform_layout, text_layout, texture_layout];
+    // Clipped drawings are limited to where the stencil value equals the reference, i.e. the
+    // number of masks applied.
+    let stencil = match has_stencil_aspect(depth_format) {
+        false => wgpu::RenderPipelineBuilder::DEFAULT_STENCIL,
+        true => {
+            let face = wgpu::StencilFaceState {
+                compare: wgpu::CompareFunction::Equal,
+                fail_op: wgpu::StencilOperation::Keep,
+                depth_fail_op: wgpu::StencilOperation::Keep,
+                pass_op: wgpu::StencilOperation::Keep,
+            };
+            wgpu::StencilState {
+                front: face,
+                back: face,
+                read_mask: !0,
+                write_mask: 0,
+            }
+        }
+    };
     wgpu::RenderPipelineBuilder::from_layout_descriptor(&bind_group_layouts[..], vs_mod)
         .fragment_shader(fs_mod)
         .color_format(dst_format)
@@ -1131,7 +1809,11 @@ fn create_render_pipeline(
             &wgpu::vertex_attr_array![2 => Float32x2],
         )
         .add_vertex_buffer::<VertexMode>(&wgpu::vertex_attr_array![3 => Uint32])
+        .add_vertex_buffer::<draw::mesh::vertex::Normal>(&wgpu::vertex_attr_array![4 => Float32x3])
+        .add_vertex_buffer::<VertexMaterial>(&wgpu::vertex_attr_array![5 => Float32x4])
+        .add_instance_buffer::<Instance>(&Instance::ATTRIBUTES)
         .depth_format(depth_format)
+        .stencil(stencil)
         .sample_count(sample_count)
         .color_blend(color_blend)
         .alpha_blend(alpha_blend)
@@ -1139,6 +1821,80 @@ fn create_render_pipeline(
         .build(device)
 }
 
+#[allow(clippy::too_many_arguments)]
+fn create_mask_render_pipeline(
+    device: &wgpu::Device,
+    uniform_layout: &wgpu::BindGroupLayout,
+    text_layout: &wgpu::BindGroupLayout,
+    vs_mod: &wgpu::ShaderModule,
+    fs_mod: &wgpu::ShaderModule,
+    dst_format: wgpu::TextureFormat,
+    depth_format: wgpu::TextureFormat,
+    sample_count: u32,
+    op: MaskOp,
+) -> wgpu::RenderPipeline {
+    let pass_op = match op {
+        MaskOp::Increment => wgpu::StencilOperation::IncrementClamp,
+        MaskOp::Decrement => wgpu::StencilOperation::DecrementClamp,
+    };
+    let face = wgpu::StencilFaceState {
+        compare: wgpu::CompareFunction::Equal,
+        fail_op: wgpu::StencilOperation::Keep,
+        depth_fail_op: wgpu::StencilOperation::Keep,
+        pass_op,
+    };
+    let bind_group_layouts = &[uniform_layout, text_layout];
+    wgpu::RenderPipelineBuilder::from_layout_descriptor(&bind_group_layouts[..], vs_mod)
+        .fragment_shader(fs_mod)
+        .color_format(dst_format)
+        .write_mask(wgpu::ColorWrites::empty())
+        .add_vertex_buffer::<draw::mesh::vertex::Point>(&wgpu::vertex_attr_array![0 => Float32x3])
+        .add_vertex_buffer::<draw::mesh::vertex::Color>(&wgpu::vertex_attr_array![1 => Float32x4])
+        .add_vertex_buffer::<draw::mesh::vertex::TexCoords>(
+            &wgpu::vertex_attr_array![2 => Float32x2],
+        )
+        .add_vertex_buffer::<VertexMode>(&wgpu::vertex_attr_array![3 => Uint32])
+        .add_vertex_buffer::<draw::mesh::vertex::Normal>(&wgpu::vertex_attr_array![4 => Float32x3])
+        .add_vertex_buffer::<VertexMaterial>(&wgpu::vertex_attr_array![5 => Float32x4])
+        .add_instance_buffer::<Instance>(&Instance::ATTRIBUTES)
+        .depth_format(depth_format)
+        .depth_write_enabled(false)
+        .depth_compare(wgpu::CompareFunction::Always)
+        .stencil_front(face)
+        .stencil_back(face)
+        .stencil_read_mask(!0)
+        .stencil_write_mask(!0)
+        .sample_count(sample_count)
+        .build(device)
+}
+
+// The commands applying the given stencil operation wherever the mask geometry covers pixels
+// whose stencil value equals the given reference.
+fn mask_commands(
+    op: MaskOp,
+    reference: u32,
+    index_range: std::ops::Range<u32>,
+    instance_range: std::ops::Range<u32>,
+) -> Vec<RenderCommand> {
+    if index_range.is_empty() {
+        return vec![];
+    }
+    vec![
+        RenderCommand::SetStencilReference(reference),
+        RenderCommand::SetMaskPipeline(op),
+        RenderCommand::DrawIndexed {
+            start_vertex: 0,
+            index_range,
+            instance_range,
+        },
+    ]
+}
+
+// Whether or not the given depth format has a stencil aspect for clip masks.
+fn has_stencil_aspect(format: wgpu::TextureFormat) -> bool {
+    matches!(format, wgpu::TextureFormat::Depth24PlusStencil8)
+}
+
 fn sampler_descriptor_hash(desc: &wgpu::SamplerDescriptor) -> SamplerId {
     let mut s = std::collections::hash_map::DefaultHasher::new();
     desc.address_mode_u.hash(&mut s);
@@ -1169,6 +1925,10 @@ fn uniforms_as_bytes(uniforms: &Uniforms) -> &[u8] {
     unsafe { wgpu::bytes::from(uniforms) }
 }
 
+fn light_uniforms_as_bytes(uniforms: &LightUniforms) -> &[u8] {
+    unsafe { wgpu::bytes::from(uniforms) }
+}
+
 fn points_as_bytes(data: &[draw::mesh::vertex::Point]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
@@ -1185,6 +1945,18 @@ fn vertex_modes_as_bytes(data: &[VertexMode]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
 
+fn normals_as_bytes(data: &[draw::mesh::vertex::Normal]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(data) }
+}
+
+fn vertex_materials_as_bytes(data: &[VertexMaterial]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(data) }
+}
+
+fn instances_as_bytes(data: &[Instance]) -> &[u8] {
+    unsafe { wgpu::bytes::from_slice(data) }
+}
+
 fn indices_as_bytes(data: &[u32]) -> &[u8] {
     unsafe { wgpu::bytes::from_slice(data) }
 }
@@ -0,0 +1,9 @@
+[[group(0), binding(0)]]
+var src: texture_2d<f32>;
+
+[[stage(fragment)]]
+fn main(
+    [[builtin(position)]] pos: vec4<f32>,
+) -> [[location(0)]] vec4<f32> {
+    return textureLoad(src, vec2<i32>(i32(pos.x), i32(pos.y)), 0);
+}
@@ -2,6 +2,24 @@ struct FragmentOutput {
     [[location(0)]] color: vec4<f32>;
 };
 
+struct Light {
+    // The direction of travel if `w` is `0.0`, otherwise the position of the light.
+    position: vec4<f32>;
+    color: vec4<f32>;
+};
+
+[[block]]
+struct Lights {
+    ambient: vec4<f32>;
+    // The direction towards the viewer if `w` is `0.0`, otherwise the position of the viewer.
+    eye: vec4<f32>;
+    count: u32;
+    // Substituted with the renderer's `MAX_LIGHTS` when the module is created.
+    lights: array<Light, MAX_LIGHTS>;
+};
+
+[[group(0), binding(1)]]
+var<uniform> lights: Lights;
 [[group(1), binding(0)]]
 var text_sampler: sampler;
 [[group(1), binding(1)]]
@@ -11,11 +29,54 @@ var tex_sampler: sampler;
 [[group(2), binding(1)]]
 var tex: texture_2d<f32>;
 
+// Shade the given color using Blinn-Phong reflection, where material is
+// `(diffuse, specular, shininess, lit)`.
+fn shade(
+    color: vec3<f32>,
+    position: vec3<f32>,
+    normal: vec3<f32>,
+    material: vec4<f32>,
+) -> vec3<f32> {
+    var view_dir: vec3<f32> = normalize(lights.eye.xyz);
+    if (lights.eye.w != 0.0) {
+        view_dir = normalize(lights.eye.xyz - position);
+    }
+    // Surfaces are lit from whichever side faces the viewer.
+    var n: vec3<f32> = normalize(normal);
+    if (dot(n, view_dir) < 0.0) {
+        n = -n;
+    }
+    var out: vec3<f32> = lights.ambient.xyz * color;
+    var i: u32 = 0u;
+    loop {
+        if (i >= lights.count) {
+            break;
+        }
+        let light: Light = lights.lights[i];
+        var light_dir: vec3<f32> = normalize(-light.position.xyz);
+        if (light.position.w != 0.0) {
+            light_dir = normalize(light.position.xyz - position);
+        }
+        let diffuse: f32 = max(dot(n, light_dir), 0.0);
+        var specular: f32 = 0.0;
+        if (diffuse > 0.0) {
+            let half_dir: vec3<f32> = normalize(light_dir + view_dir);
+            specular = pow(max(dot(n, half_dir), 0.0), material.z);
+        }
+        out = out + light.color.xyz * (color * diffuse * material.x + vec3<f32>(specular * material.y));
+        i = i + 1u;
+    }
+    return out;
+}
+
 [[stage(fragment)]]
 fn main(
     [[location(0)]] color: vec4<f32>,
     [[location(1)]] tex_coords: vec2<f32>,
     [[location(2)]] mode: u32,
+    [[location(3)]] world_position: vec3<f32>,
+    [[location(4)]] normal: vec3<f32>,
+    [[location(5)]] material: vec4<f32>,
 ) -> FragmentOutput {
     let tex_color: vec4<f32> = textureSample(tex, tex_sampler, tex_coords);
     let text_color: vec4<f32> = textureSample(text, text_sampler, tex_coords);
@@ -34,5 +95,8 @@ fn main(
             }
         }
     }
+    if (material.w != 0.0) {
+        out_color = vec4<f32>(shade(out_color.xyz, world_position, normal, material), out_color.w);
+    }
     return FragmentOutput(out_color);
 }
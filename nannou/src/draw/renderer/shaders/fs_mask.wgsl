@@ -0,0 +1,19 @@
+// Clip masks only write to the stencil buffer, so the color is discarded via the write mask.
+// Fragments of text outside of the glyphs are discarded so that text may be used as a mask.
+
+[[group(1), binding(0)]]
+var text_sampler: sampler;
+[[group(1), binding(1)]]
+var text: texture_2d<f32>;
+
+[[stage(fragment)]]
+fn main(
+    [[location(1)]] tex_coords: vec2<f32>,
+    [[location(2)]] mode: u32,
+) -> [[location(0)]] vec4<f32> {
+    let text_alpha: f32 = textureSample(text, text_sampler, tex_coords).x;
+    if (mode == u32(2) && text_alpha < 0.5) {
+        discard;
+    }
+    return vec4<f32>(0.0);
+}
@@ -0,0 +1,8 @@
+// Produces a single triangle covering the whole target.
+[[stage(vertex)]]
+fn main(
+    [[builtin(vertex_index)]] index: u32,
+) -> [[builtin(position)]] vec4<f32> {
+    let uv: vec2<f32> = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
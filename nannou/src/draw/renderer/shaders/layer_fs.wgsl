@@ -0,0 +1,124 @@
+// Composites a layer over its backdrop using the separable blend modes of the W3C "Compositing
+// and Blending" specification. Both textures contain premultiplied colors.
+
+[[block]]
+struct Uniforms {
+    blend_mode: u32;
+    opacity: f32;
+};
+
+[[group(0), binding(0)]]
+var<uniform> uniforms: Uniforms;
+[[group(0), binding(1)]]
+var backdrop: texture_2d<f32>;
+[[group(0), binding(2)]]
+var layer: texture_2d<f32>;
+
+fn hard_light(b: f32, s: f32) -> f32 {
+    if (s <= 0.5) {
+        return b * 2.0 * s;
+    }
+    let s2: f32 = 2.0 * s - 1.0;
+    return b + s2 - b * s2;
+}
+
+fn color_dodge(b: f32, s: f32) -> f32 {
+    if (b <= 0.0) {
+        return 0.0;
+    }
+    if (s >= 1.0) {
+        return 1.0;
+    }
+    return min(1.0, b / (1.0 - s));
+}
+
+fn color_burn(b: f32, s: f32) -> f32 {
+    if (b >= 1.0) {
+        return 1.0;
+    }
+    if (s <= 0.0) {
+        return 0.0;
+    }
+    return 1.0 - min(1.0, (1.0 - b) / s);
+}
+
+fn soft_light(b: f32, s: f32) -> f32 {
+    if (s <= 0.5) {
+        return b - (1.0 - 2.0 * s) * b * (1.0 - b);
+    }
+    var d: f32 = sqrt(b);
+    if (b <= 0.25) {
+        d = ((16.0 * b - 12.0) * b + 4.0) * b;
+    }
+    return b + (2.0 * s - 1.0) * (d - b);
+}
+
+// Blend the backdrop channel `b` with the source channel `s`.
+fn blend(mode: u32, b: f32, s: f32) -> f32 {
+    if (mode == 1u) {
+        return b * s;
+    }
+    if (mode == 2u) {
+        return b + s - b * s;
+    }
+    if (mode == 3u) {
+        return hard_light(s, b);
+    }
+    if (mode == 4u) {
+        return min(b, s);
+    }
+    if (mode == 5u) {
+        return max(b, s);
+    }
+    if (mode == 6u) {
+        return color_dodge(clamp(b, 0.0, 1.0), clamp(s, 0.0, 1.0));
+    }
+    if (mode == 7u) {
+        return color_burn(clamp(b, 0.0, 1.0), clamp(s, 0.0, 1.0));
+    }
+    if (mode == 8u) {
+        return hard_light(b, s);
+    }
+    if (mode == 9u) {
+        return soft_light(clamp(b, 0.0, 1.0), clamp(s, 0.0, 1.0));
+    }
+    if (mode == 10u) {
+        return abs(b - s);
+    }
+    if (mode == 11u) {
+        return b + s - 2.0 * b * s;
+    }
+    return s;
+}
+
+[[stage(fragment)]]
+fn main(
+    [[builtin(position)]] pos: vec4<f32>,
+) -> [[location(0)]] vec4<f32> {
+    let coords: vec2<i32> = vec2<i32>(i32(pos.x), i32(pos.y));
+    let b: vec4<f32> = textureLoad(backdrop, coords, 0);
+    let s: vec4<f32> = textureLoad(layer, coords, 0);
+    if (s.a <= 0.0) {
+        return b;
+    }
+
+    // Blend the unpremultiplied colors.
+    let cs: vec3<f32> = s.rgb / s.a;
+    var cb: vec3<f32> = vec3<f32>(0.0, 0.0, 0.0);
+    if (b.a > 0.0) {
+        cb = b.rgb / b.a;
+    }
+    let mode: u32 = uniforms.blend_mode;
+    let blended: vec3<f32> = vec3<f32>(
+        blend(mode, cb.r, cs.r),
+        blend(mode, cb.g, cs.g),
+        blend(mode, cb.b, cs.b),
+    );
+
+    // Composite the blended color over the backdrop.
+    let mixed: vec3<f32> = (1.0 - b.a) * cs + b.a * blended;
+    let sa: f32 = s.a * uniforms.opacity;
+    let rgb: vec3<f32> = sa * mixed + (1.0 - sa) * b.rgb;
+    let a: f32 = sa + b.a * (1.0 - sa);
+    return vec4<f32>(rgb, a);
+}
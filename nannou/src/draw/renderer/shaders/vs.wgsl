@@ -7,6 +7,9 @@ struct VertexOutput {
     [[location(0)]] color: vec4<f32>;
     [[location(1)]] tex_coords: vec2<f32>;
     [[location(2)]] mode: u32;
+    [[location(3)]] world_position: vec3<f32>;
+    [[location(4)]] normal: vec3<f32>;
+    [[location(5)]] material: vec4<f32>;
     [[builtin(position)]] pos: vec4<f32>;
 };
 
@@ -19,7 +22,29 @@ fn main(
     [[location(1)]] color: vec4<f32>,
     [[location(2)]] tex_coords: vec2<f32>,
     [[location(3)]] mode: u32,
+    [[location(4)]] normal: vec3<f32>,
+    [[location(5)]] material: vec4<f32>,
+    [[location(6)]] instance_x: vec4<f32>,
+    [[location(7)]] instance_y: vec4<f32>,
+    [[location(8)]] instance_z: vec4<f32>,
+    [[location(9)]] instance_w: vec4<f32>,
+    [[location(10)]] instance_color: vec4<f32>,
 ) -> VertexOutput {
-    let out_pos: vec4<f32> = uniforms.proj * vec4<f32>(position, 1.0);
-    return VertexOutput(color, tex_coords, mode, out_pos);
+    // Primitives that are not instanced are drawn with the identity instance.
+    let instance = mat4x4<f32>(instance_x, instance_y, instance_z, instance_w);
+    let world_position: vec4<f32> = instance * vec4<f32>(position, 1.0);
+    // Normals are normalized in the fragment shader. Instance transforms are assumed to scale
+    // uniformly.
+    let world_normal: vec3<f32> = (instance * vec4<f32>(normal, 0.0)).xyz;
+    let out_pos: vec4<f32> = uniforms.proj * world_position;
+    let out_color: vec4<f32> = color * instance_color;
+    return VertexOutput(
+        out_color,
+        tex_coords,
+        mode,
+        world_position.xyz,
+        world_normal,
+        material,
+        out_pos,
+    );
 }
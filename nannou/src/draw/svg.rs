@@ -0,0 +1,630 @@
+//! Items related to exporting a **Draw**ing as an SVG document.
+//!
+//! The commands of a **Draw** are written directly from their path events rather than via
+//! tessellation. Fills and strokes are written as vector paths, preserving curves, stroke options,
+//! dashes and linear and radial gradients, while text is written as glyph outlines. Primitives
+//! without a vector equivalent, e.g. meshes, cuboids, 3D solids, paths with per-vertex colors and
+//! conic gradients, are tessellated and written as flat shaded triangles in back-to-front order.
+//!
+//! SVG has a single `stroke-linecap` for both ends of a stroke, so the `start_cap` of a stroke is
+//! used for both and its `end_cap` is ignored.
+//!
+//! Layers are written as groups with the equivalent CSS `mix-blend-mode`. Textures, lighting,
+//! clip masks and the blend states of `Draw::blend` are not exported.
+
+use crate::color::{LinSrgba, Srgba};
+use crate::draw::mesh::vertex::Color;
+use crate::draw::primitive::path;
+use crate::draw::properties::gradient::{Kind, Spread};
+use crate::draw::properties::{Dashes, Gradient};
+use crate::draw::renderer::{GlyphCache, RenderContext, RenderPrimitive, VertexMode};
+use crate::draw::{self, BlendMode, Draw, DrawCommand, Layer, Scissor};
+use crate::geom::Point2;
+use crate::glam::{Mat4, Vec2, Vec3};
+use lyon::path::PathEvent;
+use lyon::tessellation::{FillRule, FillTessellator, LineCap, LineJoin, StrokeTessellator};
+use std::fmt::Write;
+use std::mem;
+
+/// Draw API primitives that may be written to an SVG document as vector paths.
+pub(crate) trait WritePrimitive {
+    /// Write self to the given document.
+    ///
+    /// Returns `false` if self has no vector equivalent, in which case nothing is written and the
+    /// primitive is tessellated and written as triangles instead.
+    fn write_primitive(&self, ctxt: &Context, svg: &mut Writer) -> bool;
+}
+
+/// The context provided to primitives while writing them to an SVG document.
+pub(crate) struct Context<'a> {
+    pub transform: Mat4,
+    pub theme: &'a draw::Theme,
+    pub path_event_buffer: &'a [PathEvent],
+    pub path_points_colored_buffer: &'a [(Point2, Color)],
+    pub text_buffer: &'a str,
+}
+
+/// An SVG document being written from the commands of a **Draw**.
+#[derive(Debug)]
+pub(crate) struct Writer {
+    // The logical size of the document.
+    size: Vec2,
+    // Transforms from world space to the coordinate space of the document.
+    view: Mat4,
+    // The color by which all colors are multiplied, e.g. the color of the current instance.
+    tint: LinSrgba,
+    defs: String,
+    body: String,
+    next_id: usize,
+    // Whether or not a group for the current scissor is open.
+    clip_group: bool,
+}
+
+/// Produce an SVG document from the commands of the given **Draw** for an output of the given
+/// logical size.
+///
+/// Unlike rendering, the commands of the **Draw** are left in place.
+pub fn to_string(draw: &Draw, size: Vec2) -> String {
+    let mut svg = {
+        let state = draw.state.borrow();
+        let camera = state.camera.unwrap_or_default();
+        let mut svg = Writer::new(size, &camera);
+        if let Some(color) = state.background_color {
+            let fill = svg.paint(color);
+            writeln!(svg.body, r#"<rect width="100%" height="100%" {}/>"#, fill).unwrap();
+        }
+        svg
+    };
+    write_commands(&mut svg, draw);
+    svg.finish()
+}
+
+// Write the commands of the given **Draw** to the document, including those of its layers.
+fn write_commands(svg: &mut Writer, draw: &Draw) {
+    draw.finish_remaining_drawings();
+    let state = draw.state.borrow();
+    let intermediary_state = state.intermediary_state.borrow();
+    let white = LinSrgba::new(1.0, 1.0, 1.0, 1.0);
+    let mut curr_ctxt = draw::Context::default();
+    for cmd in state.draw_commands.iter().flatten() {
+        let prim = match cmd {
+            DrawCommand::Context(ctxt) => {
+                if ctxt.scissor != curr_ctxt.scissor {
+                    svg.scissor(ctxt.scissor);
+                }
+                curr_ctxt = ctxt.clone();
+                continue;
+            }
+            DrawCommand::Primitive(prim) => prim,
+            DrawCommand::Layer(layer) => {
+                svg.layer(layer);
+                continue;
+            }
+        };
+        if curr_ctxt.scissor == Scissor::NoOverlap {
+            continue;
+        }
+
+        // Instances are written once per instance.
+        let instances = match prim {
+            draw::Primitive::Instances(draw::primitive::Instances {
+                primitive,
+                transforms,
+                colors,
+            }) => {
+                let colors = colors.iter().cloned().chain(std::iter::repeat(white));
+                transforms
+                    .iter()
+                    .zip(colors)
+                    .map(|(&t, c)| (&**primitive, (curr_ctxt.transform * t, c)))
+                    .collect()
+            }
+            prim => vec![(prim, (curr_ctxt.transform, white))],
+        };
+
+        for (prim, (transform, tint)) in instances {
+            svg.tint = tint;
+            let ctxt = Context {
+                transform,
+                theme: &state.theme,
+                path_event_buffer: &intermediary_state.path_event_buffer,
+                path_points_colored_buffer: &intermediary_state.path_points_colored_buffer,
+                text_buffer: &intermediary_state.text_buffer,
+            };
+            if !prim.write_primitive(&ctxt, svg) {
+                svg.tessellated(prim.clone(), &ctxt, &intermediary_state);
+            }
+        }
+        svg.tint = white;
+    }
+}
+
+impl WritePrimitive for draw::Primitive {
+    fn write_primitive(&self, ctxt: &Context, svg: &mut Writer) -> bool {
+        match self {
+            draw::Primitive::Arrow(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Ellipse(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Line(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Path(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Polygon(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Quad(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Rect(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Svg(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Text(prim) => prim.write_primitive(ctxt, svg),
+            draw::Primitive::Tri(prim) => prim.write_primitive(ctxt, svg),
+            _ => false,
+        }
+    }
+}
+
+/// Whether or not the given gradient can be written to SVG.
+///
+/// Conic gradients have no SVG equivalent, so primitives painted with them are tessellated
+/// instead.
+pub(crate) fn supports_gradient(gradient: Option<&Gradient>) -> bool {
+    match gradient {
+        Some(g) => !matches!(g.kind, Kind::Conic { .. }) || g.stops.is_empty(),
+        None => true,
+    }
+}
+
+impl Context<'_> {
+    /// The color of a fill or stroke, falling back to that of the theme for the given primitive.
+    pub fn color(
+        &self,
+        color: Option<LinSrgba>,
+        options: &path::Options,
+        theme_prim: &draw::theme::Primitive,
+    ) -> LinSrgba {
+        color.unwrap_or_else(|| match options {
+            path::Options::Fill(_) => self.theme.fill_lin_srgba(theme_prim),
+            path::Options::Stroke(_) => self.theme.stroke_lin_srgba(theme_prim),
+        })
+    }
+}
+
+impl Writer {
+    fn new(size: Vec2, camera: &draw::Camera) -> Self {
+        // Map clip space to the document, where the origin is the top left and y points down.
+        let viewport = Mat4::from_translation(Vec3::new(size.x * 0.5, size.y * 0.5, 0.0))
+            * Mat4::from_scale(Vec3::new(size.x * 0.5, -size.y * 0.5, 1.0));
+        let view = viewport * camera.view_projection_matrix(size.into());
+        Writer {
+            size,
+            view,
+            tint: LinSrgba::new(1.0, 1.0, 1.0, 1.0),
+            defs: String::new(),
+            body: String::new(),
+            next_id: 0,
+            clip_group: false,
+        }
+    }
+
+    /// Write a filled or stroked path.
+    ///
+    /// `transform` maps the path events to world space.
+    pub(crate) fn path<I>(
+        &mut self,
+        events: I,
+        color: LinSrgba,
+        gradient: Option<&Gradient>,
+        dashes: Option<&Dashes>,
+        transform: Mat4,
+        options: &path::Options,
+    ) where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let m = self.view * transform;
+        // Paths with an affine transform are written in local space, leaving SVG to apply the
+        // transform to the geometry, stroke and paint alike. Otherwise (e.g. with a perspective
+        // camera) points are projected individually.
+        let w = m.row(3);
+        let affine = w.x == 0.0 && w.y == 0.0 && w.w == 1.0;
+        let project = |p: lyon::math::Point| -> Point2 {
+            if affine {
+                Point2::new(p.x, p.y)
+            } else {
+                m.project_point3(Vec3::new(p.x, p.y, 0.0)).truncate()
+            }
+        };
+        let d = path_data(events, project);
+        if d.is_empty() {
+            return;
+        }
+
+        let gradient = gradient.filter(|g| !g.stops.is_empty());
+        let paint = match gradient.and_then(|g| self.gradient(g, project, scale(m))) {
+            Some(id) => format!(r#""url(#{})""#, id),
+            None => self.paint_color(color),
+        };
+        let mut attrs = match options {
+            path::Options::Fill(opts) => {
+                let rule = match opts.fill_rule {
+                    FillRule::EvenOdd => "evenodd",
+                    FillRule::NonZero => "nonzero",
+                };
+                format!(r#"fill={} fill-rule="{}""#, paint, rule)
+            }
+            path::Options::Stroke(opts) => {
+                let opts = dashes.map(|d| d.stroke_options(*opts)).unwrap_or(*opts);
+                let width = if affine {
+                    opts.line_width
+                } else {
+                    opts.line_width * scale(m)
+                };
+                // SVG has a single line cap for both ends, so the `end_cap` is ignored.
+                let mut attrs = format!(
+                    r#"fill="none" stroke={} stroke-width="{}" stroke-linecap="{}" stroke-linejoin="{}" stroke-miterlimit="{}""#,
+                    paint,
+                    num(width),
+                    line_cap(opts.start_cap),
+                    line_join(opts.line_join),
+                    num(opts.miter_limit),
+                );
+                if let Some(dashes) = dashes.filter(|d| !d.pattern.is_empty()) {
+                    let s = if affine { 1.0 } else { scale(m) };
+                    let pattern: Vec<_> = dashes.pattern.iter().map(|&l| num(l * s)).collect();
+                    write!(
+                        attrs,
+                        r#" stroke-dasharray="{}" stroke-dashoffset="{}""#,
+                        pattern.join(" "),
+                        num(dashes.offset * s),
+                    )
+                    .unwrap();
+                }
+                attrs
+            }
+        };
+        if affine {
+            write!(attrs, r#" transform="{}""#, matrix(m)).unwrap();
+        }
+        writeln!(self.body, r#"<path d="{}" {}/>"#, d, attrs).unwrap();
+    }
+
+    // Tessellate the given primitive and write its triangles.
+    //
+    // Triangles are flat shaded with the mean of their vertex colors and written from back to
+    // front. Textured primitives are not exported.
+    fn tessellated(
+        &mut self,
+        prim: draw::Primitive,
+        ctxt: &Context,
+        intermediary_state: &draw::IntermediaryState,
+    ) {
+        let mut mesh = draw::Mesh::default();
+        let render = prim.render_primitive(
+            RenderContext {
+                transform: &ctxt.transform,
+                intermediary_mesh: &intermediary_state.intermediary_mesh,
+                path_event_buffer: ctxt.path_event_buffer,
+                path_points_colored_buffer: ctxt.path_points_colored_buffer,
+                path_points_textured_buffer: &intermediary_state.path_points_textured_buffer,
+                text_buffer: ctxt.text_buffer,
+                theme: ctxt.theme,
+                glyph_cache: &mut GlyphCache::new([1, 1], 1.0, 1.0),
+                fill_tessellator: &mut FillTessellator::new(),
+                stroke_tessellator: &mut StrokeTessellator::new(),
+                output_attachment_size: self.size,
+                output_attachment_scale_factor: 1.0,
+            },
+            &mut mesh,
+        );
+        if render.vertex_mode != VertexMode::Color {
+            return;
+        }
+        let indices = mesh.indices();
+        let points = mesh.points();
+        let colors = mesh.colors();
+        let mut tris: Vec<_> = indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [t[0] as usize, t[1] as usize, t[2] as usize];
+                let [pa, pb, pc] = [a, b, c].map(|i| self.view.project_point3(points[i]));
+                let depth = pa.z + pb.z + pc.z;
+                let mut color = [0.0; 4];
+                for &i in &[a, b, c] {
+                    let c = colors[i];
+                    let rgba = [c.red, c.green, c.blue, c.alpha];
+                    for (sum, v) in color.iter_mut().zip(rgba.iter()) {
+                        *sum += v / 3.0;
+                    }
+                }
+                (depth, [pa, pb, pc], color)
+            })
+            .collect();
+        // Skip triangles viewed edge-on, e.g. the sides of a cuboid facing the camera.
+        tris.retain(|(_, [a, b, c], _)| {
+            (*b - *a).truncate().perp_dot((*c - *a).truncate()).abs() > 1e-6
+        });
+        tris.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        for (_, [a, b, c], [r, g, bl, al]) in tris {
+            let paint = self.paint(LinSrgba::new(r, g, bl, al));
+            writeln!(
+                self.body,
+                r#"<path d="M{} {}L{} {}L{} {}Z" {}/>"#,
+                num(a.x),
+                num(a.y),
+                num(b.x),
+                num(b.y),
+                num(c.x),
+                num(c.y),
+                paint,
+            )
+            .unwrap();
+        }
+    }
+
+    // Write the layer's commands within a group composited via the layer's opacity and blend mode.
+    fn layer(&mut self, layer: &Layer) {
+        let properties = layer.properties();
+        let mut attrs = String::new();
+        if properties.opacity < 1.0 {
+            write!(attrs, r#" opacity="{}""#, num(properties.opacity)).unwrap();
+        }
+        if properties.blend_mode != BlendMode::Normal {
+            let name = properties.blend_mode.css_name();
+            write!(attrs, r#" style="mix-blend-mode:{}""#, name).unwrap();
+        }
+        writeln!(self.body, "<g{}>", attrs).unwrap();
+        // Scissors within the layer are grouped independently of the scissor outside of it.
+        let clip_group = mem::replace(&mut self.clip_group, false);
+        write_commands(self, layer.draw());
+        if self.clip_group {
+            self.body.push_str("</g>\n");
+        }
+        self.body.push_str("</g>\n");
+        self.clip_group = clip_group;
+    }
+
+    // Crop all following elements to the given scissor.
+    fn scissor(&mut self, scissor: Scissor) {
+        if self.clip_group {
+            self.body.push_str("</g>\n");
+            self.clip_group = false;
+        }
+        if let Scissor::Rect(rect) = scissor {
+            let id = self.next_id("clip");
+            let (x, y) = (
+                rect.left() + self.size.x * 0.5,
+                self.size.y * 0.5 - rect.top(),
+            );
+            writeln!(
+                self.defs,
+                r#"<clipPath id="{}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                id,
+                num(x),
+                num(y),
+                num(rect.w()),
+                num(rect.h()),
+            )
+            .unwrap();
+            writeln!(self.body, r#"<g clip-path="url(#{})">"#, id).unwrap();
+            self.clip_group = true;
+        }
+    }
+
+    // Define the given gradient and return its ID, or `None` if it has no SVG equivalent.
+    fn gradient<F>(&mut self, gradient: &Gradient, project: F, scale: f32) -> Option<String>
+    where
+        F: Fn(lyon::math::Point) -> Point2,
+    {
+        let spread = match gradient.spread {
+            Spread::Pad => "pad",
+            Spread::Repeat => "repeat",
+            Spread::Reflect => "reflect",
+        };
+        let p = |p: Point2| project(lyon::math::point(p.x, p.y));
+        let (element, attrs) = match gradient.kind {
+            Kind::Linear { start, end } => {
+                let (a, b) = (p(start), p(end));
+                let attrs = format!(
+                    r#"x1="{}" y1="{}" x2="{}" y2="{}""#,
+                    num(a.x),
+                    num(a.y),
+                    num(b.x),
+                    num(b.y)
+                );
+                ("linearGradient", attrs)
+            }
+            Kind::Radial { center, radius } => {
+                let c = p(center);
+                let attrs = format!(
+                    r#"cx="{}" cy="{}" r="{}""#,
+                    num(c.x),
+                    num(c.y),
+                    num(radius * scale)
+                );
+                ("radialGradient", attrs)
+            }
+            Kind::Conic { .. } => return None,
+        };
+        let id = self.next_id("gradient");
+        write!(
+            self.defs,
+            r#"<{} id="{}" gradientUnits="userSpaceOnUse" spreadMethod="{}" {}>"#,
+            element, id, spread, attrs,
+        )
+        .unwrap();
+        for stop in &gradient.stops {
+            let (color, alpha) = self.color(stop.color);
+            write!(
+                self.defs,
+                r#"<stop offset="{}" stop-color="{}" stop-opacity="{}"/>"#,
+                num(stop.offset),
+                color,
+                num(alpha),
+            )
+            .unwrap();
+        }
+        writeln!(self.defs, "</{}>", element).unwrap();
+        Some(id)
+    }
+
+    // The `fill` attribute and, if necessary, `fill-opacity` for the given color.
+    fn paint(&self, color: LinSrgba) -> String {
+        let (color, alpha) = self.color(color);
+        if alpha < 1.0 {
+            format!(r#"fill="{}" fill-opacity="{}""#, color, num(alpha))
+        } else {
+            format!(r#"fill="{}""#, color)
+        }
+    }
+
+    // A quoted paint value, followed by an opacity attribute if necessary.
+    //
+    // Used for both fills and strokes, so the opacity applies to the whole element.
+    fn paint_color(&self, color: LinSrgba) -> String {
+        let (color, alpha) = self.color(color);
+        if alpha < 1.0 {
+            format!(r#""{}" opacity="{}""#, color, num(alpha))
+        } else {
+            format!(r#""{}""#, color)
+        }
+    }
+
+    // The hex sRGB color and alpha of the given linear color, multiplied by the tint.
+    fn color(&self, c: LinSrgba) -> (String, f32) {
+        let t = self.tint;
+        let c = LinSrgba::new(
+            c.red * t.red,
+            c.green * t.green,
+            c.blue * t.blue,
+            c.alpha * t.alpha,
+        );
+        let c = Srgba::from_linear(c);
+        let byte = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let hex = format!(
+            "#{:02x}{:02x}{:02x}",
+            byte(c.red),
+            byte(c.green),
+            byte(c.blue)
+        );
+        (hex, c.alpha.clamp(0.0, 1.0))
+    }
+
+    fn next_id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{}{}", prefix, self.next_id)
+    }
+
+    fn finish(mut self) -> String {
+        if self.clip_group {
+            self.body.push_str("</g>\n");
+        }
+        let (w, h) = (num(self.size.x), num(self.size.y));
+        let mut s = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = w,
+            h = h,
+        );
+        s.push('\n');
+        if !self.defs.is_empty() {
+            write!(s, "<defs>\n{}</defs>\n", self.defs).unwrap();
+        }
+        s.push_str(&self.body);
+        s.push_str("</svg>\n");
+        s
+    }
+}
+
+// The SVG path data for the given events.
+fn path_data<I, F>(events: I, project: F) -> String
+where
+    I: IntoIterator<Item = PathEvent>,
+    F: Fn(lyon::math::Point) -> Point2,
+{
+    let mut d = String::new();
+    let pt = |d: &mut String, cmd: &str, p: lyon::math::Point| {
+        let p = project(p);
+        write!(d, "{}{} {}", cmd, num(p.x), num(p.y)).unwrap();
+    };
+    for event in events {
+        match event {
+            PathEvent::Begin { at } => pt(&mut d, "M", at),
+            PathEvent::Line { to, .. } => pt(&mut d, "L", to),
+            PathEvent::Quadratic { ctrl, to, .. } => {
+                pt(&mut d, "Q", ctrl);
+                pt(&mut d, " ", to);
+            }
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => {
+                pt(&mut d, "C", ctrl1);
+                pt(&mut d, " ", ctrl2);
+                pt(&mut d, " ", to);
+            }
+            PathEvent::End { close, .. } => {
+                if close {
+                    d.push('Z');
+                }
+            }
+        }
+    }
+    d
+}
+
+// The SVG `matrix` transform for the xy plane of the given affine transform.
+fn matrix(m: Mat4) -> String {
+    format!(
+        "matrix({} {} {} {} {} {})",
+        num(m.x_axis.x),
+        num(m.x_axis.y),
+        num(m.y_axis.x),
+        num(m.y_axis.y),
+        num(m.w_axis.x),
+        num(m.w_axis.y),
+    )
+}
+
+// The approximate factor by which the given transform scales lengths around the local origin.
+fn scale(m: Mat4) -> f32 {
+    let o = m.project_point3(Vec3::ZERO);
+    let x = m.project_point3(Vec3::X) - o;
+    let y = m.project_point3(Vec3::Y) - o;
+    (x.x * y.y - x.y * y.x).abs().sqrt()
+}
+
+fn line_cap(cap: LineCap) -> &'static str {
+    match cap {
+        LineCap::Butt => "butt",
+        LineCap::Square => "square",
+        LineCap::Round => "round",
+    }
+}
+
+fn line_join(join: LineJoin) -> &'static str {
+    match join {
+        LineJoin::Miter | LineJoin::MiterClip => "miter",
+        LineJoin::Round => "round",
+        LineJoin::Bevel => "bevel",
+    }
+}
+
+// Format a number, rounded to three decimal places for brevity.
+fn num(v: f32) -> String {
+    let v = (v * 1000.0).round() / 1000.0;
+    if v == 0.0 {
+        "0".to_string()
+    } else {
+        v.to_string()
+    }
+}
+
+impl Draw {
+    /// Produce an SVG document from the drawing for an output of the given logical size, e.g.
+    /// `app.window_rect().wh()`.
+    ///
+    /// Unlike `to_frame`, the draw commands are left in place, allowing the same **Draw** to be
+    /// exported and rendered. See the `draw::svg` module for the supported features.
+    pub fn to_svg_string(&self, size: Vec2) -> String {
+        to_string(self, size)
+    }
+
+    /// Write an SVG document produced via `to_svg_string` to the file at the given path.
+    pub fn to_svg<P>(&self, path: P, size: Vec2) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        std::fs::write(path, self.to_svg_string(size))
+    }
+}
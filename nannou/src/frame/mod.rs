@@ -2,12 +2,15 @@
 
 use crate::color::IntoLinSrgba;
 use crate::wgpu;
+use std::cell::Cell;
 use std::ops;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use std::time::Duration;
 
+pub mod post;
 pub mod raw;
+pub mod record;
 
 pub use self::raw::RawFrame;
 
@@ -23,6 +26,10 @@ pub struct Frame<'swap_chain> {
     raw_frame: RawFrame<'swap_chain>,
     render_data: &'swap_chain RenderData,
     capture_data: &'swap_chain CaptureData,
+    // Whether or not the multisampled texture has already been resolved, e.g. for post-processing.
+    resolved: Cell<bool>,
+    // Whether or not the post-processing output should be written to the window.
+    post_processed: Cell<bool>,
 }
 
 /// Data specific to the intermediary textures.
@@ -31,8 +38,19 @@ pub struct RenderData {
     intermediary_lin_srgba: IntermediaryLinSrgba,
     msaa_samples: u32,
     size: [u32; 2],
+    swap_chain_format: wgpu::TextureFormat,
     // For writing the intermediary linear sRGBA texture to the swap chain texture.
     texture_reshaper: wgpu::TextureReshaper,
+    // The target of post-processing, created upon the first call to `Frame::post_process`.
+    post_output: Mutex<Option<PostOutput>>,
+}
+
+/// The texture to which a frame is post-processed, written to the swap chain texture in place of
+/// the intermediary linear sRGBA texture.
+#[derive(Debug)]
+struct PostOutput {
+    texture: wgpu::Texture,
+    texture_reshaper: wgpu::TextureReshaper,
 }
 
 /// Data related to the capturing of a frame.
@@ -42,6 +60,8 @@ pub(crate) struct CaptureData {
     pub(crate) next_frame_path: Mutex<Option<PathBuf>>,
     // The `TextureCapturer` used to capture the frame.
     pub(crate) texture_capturer: wgpu::TextureCapturer,
+    // If `Some`, a sequence of frames is being recorded.
+    pub(crate) recording: Mutex<Option<record::Recording>>,
 }
 
 /// Intermediary textures used as a target before resolving multisampling and writing to the
@@ -83,27 +103,54 @@ impl<'swap_chain> Frame<'swap_chain> {
             raw_frame,
             render_data,
             capture_data,
+            resolved: Cell::new(false),
+            post_processed: Cell::new(false),
+        }
+    }
+
+    // Resolve the MSAA texture to the intermediary linear sRGBA texture if necessary.
+    fn resolve(&self) {
+        if self.resolved.replace(true) {
+            return;
+        }
+        if let Some((_, ref msaa_texture_view)) =
+            self.render_data.intermediary_lin_srgba.msaa_texture
+        {
+            let mut encoder = self.raw_frame.command_encoder();
+            wgpu::resolve_texture(
+                msaa_texture_view,
+                &self.render_data.intermediary_lin_srgba.texture_view,
+                &mut *encoder,
+            );
         }
     }
 
     // The private implementation of `submit`, allowing it to be called during `drop` if submission
     // has not yet occurred.
     fn submit_inner(&mut self) {
+        // Resolve the MSAA if necessary.
+        self.resolve();
+
         let Frame {
             ref capture_data,
             ref render_data,
             ref mut raw_frame,
+            ref post_processed,
+            ..
         } = *self;
 
-        // Resolve the MSAA if necessary.
-        if let Some((_, ref msaa_texture_view)) = render_data.intermediary_lin_srgba.msaa_texture {
-            let mut encoder = raw_frame.command_encoder();
-            wgpu::resolve_texture(
-                msaa_texture_view,
-                &render_data.intermediary_lin_srgba.texture_view,
-                &mut *encoder,
-            );
-        }
+        // The texture to write to the window, i.e. the result of post-processing if any.
+        let post_output = render_data
+            .post_output
+            .lock()
+            .expect("failed to lock `post_output`");
+        let (texture, texture_reshaper) = match *post_output {
+            Some(ref output) if post_processed.get() => (&output.texture, &output.texture_reshaper),
+            _ => (
+                &render_data.intermediary_lin_srgba.texture,
+                &render_data.texture_reshaper,
+            ),
+        };
 
         // Check to see if the user specified capturing the frame.
         let mut snapshot_capture = None;
@@ -111,24 +158,52 @@ impl<'swap_chain> Frame<'swap_chain> {
             if let Some(path) = guard.take() {
                 let device = raw_frame.device_queue_pair().device();
                 let mut encoder = raw_frame.command_encoder();
-                let snapshot = capture_data.texture_capturer.capture(
-                    device,
-                    &mut *encoder,
-                    &render_data.intermediary_lin_srgba.texture,
-                );
+                let snapshot =
+                    capture_data
+                        .texture_capturer
+                        .capture(device, &mut *encoder, texture);
                 snapshot_capture = Some((path, snapshot));
             }
         }
 
+        // Check to see if the frame is part of an active recording.
+        //
+        // The recording is only locked while encoding the capture. If this is the last frame in
+        // its range, the recording is taken so that it may be finished after submission.
+        let (recording_capture, finished_recording) = {
+            let mut recording = capture_data
+                .recording
+                .lock()
+                .expect("failed to lock `recording`");
+            let mut recording_capture = None;
+            if let Some(rec) = recording.as_mut() {
+                if let Some(index) = rec.next_frame() {
+                    let device = raw_frame.device_queue_pair().device();
+                    let mut encoder = raw_frame.command_encoder();
+                    let capture = rec.capture(
+                        index,
+                        device,
+                        &mut encoder,
+                        texture,
+                        &capture_data.texture_capturer,
+                    );
+                    recording_capture = Some(capture);
+                }
+            }
+            let finished_recording = match recording.as_ref().map(|rec| rec.is_complete()) {
+                Some(true) => recording.take(),
+                _ => None,
+            };
+            (recording_capture, finished_recording)
+        };
+
         // Convert the linear sRGBA image to the swapchain image.
         //
         // To do so, we sample the linear sRGBA image and draw it to the swapchain image using
         // two triangles and a fragment shader.
         {
             let mut encoder = raw_frame.command_encoder();
-            render_data
-                .texture_reshaper
-                .encode_render_pass(raw_frame.swap_chain_texture(), &mut *encoder);
+            texture_reshaper.encode_render_pass(raw_frame.swap_chain_texture(), &mut *encoder);
         }
 
         // Submit all commands on the device queue.
@@ -156,6 +231,19 @@ impl<'swap_chain> Frame<'swap_chain> {
                 eprintln!("timed out while waiting for a worker thread to capture the frame");
             }
         }
+
+        // Write the recorded frame, ending the recording if this was the last frame in its range.
+        let device = raw_frame.device_queue_pair().device();
+        if let Some(capture) = recording_capture {
+            let workers = capture_data.texture_capturer.workers();
+            capture.write(device, workers);
+        }
+        if let Some(rec) = finished_recording {
+            if let Err(e) = rec.finish(device) {
+                // TODO: Log errors, don't print to stderr.
+                eprintln!("failed to finish recording: {}", e);
+            }
+        }
     }
 
     /// The texture to which all graphics should be drawn this frame.
@@ -251,6 +339,46 @@ impl<'swap_chain> Frame<'swap_chain> {
         self.raw_frame.clear(self.texture_view(), color);
     }
 
+    /// Apply a chain of post-processing effects to the frame.
+    ///
+    /// This should be called once all drawing for the frame is complete. The frame's texture is
+    /// resolved if multisampled, and each effect is then applied in order. The result is written
+    /// to a separate output texture which is written to the window upon submission in place of
+    /// the frame's texture, so the frame's texture itself is left unprocessed and the effects do
+    /// not accumulate between frames. Any graphics drawn to the frame after this call are lost.
+    ///
+    /// If called more than once, only the result of the last call is written to the window.
+    pub fn post_process(&self, chain: &post::Chain) {
+        if chain.is_empty() {
+            return;
+        }
+        self.resolve();
+        let device_queue_pair = self.raw_frame.device_queue_pair();
+        let device = device_queue_pair.device();
+        let mut post_output = self
+            .render_data
+            .post_output
+            .lock()
+            .expect("failed to lock `post_output`");
+        let output = post_output.get_or_insert_with(|| {
+            PostOutput::new(
+                device,
+                self.render_data.size,
+                self.render_data.swap_chain_format,
+            )
+        });
+        let mut encoder = self.raw_frame.command_encoder();
+        chain.encode(
+            device,
+            device_queue_pair.queue(),
+            &mut encoder,
+            &self.render_data.intermediary_lin_srgba.texture_view,
+            &output.texture,
+            self.raw_frame.nth(),
+        );
+        self.post_processed.set(true);
+    }
+
     /// Submit the frame to the GPU!
     ///
     /// Note that you do not need to call this manually as submission will occur automatically when
@@ -273,6 +401,7 @@ impl CaptureData {
         CaptureData {
             next_frame_path: Default::default(),
             texture_capturer: wgpu::TextureCapturer::new(Some(max_jobs), timeout),
+            recording: Default::default(),
         }
     }
 }
@@ -306,7 +435,9 @@ impl RenderData {
             intermediary_lin_srgba,
             texture_reshaper,
             size: swap_chain_dims,
+            swap_chain_format,
             msaa_samples,
+            post_output: Mutex::new(None),
         }
     }
 
@@ -322,6 +453,29 @@ impl RenderData {
     }
 }
 
+impl PostOutput {
+    fn new(
+        device: &wgpu::Device,
+        swap_chain_dims: [u32; 2],
+        swap_chain_format: wgpu::TextureFormat,
+    ) -> Self {
+        let texture = create_lin_srgba_texture(device, swap_chain_dims);
+        let texture_view = texture.view().build();
+        let texture_reshaper = wgpu::TextureReshaper::new(
+            device,
+            &texture_view,
+            1,
+            texture_view.sample_type(),
+            1,
+            swap_chain_format,
+        );
+        PostOutput {
+            texture,
+            texture_reshaper,
+        }
+    }
+}
+
 impl<'swap_chain> Drop for Frame<'swap_chain> {
     fn drop(&mut self) {
         if !self.raw_frame.is_submitted() {
@@ -347,7 +501,12 @@ fn create_lin_srgba_texture(device: &wgpu::Device, swap_chain_dims: [u32; 2]) ->
     wgpu::TextureBuilder::new()
         .size(swap_chain_dims)
         .format(Frame::TEXTURE_FORMAT)
-        .usage(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING)
+        .usage(
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC
+                | wgpu::TextureUsages::COPY_DST,
+        )
         .build(device)
 }
 
@@ -0,0 +1,482 @@
+//! Items related to post-processing a **Frame** with a chain of full-screen shader passes.
+//!
+//! An **Effect** is a WGSL fragment shader that is run once for every pixel of the frame, reading
+//! from the result of the previous effect. A **Chain** of effects may be applied to a frame via
+//! `frame.post_process(&chain)` once all drawing for the frame is complete.
+//!
+//! The frame's texture is only ever read. Effects ping-pong between a texture owned by the chain
+//! and a separate output texture owned by the window, which is then written to the window in place
+//! of the frame's texture. As a result, the processed image never accumulates within the frame's
+//! texture, regardless of whether or not it is multisampled.
+//!
+//! ## Writing Effects
+//!
+//! The source given to `Effect::new` must contain a fragment entry point named `main` that
+//! receives the texture coordinates at `location(0)` and returns the color at `location(0)`. The
+//! following items are declared for use by the shader:
+//!
+//! - `src_texture`: The output of the previous effect, or the frame's texture for the first.
+//! - `history_texture`: If `Effect::history` is enabled, this effect's output from the previous
+//!   frame. Otherwise, the same texture as `src_texture`.
+//! - `tex_sampler`: A linear sampler for the above textures.
+//! - `uniforms`: A struct with the `resolution` of the frame in pixels, the `time` in seconds
+//!   since the chain was created and the `frame` number, followed by an `f32` field for each
+//!   parameter given via `Effect::param`.
+//!
+//! ```wgsl
+//! [[stage(fragment)]]
+//! fn main([[location(0)]] tex_coords: vec2<f32>) -> [[location(0)]] vec4<f32> {
+//!     let color = textureSample(src_texture, tex_sampler, tex_coords);
+//!     let inverted = vec3<f32>(1.0) - color.rgb;
+//!     return vec4<f32>(mix(color.rgb, inverted, vec3<f32>(uniforms.amount)), color.a);
+//! }
+//! ```
+
+use crate::frame::Frame;
+use crate::wgpu;
+use instant::Instant;
+use std::cell::RefCell;
+
+/// A single full-screen shader pass within a post-processing **Chain**.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Effect {
+    source: String,
+    params: Vec<(String, f32)>,
+    history: bool,
+}
+
+/// An ordered list of **Effect**s that may be applied to a **Frame**.
+///
+/// The GPU resources for each effect are created the first time the chain is applied, and are
+/// re-used until the effect's source or parameter names change.
+#[derive(Debug)]
+pub struct Chain {
+    effects: Vec<Effect>,
+    start: Instant,
+    resources: RefCell<Option<Resources>>,
+}
+
+// GPU resources shared by all passes of the chain.
+#[derive(Debug)]
+struct Resources {
+    size: [u32; 2],
+    vs_mod: wgpu::ShaderModule,
+    sampler: wgpu::Sampler,
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_layout: wgpu::PipelineLayout,
+    // The texture with which the frame's intermediary texture is ping-ponged.
+    texture: wgpu::Texture,
+    texture_view: wgpu::TextureView,
+    passes: Vec<Pass>,
+}
+
+// GPU resources for a single effect.
+#[derive(Debug)]
+struct Pass {
+    // The full source of the fragment shader, used to detect changes to the effect.
+    source: String,
+    _fs_mod: wgpu::ShaderModule,
+    pipeline: wgpu::RenderPipeline,
+    uniform_buffer: wgpu::Buffer,
+    history: Option<(wgpu::Texture, wgpu::TextureView)>,
+}
+
+// The names of the uniforms that precede those of the effect's parameters.
+const BUILTIN_UNIFORMS: &[&str] = &["resolution", "time", "frame"];
+
+// The size in bytes of the uniforms that precede the effect's parameters.
+const BUILTIN_UNIFORMS_SIZE: usize = 16;
+
+impl Effect {
+    /// An effect with the given WGSL fragment shader source.
+    ///
+    /// See the [module documentation](./index.html) for the items available to the shader.
+    pub fn new<S>(source: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Effect {
+            source: source.into(),
+            params: vec![],
+            history: false,
+        }
+    }
+
+    /// Declare a parameter, accessible within the shader as `uniforms.<name>`.
+    ///
+    /// If a parameter with the given name already exists, its value is replaced.
+    ///
+    /// **Panics** if `name` is not a valid WGSL identifier or is the name of a built-in uniform.
+    pub fn param(mut self, name: &str, value: f32) -> Self {
+        let is_ident = name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        assert!(is_ident, "`{}` is not a valid parameter name", name);
+        assert!(
+            !BUILTIN_UNIFORMS.contains(&name),
+            "`{}` is the name of a built-in uniform",
+            name
+        );
+        match self.params.iter_mut().find(|(n, _)| n == name) {
+            Some(param) => param.1 = value,
+            None => self.params.push((name.to_string(), value)),
+        }
+        self
+    }
+
+    /// Set the value of an existing parameter.
+    ///
+    /// Returns `false` if the effect has no parameter with the given name.
+    pub fn set_param(&mut self, name: &str, value: f32) -> bool {
+        match self.params.iter_mut().find(|(n, _)| n == name) {
+            Some(param) => {
+                param.1 = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The value of the parameter with the given name.
+    pub fn get_param(&self, name: &str) -> Option<f32> {
+        self.params.iter().find(|(n, _)| n == name).map(|&(_, v)| v)
+    }
+
+    /// Whether or not the effect's output from the previous frame is provided via
+    /// `history_texture`.
+    ///
+    /// Enabling history requires an additional texture and copy per frame.
+    pub fn history(mut self, enabled: bool) -> Self {
+        self.history = enabled;
+        self
+    }
+
+    /// A gaussian blur spanning `radius` pixels in each direction.
+    pub fn blur(radius: f32) -> Self {
+        Effect::new(include_str!("shaders/blur.wgsl")).param("radius", radius)
+    }
+
+    /// Adds a glow around the parts of the image brighter than `threshold`.
+    ///
+    /// The glow spans `radius` pixels and is scaled by `intensity`.
+    pub fn bloom(threshold: f32, intensity: f32, radius: f32) -> Self {
+        Effect::new(include_str!("shaders/bloom.wgsl"))
+            .param("threshold", threshold)
+            .param("intensity", intensity)
+            .param("radius", radius)
+    }
+
+    /// Adjusts the `exposure` in stops, the `contrast` around middle grey and the `saturation`.
+    ///
+    /// Values of `0.0`, `1.0` and `1.0` respectively leave the image unchanged.
+    pub fn color_grade(exposure: f32, contrast: f32, saturation: f32) -> Self {
+        Effect::new(include_str!("shaders/color_grade.wgsl"))
+            .param("exposure", exposure)
+            .param("contrast", contrast)
+            .param("saturation", saturation)
+    }
+
+    /// Emulates a CRT display.
+    ///
+    /// `curvature` bends the image as though projected onto a curved screen, `scanlines` in the
+    /// range `0.0..=1.0` darkens every second row of pixels and `vignette` darkens the border.
+    pub fn crt(curvature: f32, scanlines: f32, vignette: f32) -> Self {
+        Effect::new(include_str!("shaders/crt.wgsl"))
+            .param("curvature", curvature)
+            .param("scanlines", scanlines)
+            .param("vignette", vignette)
+    }
+
+    /// Leaves trails by keeping the brighter of each pixel and the effect's output from the
+    /// previous frame multiplied by `decay`.
+    pub fn feedback(decay: f32) -> Self {
+        Effect::new(include_str!("shaders/feedback.wgsl"))
+            .param("decay", decay)
+            .history(true)
+    }
+
+    /// The full source of the fragment shader, including the declarations of the textures, sampler
+    /// and uniforms.
+    pub fn shader_source(&self) -> String {
+        let mut fields = String::new();
+        for (name, _) in &self.params {
+            fields.push_str(&format!("    {}: f32;\n", name));
+        }
+        format!(
+            "[[block]]
+struct Uniforms {{
+    resolution: vec2<f32>;
+    time: f32;
+    frame: u32;
+{}}};
+
+[[group(0), binding(0)]]
+var<uniform> uniforms: Uniforms;
+[[group(0), binding(1)]]
+var tex_sampler: sampler;
+[[group(0), binding(2)]]
+var src_texture: texture_2d<f32>;
+[[group(0), binding(3)]]
+var history_texture: texture_2d<f32>;
+
+{}",
+            fields, self.source
+        )
+    }
+
+    // The size of the uniform buffer, rounded up to the 16 byte alignment of uniform structs.
+    fn uniforms_size(&self) -> usize {
+        let size = BUILTIN_UNIFORMS_SIZE + self.params.len() * std::mem::size_of::<f32>();
+        (size + 15) & !15
+    }
+
+    // The uniform data in the layout of the generated `Uniforms` struct.
+    fn uniforms_bytes(&self, [w, h]: [u32; 2], time: f32, frame: u64) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.uniforms_size());
+        bytes.extend_from_slice(&(w as f32).to_ne_bytes());
+        bytes.extend_from_slice(&(h as f32).to_ne_bytes());
+        bytes.extend_from_slice(&time.to_ne_bytes());
+        bytes.extend_from_slice(&(frame as u32).to_ne_bytes());
+        for &(_, value) in &self.params {
+            bytes.extend_from_slice(&value.to_ne_bytes());
+        }
+        bytes.resize(self.uniforms_size(), 0);
+        bytes
+    }
+}
+
+impl Chain {
+    /// An empty chain of effects.
+    pub fn new() -> Self {
+        Chain {
+            effects: vec![],
+            start: Instant::now(),
+            resources: RefCell::new(None),
+        }
+    }
+
+    /// Append an effect to the end of the chain.
+    pub fn effect(mut self, effect: Effect) -> Self {
+        self.push(effect);
+        self
+    }
+
+    /// Append an effect to the end of the chain.
+    pub fn push(&mut self, effect: Effect) {
+        self.effects.push(effect);
+    }
+
+    /// The effects of the chain in the order in which they are applied.
+    pub fn effects(&self) -> &[Effect] {
+        &self.effects
+    }
+
+    /// The effects of the chain, e.g. for updating their parameters.
+    pub fn effects_mut(&mut self) -> &mut Vec<Effect> {
+        &mut self.effects
+    }
+
+    /// The number of effects in the chain.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Whether or not the chain contains no effects.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Encode the passes of the chain, reading from `src` and writing the result to `dst`.
+    ///
+    /// `src` is only read, so it may be re-used as the source for subsequent frames. Both textures
+    /// must have the same size and the format `Frame::TEXTURE_FORMAT`, and must not be
+    /// multisampled. The `dst` texture must have the `RENDER_ATTACHMENT`, `TEXTURE_BINDING` and
+    /// `COPY_SRC` usages.
+    ///
+    /// This is called by `Frame::post_process`, but may also be used to process other textures,
+    /// e.g. those of a `draw::RenderTarget`.
+    pub fn encode(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        src: &wgpu::TextureView,
+        dst: &wgpu::Texture,
+        frame: u64,
+    ) {
+        if self.effects.is_empty() {
+            return;
+        }
+        let size = dst.size();
+        let time = self.start.elapsed().as_secs_f32();
+        let mut resources = self.resources.borrow_mut();
+        let res = resources.get_or_insert_with(|| Resources::new(device, size));
+        res.update(device, size, &self.effects);
+
+        let dst_view = dst.view().build();
+        let len = self.effects.len();
+        for (i, (effect, pass)) in self.effects.iter().zip(&res.passes).enumerate() {
+            // Ping-pong between the chain's texture and `dst`, such that the last pass writes to
+            // `dst` and `src` is only read by the first.
+            let to_dst = (len - i) % 2 == 1;
+            let (target, target_view) = match to_dst {
+                true => (dst, &dst_view),
+                false => (&res.texture, &res.texture_view),
+            };
+            let input = match (i, to_dst) {
+                (0, _) => src,
+                (_, true) => &res.texture_view,
+                (_, false) => &dst_view,
+            };
+            let history = pass.history.as_ref().map(|(_, view)| view).unwrap_or(input);
+            let uniforms = effect.uniforms_bytes(size, time, frame);
+            queue.write_buffer(&pass.uniform_buffer, 0, &uniforms);
+            let bind_group = wgpu::BindGroupBuilder::new()
+                .buffer_bytes(&pass.uniform_buffer, 0, None)
+                .sampler(&res.sampler)
+                .texture_view(input)
+                .texture_view(history)
+                .build(device, &res.bind_group_layout);
+            {
+                let mut render_pass = wgpu::RenderPassBuilder::new()
+                    .color_attachment(target_view, |color| color)
+                    .begin(encoder);
+                render_pass.set_pipeline(&pass.pipeline);
+                render_pass.set_bind_group(0, &bind_group, &[]);
+                render_pass.draw(0..3, 0..1);
+            }
+            if let Some((ref history, _)) = pass.history {
+                encoder.copy_texture_to_texture(
+                    target.as_image_copy(),
+                    history.as_image_copy(),
+                    target.extent(),
+                );
+            }
+        }
+    }
+}
+
+impl Resources {
+    fn new(device: &wgpu::Device, size: [u32; 2]) -> Self {
+        let vs_desc = wgpu::include_wgsl!("shaders/vs.wgsl");
+        let vs_mod = device.create_shader_module(&vs_desc);
+        let sampler_desc = wgpu::SamplerBuilder::new().into_descriptor();
+        let sampler_filtering = wgpu::sampler_filtering(&sampler_desc);
+        let sampler = device.create_sampler(&sampler_desc);
+        let sample_type = wgpu::TextureSampleType::Float { filterable: true };
+        let bind_group_layout = wgpu::BindGroupLayoutBuilder::new()
+            .uniform_buffer(wgpu::ShaderStages::FRAGMENT, false)
+            .sampler(wgpu::ShaderStages::FRAGMENT, sampler_filtering)
+            .texture(
+                wgpu::ShaderStages::FRAGMENT,
+                false,
+                wgpu::TextureViewDimension::D2,
+                sample_type,
+            )
+            .texture(
+                wgpu::ShaderStages::FRAGMENT,
+                false,
+                wgpu::TextureViewDimension::D2,
+                sample_type,
+            )
+            .build(device);
+        let desc = wgpu::PipelineLayoutDescriptor {
+            label: Some("nannou_post_process"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        };
+        let pipeline_layout = device.create_pipeline_layout(&desc);
+        let texture = create_texture(device, size);
+        let texture_view = texture.view().build();
+        Resources {
+            size,
+            vs_mod,
+            sampler,
+            bind_group_layout,
+            pipeline_layout,
+            texture,
+            texture_view,
+            passes: vec![],
+        }
+    }
+
+    // Ensure the textures match the given size and there is an up-to-date pass for each effect.
+    fn update(&mut self, device: &wgpu::Device, size: [u32; 2], effects: &[Effect]) {
+        if self.size != size {
+            self.size = size;
+            self.texture = create_texture(device, size);
+            self.texture_view = self.texture.view().build();
+            for pass in &mut self.passes {
+                pass.history = None;
+            }
+        }
+        self.passes.truncate(effects.len());
+        for (i, effect) in effects.iter().enumerate() {
+            let source = effect.shader_source();
+            let is_current = self.passes.get(i).map(|pass| pass.source == source);
+            if is_current != Some(true) {
+                let pass = self.create_pass(device, effect, source);
+                match i < self.passes.len() {
+                    true => self.passes[i] = pass,
+                    false => self.passes.push(pass),
+                }
+            }
+            let pass = &mut self.passes[i];
+            match (effect.history, pass.history.is_some()) {
+                (true, false) => {
+                    let texture = create_texture(device, size);
+                    let view = texture.view().build();
+                    pass.history = Some((texture, view));
+                }
+                (false, true) => pass.history = None,
+                _ => (),
+            }
+        }
+    }
+
+    fn create_pass(&self, device: &wgpu::Device, effect: &Effect, source: String) -> Pass {
+        let fs_desc = wgpu::ShaderModuleDescriptor {
+            label: Some("nannou_post_process_effect"),
+            source: wgpu::ShaderSource::Wgsl(source.as_str().into()),
+        };
+        let fs_mod = device.create_shader_module(&fs_desc);
+        let pipeline =
+            wgpu::RenderPipelineBuilder::from_layout(&self.pipeline_layout, &self.vs_mod)
+                .fragment_shader(&fs_mod)
+                .color_format(Frame::TEXTURE_FORMAT)
+                .color_blend(wgpu::BlendComponent::REPLACE)
+                .alpha_blend(wgpu::BlendComponent::REPLACE)
+                .build(device);
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("nannou_post_process_uniforms"),
+            size: effect.uniforms_size() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Pass {
+            source,
+            _fs_mod: fs_mod,
+            pipeline,
+            uniform_buffer,
+            history: None,
+        }
+    }
+}
+
+impl Default for Chain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn create_texture(device: &wgpu::Device, size: [u32; 2]) -> wgpu::Texture {
+    wgpu::TextureBuilder::new()
+        .size(size)
+        .format(Frame::TEXTURE_FORMAT)
+        .usage(
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC
+                | wgpu::TextureUsages::COPY_DST,
+        )
+        .build(device)
+}
@@ -0,0 +1,22 @@
+// Adds a blurred copy of the parts of the image brighter than `threshold`.
+[[stage(fragment)]]
+fn main(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> [[location(0)]] vec4<f32> {
+    let src: vec4<f32> = textureSample(src_texture, tex_sampler, tex_coords);
+    let texel: vec2<f32> = uniforms.radius / uniforms.resolution;
+    var sum: vec3<f32> = vec3<f32>(0.0);
+    var weight_sum: f32 = 0.0;
+    for (var x: i32 = -4; x <= 4; x = x + 1) {
+        for (var y: i32 = -4; y <= 4; y = y + 1) {
+            let offset: vec2<f32> = vec2<f32>(f32(x), f32(y)) / 4.0;
+            let weight: f32 = exp(-2.0 * dot(offset, offset));
+            let uv: vec2<f32> = tex_coords + offset * texel;
+            let color: vec3<f32> = textureSample(src_texture, tex_sampler, uv).rgb;
+            sum = sum + max(color - vec3<f32>(uniforms.threshold), vec3<f32>(0.0)) * weight;
+            weight_sum = weight_sum + weight;
+        }
+    }
+    let bloom: vec3<f32> = sum / weight_sum * uniforms.intensity;
+    return vec4<f32>(src.rgb + bloom, src.a);
+}
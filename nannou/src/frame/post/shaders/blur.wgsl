@@ -0,0 +1,19 @@
+// A gaussian blur sampled over a 9x9 grid spanning `radius` pixels in each direction.
+[[stage(fragment)]]
+fn main(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> [[location(0)]] vec4<f32> {
+    let texel: vec2<f32> = uniforms.radius / uniforms.resolution;
+    var sum: vec4<f32> = vec4<f32>(0.0);
+    var weight_sum: f32 = 0.0;
+    for (var x: i32 = -4; x <= 4; x = x + 1) {
+        for (var y: i32 = -4; y <= 4; y = y + 1) {
+            let offset: vec2<f32> = vec2<f32>(f32(x), f32(y)) / 4.0;
+            let weight: f32 = exp(-2.0 * dot(offset, offset));
+            let uv: vec2<f32> = tex_coords + offset * texel;
+            sum = sum + textureSample(src_texture, tex_sampler, uv) * weight;
+            weight_sum = weight_sum + weight;
+        }
+    }
+    return sum / weight_sum;
+}
@@ -0,0 +1,13 @@
+// Adjusts exposure in stops, then contrast around middle grey, then saturation.
+[[stage(fragment)]]
+fn main(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> [[location(0)]] vec4<f32> {
+    let src: vec4<f32> = textureSample(src_texture, tex_sampler, tex_coords);
+    let exposed: vec3<f32> = src.rgb * exp2(uniforms.exposure);
+    let grey: vec3<f32> = vec3<f32>(0.18);
+    let contrasted: vec3<f32> = (exposed - grey) * uniforms.contrast + grey;
+    let luma: f32 = dot(contrasted, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let saturated: vec3<f32> = mix(vec3<f32>(luma), contrasted, vec3<f32>(uniforms.saturation));
+    return vec4<f32>(max(saturated, vec3<f32>(0.0)), src.a);
+}
@@ -0,0 +1,16 @@
+// Emulates a curved CRT display with scanlines and a darkened border.
+[[stage(fragment)]]
+fn main(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> [[location(0)]] vec4<f32> {
+    let centered: vec2<f32> = tex_coords * 2.0 - 1.0;
+    let warped: vec2<f32> = centered + centered * centered.yx * centered.yx * uniforms.curvature;
+    let uv: vec2<f32> = warped * 0.5 + 0.5;
+    let inside: f32 = step(0.0, uv.x) * step(uv.x, 1.0) * step(0.0, uv.y) * step(uv.y, 1.0);
+    let color: vec3<f32> = textureSample(src_texture, tex_sampler, uv).rgb;
+    let scanline: f32 = 0.5 + 0.5 * sin(uv.y * uniforms.resolution.y * 3.14159265);
+    let lines: f32 = mix(1.0, scanline, uniforms.scanlines);
+    let edge: f32 = clamp(16.0 * uv.x * uv.y * (1.0 - uv.x) * (1.0 - uv.y), 0.0, 1.0);
+    let vignette: f32 = pow(edge, uniforms.vignette);
+    return vec4<f32>(color * lines * vignette * inside, 1.0);
+}
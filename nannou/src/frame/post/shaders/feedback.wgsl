@@ -0,0 +1,9 @@
+// Keeps the brighter of the input and the faded output of the previous frame.
+[[stage(fragment)]]
+fn main(
+    [[location(0)]] tex_coords: vec2<f32>,
+) -> [[location(0)]] vec4<f32> {
+    let src: vec4<f32> = textureSample(src_texture, tex_sampler, tex_coords);
+    let previous: vec4<f32> = textureSample(history_texture, tex_sampler, tex_coords);
+    return max(src, previous * uniforms.decay);
+}
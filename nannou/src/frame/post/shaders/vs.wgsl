@@ -0,0 +1,15 @@
+struct VertexOutput {
+    [[location(0)]] tex_coords: vec2<f32>;
+    [[builtin(position)]] out_pos: vec4<f32>;
+};
+
+// Produces a single triangle covering the whole target.
+[[stage(vertex)]]
+fn main(
+    [[builtin(vertex_index)]] index: u32,
+) -> VertexOutput {
+    let uv: vec2<f32> = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    let out_pos: vec4<f32> = vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+    let tex_coords: vec2<f32> = vec2<f32>(uv.x, 1.0 - uv.y);
+    return VertexOutput(tex_coords, out_pos);
+}
@@ -0,0 +1,504 @@
+//! Items related to recording sequences of frames from a window to disk or to an external encoder.
+//!
+//! Recordings are started via `Window::record` and stopped via `Window::stop_recording` or once the
+//! requested range of frames has been written.
+
+use crate::wgpu;
+use crate::window::Window;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::atomic::{self, AtomicU32};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+/// A builder for starting a recording of a window's frames.
+///
+/// Created via `Window::record`.
+#[derive(Debug)]
+pub struct Builder<'a> {
+    window: &'a Window,
+    directory: PathBuf,
+    name_pattern: String,
+    format: Format,
+    frames: Range<u64>,
+    encoder: Option<Command>,
+}
+
+/// The image format to which recorded frames are written.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// 8-bit per channel non-linear sRGBA PNG images.
+    Png,
+    /// 8-bit per channel non-linear sRGBA TIFF images.
+    Tiff,
+    /// 16-bit per channel non-linear sRGBA PNG images.
+    ///
+    /// Frames are read directly from the high bit depth intermediary texture, retaining more
+    /// precision for post-production.
+    Png16,
+    /// 16-bit per channel non-linear sRGBA TIFF images.
+    ///
+    /// Frames are read directly from the high bit depth intermediary texture, retaining more
+    /// precision for post-production.
+    Tiff16,
+}
+
+/// The state of an active recording.
+#[derive(Debug)]
+pub(crate) struct Recording {
+    directory: PathBuf,
+    name_pattern: String,
+    format: Format,
+    frames: Range<u64>,
+    // The number of frames submitted since the recording started.
+    frames_seen: u64,
+    // The number of frames that are currently being read from the GPU and written.
+    active_jobs: Arc<AtomicU32>,
+    encoder: Option<Encoder>,
+}
+
+/// A frame that has been captured during submission and is yet to be read and written.
+///
+/// Holds everything required to write the frame, so that the recording need not be locked while
+/// doing so.
+pub(crate) struct Capture {
+    index: u64,
+    path: PathBuf,
+    image: CapturedImage,
+    frames_tx: Option<FramesTx>,
+    active_jobs: Arc<AtomicU32>,
+}
+
+// The texels of a captured frame, yet to be read from the GPU.
+enum CapturedImage {
+    Rgba8(wgpu::TextureSnapshot),
+    Rgba16(wgpu::RowPaddedBuffer),
+}
+
+// Sends the index and raw pixels of each frame to the encoder, or `None` if the frame could not
+// be read.
+type FramesTx = mpsc::Sender<(u64, Option<Vec<u8>>)>;
+
+// An external encoder process whose stdin receives the raw frames in order.
+#[derive(Debug)]
+struct Encoder {
+    child: Child,
+    frames_tx: FramesTx,
+    writer: thread::JoinHandle<io::Result<()>>,
+}
+
+/// The maximum number of frames the encoder buffers while waiting on an earlier frame.
+///
+/// At most one frame per worker is read at a time, so exceeding this indicates a lost frame.
+const MAX_PENDING_FRAMES: usize = 64;
+
+impl<'a> Builder<'a> {
+    /// The directory used if none is specified.
+    pub const DEFAULT_DIRECTORY: &'static str = "frames";
+    /// The file name pattern used if none is specified.
+    pub const DEFAULT_NAME_PATTERN: &'static str = "#####";
+    /// The format used if none is specified.
+    pub const DEFAULT_FORMAT: Format = Format::Png;
+
+    pub(crate) fn new(window: &'a Window) -> Self {
+        Builder {
+            window,
+            directory: Self::DEFAULT_DIRECTORY.into(),
+            name_pattern: Self::DEFAULT_NAME_PATTERN.into(),
+            format: Self::DEFAULT_FORMAT,
+            frames: 0..u64::MAX,
+            encoder: None,
+        }
+    }
+
+    /// The directory to which frames are written. It will be created if it does not exist.
+    ///
+    /// By default, this is `Builder::DEFAULT_DIRECTORY` relative to the working directory.
+    pub fn directory<P>(mut self, directory: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        self.directory = directory.as_ref().to_path_buf();
+        self
+    }
+
+    /// The pattern used to name each frame's image file, excluding the extension.
+    ///
+    /// The first run of `#` characters is replaced by the index of the frame, zero-padded to the
+    /// length of the run. E.g. `"frame_####"` produces `frame_0000.png`, `frame_0001.png`, etc. If
+    /// the pattern contains no `#`, the index is appended.
+    pub fn name_pattern<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.name_pattern = pattern.into();
+        self
+    }
+
+    /// The image format to which frames are written.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// The range of frames to record, counted from the first frame submitted after the recording
+    /// starts.
+    ///
+    /// Frames before the start of the range are skipped. The recording stops automatically once
+    /// the end of the range is reached.
+    pub fn frames(mut self, frames: Range<u64>) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Record exactly `count` frames and then stop.
+    pub fn frame_count(self, count: u64) -> Self {
+        self.frames(0..count)
+    }
+
+    /// Rather than writing image files, pipe each frame to the stdin of the given command.
+    ///
+    /// Frames are written in order as raw, tightly packed, 8-bit non-linear sRGBA pixels with
+    /// rows ordered from top to bottom. For example, using `ffmpeg` to encode a video:
+    ///
+    /// ```ignore
+    /// let [w, h] = window.inner_size_pixels().into();
+    /// let mut cmd = std::process::Command::new("ffmpeg");
+    /// cmd.args(&["-f", "rawvideo", "-pix_fmt", "rgba", "-s", &format!("{}x{}", w, h)])
+    ///     .args(&["-r", "60", "-i", "-", "-pix_fmt", "yuv420p", "out.mp4"]);
+    /// window.record().encoder(cmd).start().unwrap();
+    /// ```
+    ///
+    /// The `format` and `directory` are ignored when an encoder is specified.
+    pub fn encoder(mut self, command: Command) -> Self {
+        self.encoder = Some(command);
+        self
+    }
+
+    /// Start the recording, replacing any recording that is already active on the window.
+    ///
+    /// Returns an error if the output directory could not be created or the encoder process could
+    /// not be spawned.
+    pub fn start(self) -> io::Result<()> {
+        let Builder {
+            window,
+            directory,
+            name_pattern,
+            format,
+            frames,
+            encoder,
+        } = self;
+
+        window.stop_recording()?;
+
+        let encoder = match encoder {
+            Some(command) => Some(Encoder::spawn(command, frames.start)?),
+            None => {
+                std::fs::create_dir_all(&directory)?;
+                None
+            }
+        };
+
+        let recording = Recording {
+            directory,
+            name_pattern,
+            format,
+            frames,
+            frames_seen: 0,
+            active_jobs: Arc::new(AtomicU32::new(0)),
+            encoder,
+        };
+        let frame_data = window
+            .frame_data
+            .as_ref()
+            .expect("recording requires that `view` draws to a `Frame` (not a `RawFrame`)");
+        *frame_data
+            .capture
+            .recording
+            .lock()
+            .expect("failed to lock `recording`") = Some(recording);
+        Ok(())
+    }
+}
+
+impl Format {
+    /// The file extension associated with the format.
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            Format::Png | Format::Png16 => "png",
+            Format::Tiff | Format::Tiff16 => "tiff",
+        }
+    }
+
+    /// Whether or not the format stores 16 bits per channel.
+    pub fn is_16_bit(&self) -> bool {
+        match *self {
+            Format::Png16 | Format::Tiff16 => true,
+            Format::Png | Format::Tiff => false,
+        }
+    }
+}
+
+impl Recording {
+    /// Whether or not all frames in the requested range have been captured.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.frames_seen >= self.frames.end
+    }
+
+    /// Called for each submitted frame, returning the index of the frame if it should be captured.
+    pub(crate) fn next_frame(&mut self) -> Option<u64> {
+        let index = self.frames_seen;
+        self.frames_seen += 1;
+        if self.frames.contains(&index) {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Encode the commands for capturing the given frame texture.
+    ///
+    /// The texture must be the resolved, non-multisampled intermediary texture.
+    pub(crate) fn capture(
+        &self,
+        index: u64,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        texture_capturer: &wgpu::TextureCapturer,
+    ) -> Capture {
+        let image = if self.format.is_16_bit() && self.encoder.is_none() {
+            CapturedImage::Rgba16(texture.to_buffer(device, encoder))
+        } else {
+            CapturedImage::Rgba8(texture_capturer.capture(device, encoder, texture))
+        };
+        Capture {
+            index,
+            path: self.frame_path(index),
+            image,
+            frames_tx: self.encoder.as_ref().map(|e| e.frames_tx.clone()),
+            active_jobs: self.active_jobs.clone(),
+        }
+    }
+
+    /// Block until all active jobs have completed and, if piping to an encoder, wait for the
+    /// encoder process to exit.
+    pub(crate) fn finish(self, device: &wgpu::Device) -> io::Result<()> {
+        while self.active_jobs.load(atomic::Ordering::SeqCst) > 0 {
+            device.poll(wgpu::Maintain::Wait);
+            thread::sleep(Duration::from_micros(100));
+        }
+        match self.encoder {
+            None => Ok(()),
+            Some(encoder) => encoder.finish(),
+        }
+    }
+
+    // The path to which the frame with the given index is written.
+    fn frame_path(&self, index: u64) -> PathBuf {
+        let name = frame_name(&self.name_pattern, index);
+        self.directory
+            .join(name)
+            .with_extension(self.format.extension())
+    }
+}
+
+impl Capture {
+    /// Spawn a job that reads the captured frame and writes it to its destination.
+    ///
+    /// Rather than dropping frames, this blocks until a worker is available, throttling the
+    /// application loop to the rate at which frames can be written.
+    pub(crate) fn write(self, device: &wgpu::Device, workers: u32) {
+        let Capture {
+            index,
+            path,
+            image,
+            frames_tx,
+            active_jobs,
+        } = self;
+        let workers = workers.max(1);
+        while active_jobs.load(atomic::Ordering::SeqCst) >= workers {
+            device.poll(wgpu::Maintain::Poll);
+            thread::sleep(Duration::from_micros(100));
+        }
+
+        active_jobs.fetch_add(1, atomic::Ordering::SeqCst);
+        let job = async move {
+            let result = match image {
+                CapturedImage::Rgba8(snapshot) => match snapshot.read_async().await {
+                    Err(e) => {
+                        // Let the encoder know to skip the frame rather than wait for it.
+                        if let Some(tx) = frames_tx {
+                            tx.send((index, None)).ok();
+                        }
+                        Err(format!("failed to async read captured frame: {:?}", e))
+                    }
+                    Ok(image) => {
+                        let image = image.to_owned();
+                        match frames_tx {
+                            Some(tx) => tx
+                                .send((index, Some(image.into_raw())))
+                                .map_err(|_| "encoder is no longer receiving frames".to_string()),
+                            None => image.save(&path).map_err(|e| {
+                                format!("failed to save frame to \"{}\": {}", path.display(), e)
+                            }),
+                        }
+                    }
+                },
+                CapturedImage::Rgba16(buffer) => match buffer.read().await {
+                    Err(e) => Err(format!("failed to async read captured frame: {:?}", e)),
+                    Ok(mapping) => {
+                        // Safe, as every bit pattern of the `Rgba16Float` texels is a valid `u16`.
+                        let view = unsafe { mapping.as_image::<image::Rgba<u16>>() };
+                        let image = lin_f16_to_srgb_u16(&view);
+                        image.save(&path).map_err(|e| {
+                            format!("failed to save frame to \"{}\": {}", path.display(), e)
+                        })
+                    }
+                },
+            };
+            if let Err(e) = result {
+                // TODO: Log errors, don't print to stderr.
+                eprintln!("{}", e);
+            }
+            active_jobs.fetch_sub(1, atomic::Ordering::SeqCst);
+        };
+
+        #[cfg(not(target_os = "unknown"))]
+        async_std::task::spawn(job);
+        #[cfg(target_os = "unknown")]
+        async_std::task::spawn_local(job);
+    }
+}
+
+impl Encoder {
+    // Spawn the encoder process along with a thread that writes frames to its stdin in order.
+    fn spawn(mut command: Command, first_index: u64) -> io::Result<Self> {
+        let mut child = command.stdin(Stdio::piped()).spawn()?;
+        let stdin = child.stdin.take().expect("encoder stdin was not piped");
+        let (frames_tx, frames_rx) = mpsc::channel();
+        let writer = thread::Builder::new()
+            .name("nannou_record_encoder".into())
+            .spawn(move || write_frames_in_order(stdin, frames_rx, first_index))?;
+        Ok(Encoder {
+            child,
+            frames_tx,
+            writer,
+        })
+    }
+
+    // Close the encoder's stdin once all frames are written and wait for it to exit.
+    fn finish(self) -> io::Result<()> {
+        let Encoder {
+            mut child,
+            frames_tx,
+            writer,
+        } = self;
+        drop(frames_tx);
+        let written = writer
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("writer panicked")));
+        let status = child.wait()?;
+        written?;
+        if !status.success() {
+            let msg = format!("encoder exited with {}", status);
+            return Err(io::Error::other(msg));
+        }
+        Ok(())
+    }
+}
+
+// Frames may be read back from the GPU out of order, so buffer them until they can be written in
+// sequence. Frames that failed to be read are received as `None` and skipped.
+//
+// Returns an error rather than writing frames out of order if a frame is never received.
+fn write_frames_in_order(
+    mut stdin: ChildStdin,
+    frames_rx: mpsc::Receiver<(u64, Option<Vec<u8>>)>,
+    mut next_index: u64,
+) -> io::Result<()> {
+    let mut pending = BTreeMap::new();
+    let missing = |index| io::Error::other(format!("frame {} was never received", index));
+    for (index, bytes) in frames_rx {
+        pending.insert(index, bytes);
+        while let Some(bytes) = pending.remove(&next_index) {
+            if let Some(bytes) = bytes {
+                stdin.write_all(&bytes)?;
+            }
+            next_index += 1;
+        }
+        if pending.len() > MAX_PENDING_FRAMES {
+            return Err(missing(next_index));
+        }
+    }
+    if !pending.is_empty() {
+        return Err(missing(next_index));
+    }
+    stdin.flush()
+}
+
+// Substitute the frame index into the name pattern.
+fn frame_name(pattern: &str, index: u64) -> String {
+    match pattern.find('#') {
+        None => format!("{}{}", pattern, index),
+        Some(start) => {
+            let width = pattern[start..].chars().take_while(|&c| c == '#').count();
+            let end = start + width;
+            format!(
+                "{}{:0width$}{}",
+                &pattern[..start],
+                index,
+                &pattern[end..],
+                width = width
+            )
+        }
+    }
+}
+
+// Convert an image of linear half-float texels to 16-bit non-linear sRGBA.
+fn lin_f16_to_srgb_u16<I>(view: &I) -> image::ImageBuffer<image::Rgba<u16>, Vec<u16>>
+where
+    I: image::GenericImageView<Pixel = image::Rgba<u16>>,
+{
+    let encode = |c: u16, srgb: bool| {
+        let c = f16_to_f32(c).clamp(0.0, 1.0);
+        let c = if srgb { linear_to_srgb(c) } else { c };
+        (c * u16::MAX as f32).round() as u16
+    };
+    let (w, h) = view.dimensions();
+    image::ImageBuffer::from_fn(w, h, |x, y| {
+        let [r, g, b, a] = view.get_pixel(x, y).0;
+        image::Rgba([
+            encode(r, true),
+            encode(g, true),
+            encode(b, true),
+            encode(a, false),
+        ])
+    })
+}
+
+// The sRGB transfer function.
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Convert the bits of an IEEE 754 half-precision float to an `f32`.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let mantissa = (bits & 0x3ff) as f32;
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        0x1f if mantissa == 0.0 => sign * f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
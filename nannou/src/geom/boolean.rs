@@ -0,0 +1,293 @@
+//! Boolean operations on polygons and paths, i.e. union, intersection, difference and xor.
+//!
+//! A **Shape** is described by any number of closed contours, e.g. the points of a
+//! `geom::Polygon` or the flattened sub-paths of a `geom::Path`. Contours may overlap,
+//! self-intersect and describe holes, in which case the area covered by the shape is determined by
+//! its **FillRule**.
+//!
+//! The result of an operation is a **Shape** whose contours never overlap, with outer contours
+//! wound counter-clockwise and holes wound clockwise. Results may be drawn via
+//! `draw.polygon().events(shape.to_path().iter())` or stroked via `draw.path()`.
+//!
+//! ## Precision
+//!
+//! Operations are performed by `i_overlay` in fixed point. The combined bounding box of the
+//! operands is mapped onto a grid of 32-bit integers, snapping coordinates to a step of roughly
+//! half the larger side of the bounding box divided by 2^29, i.e. about a billionth of its size.
+//! Detail smaller than this step is merged or discarded silently. For example, the step is around
+//! 0.00002 for shapes within a 10,000 point square, while subtracting a unit triangle from a
+//! triangle spanning 1e30 points leaves the larger triangle untouched.
+//!
+//! Results are only reliable when the features of interest are no smaller than about a millionth
+//! of the combined bounds, so operands with very different magnitudes should be scaled or split
+//! up beforehand. Coordinates must also be finite.
+
+use crate::geom::{self, Path, Point2};
+use i_overlay::core::fill_rule::FillRule as OverlayFillRule;
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::float::single::SingleFloatOverlay;
+use lyon::path::iterator::PathIterator;
+use lyon::path::PathEvent;
+
+pub use crate::geom::path::DEFAULT_TOLERANCE;
+pub use lyon::path::FillRule;
+
+/// The boolean operations that may be applied to a pair of shapes.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Op {
+    /// The area covered by either shape.
+    Union,
+    /// The area covered by both shapes.
+    Intersection,
+    /// The area covered by the first shape but not the second.
+    Difference,
+    /// The area covered by exactly one of the shapes.
+    Xor,
+}
+
+/// A 2D shape described by a set of closed contours.
+///
+/// Operations on shapes are subject to the fixed point precision described within the module
+/// documentation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shape {
+    contours: Vec<Vec<Point2>>,
+    fill_rule: FillRule,
+}
+
+type Contours = Vec<Vec<[f32; 2]>>;
+
+impl Op {
+    fn to_overlay_rule(self) -> OverlayRule {
+        match self {
+            Op::Union => OverlayRule::Union,
+            Op::Intersection => OverlayRule::Intersect,
+            Op::Difference => OverlayRule::Difference,
+            Op::Xor => OverlayRule::Xor,
+        }
+    }
+}
+
+impl Shape {
+    /// An empty shape with the `EvenOdd` fill rule.
+    pub fn new() -> Self {
+        Shape {
+            contours: vec![],
+            fill_rule: FillRule::EvenOdd,
+        }
+    }
+
+    /// A shape described by a single contour, e.g. the points of a `geom::Polygon`.
+    ///
+    /// The contour is implicitly closed.
+    pub fn from_points<I>(points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Point2>,
+    {
+        Self::new().contour(points)
+    }
+
+    /// A shape described by the given contours.
+    pub fn from_contours<I>(contours: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        <I::Item as IntoIterator>::Item: Into<Point2>,
+    {
+        contours.into_iter().fold(Self::new(), Self::contour)
+    }
+
+    /// A shape described by the sub-paths of the given path.
+    ///
+    /// Curves are flattened into line segments with the given tolerance, e.g.
+    /// `DEFAULT_TOLERANCE`. Open sub-paths are implicitly closed.
+    pub fn from_path(path: &Path, tolerance: f32) -> Self {
+        Self::from_path_events(path.iter(), tolerance)
+    }
+
+    /// A shape described by the sub-paths of the given path events.
+    ///
+    /// Curves are flattened into line segments with the given tolerance, e.g.
+    /// `DEFAULT_TOLERANCE`. Open sub-paths are implicitly closed.
+    pub fn from_path_events<I>(events: I, tolerance: f32) -> Self
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let mut contours = vec![];
+        let mut contour = vec![];
+        for event in events.into_iter().flattened(tolerance) {
+            match event {
+                PathEvent::Begin { at } => {
+                    contour.clear();
+                    contour.push(Point2::new(at.x, at.y));
+                }
+                PathEvent::Line { to, .. } => contour.push(Point2::new(to.x, to.y)),
+                PathEvent::End { .. } => contours.push(std::mem::take(&mut contour)),
+                // Curves are flattened into lines.
+                PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => (),
+            }
+        }
+        Self::from_contours(contours)
+    }
+
+    /// Add a contour to the shape.
+    ///
+    /// The contour is implicitly closed. Contours with less than three points are ignored.
+    pub fn contour<I>(mut self, points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Point2>,
+    {
+        let mut points: Vec<Point2> = points.into_iter().map(Into::into).collect();
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        if points.len() > 2 {
+            self.contours.push(points);
+        }
+        self
+    }
+
+    /// Specify the rule used to determine the area covered by overlapping or self-intersecting
+    /// contours.
+    ///
+    /// By default this is `EvenOdd`, matching the default fill rule of `draw.polygon()`.
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// The contours describing the shape.
+    pub fn contours(&self) -> &[Vec<Point2>] {
+        &self.contours
+    }
+
+    /// The rule used to determine the area covered by the shape.
+    pub fn get_fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Whether or not the shape has no contours.
+    pub fn is_empty(&self) -> bool {
+        self.contours.is_empty()
+    }
+
+    /// Apply the given boolean operation to this shape and the other.
+    ///
+    /// The area covered by each shape is determined by its own fill rule. The resulting shape
+    /// retains the fill rule of this shape.
+    pub fn op(&self, other: &Shape, op: Op) -> Shape {
+        let fill_rule = self.fill_rule;
+        let rule = op.to_overlay_rule();
+        let contours = if self.fill_rule == other.fill_rule {
+            self.to_contours()
+                .overlay(&other.to_contours(), rule, to_overlay_fill_rule(fill_rule))
+        } else {
+            // Resolve each shape with its own rule. The resulting contours never overlap, so may
+            // be combined with either rule.
+            self.simplify().to_contours().overlay(
+                &other.simplify().to_contours(),
+                rule,
+                OverlayFillRule::NonZero,
+            )
+        };
+        Self::from_contours(contours.into_iter().flatten()).fill_rule(fill_rule)
+    }
+
+    /// The area covered by either this shape or the other.
+    pub fn union(&self, other: &Shape) -> Shape {
+        self.op(other, Op::Union)
+    }
+
+    /// The area covered by both this shape and the other.
+    pub fn intersection(&self, other: &Shape) -> Shape {
+        self.op(other, Op::Intersection)
+    }
+
+    /// The area covered by this shape but not the other.
+    pub fn difference(&self, other: &Shape) -> Shape {
+        self.op(other, Op::Difference)
+    }
+
+    /// The area covered by exactly one of this shape and the other.
+    pub fn xor(&self, other: &Shape) -> Shape {
+        self.op(other, Op::Xor)
+    }
+
+    /// Resolve overlapping and self-intersecting contours into an equivalent shape whose contours
+    /// never overlap.
+    pub fn simplify(&self) -> Shape {
+        let empty = Contours::new();
+        let fill_rule = to_overlay_fill_rule(self.fill_rule);
+        let contours = self
+            .to_contours()
+            .overlay(&empty, OverlayRule::Subject, fill_rule);
+        Self::from_contours(contours.into_iter().flatten()).fill_rule(self.fill_rule)
+    }
+
+    /// The sum of the signed areas of the contours, where counter-clockwise contours are positive
+    /// and clockwise contours are negative.
+    ///
+    /// For shapes produced by boolean operations, this is the area covered by the shape.
+    pub fn area(&self) -> f32 {
+        self.contours.iter().map(|c| signed_area(c)).sum()
+    }
+
+    /// The `Rect` that bounds the shape.
+    ///
+    /// Returns `None` if the shape is empty.
+    pub fn bounding_rect(&self) -> Option<geom::Rect> {
+        geom::bounding_rect(self.contours.iter().flatten().cloned())
+    }
+
+    /// A path with a closed sub-path for each contour of the shape.
+    pub fn to_path(&self) -> Path {
+        let mut builder = Path::builder();
+        for contour in &self.contours {
+            let mut points = contour.iter();
+            if let Some(&first) = points.next() {
+                builder = builder.begin(first);
+                for &p in points {
+                    builder = builder.line_to(p);
+                }
+                builder = builder.close();
+            }
+        }
+        builder.build()
+    }
+
+    fn to_contours(&self) -> Contours {
+        self.contours
+            .iter()
+            .map(|c| c.iter().map(|p| p.to_array()).collect())
+            .collect()
+    }
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Shape> for Path {
+    fn from(shape: Shape) -> Self {
+        shape.to_path()
+    }
+}
+
+fn to_overlay_fill_rule(fill_rule: FillRule) -> OverlayFillRule {
+    match fill_rule {
+        FillRule::EvenOdd => OverlayFillRule::EvenOdd,
+        FillRule::NonZero => OverlayFillRule::NonZero,
+    }
+}
+
+fn signed_area(contour: &[Point2]) -> f32 {
+    let n = contour.len();
+    let twice_area: f32 = (0..n)
+        .map(|i| contour[i].perp_dot(contour[(i + 1) % n]))
+        .sum();
+    twice_area * 0.5
+}
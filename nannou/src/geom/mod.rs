@@ -9,7 +9,9 @@
 //! - Functions for determining the bounding rectangle or cuboid.
 //! - A function for finding the centroid.
 
+pub mod boolean;
 pub mod path;
+pub mod svg;
 
 pub use self::path::{path, Path};
 pub use nannou_core::geom::*;
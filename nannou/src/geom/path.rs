@@ -3,10 +3,26 @@
 //! This module attempts to provide abstractions around the various `Path` and `Builder` types
 //! offerred by `lyon` in a way that interoperates a little more fluidly and consistently with the
 //! rest of nannou's API.
+//!
+//! Paths may be measured via **Measure**, providing their length, the position, tangent and normal
+//! at any distance along them, evenly spaced resampling, extraction of the section between two
+//! distances and offset curves.
+
+use crate::geom::{Point2, Vec2};
+use lyon::path::iterator::PathIterator;
+use lyon::path::PathEvent;
+
+/// The default tolerance used when approximating curves with line segments for measurement.
+pub const DEFAULT_TOLERANCE: f32 = lyon::tessellation::FillOptions::DEFAULT_TOLERANCE;
 
-use crate::geom::Point2;
+/// The miter limit used when joining the segments of offset curves.
+///
+/// Where the miter of a corner would extend beyond this multiple of the offset distance, the
+/// corner is bevelled instead.
+pub const MITER_LIMIT: f32 = lyon::tessellation::StrokeOptions::DEFAULT_MITER_LIMIT;
 
 /// A wrapper around a 2D lyon path exposing a nannou-friendly API.
+#[derive(Clone, Debug)]
 pub struct Path {
     path: lyon::path::Path,
 }
@@ -16,6 +32,40 @@ pub struct Builder {
     builder: lyon::path::path::Builder,
 }
 
+/// The measurement of a **Path** along its length.
+///
+/// Curves are approximated with line segments within the tolerance given upon construction.
+/// Distances span all sub-paths in order, with no distance between the end of one sub-path and
+/// the start of the next.
+#[derive(Clone, Debug)]
+pub struct Measure {
+    contours: Vec<Contour>,
+    length: f32,
+}
+
+/// The position, tangent and normal at some distance along a path.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sample {
+    /// The distance along the path.
+    pub distance: f32,
+    /// The point on the path.
+    pub position: Point2,
+    /// The unit vector in the direction of travel.
+    pub tangent: Vec2,
+    /// The unit vector to the right of the direction of travel, i.e. the tangent rotated clockwise
+    /// by 90 degrees. For counter-clockwise sub-paths this points outward.
+    pub normal: Vec2,
+}
+
+// A flattened sub-path. The points of closed sub-paths end with their first point.
+#[derive(Clone, Debug)]
+struct Contour {
+    points: Vec<Point2>,
+    // The distance along the whole path to each point.
+    distances: Vec<f32>,
+    closed: bool,
+}
+
 impl Path {
     /// Begin building a new path.
     pub fn builder() -> Builder {
@@ -72,6 +122,24 @@ impl Path {
             path: self.path.iter().chain(other.iter()).collect(),
         }
     }
+
+    /// Measure the path, approximating curves with line segments within the given tolerance.
+    pub fn measure(&self, tolerance: f32) -> Measure {
+        Measure::new(self.iter(), tolerance)
+    }
+
+    /// The total length of all sub-paths, measured with the `DEFAULT_TOLERANCE`.
+    pub fn length(&self) -> f32 {
+        self.measure(DEFAULT_TOLERANCE).length()
+    }
+
+    /// The curve offset from this path by the given distance along the normal, measured with the
+    /// `DEFAULT_TOLERANCE`.
+    ///
+    /// See `Measure::offset`.
+    pub fn offset(&self, distance: f32) -> Self {
+        self.measure(DEFAULT_TOLERANCE).offset(distance)
+    }
 }
 
 impl Builder {
@@ -116,6 +184,12 @@ impl Builder {
         self
     }
 
+    /// Ends the current sub-path without closing it.
+    pub fn end(mut self) -> Self {
+        self.builder.end(false);
+        self
+    }
+
     /// Add a quadratic bezier curve to the path.
     pub fn quadratic_bezier_to(mut self, ctrl: Point2, to: Point2) -> Self {
         self.builder
@@ -149,6 +223,266 @@ impl Builder {
     }
 }
 
+impl Measure {
+    /// Measure the given path events, approximating curves with line segments within the given
+    /// tolerance.
+    pub fn new<I>(events: I, tolerance: f32) -> Self
+    where
+        I: IntoIterator<Item = PathEvent>,
+    {
+        let mut contours = vec![];
+        let mut points = vec![];
+        let mut length = 0.0;
+        for event in events.into_iter().flattened(tolerance) {
+            match event {
+                PathEvent::Begin { at } => {
+                    points.clear();
+                    points.push(Point2::new(at.x, at.y));
+                }
+                PathEvent::Line { to, .. } => {
+                    let to = Point2::new(to.x, to.y);
+                    if points.last() != Some(&to) {
+                        points.push(to);
+                    }
+                }
+                PathEvent::End { close, .. } => {
+                    let mut points = std::mem::take(&mut points);
+                    let closed = close && points.len() > 2;
+                    if closed && points.first() != points.last() {
+                        points.push(points[0]);
+                    }
+                    if points.len() < 2 {
+                        continue;
+                    }
+                    let mut distances = Vec::with_capacity(points.len());
+                    distances.push(length);
+                    for w in points.windows(2) {
+                        length += w[0].distance(w[1]);
+                        distances.push(length);
+                    }
+                    contours.push(Contour {
+                        points,
+                        distances,
+                        closed,
+                    });
+                }
+                // Curves are flattened into lines.
+                PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => (),
+            }
+        }
+        Measure { contours, length }
+    }
+
+    /// The total length of all sub-paths.
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// Sample the path at the given distance along it, clamped to the length of the path.
+    ///
+    /// Returns `None` if the path has no length.
+    pub fn sample(&self, distance: f32) -> Option<Sample> {
+        if self.length <= 0.0 {
+            return None;
+        }
+        let distance = distance.clamp(0.0, self.length);
+        let ix = self
+            .contours
+            .partition_point(|c| c.end() < distance)
+            .min(self.contours.len() - 1);
+        self.contours[ix].sample(distance)
+    }
+
+    /// Sample the path at the given normalised distance along it, where `0.0` is the start and
+    /// `1.0` is the end.
+    pub fn sample_normalized(&self, t: f32) -> Option<Sample> {
+        self.sample(t * self.length)
+    }
+
+    /// Produce the given number of samples, evenly spaced from the start of the path to the end.
+    pub fn samples(&self, count: usize) -> impl Iterator<Item = Sample> + '_ {
+        let step = match count {
+            0 | 1 => 0.0,
+            n => self.length / (n - 1) as f32,
+        };
+        (0..count).filter_map(move |i| self.sample(i as f32 * step))
+    }
+
+    /// Resample the path with points evenly spaced along each sub-path.
+    ///
+    /// The spacing within each sub-path is adjusted to the nearest distance to the given spacing
+    /// that evenly divides its length, so that the ends of each sub-path are retained.
+    pub fn resample(&self, spacing: f32) -> Path {
+        let mut builder = Builder::new();
+        for contour in &self.contours {
+            let (start, len) = (contour.start(), contour.len());
+            let min = if contour.closed { 3 } else { 1 };
+            let n = match spacing > 0.0 {
+                true => ((len / spacing).round() as usize).max(min),
+                false => min,
+            };
+            let step = len / n as f32;
+            let end = if contour.closed { n - 1 } else { n };
+            for i in 0..=end {
+                let p = match i {
+                    i if i == n => contour.points[contour.points.len() - 1],
+                    i => contour.position(start + i as f32 * step),
+                };
+                builder = match i {
+                    0 => builder.begin(p),
+                    _ => builder.line_to(p),
+                };
+            }
+            builder = match contour.closed {
+                true => builder.close(),
+                false => builder.end(),
+            };
+        }
+        builder.build()
+    }
+
+    /// The section of the path between the given distances along it.
+    ///
+    /// A new sub-path is begun wherever the section spans the end of a sub-path. Curves within
+    /// the section are approximated with line segments.
+    pub fn section(&self, start: f32, end: f32) -> Path {
+        let mut builder = Builder::new();
+        for contour in &self.contours {
+            if contour.end() < start || end < contour.start() || start >= end {
+                continue;
+            }
+            let whole = start <= contour.start() && contour.end() <= end;
+            let (a, b) = (start.max(contour.start()), end.min(contour.end()));
+            builder = builder.begin(contour.position(a));
+            let interior = contour
+                .distances
+                .iter()
+                .zip(&contour.points)
+                .filter(|&(&d, _)| a < d && d < b);
+            for (_, &p) in interior {
+                builder = builder.line_to(p);
+            }
+            builder = builder.line_to(contour.position(b));
+            builder = match whole && contour.closed {
+                true => builder.close(),
+                false => builder.end(),
+            };
+        }
+        builder.build()
+    }
+
+    /// The curve offset from the path by the given distance along its normal.
+    ///
+    /// Positive distances offset to the right of the direction of travel, i.e. outward for
+    /// counter-clockwise sub-paths, while negative distances offset to the left. Corners are
+    /// mitered up to the `MITER_LIMIT` and bevelled beyond it.
+    ///
+    /// Offsetting the concave parts of a path by more than their radius of curvature produces
+    /// self-intersecting loops. These may be removed via `geom::boolean::Shape::simplify`.
+    pub fn offset(&self, distance: f32) -> Path {
+        let mut builder = Builder::new();
+        for contour in &self.contours {
+            let normals: Vec<Vec2> = contour
+                .points
+                .windows(2)
+                .map(|w| normal(w[1] - w[0]))
+                .collect();
+            let n = normals.len();
+            // The last point of a closed sub-path shares the join of the first.
+            let count = if contour.closed { n } else { n + 1 };
+            let mut points = vec![];
+            for (i, &p) in contour.points.iter().enumerate().take(count) {
+                let prev = match i {
+                    0 if contour.closed => normals[n - 1],
+                    0 => normals[0],
+                    i => normals[i - 1],
+                };
+                let next = normals[i.min(n - 1)];
+                join(&mut points, p, prev, next, distance);
+            }
+            let mut points = points.into_iter();
+            if let Some(first) = points.next() {
+                builder = builder.begin(first);
+                for p in points {
+                    builder = builder.line_to(p);
+                }
+                builder = match contour.closed {
+                    true => builder.close(),
+                    false => builder.end(),
+                };
+            }
+        }
+        builder.build()
+    }
+}
+
+impl Sample {
+    /// The angle of the tangent in radians, counter-clockwise from the positive *x* axis.
+    pub fn angle(&self) -> f32 {
+        self.tangent.y.atan2(self.tangent.x)
+    }
+}
+
+impl Contour {
+    fn start(&self) -> f32 {
+        self.distances[0]
+    }
+
+    fn end(&self) -> f32 {
+        self.distances[self.distances.len() - 1]
+    }
+
+    fn len(&self) -> f32 {
+        self.end() - self.start()
+    }
+
+    // The index of the segment containing the given distance.
+    fn segment(&self, distance: f32) -> usize {
+        let ix = self.distances.partition_point(|&d| d <= distance);
+        ix.max(1).min(self.points.len() - 1) - 1
+    }
+
+    fn position(&self, distance: f32) -> Point2 {
+        let i = self.segment(distance);
+        let (d0, d1) = (self.distances[i], self.distances[i + 1]);
+        let t = if d1 > d0 {
+            (distance - d0) / (d1 - d0)
+        } else {
+            0.0
+        };
+        self.points[i].lerp(self.points[i + 1], t.clamp(0.0, 1.0))
+    }
+
+    fn sample(&self, distance: f32) -> Option<Sample> {
+        let i = self.segment(distance);
+        let tangent = (self.points[i + 1] - self.points[i]).try_normalize()?;
+        Some(Sample {
+            distance,
+            position: self.position(distance),
+            tangent,
+            normal: normal(tangent),
+        })
+    }
+}
+
+// The unit vector to the right of the given direction.
+fn normal(direction: Vec2) -> Vec2 {
+    Vec2::new(direction.y, -direction.x).normalize_or_zero()
+}
+
+// Push the offset points for the corner at `p` between segments with the given normals.
+fn join(points: &mut Vec<Point2>, p: Point2, prev: Vec2, next: Vec2, distance: f32) {
+    let mid = prev + next;
+    let cos = mid.length() * 0.5;
+    if cos * MITER_LIMIT >= 1.0 {
+        // The miter extends by `1 / cos(θ / 2)` where θ is the angle between the normals.
+        points.push(p + mid.normalize() * (distance / cos));
+    } else {
+        points.push(p + prev * distance);
+        points.push(p + next * distance);
+    }
+}
+
 // lyon builder traits
 
 impl lyon::path::builder::Build for Builder {
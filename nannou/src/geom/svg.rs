@@ -0,0 +1,1012 @@
+//! Items related to loading vector graphics from SVG documents.
+//!
+//! A **Document** is parsed into a list of **Shape**s, each describing a **Path** along with its
+//! fill, stroke and transform. Documents may be drawn via `draw.svg(&document)`.
+//!
+//! The basic shapes (`path`, `rect`, `circle`, `ellipse`, `line`, `polyline` and `polygon`) are
+//! supported within nested groups, along with their presentation attributes and inline `style`
+//! declarations. Gradient and pattern paints, clipping paths, masks, text, images, `use`
+//! elements and style sheets are currently ignored.
+
+use crate::color::{named, LinSrgba, Srgb, Srgba};
+use crate::geom::{self, Path};
+use crate::glam::{Mat4, Vec2, Vec3};
+use lyon::math::{point, vector, Angle, Point};
+use lyon::path::builder::SvgPathBuilder;
+use lyon::path::ArcFlags;
+use lyon::tessellation::{FillRule, LineCap, LineJoin, StrokeOptions};
+use std::str::FromStr;
+use xml::reader::{EventReader, XmlEvent};
+
+/// A vector graphics document loaded from SVG.
+#[derive(Clone, Debug)]
+pub struct Document {
+    /// The size of the document, as described by its `width` and `height` attributes.
+    ///
+    /// Defaults to the size of the `view_box` if unspecified.
+    pub size: Vec2,
+    /// The region of the document's coordinate space that is visible.
+    ///
+    /// Note that, as in SVG, the *y* axis of this coordinate space points down.
+    pub view_box: geom::Rect,
+    /// All visible shapes within the document, in the order in which they should be drawn.
+    pub shapes: Vec<Shape>,
+}
+
+/// A single shape within a **Document**.
+#[derive(Clone, Debug)]
+pub struct Shape {
+    /// The `id` attribute of the element from which the shape was loaded, if any.
+    pub id: Option<String>,
+    /// The outline of the shape within its own coordinate space.
+    pub path: Path,
+    /// Transforms the path into the coordinate space of the document.
+    pub transform: Mat4,
+    /// The fill of the shape, if any.
+    pub fill: Option<Fill>,
+    /// The stroke of the shape, if any.
+    pub stroke: Option<Stroke>,
+}
+
+/// The fill of a **Shape**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fill {
+    /// The fill color, with `opacity` and `fill-opacity` applied to the alpha channel.
+    pub color: LinSrgba,
+    /// The rule used to determine the inside of the path.
+    pub rule: FillRule,
+}
+
+/// The stroke of a **Shape**.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stroke {
+    /// The stroke color, with `opacity` and `stroke-opacity` applied to the alpha channel.
+    pub color: LinSrgba,
+    /// The width, caps, joins and miter limit of the stroke.
+    pub options: StrokeOptions,
+    /// Alternating lengths of dashes and gaps. Empty if the stroke is solid.
+    pub dash_array: Vec<f32>,
+    /// The distance into the dash pattern at which the stroke begins.
+    pub dash_offset: f32,
+}
+
+/// Returned when loading an SVG **Document** fails.
+#[derive(Debug)]
+pub enum Error {
+    /// An error occurred while reading the file.
+    Io(std::io::Error),
+    /// The document is not well-formed XML.
+    Xml(xml::reader::Error),
+}
+
+// The properties inherited by an element from its ancestors.
+#[derive(Clone, Debug)]
+struct State {
+    transform: Mat4,
+    opacity: f32,
+    color: Srgb,
+    fill: Option<Srgb>,
+    fill_opacity: f32,
+    fill_rule: FillRule,
+    stroke: Option<Srgb>,
+    stroke_opacity: f32,
+    stroke_width: f32,
+    line_cap: LineCap,
+    line_join: LineJoin,
+    miter_limit: f32,
+    dash_array: Vec<f32>,
+    dash_offset: f32,
+    visible: bool,
+}
+
+// The attributes of an element, with any `style` declarations appended so that they take
+// precedence over presentation attributes.
+struct Attributes(Vec<(String, String)>);
+
+// A simple tokenizer for the number lists used throughout SVG attributes.
+struct Lexer<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+// The elements whose contents are never rendered directly.
+const SKIPPED_ELEMENTS: &[&str] = &[
+    "clipPath",
+    "defs",
+    "linearGradient",
+    "marker",
+    "mask",
+    "metadata",
+    "pattern",
+    "radialGradient",
+    "script",
+    "style",
+    "symbol",
+    "text",
+    "title",
+];
+
+// The size used when a document specifies neither a size nor a view box, as in browsers.
+const DEFAULT_SIZE: [f32; 2] = [300.0, 150.0];
+
+impl Document {
+    /// Load and parse the SVG document at the given file path.
+    pub fn from_path<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let svg = std::fs::read_to_string(path)?;
+        Self::parse(&svg)
+    }
+
+    /// Parse an SVG document from a string.
+    ///
+    /// Malformed attributes are ignored, and path data is read up until the first error, as in
+    /// browsers. An error is only returned if the document is not well-formed XML.
+    pub fn parse(svg: &str) -> Result<Self, Error> {
+        let mut states = vec![State::default()];
+        let mut skip_depth = 0;
+        let mut size = None;
+        let mut view_box = None;
+        let mut shapes = vec![];
+        for event in EventReader::from_str(svg) {
+            match event? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => {
+                    if skip_depth > 0 {
+                        skip_depth += 1;
+                        continue;
+                    }
+                    let attrs = Attributes::new(
+                        attributes
+                            .into_iter()
+                            .map(|attr| (attr.name.local_name, attr.value)),
+                    );
+                    let parent = states.last().expect("no parent state");
+                    let name = name.local_name.as_str();
+                    if SKIPPED_ELEMENTS.contains(&name) || attrs.get("display") == Some("none") {
+                        skip_depth = 1;
+                        continue;
+                    }
+                    let state = parent.child(&attrs);
+                    if name == "svg" && states.len() == 1 {
+                        view_box = attrs.get("viewBox").and_then(parse_view_box);
+                        let w = attrs.get("width").and_then(parse_length);
+                        let h = attrs.get("height").and_then(parse_length);
+                        size = w.zip(h).map(|(w, h)| Vec2::new(w, h));
+                    } else if let Some(path) = shape_path(name, &attrs) {
+                        if state.visible {
+                            let id = attrs.get("id").map(str::to_string);
+                            shapes.push(state.shape(id, path));
+                        }
+                    }
+                    states.push(state);
+                }
+                XmlEvent::EndElement { .. } => {
+                    if skip_depth > 0 {
+                        skip_depth -= 1;
+                    } else {
+                        states.pop();
+                    }
+                }
+                _ => (),
+            }
+        }
+        let size = size
+            .or_else(|| view_box.map(|r: geom::Rect| r.wh()))
+            .unwrap_or_else(|| DEFAULT_SIZE.into());
+        let view_box = view_box.unwrap_or_else(|| {
+            geom::Rect::from_x_y_w_h(size.x * 0.5, size.y * 0.5, size.x, size.y)
+        });
+        Ok(Document {
+            size,
+            view_box,
+            shapes,
+        })
+    }
+
+    /// The transform from the coordinate space of the document to that of nannou.
+    ///
+    /// The view box is centred on the origin with the *y* axis pointing up, and uniformly scaled
+    /// to fit within the document's `size`.
+    pub fn transform(&self) -> Mat4 {
+        let (w, h) = self.view_box.w_h();
+        let scale = if w > 0.0 && h > 0.0 {
+            (self.size.x / w).min(self.size.y / h)
+        } else {
+            1.0
+        };
+        let [x, y] = self.view_box.xy().to_array();
+        Mat4::from_scale(Vec3::new(scale, -scale, 1.0))
+            * Mat4::from_translation(Vec3::new(-x, -y, 0.0))
+    }
+
+    /// Find the first shape with the given `id`.
+    pub fn shape(&self, id: &str) -> Option<&Shape> {
+        self.shapes.iter().find(|s| s.id.as_deref() == Some(id))
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let black = Srgb::new(0.0, 0.0, 0.0);
+        State {
+            transform: Mat4::IDENTITY,
+            opacity: 1.0,
+            color: black,
+            fill: Some(black),
+            fill_opacity: 1.0,
+            fill_rule: FillRule::NonZero,
+            stroke: None,
+            stroke_opacity: 1.0,
+            stroke_width: 1.0,
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter,
+            miter_limit: 4.0,
+            dash_array: vec![],
+            dash_offset: 0.0,
+            visible: true,
+        }
+    }
+}
+
+impl State {
+    // The state of a child element with the given attributes.
+    fn child(&self, attrs: &Attributes) -> Self {
+        let mut state = self.clone();
+        if let Some(t) = attrs.get("transform") {
+            state.transform = self.transform * parse_transform(t);
+        }
+        if let Some(o) = attrs.get("opacity").and_then(parse_opacity) {
+            state.opacity *= o;
+        }
+        if let Some(c) = attrs.get("color").and_then(parse_color) {
+            state.color = c;
+        }
+        if let Some(paint) = attrs.get("fill").and_then(|s| parse_paint(s, state.color)) {
+            state.fill = paint;
+        }
+        if let Some(o) = attrs.get("fill-opacity").and_then(parse_opacity) {
+            state.fill_opacity = o;
+        }
+        match attrs.get("fill-rule") {
+            Some("nonzero") => state.fill_rule = FillRule::NonZero,
+            Some("evenodd") => state.fill_rule = FillRule::EvenOdd,
+            _ => (),
+        }
+        if let Some(paint) = attrs
+            .get("stroke")
+            .and_then(|s| parse_paint(s, state.color))
+        {
+            state.stroke = paint;
+        }
+        if let Some(o) = attrs.get("stroke-opacity").and_then(parse_opacity) {
+            state.stroke_opacity = o;
+        }
+        if let Some(w) = attrs.get("stroke-width").and_then(parse_length) {
+            state.stroke_width = w;
+        }
+        match attrs.get("stroke-linecap") {
+            Some("butt") => state.line_cap = LineCap::Butt,
+            Some("round") => state.line_cap = LineCap::Round,
+            Some("square") => state.line_cap = LineCap::Square,
+            _ => (),
+        }
+        match attrs.get("stroke-linejoin") {
+            Some("miter") => state.line_join = LineJoin::Miter,
+            Some("miter-clip") => state.line_join = LineJoin::MiterClip,
+            Some("round") => state.line_join = LineJoin::Round,
+            Some("bevel") => state.line_join = LineJoin::Bevel,
+            _ => (),
+        }
+        if let Some(m) = attrs.get("stroke-miterlimit").and_then(parse_number) {
+            state.miter_limit = m;
+        }
+        if let Some(dashes) = attrs.get("stroke-dasharray") {
+            state.dash_array = parse_dash_array(dashes);
+        }
+        if let Some(offset) = attrs.get("stroke-dashoffset").and_then(parse_length) {
+            state.dash_offset = offset;
+        }
+        match attrs.get("visibility") {
+            Some("visible") => state.visible = true,
+            Some("hidden") | Some("collapse") => state.visible = false,
+            _ => (),
+        }
+        state
+    }
+
+    // Produce a shape with the given path, styled by this state.
+    fn shape(&self, id: Option<String>, path: Path) -> Shape {
+        let fill = self.fill.map(|c| Fill {
+            color: lin_srgba(c, self.opacity * self.fill_opacity),
+            rule: self.fill_rule,
+        });
+        let stroke = self.stroke.filter(|_| self.stroke_width > 0.0).map(|c| {
+            let options = StrokeOptions::default()
+                .with_line_width(self.stroke_width)
+                .with_line_cap(self.line_cap)
+                .with_line_join(self.line_join)
+                .with_miter_limit(self.miter_limit.max(StrokeOptions::MINIMUM_MITER_LIMIT));
+            Stroke {
+                color: lin_srgba(c, self.opacity * self.stroke_opacity),
+                options,
+                dash_array: self.dash_array.clone(),
+                dash_offset: self.dash_offset,
+            }
+        });
+        Shape {
+            id,
+            path,
+            transform: self.transform,
+            fill,
+            stroke,
+        }
+    }
+}
+
+impl Attributes {
+    fn new<I>(attrs: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut attrs: Vec<_> = attrs.into_iter().collect();
+        let style = attrs
+            .iter()
+            .find(|(name, _)| name == "style")
+            .map(|(_, style)| style.clone());
+        if let Some(style) = style {
+            let declarations = style.split(';').filter_map(|decl| {
+                let mut parts = decl.splitn(2, ':');
+                let name = parts.next()?.trim();
+                let value = parts.next()?.trim();
+                Some((name.to_string(), value.to_string()))
+            });
+            attrs.extend(declarations);
+        }
+        Attributes(attrs)
+    }
+
+    // The last value of the named attribute, ignoring `inherit` as it is the default behaviour.
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.trim())
+            .filter(|v| *v != "inherit")
+    }
+
+    fn length(&self, name: &str) -> Option<f32> {
+        self.get(name).and_then(parse_length)
+    }
+}
+
+impl<'a> Lexer<'a> {
+    fn new(s: &'a str) -> Self {
+        Lexer {
+            bytes: s.as_bytes(),
+            index: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.index).cloned()
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(b) = self.peek() {
+            if !(b.is_ascii_whitespace() || b == b',') {
+                break;
+            }
+            self.index += 1;
+        }
+    }
+
+    fn is_done(&mut self) -> bool {
+        self.skip_separators();
+        self.index >= self.bytes.len()
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.index;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.index += 1;
+        }
+        self.index - start
+    }
+
+    // Read the next number, e.g. `-1.5e3`. Numbers may follow each other without separators,
+    // e.g. `1.5.5` is read as `1.5` and `.5`.
+    fn number(&mut self) -> Option<f32> {
+        self.skip_separators();
+        let start = self.index;
+        if let Some(b'+') | Some(b'-') = self.peek() {
+            self.index += 1;
+        }
+        let mut digits = self.skip_digits();
+        if self.peek() == Some(b'.') {
+            self.index += 1;
+            digits += self.skip_digits();
+        }
+        if digits == 0 {
+            self.index = start;
+            return None;
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            let mantissa_end = self.index;
+            self.index += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.index += 1;
+            }
+            if self.skip_digits() == 0 {
+                self.index = mantissa_end;
+            }
+        }
+        std::str::from_utf8(&self.bytes[start..self.index])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    // Read an arc flag, which may be a single `0` or `1` without a following separator.
+    fn flag(&mut self) -> Option<bool> {
+        self.skip_separators();
+        let flag = match self.peek()? {
+            b'0' => false,
+            b'1' => true,
+            _ => return None,
+        };
+        self.index += 1;
+        Some(flag)
+    }
+
+    fn point(&mut self) -> Option<Point> {
+        Some(point(self.number()?, self.number()?))
+    }
+
+    // Read the next path command letter, if the next token is one.
+    fn command(&mut self) -> Option<u8> {
+        self.skip_separators();
+        let b = self.peek()?;
+        if b"MmZzLlHhVvCcSsQqTtAa".contains(&b) {
+            self.index += 1;
+            return Some(b);
+        }
+        None
+    }
+
+    // Read an identifier, e.g. the name of a transform function or unit.
+    fn ident(&mut self) -> &'a str {
+        self.skip_separators();
+        let start = self.index;
+        while let Some(b) = self.peek() {
+            if !(b.is_ascii_alphabetic() || b == b'%') {
+                break;
+            }
+            self.index += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.index]).unwrap_or("")
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        while let Some(b) = self.peek() {
+            if !b.is_ascii_whitespace() {
+                break;
+            }
+            self.index += 1;
+        }
+        if self.peek()? != byte {
+            return None;
+        }
+        self.index += 1;
+        Some(())
+    }
+}
+
+impl FromStr for Document {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<xml::reader::Error> for Error {
+    fn from(e: xml::reader::Error) -> Self {
+        Error::Xml(e)
+    }
+}
+
+impl std::error::Error for Error {
+    fn cause(&self) -> Option<&dyn std::error::Error> {
+        match *self {
+            Error::Io(ref e) => Some(e),
+            Error::Xml(ref e) => Some(e),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match *self {
+            Error::Io(ref e) => std::fmt::Display::fmt(e, f),
+            Error::Xml(ref e) => std::fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+/// Parse SVG path data, e.g. the `d` attribute of a `path` element, into a **Path**.
+///
+/// As in browsers, the path is built up until the first error in the data.
+pub fn parse_path_data(data: &str) -> Path {
+    let mut builder = geom::path().with_svg();
+    let mut lexer = Lexer::new(data);
+    let mut command = None;
+    while !lexer.is_done() {
+        if let Some(c) = lexer.command() {
+            command = Some(c);
+        }
+        let c = match command {
+            Some(c) => c,
+            None => break,
+        };
+        if path_segment(&mut lexer, &mut builder, c).is_none() {
+            break;
+        }
+        // Coordinates following a move are implicit line segments.
+        command = match c {
+            b'M' => Some(b'L'),
+            b'm' => Some(b'l'),
+            b'Z' | b'z' => None,
+            c => Some(c),
+        };
+    }
+    builder.build()
+}
+
+// Read the arguments for a single path segment and submit it to the builder.
+fn path_segment<B>(lexer: &mut Lexer, b: &mut B, command: u8) -> Option<()>
+where
+    B: SvgPathBuilder,
+{
+    match command {
+        b'M' => b.move_to(lexer.point()?),
+        b'm' => b.relative_move_to(lexer.point()?.to_vector()),
+        b'L' => b.line_to(lexer.point()?),
+        b'l' => b.relative_line_to(lexer.point()?.to_vector()),
+        b'H' => b.horizontal_line_to(lexer.number()?),
+        b'h' => b.relative_horizontal_line_to(lexer.number()?),
+        b'V' => b.vertical_line_to(lexer.number()?),
+        b'v' => b.relative_vertical_line_to(lexer.number()?),
+        b'C' => b.cubic_bezier_to(lexer.point()?, lexer.point()?, lexer.point()?),
+        b'c' => {
+            let (c1, c2, to) = (lexer.point()?, lexer.point()?, lexer.point()?);
+            b.relative_cubic_bezier_to(c1.to_vector(), c2.to_vector(), to.to_vector());
+        }
+        b'S' => b.smooth_cubic_bezier_to(lexer.point()?, lexer.point()?),
+        b's' => {
+            let (c2, to) = (lexer.point()?, lexer.point()?);
+            b.smooth_relative_cubic_bezier_to(c2.to_vector(), to.to_vector());
+        }
+        b'Q' => b.quadratic_bezier_to(lexer.point()?, lexer.point()?),
+        b'q' => {
+            let (c, to) = (lexer.point()?, lexer.point()?);
+            b.relative_quadratic_bezier_to(c.to_vector(), to.to_vector());
+        }
+        b'T' => b.smooth_quadratic_bezier_to(lexer.point()?),
+        b't' => b.smooth_relative_quadratic_bezier_to(lexer.point()?.to_vector()),
+        b'A' | b'a' => {
+            let radii = vector(lexer.number()?, lexer.number()?);
+            let x_rotation = Angle::degrees(lexer.number()?);
+            let flags = ArcFlags {
+                large_arc: lexer.flag()?,
+                sweep: lexer.flag()?,
+            };
+            let to = lexer.point()?;
+            if command == b'A' {
+                b.arc_to(radii, x_rotation, flags, to);
+            } else {
+                b.relative_arc_to(radii, x_rotation, flags, to.to_vector());
+            }
+        }
+        b'Z' | b'z' => b.close(),
+        _ => return None,
+    }
+    Some(())
+}
+
+// The path for the given basic shape element, if it is one.
+fn shape_path(name: &str, attrs: &Attributes) -> Option<Path> {
+    let len = |name| attrs.length(name).unwrap_or(0.0);
+    let mut b = geom::path().with_svg();
+    match name {
+        "path" => return Some(parse_path_data(attrs.get("d")?)),
+        "rect" => {
+            let (x, y, w, h) = (len("x"), len("y"), len("width"), len("height"));
+            if w <= 0.0 || h <= 0.0 {
+                return None;
+            }
+            let (rx, ry) = match (attrs.length("rx"), attrs.length("ry")) {
+                (Some(rx), Some(ry)) => (rx, ry),
+                (Some(r), None) | (None, Some(r)) => (r, r),
+                (None, None) => (0.0, 0.0),
+            };
+            let (rx, ry) = (rx.max(0.0).min(w * 0.5), ry.max(0.0).min(h * 0.5));
+            let radii = vector(rx, ry);
+            let flags = ArcFlags {
+                large_arc: false,
+                sweep: true,
+            };
+            let corner = |b: &mut lyon::path::builder::WithSvg<_>, x, y| {
+                if rx > 0.0 && ry > 0.0 {
+                    b.arc_to(radii, Angle::zero(), flags, point(x, y));
+                }
+            };
+            b.move_to(point(x + rx, y));
+            b.line_to(point(x + w - rx, y));
+            corner(&mut b, x + w, y + ry);
+            b.line_to(point(x + w, y + h - ry));
+            corner(&mut b, x + w - rx, y + h);
+            b.line_to(point(x + rx, y + h));
+            corner(&mut b, x, y + h - ry);
+            b.line_to(point(x, y + ry));
+            corner(&mut b, x + rx, y);
+            b.close();
+        }
+        "circle" | "ellipse" => {
+            let (cx, cy) = (len("cx"), len("cy"));
+            let (rx, ry) = match name {
+                "circle" => (len("r"), len("r")),
+                _ => (len("rx"), len("ry")),
+            };
+            if rx <= 0.0 || ry <= 0.0 {
+                return None;
+            }
+            let radii = vector(rx, ry);
+            let flags = ArcFlags {
+                large_arc: false,
+                sweep: true,
+            };
+            b.move_to(point(cx + rx, cy));
+            b.arc_to(radii, Angle::zero(), flags, point(cx - rx, cy));
+            b.arc_to(radii, Angle::zero(), flags, point(cx + rx, cy));
+            b.close();
+        }
+        "line" => {
+            b.move_to(point(len("x1"), len("y1")));
+            b.line_to(point(len("x2"), len("y2")));
+        }
+        "polyline" | "polygon" => {
+            let mut lexer = Lexer::new(attrs.get("points")?);
+            b.move_to(lexer.point()?);
+            while let Some(p) = lexer.point() {
+                b.line_to(p);
+            }
+            if name == "polygon" {
+                b.close();
+            }
+        }
+        _ => return None,
+    }
+    Some(b.build())
+}
+
+/// Parse an SVG transform list, e.g. `translate(10 20) rotate(45)`, into a **Mat4**.
+///
+/// Parsing stops at the first malformed transform.
+pub fn parse_transform(s: &str) -> Mat4 {
+    let mut lexer = Lexer::new(s);
+    let mut transform = Mat4::IDENTITY;
+    while !lexer.is_done() {
+        let name = lexer.ident();
+        if lexer.expect(b'(').is_none() {
+            break;
+        }
+        let mut args = vec![];
+        while let Some(n) = lexer.number() {
+            args.push(n);
+        }
+        if lexer.expect(b')').is_none() {
+            break;
+        }
+        let m = match (name, &args[..]) {
+            ("matrix", &[a, b, c, d, e, f]) => Mat4::from_cols_array(&[
+                a, b, 0.0, 0.0, c, d, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, e, f, 0.0, 1.0,
+            ]),
+            ("translate", &[x]) => Mat4::from_translation(Vec3::new(x, 0.0, 0.0)),
+            ("translate", &[x, y]) => Mat4::from_translation(Vec3::new(x, y, 0.0)),
+            ("scale", &[s]) => Mat4::from_scale(Vec3::new(s, s, 1.0)),
+            ("scale", &[x, y]) => Mat4::from_scale(Vec3::new(x, y, 1.0)),
+            ("rotate", &[a]) => Mat4::from_rotation_z(a.to_radians()),
+            ("rotate", &[a, x, y]) => {
+                let c = Vec3::new(x, y, 0.0);
+                Mat4::from_translation(c)
+                    * Mat4::from_rotation_z(a.to_radians())
+                    * Mat4::from_translation(-c)
+            }
+            ("skewX", &[a]) => {
+                let mut m = Mat4::IDENTITY;
+                m.y_axis.x = a.to_radians().tan();
+                m
+            }
+            ("skewY", &[a]) => {
+                let mut m = Mat4::IDENTITY;
+                m.x_axis.y = a.to_radians().tan();
+                m
+            }
+            _ => break,
+        };
+        transform *= m;
+    }
+    transform
+}
+
+/// Parse an SVG color, e.g. `#f80`, `#ff8800`, `rgb(255, 136, 0)` or `orange`.
+pub fn parse_color(s: &str) -> Option<Srgb> {
+    let s = s.trim();
+    if let Some(hex) = s.strip_prefix('#') {
+        let digit = |i: usize| u8::from_str_radix(hex.get(i..i + 1)?, 16).ok();
+        let byte = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        let (r, g, b) = match hex.len() {
+            3 => (digit(0)? * 17, digit(1)? * 17, digit(2)? * 17),
+            6 => (byte(0)?, byte(2)?, byte(4)?),
+            _ => return None,
+        };
+        return Some(Srgb::new(r, g, b).into_format());
+    }
+    let lower = s.to_ascii_lowercase();
+    if let Some(args) = lower.strip_prefix("rgb(").and_then(|s| s.strip_suffix(')')) {
+        let mut lexer = Lexer::new(args);
+        let mut channel = || {
+            let n = lexer.number()?;
+            match lexer.ident() {
+                "%" => Some(n / 100.0),
+                "" => Some(n / 255.0),
+                _ => None,
+            }
+            .map(|c| c.clamp(0.0, 1.0))
+        };
+        return Some(Srgb::new(channel()?, channel()?, channel()?));
+    }
+    NAMED_COLORS
+        .binary_search_by(|(name, _)| (*name).cmp(&lower[..]))
+        .ok()
+        .map(|ix| NAMED_COLORS[ix].1.into_format())
+}
+
+// Parse a `fill` or `stroke` paint, returning `None` if the paint is invalid.
+//
+// Paint servers such as gradients are unsupported, so `url(...)` paints resolve to their
+// fallback color if one is given, or otherwise to `none`.
+fn parse_paint(s: &str, current_color: Srgb) -> Option<Option<Srgb>> {
+    let s = match s.strip_prefix("url(") {
+        Some(s) => match s.find(')') {
+            Some(end) if !s[end + 1..].trim().is_empty() => s[end + 1..].trim(),
+            _ => return Some(None),
+        },
+        None => s,
+    };
+    match s {
+        "none" | "transparent" => Some(None),
+        "currentColor" => Some(Some(current_color)),
+        s => parse_color(s).map(Some),
+    }
+}
+
+fn parse_number(s: &str) -> Option<f32> {
+    let mut lexer = Lexer::new(s);
+    let n = lexer.number()?;
+    match lexer.is_done() {
+        true => Some(n),
+        false => None,
+    }
+}
+
+fn parse_opacity(s: &str) -> Option<f32> {
+    parse_number(s).map(|o| o.clamp(0.0, 1.0))
+}
+
+// Parse a length, converting absolute units to pixels. Relative units are unsupported.
+fn parse_length(s: &str) -> Option<f32> {
+    let mut lexer = Lexer::new(s);
+    let n = lexer.number()?;
+    let scale = match lexer.ident() {
+        "" | "px" => 1.0,
+        "pt" => 4.0 / 3.0,
+        "pc" => 16.0,
+        "mm" => 96.0 / 25.4,
+        "cm" => 96.0 / 2.54,
+        "in" => 96.0,
+        _ => return None,
+    };
+    match lexer.is_done() {
+        true => Some(n * scale),
+        false => None,
+    }
+}
+
+fn parse_view_box(s: &str) -> Option<geom::Rect> {
+    let mut lexer = Lexer::new(s);
+    let (x, y) = (lexer.number()?, lexer.number()?);
+    let (w, h) = (lexer.number()?, lexer.number()?);
+    if w <= 0.0 || h <= 0.0 {
+        return None;
+    }
+    Some(geom::Rect::from_x_y_w_h(x + w * 0.5, y + h * 0.5, w, h))
+}
+
+// A dash array is ignored if any of its lengths are negative or if they sum to zero.
+fn parse_dash_array(s: &str) -> Vec<f32> {
+    let mut lexer = Lexer::new(s);
+    let mut dashes = vec![];
+    while let Some(n) = lexer.number() {
+        dashes.push(n);
+    }
+    if dashes.iter().any(|&d| d < 0.0) || dashes.iter().sum::<f32>() <= 0.0 {
+        dashes.clear();
+    }
+    dashes
+}
+
+fn lin_srgba(color: Srgb, alpha: f32) -> LinSrgba {
+    Srgba::from_components((color.red, color.green, color.blue, alpha)).into_linear()
+}
+
+// The CSS color keywords supported by SVG, sorted by name.
+const NAMED_COLORS: &[(&str, Srgb<u8>)] = &[
+    ("aliceblue", named::ALICEBLUE),
+    ("antiquewhite", named::ANTIQUEWHITE),
+    ("aqua", named::AQUA),
+    ("aquamarine", named::AQUAMARINE),
+    ("azure", named::AZURE),
+    ("beige", named::BEIGE),
+    ("bisque", named::BISQUE),
+    ("black", named::BLACK),
+    ("blanchedalmond", named::BLANCHEDALMOND),
+    ("blue", named::BLUE),
+    ("blueviolet", named::BLUEVIOLET),
+    ("brown", named::BROWN),
+    ("burlywood", named::BURLYWOOD),
+    ("cadetblue", named::CADETBLUE),
+    ("chartreuse", named::CHARTREUSE),
+    ("chocolate", named::CHOCOLATE),
+    ("coral", named::CORAL),
+    ("cornflowerblue", named::CORNFLOWERBLUE),
+    ("cornsilk", named::CORNSILK),
+    ("crimson", named::CRIMSON),
+    ("cyan", named::CYAN),
+    ("darkblue", named::DARKBLUE),
+    ("darkcyan", named::DARKCYAN),
+    ("darkgoldenrod", named::DARKGOLDENROD),
+    ("darkgray", named::DARKGRAY),
+    ("darkgreen", named::DARKGREEN),
+    ("darkgrey", named::DARKGREY),
+    ("darkkhaki", named::DARKKHAKI),
+    ("darkmagenta", named::DARKMAGENTA),
+    ("darkolivegreen", named::DARKOLIVEGREEN),
+    ("darkorange", named::DARKORANGE),
+    ("darkorchid", named::DARKORCHID),
+    ("darkred", named::DARKRED),
+    ("darksalmon", named::DARKSALMON),
+    ("darkseagreen", named::DARKSEAGREEN),
+    ("darkslateblue", named::DARKSLATEBLUE),
+    ("darkslategray", named::DARKSLATEGRAY),
+    ("darkslategrey", named::DARKSLATEGREY),
+    ("darkturquoise", named::DARKTURQUOISE),
+    ("darkviolet", named::DARKVIOLET),
+    ("deeppink", named::DEEPPINK),
+    ("deepskyblue", named::DEEPSKYBLUE),
+    ("dimgray", named::DIMGRAY),
+    ("dimgrey", named::DIMGREY),
+    ("dodgerblue", named::DODGERBLUE),
+    ("firebrick", named::FIREBRICK),
+    ("floralwhite", named::FLORALWHITE),
+    ("forestgreen", named::FORESTGREEN),
+    ("fuchsia", named::FUCHSIA),
+    ("gainsboro", named::GAINSBORO),
+    ("ghostwhite", named::GHOSTWHITE),
+    ("gold", named::GOLD),
+    ("goldenrod", named::GOLDENROD),
+    ("gray", named::GRAY),
+    ("green", named::GREEN),
+    ("greenyellow", named::GREENYELLOW),
+    ("grey", named::GREY),
+    ("honeydew", named::HONEYDEW),
+    ("hotpink", named::HOTPINK),
+    ("indianred", named::INDIANRED),
+    ("indigo", named::INDIGO),
+    ("ivory", named::IVORY),
+    ("khaki", named::KHAKI),
+    ("lavender", named::LAVENDER),
+    ("lavenderblush", named::LAVENDERBLUSH),
+    ("lawngreen", named::LAWNGREEN),
+    ("lemonchiffon", named::LEMONCHIFFON),
+    ("lightblue", named::LIGHTBLUE),
+    ("lightcoral", named::LIGHTCORAL),
+    ("lightcyan", named::LIGHTCYAN),
+    ("lightgoldenrodyellow", named::LIGHTGOLDENRODYELLOW),
+    ("lightgray", named::LIGHTGRAY),
+    ("lightgreen", named::LIGHTGREEN),
+    ("lightgrey", named::LIGHTGREY),
+    ("lightpink", named::LIGHTPINK),
+    ("lightsalmon", named::LIGHTSALMON),
+    ("lightseagreen", named::LIGHTSEAGREEN),
+    ("lightskyblue", named::LIGHTSKYBLUE),
+    ("lightslategray", named::LIGHTSLATEGRAY),
+    ("lightslategrey", named::LIGHTSLATEGREY),
+    ("lightsteelblue", named::LIGHTSTEELBLUE),
+    ("lightyellow", named::LIGHTYELLOW),
+    ("lime", named::LIME),
+    ("limegreen", named::LIMEGREEN),
+    ("linen", named::LINEN),
+    ("magenta", named::MAGENTA),
+    ("maroon", named::MAROON),
+    ("mediumaquamarine", named::MEDIUMAQUAMARINE),
+    ("mediumblue", named::MEDIUMBLUE),
+    ("mediumorchid", named::MEDIUMORCHID),
+    ("mediumpurple", named::MEDIUMPURPLE),
+    ("mediumseagreen", named::MEDIUMSEAGREEN),
+    ("mediumslateblue", named::MEDIUMSLATEBLUE),
+    ("mediumspringgreen", named::MEDIUMSPRINGGREEN),
+    ("mediumturquoise", named::MEDIUMTURQUOISE),
+    ("mediumvioletred", named::MEDIUMVIOLETRED),
+    ("midnightblue", named::MIDNIGHTBLUE),
+    ("mintcream", named::MINTCREAM),
+    ("mistyrose", named::MISTYROSE),
+    ("moccasin", named::MOCCASIN),
+    ("navajowhite", named::NAVAJOWHITE),
+    ("navy", named::NAVY),
+    ("oldlace", named::OLDLACE),
+    ("olive", named::OLIVE),
+    ("olivedrab", named::OLIVEDRAB),
+    ("orange", named::ORANGE),
+    ("orangered", named::ORANGERED),
+    ("orchid", named::ORCHID),
+    ("palegoldenrod", named::PALEGOLDENROD),
+    ("palegreen", named::PALEGREEN),
+    ("paleturquoise", named::PALETURQUOISE),
+    ("palevioletred", named::PALEVIOLETRED),
+    ("papayawhip", named::PAPAYAWHIP),
+    ("peachpuff", named::PEACHPUFF),
+    ("peru", named::PERU),
+    ("pink", named::PINK),
+    ("plum", named::PLUM),
+    ("powderblue", named::POWDERBLUE),
+    ("purple", named::PURPLE),
+    ("rebeccapurple", named::REBECCAPURPLE),
+    ("red", named::RED),
+    ("rosybrown", named::ROSYBROWN),
+    ("royalblue", named::ROYALBLUE),
+    ("saddlebrown", named::SADDLEBROWN),
+    ("salmon", named::SALMON),
+    ("sandybrown", named::SANDYBROWN),
+    ("seagreen", named::SEAGREEN),
+    ("seashell", named::SEASHELL),
+    ("sienna", named::SIENNA),
+    ("silver", named::SILVER),
+    ("skyblue", named::SKYBLUE),
+    ("slateblue", named::SLATEBLUE),
+    ("slategray", named::SLATEGRAY),
+    ("slategrey", named::SLATEGREY),
+    ("snow", named::SNOW),
+    ("springgreen", named::SPRINGGREEN),
+    ("steelblue", named::STEELBLUE),
+    ("tan", named::TAN),
+    ("teal", named::TEAL),
+    ("thistle", named::THISTLE),
+    ("tomato", named::TOMATO),
+    ("turquoise", named::TURQUOISE),
+    ("violet", named::VIOLET),
+    ("wheat", named::WHEAT),
+    ("white", named::WHITE),
+    ("whitesmoke", named::WHITESMOKE),
+    ("yellow", named::YELLOW),
+    ("yellowgreen", named::YELLOWGREEN),
+];
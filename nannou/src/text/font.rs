@@ -1,9 +1,32 @@
-//! The `font::Id` and `font::Map` types.
+//! The `Font`, `font::Id` and `font::Map` types, along with the font `Database` used to discover
+//! the fonts installed on the system.
 
-use crate::text::{Font, FontCollection};
+use crate::text::FontCollection;
+use rusttype::SharedBytes;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
+use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// A font used to lay out and draw text.
+///
+/// Dereferences to the inner `rusttype::Font`. Fonts loaded via the functions of this module also
+/// retain the data from which they were loaded, which is required to shape text. Text using fonts
+/// without data is laid out glyph by glyph without shaping.
+#[derive(Clone)]
+pub struct Font {
+    font: rusttype::Font<'static>,
+    shaping: Option<Arc<Shaping>>,
+}
+
+// The data of a font along with the `rustybuzz::Face` parsed from it, retained for shaping.
+struct Shaping {
+    // Borrows from `bytes` and so must be declared, and in turn dropped, first.
+    face: rustybuzz::Face<'static>,
+    bytes: SharedBytes<'static>,
+    index: u32,
+}
 
 /// A type-safe wrapper around the `FontId`.
 ///
@@ -33,6 +56,92 @@ pub struct Ids<'a> {
     keys: std::collections::hash_map::Keys<'a, Id, Font>,
 }
 
+/// A database of font faces, e.g. those installed on the system, that may be queried by family
+/// name, weight and style.
+///
+/// Only the metadata of each face is retained. The font data is read again when a face is loaded
+/// via `load` or `font`, so fonts should be loaded once and stored, e.g. within the model.
+///
+/// ```no_run
+/// # use nannou::text::font::{Database, Family, Query};
+/// # fn main() {
+/// let mut db = Database::new();
+/// db.load_system_fonts();
+/// let query = Query::new().family("Fira Sans").family(Family::SansSerif).bold();
+/// let font = db.font(&query).expect("no sans-serif fonts found");
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Database {
+    faces: Vec<Face>,
+}
+
+/// The metadata of a single font face within a **Database**.
+#[derive(Clone, Debug)]
+pub struct Face {
+    /// Where the face's font data is located.
+    pub source: Source,
+    /// The index of the face within its font collection, or `0` for a single font file.
+    pub index: u32,
+    /// The family name of the face, e.g. "Noto Sans".
+    pub family: String,
+    /// The weight of the face.
+    pub weight: Weight,
+    /// The style of the face.
+    pub style: Style,
+    /// Whether or not all glyphs of the face have the same advance.
+    pub monospaced: bool,
+}
+
+/// The location of the data of a font **Face**.
+#[derive(Clone, Debug)]
+pub enum Source {
+    /// A font file, e.g. `.ttf`, `.otf` or `.ttc`.
+    File(PathBuf),
+    /// Font data in memory.
+    Bytes(SharedBytes<'static>),
+}
+
+/// The weight of a font face, ranging from `100` (thin) to `900` (black).
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Weight(pub u16);
+
+/// The style of a font face.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Style {
+    #[default]
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// A font family requested via a **Query**.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Family {
+    /// A family with the given name, matched case-insensitively.
+    Name(String),
+    /// The first installed family of a list of common serif families.
+    Serif,
+    /// The first installed family of a list of common sans-serif families.
+    SansSerif,
+    /// The first installed monospaced family, preferring a list of common families.
+    Monospace,
+}
+
+/// A request for the font face that best matches a list of families, a weight and a style.
+///
+/// Families are tried in order. Within the first family with any faces, the face with the closest
+/// style and then the closest weight is chosen, following the CSS font matching algorithm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Query {
+    /// The families to try in order.
+    pub families: Vec<Family>,
+    /// The desired weight.
+    pub weight: Weight,
+    /// The desired style.
+    pub style: Style,
+}
+
 /// Returned when loading new fonts from file or bytes.
 #[derive(Debug)]
 pub enum Error {
@@ -45,6 +154,105 @@ pub enum Error {
 /// The name of the default directory that is searched for fonts.
 pub const DEFAULT_DIRECTORY_NAME: &str = "fonts";
 
+/// The file extensions of the font files loaded by `Database::load_fonts_dir`.
+pub const FILE_EXTENSIONS: &[&str] = &["ttf", "otf", "ttc", "otc"];
+
+const SERIF_FAMILIES: &[&str] = &[
+    "Noto Serif",
+    "DejaVu Serif",
+    "Liberation Serif",
+    "Times New Roman",
+    "Times",
+    "Georgia",
+];
+const SANS_SERIF_FAMILIES: &[&str] = &[
+    "Noto Sans",
+    "DejaVu Sans",
+    "Liberation Sans",
+    "Helvetica",
+    "Arial",
+    "Segoe UI",
+];
+const MONOSPACE_FAMILIES: &[&str] = &[
+    "Noto Sans Mono",
+    "DejaVu Sans Mono",
+    "Liberation Mono",
+    "Menlo",
+    "Consolas",
+    "Courier New",
+];
+
+impl Font {
+    /// A font along with the data from which it was loaded and the index of the font within it,
+    /// e.g. for fonts loaded via `rusttype::Font::from_bytes` directly.
+    ///
+    /// The font is laid out without shaping if the data cannot be parsed.
+    pub fn with_data<B>(font: rusttype::Font<'static>, bytes: B, index: u32) -> Self
+    where
+        B: Into<SharedBytes<'static>>,
+    {
+        let shaping = Shaping::new(bytes.into(), index).map(Arc::new);
+        Font { font, shaping }
+    }
+
+    /// The inner `rusttype::Font`.
+    pub fn rusttype(&self) -> &rusttype::Font<'static> {
+        &self.font
+    }
+
+    /// Consume the font, returning the inner `rusttype::Font`.
+    pub fn into_rusttype(self) -> rusttype::Font<'static> {
+        self.font
+    }
+
+    /// The data from which the font was loaded along with the index of the font within it, if
+    /// retained.
+    pub fn data(&self) -> Option<(&SharedBytes<'static>, u32)> {
+        self.shaping.as_ref().map(|s| (&s.bytes, s.index))
+    }
+
+    // The face used to shape text with the font, if its data was retained.
+    pub(crate) fn shaping_face(&self) -> Option<&rustybuzz::Face<'_>> {
+        self.shaping.as_ref().map(|s| &s.face)
+    }
+}
+
+impl Shaping {
+    fn new(bytes: SharedBytes<'static>, index: u32) -> Option<Self> {
+        // SAFETY: The slice refers to the contents of `bytes`, which are either `'static` or
+        // behind an `Arc` that is never mutated. `bytes` lives for as long as the face borrowing
+        // from it, and the face is only ever lent out for the lifetime of the `Shaping`.
+        let data: &'static [u8] =
+            unsafe { std::slice::from_raw_parts(bytes.as_ptr(), bytes.len()) };
+        let face = rustybuzz::Face::from_slice(data, index)?;
+        Some(Shaping { face, bytes, index })
+    }
+}
+
+impl Deref for Font {
+    type Target = rusttype::Font<'static>;
+    fn deref(&self) -> &Self::Target {
+        &self.font
+    }
+}
+
+impl From<rusttype::Font<'static>> for Font {
+    fn from(font: rusttype::Font<'static>) -> Self {
+        Font {
+            font,
+            shaping: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for Font {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Font")
+            .field("shaped", &self.shaping.is_some())
+            .finish()
+    }
+}
+
 impl Id {
     /// Returns the inner `usize` from the `Id`.
     pub fn index(self) -> usize {
@@ -132,12 +340,101 @@ where
 }
 
 /// Load a single `Font` from a file at the given path.
+///
+/// The font's data is retained so that text using the font may be shaped. See `Font::data`.
 pub fn from_file<P>(path: P) -> Result<Font, Error>
 where
     P: AsRef<std::path::Path>,
 {
-    let collection = collection_from_file(path)?;
-    collection.into_font().or(Err(Error::NoFont))
+    let bytes = std::fs::read(path)?;
+    from_bytes(bytes)
+}
+
+/// Load a single `Font` from the given font data, e.g. the contents of a `.ttf` or `.otf` file.
+///
+/// The font's data is retained so that text using the font may be shaped. See `Font::data`.
+pub fn from_bytes<B>(bytes: B) -> Result<Font, Error>
+where
+    B: Into<SharedBytes<'static>>,
+{
+    let bytes = bytes.into();
+    let collection = FontCollection::from_bytes(bytes.clone()).map_err(std::io::Error::from)?;
+    let font = collection.into_font().or(Err(Error::NoFont))?;
+    Ok(Font::with_data(font, bytes, 0))
+}
+
+/// Load the `Font` at the given index within the given font collection data, e.g. the contents of
+/// a `.ttc` file.
+///
+/// The font's data is retained so that text using the font may be shaped. See `Font::data`.
+pub fn from_bytes_at<B>(bytes: B, index: u32) -> Result<Font, Error>
+where
+    B: Into<SharedBytes<'static>>,
+{
+    let bytes = bytes.into();
+    let collection = FontCollection::from_bytes(bytes.clone()).map_err(std::io::Error::from)?;
+    let font = collection.font_at(index as usize).or(Err(Error::NoFont))?;
+    Ok(Font::with_data(font, bytes, index))
+}
+
+/// Whether or not the given font has a glyph for the given character.
+pub fn has_glyph(font: &Font, ch: char) -> bool {
+    font.glyph(ch).id().0 != 0
+}
+
+/// The directories in which fonts are installed on this platform.
+///
+/// On Linux and other unix-like platforms, these are the `fonts` directories within each of the
+/// XDG data directories (e.g. `/usr/share/fonts` and `~/.local/share/fonts`) along with
+/// `~/.fonts`. Only the directories that exist are returned.
+pub fn system_directories() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+
+    #[cfg(target_os = "windows")]
+    {
+        if let Some(root) = std::env::var_os("SYSTEMROOT") {
+            dirs.push(PathBuf::from(root).join("Fonts"));
+        }
+        if let Some(local) = std::env::var_os("LOCALAPPDATA") {
+            dirs.push(PathBuf::from(local).join("Microsoft\\Windows\\Fonts"));
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        dirs.push(PathBuf::from("/System/Library/Fonts"));
+        dirs.push(PathBuf::from("/Library/Fonts"));
+        if let Some(home) = std::env::var_os("HOME").map(PathBuf::from) {
+            dirs.push(home.join("Library/Fonts"));
+        }
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        let data_home = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|home| home.join(".local/share")));
+        dirs.extend(data_home.map(|dir| dir.join("fonts")));
+        let data_dirs = std::env::var("XDG_DATA_DIRS")
+            .ok()
+            .filter(|dirs| !dirs.is_empty())
+            .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+        for dir in data_dirs.split(':').filter(|dir| !dir.is_empty()) {
+            dirs.push(Path::new(dir).join("fonts"));
+        }
+        if let Some(ref home) = home {
+            dirs.push(home.join(".fonts"));
+        }
+    }
+
+    let mut existing: Vec<PathBuf> = vec![];
+    for dir in dirs {
+        if dir.is_dir() && !existing.contains(&dir) {
+            existing.push(dir);
+        }
+    }
+    existing
 }
 
 /// Load the default notosans font.
@@ -147,9 +444,10 @@ where
 pub fn default_notosans() -> Font {
     let collection = FontCollection::from_bytes(notosans::REGULAR_TTF)
         .expect("failed to load the `notosans::REGULAR_TTF` font collection");
-    collection
+    let font = collection
         .into_font()
-        .expect("the `notosans::REGULAR_TTF` font collection contained no fonts")
+        .expect("the `notosans::REGULAR_TTF` font collection contained no fonts");
+    Font::with_data(font, notosans::REGULAR_TTF, 0)
 }
 
 /// The directory that is searched for default fonts.
@@ -189,6 +487,328 @@ pub fn default(assets: &Path) -> Result<Font, Error> {
     Err(Error::NoFont)
 }
 
+impl Database {
+    /// An empty database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A database of the fonts installed on the system. See `load_system_fonts`.
+    pub fn system() -> Self {
+        let mut db = Self::new();
+        db.load_system_fonts();
+        db
+    }
+
+    /// Load the metadata of the fonts within each of the `system_directories`.
+    pub fn load_system_fonts(&mut self) {
+        for dir in system_directories() {
+            self.load_fonts_dir(dir);
+        }
+    }
+
+    /// Load the metadata of every font file within the given directory and its subdirectories.
+    ///
+    /// Files without one of the `FILE_EXTENSIONS` or that fail to parse are skipped.
+    pub fn load_fonts_dir<P>(&mut self, dir: P)
+    where
+        P: AsRef<Path>,
+    {
+        for entry in crate::io::walk_dir(dir).follow_links(true) {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) => continue,
+            };
+            let path = entry.path();
+            let is_font = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| FILE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+                .unwrap_or(false);
+            if is_font && entry.file_type().is_file() {
+                self.load_font_file(path).ok();
+            }
+        }
+    }
+
+    /// Load the metadata of each face within the font file at the given path.
+    pub fn load_font_file<P>(&mut self, path: P) -> Result<(), Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        self.push_faces(&bytes, || Source::File(path.to_path_buf()))
+    }
+
+    /// Load the metadata of each face within the given font data, retaining the data.
+    pub fn load_font_data<B>(&mut self, bytes: B) -> Result<(), Error>
+    where
+        B: Into<SharedBytes<'static>>,
+    {
+        let bytes = bytes.into();
+        let source = Source::Bytes(bytes.clone());
+        self.push_faces(&bytes, || source.clone())
+    }
+
+    // Parse the metadata of each face within the given font data.
+    fn push_faces<F>(&mut self, bytes: &[u8], source: F) -> Result<(), Error>
+    where
+        F: Fn() -> Source,
+    {
+        let count = ttf_parser::fonts_in_collection(bytes).unwrap_or(1);
+        let len = self.faces.len();
+        for index in 0..count {
+            let face = match ttf_parser::Face::parse(bytes, index) {
+                Ok(face) => face,
+                Err(_) => continue,
+            };
+            let family = match face_family(&face) {
+                Some(family) => family,
+                None => continue,
+            };
+            let style = match face.style() {
+                ttf_parser::Style::Normal => Style::Normal,
+                ttf_parser::Style::Italic => Style::Italic,
+                ttf_parser::Style::Oblique => Style::Oblique,
+            };
+            self.faces.push(Face {
+                source: source(),
+                index,
+                family,
+                weight: Weight(face.weight().to_number()),
+                style,
+                monospaced: face.is_monospaced(),
+            });
+        }
+        if self.faces.len() == len {
+            return Err(Error::NoFont);
+        }
+        Ok(())
+    }
+
+    /// The metadata of every face within the database.
+    pub fn faces(&self) -> &[Face] {
+        &self.faces
+    }
+
+    /// The name of every family within the database in alphabetical order.
+    pub fn families(&self) -> Vec<&str> {
+        let mut families: Vec<&str> = self.faces.iter().map(|f| &f.family[..]).collect();
+        families.sort_unstable();
+        families.dedup();
+        families
+    }
+
+    /// The face that best matches the given query, if any of the query's families are present.
+    pub fn query(&self, query: &Query) -> Option<&Face> {
+        for family in &query.families {
+            let names: Vec<&str> = match *family {
+                Family::Name(ref name) => vec![name],
+                Family::Serif => SERIF_FAMILIES.to_vec(),
+                Family::SansSerif => SANS_SERIF_FAMILIES.to_vec(),
+                Family::Monospace => MONOSPACE_FAMILIES.to_vec(),
+            };
+            for name in names {
+                let faces = self
+                    .faces
+                    .iter()
+                    .filter(|f| f.family.eq_ignore_ascii_case(name));
+                if let Some(face) = best_match(faces, query) {
+                    return Some(face);
+                }
+            }
+            if *family == Family::Monospace {
+                let faces = self.faces.iter().filter(|f| f.monospaced);
+                if let Some(face) = best_match(faces, query) {
+                    return Some(face);
+                }
+            }
+        }
+        None
+    }
+
+    /// Load the given face.
+    ///
+    /// The font's data is retained so that text using the font may be shaped. See `Font::data`.
+    pub fn load(&self, face: &Face) -> Result<Font, Error> {
+        let bytes = match face.source {
+            Source::File(ref path) => SharedBytes::from(std::fs::read(path)?),
+            Source::Bytes(ref bytes) => bytes.clone(),
+        };
+        from_bytes_at(bytes, face.index)
+    }
+
+    /// Load the face that best matches the given query.
+    ///
+    /// Returns `Error::NoFont` if none of the query's families are present.
+    pub fn font(&self, query: &Query) -> Result<Font, Error> {
+        let face = self.query(query).ok_or(Error::NoFont)?;
+        self.load(face)
+    }
+
+    /// Load the best matching face of each of the given families that are present, in order.
+    ///
+    /// This is useful for producing a list of fallback fonts, e.g. for emoji or CJK characters.
+    /// See `text::Builder::fallback_fonts`.
+    pub fn fonts<I>(&self, families: I) -> Vec<Font>
+    where
+        I: IntoIterator,
+        I::Item: Into<Family>,
+    {
+        families
+            .into_iter()
+            .filter_map(|family| self.font(&Query::new().family(family)).ok())
+            .collect()
+    }
+}
+
+impl Weight {
+    pub const THIN: Self = Weight(100);
+    pub const EXTRA_LIGHT: Self = Weight(200);
+    pub const LIGHT: Self = Weight(300);
+    pub const NORMAL: Self = Weight(400);
+    pub const MEDIUM: Self = Weight(500);
+    pub const SEMI_BOLD: Self = Weight(600);
+    pub const BOLD: Self = Weight(700);
+    pub const EXTRA_BOLD: Self = Weight(800);
+    pub const BLACK: Self = Weight(900);
+}
+
+impl Default for Weight {
+    fn default() -> Self {
+        Weight::NORMAL
+    }
+}
+
+impl Query {
+    /// A query without any families for the normal weight and style.
+    pub fn new() -> Self {
+        Query {
+            families: vec![],
+            weight: Weight::NORMAL,
+            style: Style::Normal,
+        }
+    }
+
+    /// Append a family to try, e.g. `"Fira Sans"` or `Family::SansSerif`.
+    pub fn family<F>(mut self, family: F) -> Self
+    where
+        F: Into<Family>,
+    {
+        self.families.push(family.into());
+        self
+    }
+
+    /// The desired weight.
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Shorthand for `weight(Weight::BOLD)`.
+    pub fn bold(self) -> Self {
+        self.weight(Weight::BOLD)
+    }
+
+    /// The desired style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Shorthand for `style(Style::Italic)`.
+    pub fn italic(self) -> Self {
+        self.style(Style::Italic)
+    }
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> From<&'a str> for Family {
+    fn from(name: &'a str) -> Self {
+        Family::Name(name.to_string())
+    }
+}
+
+impl From<String> for Family {
+    fn from(name: String) -> Self {
+        Family::Name(name)
+    }
+}
+
+// The family name of the face, preferring the typographic family and US English names.
+fn face_family(face: &ttf_parser::Face) -> Option<String> {
+    let name = |id: u16| {
+        let names = face.names().into_iter().filter(|n| n.name_id == id);
+        let mut english = None;
+        let mut other = None;
+        for n in names {
+            let is_english = n.language() == ttf_parser::Language::English_UnitedStates;
+            match n.to_string() {
+                Some(s) if is_english => english = english.or(Some(s)),
+                Some(s) => other = other.or(Some(s)),
+                None => (),
+            }
+        }
+        english.or(other)
+    };
+    name(ttf_parser::name_id::TYPOGRAPHIC_FAMILY).or_else(|| name(ttf_parser::name_id::FAMILY))
+}
+
+// The face with the closest style and then the closest weight to those of the query.
+fn best_match<'a, I>(faces: I, query: &Query) -> Option<&'a Face>
+where
+    I: IntoIterator<Item = &'a Face>,
+{
+    faces.into_iter().min_by_key(|f| {
+        (
+            style_distance(query.style, f.style),
+            weight_distance(query.weight, f.weight),
+        )
+    })
+}
+
+// The order in which styles are preferred for the desired style.
+fn style_distance(desired: Style, style: Style) -> u8 {
+    match (desired, style) {
+        (a, b) if a == b => 0,
+        (Style::Normal, Style::Oblique) => 1,
+        (Style::Normal, _) => 2,
+        (_, Style::Normal) => 2,
+        _ => 1,
+    }
+}
+
+// The order in which weights are preferred for the desired weight as described by CSS: for
+// weights between 400 and 500, heavier weights up to 500 are tried first, followed by lighter
+// weights and then heavier weights. Lighter weights are tried first for weights below 400, and
+// heavier weights first for weights above 500.
+fn weight_distance(desired: Weight, weight: Weight) -> (u8, u16) {
+    let (d, w) = (desired.0, weight.0);
+    let diff = w.abs_diff(d);
+    let rank = if w == d {
+        0
+    } else if (400..=500).contains(&d) {
+        if w > d && w <= 500 {
+            1
+        } else if w < d {
+            2
+        } else {
+            3
+        }
+    } else if (d < 400) == (w < d) {
+        1
+    } else {
+        2
+    };
+    (rank, diff)
+}
+
 impl Iterator for NewIds {
     type Item = Id;
     fn next(&mut self) -> Option<Self::Item> {
@@ -61,19 +61,7 @@ impl<'a, 'b> Iterator for Rects<'a, 'b> {
     fn next(&mut self) -> Option<Self::Item> {
         let Rects { ref mut layout, y } = *self;
         layout.next().map(|g| {
-            let left = g.position().x;
-            let (right, height) = g
-                .pixel_bounding_box()
-                .map(|bb| (bb.max.x as Scalar, (bb.max.y - bb.min.y) as Scalar))
-                .unwrap_or_else(|| {
-                    let w = g.unpositioned().h_metrics().advance_width as Scalar;
-                    let r = left + w;
-                    let h = 0.0;
-                    (r, h)
-                });
-            let x = Range::new(left, right);
-            let y = Range::new(y.start, y.start + height);
-            let r = Rect { x: x, y: y };
+            let r = rect(&g, y);
             let g = g.into_unpositioned();
             (g, r)
         })
@@ -207,6 +195,43 @@ where
     }
 }
 
+/// Produce an iterator yielding the `Rect` for each glyph in the given line of text, where the
+/// given `line_rect` describes the position of the line.
+pub fn rects<'a, 'b>(
+    line: &'b str,
+    line_rect: Rect,
+    font: &'a text::Font,
+    font_size: FontSize,
+) -> Rects<'a, 'b> {
+    let scale = text::pt_to_scale(font_size);
+    let (x, y) = (line_rect.left(), line_rect.top());
+    let point = text::rt::Point { x, y };
+    Rects {
+        layout: font.layout(line, scale, point),
+        y: line_rect.y,
+    }
+}
+
+/// The `Rect` of the given positioned glyph within a line whose *y* axis is described by `y`.
+///
+/// The rect begins at the glyph's position and ends at the right edge of its pixel bounding box,
+/// or at its advance for glyphs without any outline (e.g. spaces).
+pub fn rect(g: &rusttype::PositionedGlyph, y: Range) -> Rect {
+    let left = g.position().x;
+    let (right, height) = g
+        .pixel_bounding_box()
+        .map(|bb| (bb.max.x as Scalar, (bb.max.y - bb.min.y) as Scalar))
+        .unwrap_or_else(|| {
+            let w = g.unpositioned().h_metrics().advance_width as Scalar;
+            let r = left + w;
+            let h = 0.0;
+            (r, h)
+        });
+    let x = Range::new(left, right);
+    let y = Range::new(y.start, y.start + height);
+    Rect { x, y }
+}
+
 /// Find the index of the character that directly follows the cursor at the given `cursor_idx`.
 ///
 /// Returns `None` if either the given `cursor::Index` `line` or `idx` fields are out of bounds
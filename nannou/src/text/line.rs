@@ -106,6 +106,16 @@ pub struct Rects<I> {
     next: Option<Rect>,
 }
 
+/// An iterator yielding a `Rect` for each line of text, where each line has its own font size.
+#[derive(Clone)]
+pub struct RectsSized<I> {
+    infos: I,
+    max_width: Scalar,
+    x_align: text::Justify,
+    line_spacing: Scalar,
+    next_line_top: Scalar,
+}
+
 /// An iterator yielding a `Rect` for each selected line in a block of text.
 ///
 /// The yielded `Rect`s represent the selected range within each line of text.
@@ -230,57 +240,7 @@ fn advance_width_and_height(
 /// Returns the next index at which the text naturally breaks via a newline character,
 /// along with the width of the line.
 fn next_break(text: &str, font: &text::Font, font_size: FontSize) -> NextBreak {
-    let scale = text::pt_to_scale(font_size);
-    let mut width = 0.0;
-    let mut height = 0.0;
-    let mut char_i = 0;
-    let mut char_indices = text.char_indices().peekable();
-    let mut last_glyph = None;
-    while let Some((byte_i, ch)) = char_indices.next() {
-        // Check for a newline.
-        if ch == '\r' {
-            if let Some(&(_, '\n')) = char_indices.peek() {
-                let break_ = Break::Newline {
-                    byte: byte_i,
-                    char: char_i,
-                    len_bytes: 2, // skip "\r\n"
-                    len_chars: 2,
-                };
-                return NextBreak {
-                    break_,
-                    width,
-                    height,
-                };
-            }
-        } else if ch == '\n' {
-            let break_ = Break::Newline {
-                byte: byte_i,
-                char: char_i,
-                len_bytes: 1, // skip "\n"
-                len_chars: 1,
-            };
-            return NextBreak {
-                break_,
-                width,
-                height,
-            };
-        }
-
-        // Update the width.
-        let (adv_w, h) = advance_width_and_height(ch, font, scale, &mut last_glyph);
-        width += adv_w;
-        height = height.max(h);
-        char_i += 1;
-    }
-    let break_ = Break::End {
-        byte: text.len(),
-        char: char_i,
-    };
-    NextBreak {
-        break_,
-        width,
-        height,
-    }
+    next_break_styled(text, None, f32::MAX, |_| (font, font_size))
 }
 
 /// Returns the next index at which the text will break by either:
@@ -294,75 +254,8 @@ fn next_break_by_character(
     font_size: FontSize,
     max_width: Scalar,
 ) -> NextBreak {
-    let scale = text::pt_to_scale(font_size);
-    let mut width = 0.0;
-    let mut height = 0.0;
-    let mut char_i = 0;
-    let mut char_indices = text.char_indices().peekable();
-    let mut last_glyph = None;
-    while let Some((byte_i, ch)) = char_indices.next() {
-        // Check for a newline.
-        if ch == '\r' {
-            if let Some(&(_, '\n')) = char_indices.peek() {
-                let break_ = Break::Newline {
-                    byte: byte_i,
-                    char: char_i,
-                    len_bytes: 2, // skip "\r\n"
-                    len_chars: 2,
-                };
-                return NextBreak {
-                    break_,
-                    width,
-                    height,
-                };
-            }
-        } else if ch == '\n' {
-            let break_ = Break::Newline {
-                byte: byte_i,
-                char: char_i,
-                len_bytes: 1, // skip "\n"
-                len_chars: 1,
-            };
-            return NextBreak {
-                break_,
-                width,
-                height,
-            };
-        }
-
-        // Add the character's width to the width so far.
-        let (adv_w, h) = advance_width_and_height(ch, font, scale, &mut last_glyph);
-        let new_width = width + adv_w;
-
-        // Check for a line wrap.
-        if new_width > max_width {
-            let break_ = Break::Wrap {
-                byte: byte_i,
-                char: char_i,
-                len_bytes: 0, // skip nothing in middle of word
-                len_chars: 0,
-            };
-            return NextBreak {
-                break_,
-                width,
-                height,
-            };
-        }
-
-        height = height.max(h);
-        width = new_width;
-        char_i += 1;
-    }
-
-    let break_ = Break::End {
-        byte: text.len(),
-        char: char_i,
-    };
-    NextBreak {
-        break_,
-        width,
-        height,
-    }
+    let wrap = Some(Wrap::Character);
+    next_break_styled(text, wrap, max_width, |_| (font, font_size))
 }
 
 /// Returns the next index at which the text will break by either:
@@ -379,18 +272,64 @@ fn next_break_by_whitespace(
     font_size: FontSize,
     max_width: Scalar,
 ) -> NextBreak {
+    let wrap = Some(Wrap::Whitespace);
+    next_break_styled(text, wrap, max_width, |_| (font, font_size))
+}
+
+/// Returns the next index at which the text will break via a newline character or the given
+/// `wrap`, where the font and font size of each character are produced by `font_at` given the
+/// byte index of the character.
+///
+/// Kerning is only applied between consecutive characters of the same font and font size.
+///
+/// Also returns the width of the line alongside the Break.
+pub fn next_break_styled<'a, F>(
+    text: &str,
+    wrap: Option<Wrap>,
+    max_width: Scalar,
+    mut font_at: F,
+) -> NextBreak
+where
+    F: FnMut(usize) -> (&'a text::Font, FontSize),
+{
+    let mut last_glyph = None;
+    let mut last_font: Option<(*const text::Font, FontSize)> = None;
+    next_break_with(text, wrap, max_width, |byte_i, ch| {
+        let (font, font_size) = font_at(byte_i);
+        let font_key = (font as *const _, font_size);
+        if last_font != Some(font_key) {
+            last_font = Some(font_key);
+            last_glyph = None;
+        }
+        let scale = text::pt_to_scale(font_size);
+        advance_width_and_height(ch, font, scale, &mut last_glyph)
+    })
+}
+
+/// Returns the next index at which the text will break via a newline character or the given
+/// `wrap`, where the advance width and height of each character are produced by `advance` given
+/// the byte index of the character and the character itself, e.g. as measured by shaping.
+///
+/// Also returns the width of the line alongside the Break.
+pub fn next_break_with<F>(
+    text: &str,
+    wrap: Option<Wrap>,
+    max_width: Scalar,
+    mut advance: F,
+) -> NextBreak
+where
+    F: FnMut(usize, char) -> (Scalar, Scalar),
+{
     struct Last {
         byte: usize,
         char: usize,
         width_before: Scalar,
     }
-    let scale = text::pt_to_scale(font_size);
     let mut last_whitespace_start = None;
     let mut width = 0.0;
     let mut height = 0.0;
     let mut char_i = 0;
     let mut char_indices = text.char_indices().peekable();
-    let mut last_glyph = None;
     while let Some((byte_i, ch)) = char_indices.next() {
         // Check for a newline.
         if ch == '\r' {
@@ -422,55 +361,73 @@ fn next_break_by_whitespace(
         }
 
         // Add the character's width to the width so far.
-        let (adv_w, h) = advance_width_and_height(ch, font, scale, &mut last_glyph);
+        let (adv_w, h) = advance(byte_i, ch);
         let new_width = width + adv_w;
 
         // Check for a line wrap.
-        if width > max_width {
-            match last_whitespace_start {
-                Some(Last {
-                    byte,
-                    char,
-                    width_before,
-                }) => {
-                    let break_ = Break::Wrap {
-                        byte: byte,
-                        char: char,
-                        len_bytes: 1, // skip one whitespace character
-                        len_chars: 1,
-                    };
-                    let width = width_before;
-                    return NextBreak {
-                        break_,
-                        width,
-                        height,
-                    };
+        match wrap {
+            Some(Wrap::Character) if new_width > max_width => {
+                let break_ = Break::Wrap {
+                    byte: byte_i,
+                    char: char_i,
+                    len_bytes: 0, // skip nothing in middle of word
+                    len_chars: 0,
+                };
+                return NextBreak {
+                    break_,
+                    width,
+                    height,
+                };
+            }
+            None | Some(Wrap::Character) => (),
+            Some(Wrap::Whitespace) => {
+                if width > max_width {
+                    match last_whitespace_start {
+                        Some(Last {
+                            byte,
+                            char,
+                            width_before,
+                        }) => {
+                            let break_ = Break::Wrap {
+                                byte: byte,
+                                char: char,
+                                len_bytes: 1, // skip one whitespace character
+                                len_chars: 1,
+                            };
+                            let width = width_before;
+                            return NextBreak {
+                                break_,
+                                width,
+                                height,
+                            };
+                        }
+                        None => {
+                            let break_ = Break::Wrap {
+                                byte: byte_i,
+                                char: char_i,
+                                len_bytes: 0, // skip nothing in middle of word
+                                len_chars: 0,
+                            };
+                            return NextBreak {
+                                break_,
+                                width,
+                                height,
+                            };
+                        }
+                    }
                 }
-                None => {
-                    let break_ = Break::Wrap {
+
+                // Check for a new whitespace.
+                if ch.is_whitespace() {
+                    last_whitespace_start = Some(Last {
                         byte: byte_i,
                         char: char_i,
-                        len_bytes: 0, // skip nothing in middle of word
-                        len_chars: 0,
-                    };
-                    return NextBreak {
-                        break_,
-                        width,
-                        height,
-                    };
+                        width_before: width,
+                    });
                 }
             }
         }
 
-        // Check for a new whitespace.
-        if ch.is_whitespace() {
-            last_whitespace_start = Some(Last {
-                byte: byte_i,
-                char: char_i,
-                width_before: width,
-            });
-        }
-
         width = new_width;
         height = height.max(h);
         char_i += 1;
@@ -487,6 +444,18 @@ fn next_break_by_whitespace(
     }
 }
 
+/// Produce the sum of the advance widths of the characters in the given text, including the
+/// kerning between them.
+///
+/// This matches the width of the line as measured by the `Info`s of the `infos` functions.
+pub fn advance_width(text: &str, font: &text::Font, font_size: FontSize) -> Scalar {
+    let scale = text::pt_to_scale(font_size);
+    let mut last_glyph = None;
+    text.chars()
+        .map(|ch| advance_width_and_height(ch, font, scale, &mut last_glyph).0)
+        .sum()
+}
+
 /// Produce the width of the given line of text including spaces (i.e. ' ').
 pub fn width(text: &str, font: &text::Font, font_size: FontSize) -> Scalar {
     let scale = text::Scale::uniform(text::pt_to_px(font_size));
@@ -562,6 +531,64 @@ pub fn infos_maybe_wrapped<'a>(
     }
 }
 
+/// Produce an `Info` for every line in the given text, where the font and font size of each
+/// character are produced by `font_at` given the byte index of the character, e.g. due to text
+/// **Span**s.
+///
+/// Lines are wrapped in the same manner as `infos_maybe_wrapped`.
+pub fn infos_styled<'a, F>(
+    text: &'a str,
+    font: &'a text::Font,
+    font_size: FontSize,
+    maybe_wrap: Option<Wrap>,
+    max_width: Scalar,
+    mut font_at: F,
+) -> Infos<'a, impl for<'b> FnMut(&'b str, &'b text::Font, FontSize, Scalar) -> NextBreak>
+where
+    F: FnMut(usize) -> (&'a text::Font, FontSize),
+{
+    // Each line is a subslice of `text`, so its offset is the distance between their pointers.
+    let text_start = text.as_ptr() as usize;
+    let max_width = match maybe_wrap {
+        None => f32::MAX,
+        Some(_) => max_width,
+    };
+    let next_break_fn = move |line: &str, _: &text::Font, _: FontSize, max_width: Scalar| {
+        let offset = line.as_ptr() as usize - text_start;
+        next_break_styled(line, maybe_wrap, max_width, |i| font_at(offset + i))
+    };
+    infos_wrapped_by(text, font, font_size, max_width, next_break_fn)
+}
+
+/// Produce an `Info` for every line in the given text, where the advance width and height of each
+/// character are produced by `advance` given the byte index of the character within `text` and
+/// the character itself, e.g. as measured by shaping.
+///
+/// Lines are wrapped in the same manner as `infos_maybe_wrapped`.
+pub fn infos_with<'a, F>(
+    text: &'a str,
+    font: &'a text::Font,
+    font_size: FontSize,
+    maybe_wrap: Option<Wrap>,
+    max_width: Scalar,
+    mut advance: F,
+) -> Infos<'a, impl for<'b> FnMut(&'b str, &'b text::Font, FontSize, Scalar) -> NextBreak>
+where
+    F: FnMut(usize, char) -> (Scalar, Scalar),
+{
+    // Each line is a subslice of `text`, so its offset is the distance between their pointers.
+    let text_start = text.as_ptr() as usize;
+    let max_width = match maybe_wrap {
+        None => f32::MAX,
+        Some(_) => max_width,
+    };
+    let next_break_fn = move |line: &str, _: &text::Font, _: FontSize, max_width: Scalar| {
+        let offset = line.as_ptr() as usize - text_start;
+        next_break_with(line, maybe_wrap, max_width, |i, ch| advance(offset + i, ch))
+    };
+    infos_wrapped_by(text, font, font_size, max_width, next_break_fn)
+}
+
 /// Produce an iterator yielding the bounding `Rect` for each line in the text.
 ///
 /// Yielded `Rect`s will begin with the top-left of the first line at a [0.0, 0.0].
@@ -603,6 +630,29 @@ where
     }
 }
 
+/// Produce an iterator yielding the bounding `Rect` for each line in the text, where each line has
+/// its own font size, e.g. the greatest font size of the text **Span**s within the line.
+///
+/// Yielded `Rect`s will begin with the top-left of the first line at a [0.0, 0.0]. Given the same
+/// font size for every line, the yielded `Rect`s match those of `rects`.
+pub fn rects_sized<I>(
+    infos: I,
+    max_width: Scalar,
+    x_align: text::Justify,
+    line_spacing: Scalar,
+) -> RectsSized<I::IntoIter>
+where
+    I: IntoIterator<Item = (Info, FontSize)>,
+{
+    RectsSized {
+        infos: infos.into_iter(),
+        max_width,
+        x_align,
+        line_spacing,
+        next_line_top: 0.0,
+    }
+}
+
 /// Produces an iterator yielding a `Rect` for the selected range in each selected line in a block
 /// of text.
 ///
@@ -787,6 +837,28 @@ where
     }
 }
 
+impl<I> Iterator for RectsSized<I>
+where
+    I: Iterator<Item = (Info, FontSize)>,
+{
+    type Item = Rect;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (info, font_size) = self.infos.next()?;
+        let x_bounds = Range::new(0.0, self.max_width);
+        let range = Range::new(0.0, info.width);
+        let x = match self.x_align {
+            text::Justify::Left => range.align_start_of(x_bounds),
+            text::Justify::Center => range.align_middle_of(x_bounds),
+            text::Justify::Right => range.align_end_of(x_bounds),
+        };
+        let y_end = self.next_line_top;
+        let y_start = y_end - font_size as Scalar;
+        self.next_line_top = y_start - self.line_spacing;
+        let y = Range::new(y_start, y_end);
+        Some(Rect { x, y })
+    }
+}
+
 impl<'a, I> Iterator for SelectedRects<'a, I>
 where
     I: Iterator<Item = (&'a str, Rect)>,
@@ -8,13 +8,17 @@ pub mod font;
 pub mod glyph;
 pub mod layout;
 pub mod line;
+pub mod shape;
+pub mod span;
 pub mod rt {
     //! Re-exported RustType geometric types.
     pub use rusttype::{gpu_cache, point, vector, Point, Rect, Vector};
 }
 
 // Re-export all relevant rusttype types here.
+pub use self::font::Font;
 pub use self::layout::Layout;
+pub use self::span::{Run, Span};
 pub use rusttype::gpu_cache::Cache as GlyphCache;
 pub use rusttype::{Glyph, GlyphId, GlyphIter, LayoutIter, Scale, ScaledGlyph};
 
@@ -24,8 +28,6 @@ use std::borrow::Cow;
 
 /// The RustType `FontCollection` type used by nannou.
 pub type FontCollection = rusttype::FontCollection<'static>;
-/// The RustType `Font` type used by nannou.
-pub type Font = rusttype::Font<'static>;
 /// The RustType `PositionedGlyph` type used by nannou.
 pub type PositionedGlyph = rusttype::PositionedGlyph<'static>;
 
@@ -42,6 +44,7 @@ pub type FontSize = u32;
 pub struct Builder<'a> {
     text: Cow<'a, str>,
     layout_builder: layout::Builder,
+    spans: Vec<Span>,
 }
 
 /// An instance of some multi-line text and its layout.
@@ -50,10 +53,22 @@ pub struct Text<'a> {
     text: Cow<'a, str>,
     font: Font,
     layout: Layout,
+    spans: Vec<Span>,
+    segments: Vec<Segment>,
     line_infos: Vec<line::Info>,
+    line_font_sizes: Vec<FontSize>,
     rect: geom::Rect,
 }
 
+// A range of text sharing the same style, with the font and font size resolved.
+#[derive(Clone)]
+struct Segment {
+    range: std::ops::Range<usize>,
+    font: Font,
+    font_size: FontSize,
+    style: span::Style,
+}
+
 /// An iterator yielding each line within the given `text` as a new `&str`, where the start and end
 /// indices into each line are provided by the given iterator.
 #[derive(Clone)]
@@ -72,7 +87,12 @@ pub type TextLines<'a> = Lines<
 >;
 
 /// An alias for the line rect iterator used internally within the `Text::line_rects` iterator.
-type LineRects<'a> = line::Rects<std::iter::Cloned<std::slice::Iter<'a, line::Info>>>;
+type LineRects<'a> = line::RectsSized<
+    std::iter::Zip<
+        std::iter::Cloned<std::slice::Iter<'a, line::Info>>,
+        std::iter::Cloned<std::slice::Iter<'a, FontSize>>,
+    >,
+>;
 
 /// An alias for the line rect iterator yielded by `Text::line_rects`.
 #[derive(Clone)]
@@ -129,9 +149,11 @@ pub enum Wrap {
 impl<'a> From<Cow<'a, str>> for Builder<'a> {
     fn from(text: Cow<'a, str>) -> Self {
         let layout_builder = Default::default();
+        let spans = vec![];
         Builder {
             text,
             layout_builder,
+            spans,
         }
     }
 }
@@ -198,6 +220,35 @@ impl<'a> Builder<'a> {
         self.map_layout(|l| l.font(font))
     }
 
+    /// Append a font used to display the characters for which the preceding fonts have no glyph,
+    /// e.g. emoji or CJK characters.
+    ///
+    /// Fallback fonts apply to the text of **Span**s with their own font too.
+    pub fn fallback_font(self, font: Font) -> Self {
+        self.map_layout(|l| l.fallback_font(font))
+    }
+
+    /// Specify the fonts used to display the characters for which the font has no glyph, in order
+    /// of preference.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// # use nannou::text::{self, font};
+    /// # fn main() {
+    /// let db = font::Database::system();
+    /// let fallback = db.fonts(["Noto Color Emoji", "Noto Sans CJK JP"]);
+    /// let text = text::text("Hello 世界")
+    ///     .fallback_fonts(fallback)
+    ///     .build(Rect::from_w_h(200.0, 100.0));
+    /// # }
+    /// ```
+    pub fn fallback_fonts<I>(self, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = Font>,
+    {
+        self.map_layout(|l| l.fallback_fonts(fonts))
+    }
+
     /// Describe the end along the *x* axis to which the text should be aligned.
     pub fn justify(self, justify: Justify) -> Self {
         self.map_layout(|l| l.justify(justify))
@@ -250,6 +301,35 @@ impl<'a> Builder<'a> {
         self.map_layout(|l| l.layout(layout))
     }
 
+    /// Style a range of the text with its own font, font size, color, weight or slant.
+    ///
+    /// ```
+    /// # use nannou::prelude::*;
+    /// # use nannou::text::{self, Span};
+    /// # fn main() {
+    /// # let rect = Rect::from_w_h(200.0, 100.0);
+    /// # let font = text::font::default_notosans();
+    /// let text = text::text("Temp: 23°C")
+    ///     .font(font)
+    ///     .span(Span::new(6..).bold().font_size(24).color(RED))
+    ///     .build(rect);
+    /// assert_eq!(text.runs().len(), 2);
+    /// # }
+    /// ```
+    pub fn span(mut self, span: Span) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Style ranges of the text via each of the given spans in order.
+    pub fn spans<I>(mut self, spans: I) -> Self
+    where
+        I: IntoIterator<Item = Span>,
+    {
+        self.spans.extend(spans);
+        self
+    }
+
     /// Build the text.
     ///
     /// This iterates over the text in order to pre-calculates the text's multi-line information
@@ -259,6 +339,7 @@ impl<'a> Builder<'a> {
     /// of text, multi-line wrapping, etc,
     pub fn build(self, rect: geom::Rect) -> Text<'a> {
         let text = self.text;
+        let spans = self.spans;
         let layout = self.layout_builder.build();
         #[allow(unreachable_code)]
         let font = layout.font.clone().unwrap_or_else(|| {
@@ -271,19 +352,149 @@ impl<'a> Builder<'a> {
             font::default(&assets).expect("failed to detect a default font")
         });
         let max_width = rect.w();
-        let line_infos =
-            line::infos_maybe_wrapped(&text, &font, layout.font_size, layout.line_wrap, max_width)
-                .collect();
+        let segments: Vec<_> = span::segments(&text, &spans)
+            .into_iter()
+            .map(|(range, style)| Segment {
+                range,
+                font: style.font.clone().unwrap_or_else(|| font.clone()),
+                font_size: style.font_size.unwrap_or(layout.font_size),
+                style,
+            })
+            .collect();
+        let segments = fallback_segments(&text, segments, &layout.fallback_fonts);
+        let metrics = char_metrics(&text, &segments);
+        let line_infos: Vec<_> = line::infos_with(
+            &text,
+            &font,
+            layout.font_size,
+            layout.line_wrap,
+            max_width,
+            |i, _| metrics[i],
+        )
+        .collect();
+        let line_font_sizes = if spans.is_empty() {
+            vec![layout.font_size; line_infos.len()]
+        } else {
+            // Each line is as tall as the greatest font size within it.
+            line_infos
+                .iter()
+                .map(|info| {
+                    let range = info.byte_range();
+                    let sizes = segments
+                        .iter()
+                        .filter(|s| s.range.start < range.end && range.start < s.range.end)
+                        .map(|s| s.font_size);
+                    match sizes.max() {
+                        Some(size) => size,
+                        None => match segments.get(segment_index(&segments, range.start)) {
+                            Some(s) if s.range.contains(&range.start) => s.font_size,
+                            _ => layout.font_size,
+                        },
+                    }
+                })
+                .collect()
+        };
         Text {
             text,
             font,
             layout,
+            spans,
+            segments,
             line_infos,
+            line_font_sizes,
             rect,
         }
     }
 }
 
+// The index of the segment containing the given byte index.
+fn segment_index(segments: &[Segment], byte: usize) -> usize {
+    let ix = segments.partition_point(|s| s.range.end <= byte);
+    ix.min(segments.len().saturating_sub(1))
+}
+
+// Split the given segments where characters are missing from the segment's font, so that each
+// such character is displayed with the first of the fallback fonts that has a glyph for it.
+//
+// Whitespace, control characters, joiners, variation selectors and combining marks remain with the
+// font of the preceding character.
+fn fallback_segments(text: &str, segments: Vec<Segment>, fallback: &[Font]) -> Vec<Segment> {
+    if fallback.is_empty() {
+        return segments;
+    }
+    let continues_cluster = |ch: char| {
+        ch.is_whitespace()
+            || ch.is_control()
+            || matches!(ch,
+                '\u{200C}'..='\u{200D}'
+                | '\u{FE00}'..='\u{FE0F}'
+                | '\u{0300}'..='\u{036F}'
+                | '\u{1F3FB}'..='\u{1F3FF}'
+                | '\u{E0020}'..='\u{E007F}')
+    };
+    let mut split = vec![];
+    for segment in segments {
+        // The index of the font of each piece of the segment, where `0` is the segment's font.
+        let mut pieces: Vec<(std::ops::Range<usize>, usize)> = vec![];
+        for (i, ch) in text[segment.range.clone()].char_indices() {
+            let byte = segment.range.start + i;
+            let font_ix = match pieces.last() {
+                Some(&(_, ix)) if continues_cluster(ch) => ix,
+                _ if font::has_glyph(&segment.font, ch) => 0,
+                _ => fallback
+                    .iter()
+                    .position(|f| font::has_glyph(f, ch))
+                    .map(|ix| ix + 1)
+                    .unwrap_or(0),
+            };
+            match pieces.last_mut() {
+                Some((range, ix)) if *ix == font_ix => range.end = byte + ch.len_utf8(),
+                _ => pieces.push((byte..byte + ch.len_utf8(), font_ix)),
+            }
+        }
+        for (range, font_ix) in pieces {
+            let font = match font_ix {
+                0 => segment.font.clone(),
+                ix => fallback[ix - 1].clone(),
+            };
+            split.push(Segment {
+                range,
+                font,
+                ..segment.clone()
+            });
+        }
+    }
+    split
+}
+
+// The advance width and height of each character of the text indexed by byte, as measured by
+// shaping each segment. The advance of each glyph is attributed to the first character of its
+// cluster.
+fn char_metrics(text: &str, segments: &[Segment]) -> Vec<(Scalar, Scalar)> {
+    let bidi = shape::Bidi::new(text);
+    let mut metrics = vec![(0.0, 0.0); text.len()];
+    for segment in segments {
+        let scale = pt_to_scale(segment.font_size);
+        for (range, direction) in bidi.logical_runs(segment.range.clone()) {
+            let run_text = &text[range.clone()];
+            for g in shape::glyphs(run_text, &segment.font, segment.font_size, direction) {
+                metrics[range.start + g.cluster].0 += g.advance;
+            }
+            for (i, ch) in run_text.char_indices() {
+                let height = segment
+                    .font
+                    .glyph(ch)
+                    .scaled(scale)
+                    .exact_bounding_box()
+                    .map(|bb| bb.min.y.abs() as Scalar)
+                    .unwrap_or(0.0);
+                metrics[range.start + i].1 = height;
+            }
+        }
+    }
+    metrics
+}
+
 impl<'a> Text<'a> {
     /// Produce an iterator yielding information about each line.
     pub fn line_infos(&self) -> &[line::Info] {
@@ -301,10 +512,24 @@ impl<'a> Text<'a> {
     }
 
     /// The font used for this text instance.
+    ///
+    /// Ranges styled via **Span**s may use other fonts. See `runs`.
     pub fn font(&self) -> &Font {
         &self.font
     }
 
+    /// The spans styling ranges of the text.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// The font size of each line of text.
+    ///
+    /// This is the layout's font size, or the greatest font size of the spans within the line.
+    pub fn line_font_sizes(&self) -> &[FontSize] {
+        &self.line_font_sizes
+    }
+
     /// The number of lines in the text.
     pub fn num_lines(&self) -> usize {
         self.line_infos.len()
@@ -324,7 +549,7 @@ impl<'a> Text<'a> {
             None => return geom::Rect::from_w_h(0.0, 0.0),
             Some(info) => info,
         };
-        let line_h = self.layout.font_size as Scalar;
+        let line_h = self.line_font_sizes[0] as Scalar;
         r.y.end -= line_h - info.height;
         r
     }
@@ -359,12 +584,8 @@ impl<'a> Text<'a> {
             None => return 0.0,
             Some(info) => info,
         };
-        exact_height(
-            info.height,
-            self.num_lines(),
-            self.layout.font_size,
-            self.layout.line_spacing,
-        )
+        let first_line_h = self.line_font_sizes[0] as Scalar;
+        self.height_by_lines() - first_line_h + info.height
     }
 
     /// Determine the total height of a block of text with the given number of lines, font size and
@@ -373,11 +594,12 @@ impl<'a> Text<'a> {
     /// The height of all lines of text are assumed to match the `font_size`. If looking for the exact
     /// height, see the `exact_height` function.
     pub fn height_by_lines(&self) -> Scalar {
-        height_by_lines(
-            self.num_lines(),
-            self.layout.font_size,
-            self.layout.line_spacing,
-        )
+        let num_lines = self.num_lines();
+        if num_lines == 0 {
+            return 0.0;
+        }
+        let lines_h: Scalar = self.line_font_sizes.iter().map(|&s| s as Scalar).sum();
+        lines_h + (num_lines - 1) as Scalar * self.layout.line_spacing
     }
 
     /// Produce an iterator yielding each wrapped line within the **Text**.
@@ -391,9 +613,10 @@ impl<'a> Text<'a> {
     /// The bounding rectangle for each line.
     pub fn line_rects(&self) -> TextLineRects {
         let offset = self.position_offset();
-        let line_rects = line::rects(
-            self.line_infos.iter().cloned(),
-            self.layout.font_size,
+        let infos = self.line_infos.iter().cloned();
+        let font_sizes = self.line_font_sizes.iter().cloned();
+        let line_rects = line::rects_sized(
+            infos.zip(font_sizes),
             self.rect.w(),
             self.layout.justify,
             self.layout.line_spacing,
@@ -408,73 +631,120 @@ impl<'a> Text<'a> {
 
     /// Produce an iterator yielding iterators yielding every glyph alongside its bounding rect for
     /// each line.
+    ///
+    /// Glyphs are laid out one character at a time with the font and font size of the layout,
+    /// ignoring any **Span**s, shaping and bidirectional ordering. See `runs` and `run_glyphs` for
+    /// laying out styled or shaped text.
     pub fn glyphs_per_line(&self) -> TextGlyphsPerLine {
         glyph::rects_per_line(self.lines_with_rects(), &self.font, self.layout.font_size)
     }
 
     /// Produce an iterator yielding every glyph alongside its bounding rect.
     ///
-    /// This is the "flattened" version of the `glyphs_per_line` method.
+    /// This is the "flattened" version of the `glyphs_per_line` method and similarly ignores any
+    /// **Span**s, shaping and bidirectional ordering.
     pub fn glyphs(&self) -> TextGlyphs {
         self.glyphs_per_line().flat_map(std::convert::identity)
     }
 
-    /// Produce an iterator yielding the path events for every glyph in every line.
-    pub fn path_events<'b>(&'b self) -> impl 'b + Iterator<Item = lyon::path::PathEvent> {
-        use lyon::path::PathEvent;
-
-        // Translate the given lyon point by the given vector.
-        fn trans_lyon_point(p: &lyon::math::Point, v: Vec2) -> lyon::math::Point {
-            lyon::math::point(p.x + v.x, p.y + v.y)
-        }
-
-        // Translate the given path event in 2D space.
-        fn trans_path_event(e: &PathEvent, v: Vec2) -> PathEvent {
-            match *e {
-                PathEvent::Begin { ref at } => PathEvent::Begin {
-                    at: trans_lyon_point(at, v),
-                },
-                PathEvent::Line { ref from, ref to } => PathEvent::Line {
-                    from: trans_lyon_point(from, v),
-                    to: trans_lyon_point(to, v),
-                },
-                PathEvent::Quadratic {
-                    ref from,
-                    ref ctrl,
-                    ref to,
-                } => PathEvent::Quadratic {
-                    from: trans_lyon_point(from, v),
-                    ctrl: trans_lyon_point(ctrl, v),
-                    to: trans_lyon_point(to, v),
-                },
-                PathEvent::Cubic {
-                    ref from,
-                    ref ctrl1,
-                    ref ctrl2,
-                    ref to,
-                } => PathEvent::Cubic {
-                    from: trans_lyon_point(from, v),
-                    ctrl1: trans_lyon_point(ctrl1, v),
-                    ctrl2: trans_lyon_point(ctrl2, v),
-                    to: trans_lyon_point(to, v),
-                },
-                PathEvent::End {
-                    ref last,
-                    ref first,
-                    ref close,
-                } => PathEvent::End {
-                    last: trans_lyon_point(last, v),
-                    first: trans_lyon_point(first, v),
-                    close: *close,
-                },
+    /// Produce the runs of glyphs sharing the same style and direction within each line.
+    ///
+    /// The runs of each line are produced in visual order, i.e. from left to right, with
+    /// right-to-left text ordered via the Unicode Bidirectional Algorithm. Without any **Span**s
+    /// or right-to-left text, there is a single run for each non-empty line using the font and
+    /// font size of the layout.
+    pub fn runs(&self) -> Vec<Run> {
+        let bidi = shape::Bidi::new(&self.text);
+        let mut runs = vec![];
+        for (line, (info, line_rect)) in self.line_infos.iter().zip(self.line_rects()).enumerate() {
+            let line_range = info.byte_range();
+            let mut x = line_rect.left();
+            for (range, direction) in bidi.visual_runs(line_range.clone()) {
+                let mut pieces: Vec<_> = self
+                    .segments
+                    .iter()
+                    .filter_map(|segment| {
+                        let start = segment.range.start.max(range.start);
+                        let end = segment.range.end.min(range.end);
+                        if start < end {
+                            Some((start..end, segment))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                if direction == shape::Direction::RightToLeft {
+                    pieces.reverse();
+                }
+                for (byte_range, segment) in pieces {
+                    let run_text = &self.text[byte_range.clone()];
+                    let glyphs =
+                        shape::glyphs(run_text, &segment.font, segment.font_size, direction);
+                    let width = shape::advance_width(&glyphs);
+                    let rect = geom::Rect {
+                        x: geom::Range::new(x, x + width),
+                        y: line_rect.y,
+                    };
+                    let preceding_text = &self.text[line_range.start..byte_range.start];
+                    let start_char = info.start_char + preceding_text.chars().count();
+                    runs.push(Run {
+                        byte_range,
+                        start_char,
+                        line,
+                        rect,
+                        font: segment.font.clone(),
+                        font_size: segment.font_size,
+                        color: segment.style.color,
+                        bold: segment.style.bold,
+                        italic: segment.style.italic,
+                        direction,
+                        glyphs,
+                    });
+                    x += width;
+                }
             }
         }
+        runs
+    }
 
-        self.glyphs().flat_map(|(g, r)| {
-            glyph::path_events(g)
-                .into_iter()
-                .flat_map(|es| es)
-                .map(move |e| trans_path_event(&e, r.bottom_left().into()))
+    /// Produce every glyph of the given run in visual order alongside its bounding rect and the
+    /// index of the first character that it represents within the text.
+    ///
+    /// The glyphs are unslanted and unemboldened. See `Run::glyph_transforms`.
+    pub fn run_glyphs(&self, run: &Run) -> Vec<(usize, ScaledGlyph<'static>, geom::Rect)> {
+        let scale = pt_to_scale(run.font_size);
+        let top = run.rect.top();
+        self.run_glyph_positions(run)
+            .into_iter()
+            .map(|(char_ix, g, x)| {
+                let point = rt::Point {
+                    x: x + g.offset.x,
+                    y: top,
+                };
+                let positioned = run.font.glyph(g.id).scaled(scale).positioned(point);
+                let rect = glyph::rect(&positioned, run.rect.y).shift_y(g.offset.y);
+                (char_ix, positioned.into_unpositioned(), rect)
+            })
+            .collect()
+    }
+
+    /// Produce an iterator yielding the path events for every glyph in every line.
+    ///
+    /// The glyphs of each **Run** are laid out with their own font and font size, and are
+    /// slanted and emboldened as necessary.
+    pub fn path_events<'b>(&'b self) -> impl 'b + Iterator<Item = lyon::path::PathEvent> {
+        self.runs().into_iter().flat_map(move |run| {
+            let mut events = vec![];
+            for (_, g, r) in self.run_glyphs(&run) {
+                let glyph_events: Vec<_> = match glyph::path_events(g) {
+                    None => continue,
+                    Some(es) => es.collect(),
+                };
+                for transform in run.glyph_transforms(r) {
+                    events.extend(glyph_events.iter().map(|e| e.transformed(&transform)));
+                }
+            }
+            events
         })
     }
 
@@ -483,6 +753,10 @@ impl<'a> Text<'a> {
     /// The window dimensions (in logical space) and scale_factor are required to transform glyph
     /// positions into rusttype's pixel-space, ready for caching into the rusttype glyph cache
     /// pixel buffer.
+    ///
+    /// Glyphs are positioned one character at a time with the font and font size of the layout,
+    /// ignoring any **Span**s, shaping and bidirectional ordering. See `rt_run_glyphs` for
+    /// positioning styled or shaped text.
     pub fn rt_glyphs<'b: 'a>(
         &'b self,
         window_size: Vec2,
@@ -497,13 +771,63 @@ impl<'a> Text<'a> {
         )
     }
 
+    /// Produce the positioned rusttype glyphs of the given run alongside the index of the first
+    /// character that each represents within the text, ready for caching with the run's font.
+    ///
+    /// See `rt_glyphs` for details on the window dimensions and scale factor.
+    pub fn rt_run_glyphs(
+        &self,
+        run: &Run,
+        window_size: Vec2,
+        scale_factor: Scalar,
+    ) -> Vec<(usize, PositionedGlyph)> {
+        // Functions for converting nannou coordinates to rusttype pixel coordinates.
+        let trans_x = |x: Scalar| (x + window_size.x / 2.0) * scale_factor;
+        let trans_y = |y: Scalar| ((-y) + window_size.y / 2.0) * scale_factor;
+        let scale = f32_pt_to_scale(run.font_size as f32 * scale_factor);
+        let bottom = run.rect.bottom();
+        self.run_glyph_positions(run)
+            .into_iter()
+            .map(|(char_ix, g, x)| {
+                let point = rt::Point {
+                    x: trans_x(x + g.offset.x),
+                    y: trans_y(bottom + g.offset.y),
+                };
+                let glyph = run.font.glyph(g.id).scaled(scale).positioned(point);
+                (char_ix, glyph)
+            })
+            .collect()
+    }
+
+    // The glyphs of the given run alongside the index of the first character that each
+    // represents and the position of each along the *x* axis.
+    fn run_glyph_positions<'b>(&self, run: &'b Run) -> Vec<(usize, &'b shape::Glyph, Scalar)> {
+        let run_text = &self.text[run.byte_range.clone()];
+        let mut char_indices = vec![0; run_text.len() + 1];
+        for (i, (byte, _)) in run_text.char_indices().enumerate() {
+            char_indices[byte] = run.start_char + i;
+        }
+        let mut x = run.rect.left();
+        run.glyphs
+            .iter()
+            .map(|g| {
+                let glyph_x = x;
+                x += g.advance;
+                (char_indices[g.cluster], g, glyph_x)
+            })
+            .collect()
+    }
+
     /// Converts this `Text` instance into an instance that owns the inner text string.
     pub fn into_owned(self) -> Text<'static> {
         let Text {
             text,
             font,
             layout,
+            spans,
+            segments,
             line_infos,
+            line_font_sizes,
             rect,
         } = self;
         let text = Cow::Owned(text.into_owned());
@@ -511,19 +835,23 @@ impl<'a> Text<'a> {
             text,
             font,
             layout,
+            spans,
+            segments,
             line_infos,
+            line_font_sizes,
             rect,
         }
     }
 
     fn position_offset(&self) -> Vec2 {
-        position_offset(
-            self.num_lines(),
-            self.layout.font_size,
-            self.layout.line_spacing,
-            self.rect,
-            self.layout.y_align,
-        )
+        let x_offset = self.rect.x.start;
+        let total_text_y_range = geom::Range::new(0.0, self.height_by_lines());
+        let total_text_y = match self.layout.y_align {
+            Align::Start => total_text_y_range.align_start_of(self.rect.y),
+            Align::Middle => total_text_y_range.align_middle_of(self.rect.y),
+            Align::End => total_text_y_range.align_end_of(self.rect.y),
+        };
+        geom::vec2(x_offset, total_text_y.end)
     }
 }
 
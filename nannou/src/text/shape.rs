@@ -0,0 +1,202 @@
+//! Items related to shaping text into positioned glyphs and ordering bidirectional text.
+//!
+//! Shaping applies the kerning, ligatures, contextual forms and mark positioning described by the
+//! OpenType tables of a font, as required by complex scripts such as Arabic or Devanagari. This
+//! requires the data of the font, which is retained for fonts loaded via the `font` module. Text
+//! using other fonts is laid out one character at a time.
+//!
+//! Text mixing left-to-right and right-to-left scripts is ordered via the Unicode Bidirectional
+//! Algorithm. See **Bidi**.
+
+use crate::glam::Vec2;
+use crate::text::{self, Font, FontSize, GlyphId, Scalar};
+use std::ops::Range;
+use unicode_bidi::BidiInfo;
+
+/// The direction in which a run of text progresses.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// A glyph produced by shaping some text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Glyph {
+    /// The ID of the glyph within the font.
+    pub id: GlyphId,
+    /// The byte index within the shaped text of the first character represented by the glyph.
+    ///
+    /// Multiple characters may map to a single glyph (e.g. ligatures), and a single character may
+    /// map to multiple glyphs (e.g. decomposed marks).
+    pub cluster: usize,
+    /// The distance along the *x* axis from the start of this glyph to the start of the next.
+    pub advance: Scalar,
+    /// The offset of the glyph from its position along the baseline, *y* up.
+    pub offset: Vec2,
+}
+
+/// The bidirectional structure of some text, used to order each line of text for display.
+pub struct Bidi<'a> {
+    info: BidiInfo<'a>,
+}
+
+impl Direction {
+    fn to_rustybuzz(self) -> rustybuzz::Direction {
+        match self {
+            Direction::LeftToRight => rustybuzz::Direction::LeftToRight,
+            Direction::RightToLeft => rustybuzz::Direction::RightToLeft,
+        }
+    }
+}
+
+impl<'a> Bidi<'a> {
+    /// Resolve the embedding level of each character of the given text.
+    ///
+    /// The base direction of each paragraph is determined by its first strong character.
+    pub fn new(text: &'a str) -> Self {
+        let info = BidiInfo::new(text, None);
+        Bidi { info }
+    }
+
+    /// The direction of the character at the given byte index.
+    pub fn direction(&self, byte: usize) -> Direction {
+        match self.info.levels.get(byte) {
+            Some(level) if level.is_rtl() => Direction::RightToLeft,
+            _ => Direction::LeftToRight,
+        }
+    }
+
+    /// Split the given byte range of the text into ranges of the same direction, in logical
+    /// order.
+    pub fn logical_runs(&self, range: Range<usize>) -> Vec<(Range<usize>, Direction)> {
+        let mut runs = vec![];
+        let mut start = range.start;
+        let mut current = None;
+        for (i, _) in self.info.text[range.clone()].char_indices() {
+            let byte = range.start + i;
+            let direction = self.direction(byte);
+            match current {
+                Some(dir) if dir == direction => (),
+                Some(dir) => {
+                    runs.push((start..byte, dir));
+                    start = byte;
+                    current = Some(direction);
+                }
+                None => current = Some(direction),
+            }
+        }
+        if let Some(dir) = current {
+            runs.push((start..range.end, dir));
+        }
+        runs
+    }
+
+    /// Split the line at the given byte range of the text into ranges of the same direction, in
+    /// visual order, i.e. from left to right.
+    ///
+    /// The line must not span multiple paragraphs, as is the case for the lines of a **Text**.
+    pub fn visual_runs(&self, line: Range<usize>) -> Vec<(Range<usize>, Direction)> {
+        if line.start >= line.end {
+            return vec![];
+        }
+        if !self.info.has_rtl() {
+            return vec![(line, Direction::LeftToRight)];
+        }
+        let para = self
+            .info
+            .paragraphs
+            .iter()
+            .find(|p| p.range.start <= line.start && line.start < p.range.end);
+        let para = match para {
+            None => return vec![(line, Direction::LeftToRight)],
+            Some(para) => para,
+        };
+        let line = line.start..line.end.min(para.range.end);
+        let (levels, runs) = self.info.visual_runs(para, line);
+        runs.into_iter()
+            .map(|run| {
+                let direction = match levels[run.start].is_rtl() {
+                    true => Direction::RightToLeft,
+                    false => Direction::LeftToRight,
+                };
+                (run, direction)
+            })
+            .collect()
+    }
+}
+
+/// Shape the given text with the given font and font size, producing glyphs in visual order, i.e.
+/// from left to right.
+///
+/// The text should contain a single direction of text, e.g. one of the runs produced by **Bidi**.
+///
+/// Returns `None` if the data of the font is unavailable. See `Font::data`.
+pub fn shape(
+    text: &str,
+    font: &Font,
+    font_size: FontSize,
+    direction: Direction,
+) -> Option<Vec<Glyph>> {
+    let face = font.shaping_face()?;
+    let mut buffer = rustybuzz::UnicodeBuffer::new();
+    buffer.push_str(text);
+    buffer.set_direction(direction.to_rustybuzz());
+    buffer.guess_segment_properties();
+    let output = rustybuzz::shape(face, &[], buffer);
+
+    // RustType scales glyphs so that the distance between the ascent and descent matches the
+    // pixel size, so font units are scaled in the same manner.
+    let v_metrics = font.v_metrics_unscaled();
+    let scale = text::pt_to_px(font_size) / (v_metrics.ascent - v_metrics.descent);
+    let glyphs = output
+        .glyph_infos()
+        .iter()
+        .zip(output.glyph_positions())
+        .map(|(info, pos)| Glyph {
+            id: GlyphId(info.glyph_id),
+            cluster: info.cluster as usize,
+            advance: pos.x_advance as Scalar * scale,
+            offset: Vec2::new(pos.x_offset as Scalar, pos.y_offset as Scalar) * scale,
+        })
+        .collect();
+    Some(glyphs)
+}
+
+/// Produce the glyphs of the given text in visual order, i.e. from left to right.
+///
+/// The text is shaped if the data of the font is available. Otherwise, the glyph of each
+/// character is laid out in turn with the kerning of the font, in reverse order for right-to-left
+/// text.
+pub fn glyphs(text: &str, font: &Font, font_size: FontSize, direction: Direction) -> Vec<Glyph> {
+    if let Some(glyphs) = shape(text, font, font_size, direction) {
+        return glyphs;
+    }
+    let scale = text::pt_to_scale(font_size);
+    let mut chars: Vec<_> = text.char_indices().collect();
+    if direction == Direction::RightToLeft {
+        chars.reverse();
+    }
+    let mut glyphs: Vec<Glyph> = Vec::with_capacity(chars.len());
+    for (cluster, ch) in chars {
+        let g = font.glyph(ch).scaled(scale);
+        let id = g.id();
+        if let Some(last) = glyphs.last_mut() {
+            last.advance += font.pair_kerning(scale, last.id, id);
+        }
+        let advance = g.h_metrics().advance_width;
+        let offset = Vec2::ZERO;
+        glyphs.push(Glyph {
+            id,
+            cluster,
+            advance,
+            offset,
+        });
+    }
+    glyphs
+}
+
+/// The sum of the advances of the given glyphs.
+pub fn advance_width(glyphs: &[Glyph]) -> Scalar {
+    glyphs.iter().map(|g| g.advance).sum()
+}
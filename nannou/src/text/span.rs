@@ -0,0 +1,236 @@
+//! Items related to styling ranges of text with their own font, font size and color.
+
+use crate::color::{conv::IntoLinSrgba, LinSrgba};
+use crate::geom::{Point2, Rect};
+use crate::text::shape::{self, Direction};
+use crate::text::{Font, FontSize};
+use std::ops::{Bound, Range, RangeBounds};
+
+/// A range of text styled with its own font, font size, color, weight or slant.
+///
+/// Spans are applied in the order in which they are added, with the properties of later spans
+/// overriding those of earlier spans where they overlap. Properties left unspecified fall back to
+/// those of the **Text**'s layout.
+#[derive(Clone, Debug)]
+pub struct Span {
+    /// The byte range of the text to which the span applies.
+    pub range: Range<usize>,
+    /// The style applied to the range.
+    pub style: Style,
+}
+
+/// The properties applied to a range of text via a **Span**.
+#[derive(Clone, Debug, Default)]
+pub struct Style {
+    /// The font used to display the range, or the font of the layout if `None`.
+    pub font: Option<Font>,
+    /// The font size of the range, or the font size of the layout if `None`.
+    pub font_size: Option<FontSize>,
+    /// The color of the range, or the color of the drawing if `None`.
+    pub color: Option<LinSrgba>,
+    /// Whether or not the glyphs are emboldened.
+    pub bold: bool,
+    /// Whether or not the glyphs are slanted.
+    pub italic: bool,
+}
+
+/// A sequence of glyphs within a single line of text that share the same style and direction.
+///
+/// Runs are produced by `Text::runs` and are used to lay out and render text with spans.
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// The byte range of the run within the text.
+    pub byte_range: Range<usize>,
+    /// The index of the first character of the run within the text.
+    pub start_char: usize,
+    /// The index of the line containing the run.
+    pub line: usize,
+    /// The *x* range spans the advance of the run's glyphs while the *y* range matches the line.
+    pub rect: Rect,
+    /// The font used to display the run.
+    pub font: Font,
+    /// The font size of the run.
+    pub font_size: FontSize,
+    /// The color of the run, if specified by a span.
+    pub color: Option<LinSrgba>,
+    /// Whether or not the glyphs are emboldened.
+    pub bold: bool,
+    /// Whether or not the glyphs are slanted.
+    pub italic: bool,
+    /// The direction of the text of the run.
+    pub direction: Direction,
+    /// The shaped glyphs of the run in visual order, i.e. from left to right.
+    ///
+    /// The `cluster` of each glyph is relative to the start of the run's `byte_range`.
+    pub glyphs: Vec<shape::Glyph>,
+}
+
+/// The horizontal shear applied to the glyphs of italic runs.
+pub const ITALIC_SHEAR: f32 = 0.2;
+
+/// The horizontal offset applied to the second pass of the glyphs of bold runs, as a fraction of
+/// the font size.
+pub const BOLD_OFFSET: f32 = 0.04;
+
+impl Span {
+    /// A span with the default style applied to the given byte range.
+    ///
+    /// Unbounded ranges extend to the start or end of the text, e.g. `Span::new(6..)`.
+    pub fn new<R>(range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => usize::MAX,
+        };
+        let range = start..end;
+        let style = Default::default();
+        Span { range, style }
+    }
+
+    /// The font used to display the range.
+    ///
+    /// This is the best way of displaying bold or italic text when the font family provides
+    /// dedicated faces for them.
+    pub fn font(mut self, font: Font) -> Self {
+        self.style.font = Some(font);
+        self
+    }
+
+    /// The font size of the range.
+    pub fn font_size(mut self, size: FontSize) -> Self {
+        self.style.font_size = Some(size);
+        self
+    }
+
+    /// The color of the range.
+    pub fn color<C>(mut self, color: C) -> Self
+    where
+        C: IntoLinSrgba<f32>,
+    {
+        self.style.color = Some(color.into_lin_srgba());
+        self
+    }
+
+    /// Embolden the glyphs of the range.
+    ///
+    /// The glyphs are drawn a second time with a slight horizontal offset, so a dedicated bold
+    /// face specified via `font` will generally look better.
+    pub fn bold(mut self) -> Self {
+        self.style.bold = true;
+        self
+    }
+
+    /// Slant the glyphs of the range.
+    ///
+    /// The glyphs are sheared along the *x* axis by `ITALIC_SHEAR`, so a dedicated italic face
+    /// specified via `font` will generally look better.
+    pub fn italic(mut self) -> Self {
+        self.style.italic = true;
+        self
+    }
+}
+
+impl Run {
+    /// The horizontal shear applied to the glyphs of the run, i.e. `ITALIC_SHEAR` if italic.
+    pub fn shear(&self) -> f32 {
+        match self.italic {
+            true => ITALIC_SHEAR,
+            false => 0.0,
+        }
+    }
+
+    /// The horizontal offsets at which each pass of the run's glyphs is drawn.
+    ///
+    /// Bold runs are drawn twice, offset by `BOLD_OFFSET` of the font size.
+    pub fn passes(&self) -> Vec<f32> {
+        match self.bold {
+            true => vec![0.0, self.font_size as f32 * BOLD_OFFSET],
+            false => vec![0.0],
+        }
+    }
+
+    /// Slant and offset the given point of a glyph of the run for the given pass offset.
+    ///
+    /// The point is slanted relative to the run's baseline.
+    pub fn transform_point(&self, p: Point2, pass_offset: f32) -> Point2 {
+        let baseline = self.rect.bottom();
+        let x = p.x + (p.y - baseline) * self.shear() + pass_offset;
+        Point2::new(x, p.y)
+    }
+
+    /// The transforms from the path events of a glyph of the run, as produced by
+    /// `glyph::path_events`, to the coordinate space of the text, one for each pass.
+    ///
+    /// The given `glyph_rect` is the rect of the glyph yielded by `Text::run_glyphs`.
+    pub fn glyph_transforms(&self, glyph_rect: Rect) -> Vec<lyon::math::Transform> {
+        let shear = self.shear();
+        self.passes()
+            .into_iter()
+            .map(|offset| {
+                let (x, y) = (glyph_rect.left() + offset, glyph_rect.bottom());
+                lyon::math::Transform::new(1.0, 0.0, shear, 1.0, x, y)
+            })
+            .collect()
+    }
+}
+
+impl Style {
+    /// Apply the properties specified by the given style on top of this one.
+    pub fn apply(&mut self, style: &Style) {
+        if let Some(ref font) = style.font {
+            self.font = Some(font.clone());
+        }
+        if let Some(size) = style.font_size {
+            self.font_size = Some(size);
+        }
+        if let Some(color) = style.color {
+            self.color = Some(color);
+        }
+        self.bold |= style.bold;
+        self.italic |= style.italic;
+    }
+}
+
+/// Split the given text into segments at the boundaries of the given spans, producing the style
+/// of each segment.
+///
+/// Span ranges are clamped to the text and to the nearest character boundaries. Every byte of the
+/// text is covered by exactly one segment.
+pub fn segments(text: &str, spans: &[Span]) -> Vec<(Range<usize>, Style)> {
+    let clamp = |mut i: usize| {
+        i = i.min(text.len());
+        while !text.is_char_boundary(i) {
+            i -= 1;
+        }
+        i
+    };
+    let mut bounds = vec![0, text.len()];
+    for span in spans {
+        bounds.push(clamp(span.range.start));
+        bounds.push(clamp(span.range.end));
+    }
+    bounds.sort_unstable();
+    bounds.dedup();
+    bounds
+        .windows(2)
+        .map(|w| {
+            let range = w[0]..w[1];
+            let mut style = Style::default();
+            for span in spans {
+                let (start, end) = (clamp(span.range.start), clamp(span.range.end));
+                if start <= range.start && range.end <= end {
+                    style.apply(&span.style);
+                }
+            }
+            (range, style)
+        })
+        .collect()
+}
@@ -1506,6 +1506,59 @@ impl Window {
         self.capture_frame_inner(path.as_ref());
     }
 
+    /// Begin building a recording of a sequence of this window's frames.
+    ///
+    /// Frames may be written to a directory of image files or piped to an external encoder
+    /// process. Unlike `capture_frame`, recording never drops frames; the application loop is
+    /// throttled to the rate at which frames can be written instead.
+    ///
+    /// ```no_run
+    /// # use nannou::prelude::*;
+    /// # fn view(app: &App, frame: Frame) {
+    /// if frame.nth() == 0 {
+    ///     app.main_window()
+    ///         .record()
+    ///         .directory("frames")
+    ///         .frame_count(600)
+    ///         .start()
+    ///         .expect("failed to start recording");
+    /// }
+    /// # }
+    /// # fn main() {}
+    /// ```
+    pub fn record(&self) -> frame::record::Builder<'_> {
+        frame::record::Builder::new(self)
+    }
+
+    /// Stop the active recording, if any.
+    ///
+    /// Blocks until all pending frames have been written and, if an encoder was specified, until
+    /// the encoder process has exited.
+    pub fn stop_recording(&self) -> std::io::Result<()> {
+        let frame_data = match self.frame_data.as_ref() {
+            None => return Ok(()),
+            Some(frame_data) => frame_data,
+        };
+        let recording = frame_data
+            .capture
+            .recording
+            .lock()
+            .expect("failed to lock `recording`")
+            .take();
+        match recording {
+            None => Ok(()),
+            Some(recording) => recording.finish(self.device()),
+        }
+    }
+
+    /// Whether or not the window's frames are currently being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.frame_data
+            .as_ref()
+            .and_then(|data| data.capture.recording.lock().ok().map(|r| r.is_some()))
+            .unwrap_or(false)
+    }
+
     /// Produces a reference to the inner winit window.
     ///
     /// This is sometimes useful for integration with other winit-aware libraries (e.g. UI).
@@ -1514,21 +1567,11 @@ impl Window {
     }
 
     fn capture_frame_inner(&self, path: &Path) {
-        // If the parent directory does not exist, create it.
-        let dir = path.parent().expect("capture_frame path has no directory");
-        if !dir.exists() {
-            std::fs::create_dir_all(&dir).expect("failed to create `capture_frame` directory");
-        }
-
-        let mut capture_next_frame_path = self
+        let frame_data = self
             .frame_data
             .as_ref()
-            .expect("window capture requires that `view` draws to a `Frame` (not a `RawFrame`)")
-            .capture
-            .next_frame_path
-            .lock()
-            .expect("failed to lock `capture_next_frame_path`");
-        *capture_next_frame_path = Some(path.to_path_buf());
+            .expect("window capture requires that `view` draws to a `Frame` (not a `RawFrame`)");
+        capture_frame_to_path(frame_data, path);
     }
 
     /// Block and wait for all active capture frame jobs to complete.
@@ -1547,10 +1590,32 @@ impl Window {
     }
 }
 
+// Request that the next frame rendered with the given frame data is written to `path`.
+//
+// Shared between windows and the offscreen target of headless apps.
+pub(crate) fn capture_frame_to_path(frame_data: &FrameData, path: &Path) {
+    // If the parent directory does not exist, create it.
+    let dir = path.parent().expect("capture_frame path has no directory");
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir).expect("failed to create `capture_frame` directory");
+    }
+
+    let mut capture_next_frame_path = frame_data
+        .capture
+        .next_frame_path
+        .lock()
+        .expect("failed to lock `capture_next_frame_path`");
+    *capture_next_frame_path = Some(path.to_path_buf());
+}
+
 // Drop implementations.
 
 impl Drop for Window {
     fn drop(&mut self) {
+        if let Err(e) = self.stop_recording() {
+            // TODO: Replace eprintlns with proper logging.
+            eprintln!("failed to finish recording: {}", e);
+        }
         if self.await_capture_frame_jobs().is_err() {
             // TODO: Replace eprintlns with proper logging.
             eprintln!("timed out while waiting for capture jobs to complete");
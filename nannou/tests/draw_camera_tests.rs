@@ -0,0 +1,32 @@
+use nannou::draw::camera::{Camera, Orbit};
+use nannou::glam::Vec3;
+
+fn approx_eq(a: Vec3, b: Vec3) -> bool {
+    (a - b).length() < 1e-3
+}
+
+#[test]
+fn perspective_camera_fits_xy_plane_to_height() {
+    let [w, h] = [800.0, 600.0];
+    let camera = Camera::perspective(Camera::DEFAULT_FOV_Y, h);
+    let view_proj = camera.view_projection_matrix([w, h]);
+    // The top right corner of the output at z = 0 maps to the top right corner of clip space.
+    let ndc = view_proj.project_point3(Vec3::new(w * 0.5, h * 0.5, 0.0));
+    assert!(approx_eq(
+        ndc.truncate().extend(0.0),
+        Vec3::new(1.0, 1.0, 0.0)
+    ));
+    assert!(ndc.z > 0.0 && ndc.z < 1.0);
+}
+
+#[test]
+fn orbit_matches_perspective_camera_and_clamps_pitch() {
+    let h = 600.0;
+    let mut orbit = Orbit::new(h);
+    let camera = Camera::perspective(Camera::DEFAULT_FOV_Y, h);
+    assert!(approx_eq(orbit.camera().eye, camera.eye));
+    orbit.rotate(0.0, 10.0);
+    assert_eq!(orbit.pitch, Orbit::MAX_PITCH);
+    // The distance to the target is preserved while orbiting.
+    assert!((orbit.eye().length() - orbit.distance).abs() < 1e-2);
+}
@@ -0,0 +1,72 @@
+use nannou::draw::primitive::Primitive;
+use nannou::draw::{Draw, DrawCommand};
+
+// Count the primitives within the given commands.
+fn primitive_count<'a, I>(cmds: I) -> usize
+where
+    I: IntoIterator<Item = &'a DrawCommand>,
+{
+    cmds.into_iter()
+        .filter(|cmd| matches!(cmd, DrawCommand::Primitive(_)))
+        .count()
+}
+
+#[test]
+fn clip_moves_mask_primitives_into_context() {
+    let draw = Draw::new();
+    draw.rect().w_h(10.0, 10.0);
+    let clipped = draw.x(5.0).clip(|mask| {
+        mask.ellipse().radius(20.0);
+        mask.tri();
+    });
+    clipped.rect().w_h(30.0, 30.0);
+    draw.rect();
+
+    let cmds: Vec<_> = draw.drain_commands().collect();
+    assert_eq!(primitive_count(&cmds), 3);
+
+    // Only the second rect is clipped, by a mask of two primitives drawn with the translation.
+    let clips: Vec<_> = cmds
+        .iter()
+        .filter_map(|cmd| match cmd {
+            DrawCommand::Context(ctxt) => Some(ctxt.clip.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(clips.len(), 3);
+    assert!(clips[0].is_empty() && clips[2].is_empty());
+    let mask = &clips[1][0];
+    assert!(!mask.inverted);
+    assert_eq!(primitive_count(mask.commands.iter()), 2);
+    match mask.commands[0] {
+        DrawCommand::Context(ref ctxt) => assert_eq!(ctxt.transform.w_axis.x, 5.0),
+        _ => panic!("mask does not begin with its context"),
+    }
+    match mask.commands[1] {
+        DrawCommand::Primitive(Primitive::Ellipse(_)) => (),
+        _ => panic!("expected the ellipse of the mask"),
+    }
+}
+
+#[test]
+fn nested_clips_stack_masks() {
+    let draw = Draw::new();
+    let outer = draw.clip(|mask| {
+        mask.rect();
+    });
+    let inner = outer.clip_inverted(|mask| {
+        mask.ellipse();
+    });
+    inner.rect();
+
+    let clip = draw
+        .drain_commands()
+        .find_map(|cmd| match cmd {
+            DrawCommand::Context(ctxt) => Some(ctxt.clip),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(clip.len(), 2);
+    assert!(!clip[0].inverted && clip[1].inverted);
+    assert_ne!(clip[0], clip[1]);
+}
@@ -0,0 +1,119 @@
+use nannou::color::{BLUE, RED};
+use nannou::draw::mesh::vertex::Color;
+use nannou::draw::primitive::Cuboid;
+use nannou::draw::properties::{SetColor, SetDimensions};
+use nannou::draw::renderer::{GlyphCache, RenderContext, RenderPrimitive};
+use nannou::draw::{Mesh, Theme};
+use nannou::geom::{cuboid::Face, Rect};
+use nannou::glam::{Mat4, Vec2, Vec3};
+use nannou::lyon::tessellation::{FillTessellator, StrokeTessellator};
+use nannou::text;
+
+// Render the cuboid into a fresh mesh without any transform.
+fn render(cuboid: Cuboid) -> Mesh {
+    let mut glyph_cache = GlyphCache {
+        cache: text::GlyphCache::builder().dimensions(1, 1).build(),
+        pixel_buffer: vec![0],
+        requires_upload: false,
+    };
+    let ctxt = RenderContext {
+        transform: &Mat4::IDENTITY,
+        intermediary_mesh: &Mesh::default(),
+        path_event_buffer: &[],
+        path_points_colored_buffer: &[],
+        path_points_textured_buffer: &[],
+        text_buffer: "",
+        theme: &Theme::default(),
+        glyph_cache: &mut glyph_cache,
+        fill_tessellator: &mut FillTessellator::new(),
+        stroke_tessellator: &mut StrokeTessellator::new(),
+        output_attachment_size: Vec2::new(100.0, 100.0),
+        output_attachment_scale_factor: 1.0,
+    };
+    let mut mesh = Mesh::default();
+    cuboid.render_primitive(ctxt, &mut mesh);
+    mesh
+}
+
+fn color(c: nannou::color::Srgb<u8>) -> Color {
+    c.into_format::<f32>().into_linear().into()
+}
+
+// Faces in the order in which the cuboid writes their vertices.
+const FACES: [Face; 6] = [
+    Face::Back,
+    Face::Right,
+    Face::Top,
+    Face::Front,
+    Face::Bottom,
+    Face::Left,
+];
+
+#[test]
+fn cuboid_has_a_quad_per_face() {
+    let mesh = render(Cuboid::default().w_h_d(20.0, 40.0, 60.0));
+    assert_eq!(mesh.points().len(), 24);
+    assert_eq!(mesh.normals().len(), 24);
+    assert_eq!(mesh.indices().len(), 36);
+    assert!(mesh.indices().iter().all(|&i| i < 24));
+
+    // Each face lies in the plane at its normal, half the cuboid's size from the centre.
+    let half = Vec3::new(10.0, 20.0, 30.0);
+    for (i, quad) in mesh.points().chunks(4).enumerate() {
+        let normal = mesh.normals()[i * 4];
+        assert!(mesh.normals()[i * 4..i * 4 + 4]
+            .iter()
+            .all(|&n| n == normal));
+        for p in quad {
+            assert!((p.dot(normal) - (half * normal).length()).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn cuboid_faces_may_be_colored() {
+    let cuboid = Cuboid::default()
+        .color(RED)
+        .face_color(Face::Top, BLUE)
+        .face_color(Face::Left, RED)
+        .face_color(Face::Left, BLUE);
+    let mesh = render(cuboid);
+    for (face, colors) in FACES.iter().zip(mesh.colors().chunks(4)) {
+        let expected = match face {
+            Face::Top | Face::Left => color(BLUE),
+            _ => color(RED),
+        };
+        assert!(colors.iter().all(|&c| c == expected), "{:?}", face);
+    }
+}
+
+#[test]
+fn cuboid_faces_map_the_texture_area() {
+    let full = [
+        Vec2::new(0.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+    ];
+    let quarter = [
+        Vec2::new(0.0, 0.5),
+        Vec2::new(0.5, 0.5),
+        Vec2::new(0.5, 0.0),
+        Vec2::new(0.0, 0.0),
+    ];
+    let area = Rect::from_x_y_w_h(0.25, 0.25, 0.5, 0.5);
+    let mesh = render(Cuboid::default().face_area(Face::Front, area));
+    for (face, tex_coords) in FACES.iter().zip(mesh.tex_coords().chunks(4)) {
+        let expected = match face {
+            Face::Front => &quarter,
+            _ => &full,
+        };
+        assert_eq!(tex_coords, expected, "{:?}", face);
+    }
+
+    // Setting the area of every face clears the per-face areas.
+    let mesh = render(Cuboid::default().face_area(Face::Front, area).area(area));
+    for tex_coords in mesh.tex_coords().chunks(4) {
+        assert_eq!(tex_coords, &quarter);
+    }
+}
@@ -0,0 +1,146 @@
+use nannou::draw::properties::Dashes;
+use nannou::lyon::math::point;
+use nannou::lyon::path::iterator::FromPolyline;
+use nannou::lyon::path::PathEvent;
+
+fn dashes(pattern: &[f32], offset: f32) -> Dashes {
+    Dashes {
+        pattern: pattern.to_vec(),
+        offset,
+        cap: None,
+    }
+}
+
+// The `(first, last)` x coordinates of each dash along a horizontal path, rounded to 3 decimals.
+fn dash_extents(events: &[PathEvent]) -> Vec<(f32, f32)> {
+    let round = |x: f32| (x * 1000.0).round() / 1000.0;
+    events
+        .iter()
+        .filter_map(|e| match *e {
+            PathEvent::End { last, first, close } => {
+                assert!(!close);
+                Some((round(first.x), round(last.x)))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn dash_straight_line() {
+    let line = FromPolyline::open(vec![point(0.0, 0.0), point(25.0, 0.0)].into_iter());
+    let events = dashes(&[5.0, 5.0], 0.0).dash(line, 0.1);
+    assert_eq!(
+        dash_extents(&events),
+        vec![(0.0, 5.0), (10.0, 15.0), (20.0, 25.0)]
+    );
+}
+
+#[test]
+fn dash_offset_and_odd_pattern() {
+    // `[4, 2, 4]` is repeated to `[4, 2, 4, 4, 2, 4]`, i.e. the lengths alternate between dashes
+    // and gaps.
+    let line = FromPolyline::open(vec![point(0.0, 0.0), point(20.0, 0.0)].into_iter());
+    let events = dashes(&[4.0, 2.0, 4.0], 2.0).dash(line, 0.1);
+    assert_eq!(
+        dash_extents(&events),
+        vec![(0.0, 2.0), (4.0, 8.0), (12.0, 14.0), (18.0, 20.0)]
+    );
+}
+
+#[test]
+fn dot_pattern_starts_with_a_dot() {
+    let line = FromPolyline::open(vec![point(0.0, 0.0), point(10.0, 0.0)].into_iter());
+    let events = dashes(&[0.0, 5.0], 0.0).dash(line, 0.1);
+    assert_eq!(
+        dash_extents(&events),
+        vec![(0.0, 0.0), (5.0, 5.0), (10.0, 10.0)]
+    );
+}
+
+#[test]
+fn dash_continues_around_corners_and_closes() {
+    let square = vec![
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(0.0, 10.0),
+    ];
+    let events = dashes(&[15.0, 5.0], 0.0).dash(FromPolyline::closed(square.into_iter()), 0.1);
+    let ends: Vec<_> = events
+        .iter()
+        .filter_map(|e| match *e {
+            PathEvent::End { first, last, .. } => Some((first, last)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        ends,
+        vec![
+            (point(0.0, 0.0), point(10.0, 5.0)),
+            (point(10.0, 10.0), point(0.0, 5.0)),
+        ]
+    );
+}
+
+#[test]
+fn invalid_patterns_are_solid() {
+    let points = vec![point(0.0, 0.0), point(25.0, 0.0)];
+    for pattern in &[&[][..], &[0.0, 0.0][..], &[5.0, -1.0][..]] {
+        let line: Vec<_> = FromPolyline::open(points.clone().into_iter()).collect();
+        let events = dashes(pattern, 0.0).dash(line.clone(), 0.1);
+        assert_eq!(events, line);
+    }
+}
+
+// Whether all points of the events are finite.
+fn all_finite(events: &[PathEvent]) -> bool {
+    events.iter().all(|e| match *e {
+        PathEvent::Begin { at } => at.x.is_finite() && at.y.is_finite(),
+        PathEvent::Line { from, to } => [from, to]
+            .iter()
+            .all(|p| p.x.is_finite() && p.y.is_finite()),
+        PathEvent::End { last, first, .. } => [last, first]
+            .iter()
+            .all(|p| p.x.is_finite() && p.y.is_finite()),
+        _ => true,
+    })
+}
+
+#[test]
+fn zero_length_segments_are_skipped() {
+    // The offset leaves less than the dash epsilon of the first gap, which ends at a repeated
+    // point.
+    let points = vec![
+        point(0.0, 0.0),
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 0.0),
+        point(20.0, 0.0),
+    ];
+    let events = dashes(&[0.0, 5.0], 4.99995).dash(FromPolyline::open(points.into_iter()), 0.1);
+    assert!(all_finite(&events));
+    assert_eq!(
+        dash_extents(&events),
+        vec![
+            (0.0, 0.0),
+            (5.0, 5.0),
+            (10.0, 10.0),
+            (15.0, 15.0),
+            (20.0, 20.0)
+        ]
+    );
+
+    // The closing segment of a closed path whose last point equals its first has no length.
+    let triangle = vec![
+        point(0.0, 0.0),
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(0.0, 0.0),
+    ];
+    let closed = FromPolyline::closed(triangle.into_iter());
+    let events = dashes(&[0.0, 5.0], 4.99995).dash(closed, 0.1);
+    assert!(all_finite(&events));
+    assert!(!events.is_empty());
+}
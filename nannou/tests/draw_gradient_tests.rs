@@ -0,0 +1,70 @@
+use nannou::color::LinSrgba;
+use nannou::draw::properties::gradient::Spread;
+use nannou::draw::Gradient;
+use nannou::geom::{pt2, Point2};
+
+fn approx_eq(a: LinSrgba, b: LinSrgba) -> bool {
+    (a.red - b.red).abs() < 1e-4
+        && (a.green - b.green).abs() < 1e-4
+        && (a.blue - b.blue).abs() < 1e-4
+        && (a.alpha - b.alpha).abs() < 1e-4
+}
+
+fn black() -> LinSrgba {
+    LinSrgba::new(0.0, 0.0, 0.0, 1.0)
+}
+
+fn white() -> LinSrgba {
+    LinSrgba::new(1.0, 1.0, 1.0, 1.0)
+}
+
+#[test]
+fn stops_are_sorted_and_spread() {
+    let gradient = Gradient::linear(pt2(0.0, 0.0), pt2(100.0, 0.0))
+        .stop(1.0, white())
+        .stop(0.0, black());
+    let offsets: Vec<_> = gradient.stops.iter().map(|s| s.offset).collect();
+    assert_eq!(offsets, vec![0.0, 1.0]);
+    let grey = LinSrgba::new(0.25, 0.25, 0.25, 1.0);
+    assert!(approx_eq(gradient.color(pt2(25.0, 50.0)), grey));
+    assert!(approx_eq(gradient.color_at(1.25), white()));
+    let repeat = gradient.clone().spread(Spread::Repeat);
+    assert!(approx_eq(repeat.color_at(1.25), grey));
+    let reflect = gradient.spread(Spread::Reflect);
+    let light = LinSrgba::new(0.75, 0.75, 0.75, 1.0);
+    assert!(approx_eq(reflect.color_at(1.25), light));
+}
+
+#[test]
+fn subdivide_refines_triangles_across_stops() {
+    let gradient = Gradient::linear(pt2(0.0, 0.0), pt2(100.0, 0.0))
+        .stop(0.0, black())
+        .stop(0.5, white())
+        .stop(1.0, black());
+    let mut points: Vec<Point2> = vec![pt2(0.0, 0.0), pt2(100.0, 0.0), pt2(100.0, 100.0)];
+    let mut indices = vec![0, 1, 2];
+    gradient.subdivide(&mut points, &mut indices);
+    assert!(indices.len() > 3);
+    assert_eq!(indices.len() % 3, 0);
+    assert!(indices.iter().all(|&i| (i as usize) < points.len()));
+    // The white stop is now represented exactly by a vertex.
+    assert!(points.iter().any(|p| p.x == 50.0));
+    // Interpolating vertex colors closely approximates the gradient at each triangle's centroid.
+    for tri in indices.chunks(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| points[i as usize]);
+        let centroid = (a + b + c) / 3.0;
+        let expected = gradient.color(centroid).red;
+        let actual = (gradient.color(a).red + gradient.color(b).red + gradient.color(c).red) / 3.0;
+        assert!((expected - actual).abs() < 0.05);
+    }
+}
+
+#[test]
+fn subdivide_leaves_uniform_triangles() {
+    let gradient = Gradient::radial(pt2(0.0, 0.0), 10.0).stop(0.0, white());
+    let mut points: Vec<Point2> = vec![pt2(0.0, 0.0), pt2(100.0, 0.0), pt2(100.0, 100.0)];
+    let mut indices = vec![0, 1, 2];
+    gradient.subdivide(&mut points, &mut indices);
+    assert_eq!(indices, vec![0, 1, 2]);
+    assert_eq!(points.len(), 3);
+}
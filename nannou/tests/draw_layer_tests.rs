@@ -0,0 +1,41 @@
+use nannou::draw::{svg, BlendMode, Draw, DrawCommand};
+use nannou::geom::vec2;
+
+#[test]
+fn layer_records_command_with_properties() {
+    let draw = Draw::new();
+    draw.rect();
+    let layer = draw.layer().opacity(1.5).blend_mode(BlendMode::Multiply);
+    layer.ellipse();
+    draw.tri();
+
+    let cmds: Vec<_> = draw.drain_commands().collect();
+    let layers: Vec<_> = cmds
+        .iter()
+        .filter_map(|cmd| match cmd {
+            DrawCommand::Layer(layer) => Some(layer.properties()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(layers.len(), 1);
+    assert_eq!(layers[0].opacity, 1.0);
+    assert_eq!(layers[0].blend_mode, BlendMode::Multiply);
+
+    // The layer's contents are kept separate from those of the parent.
+    let layer_cmds: Vec<_> = layer.drain_commands().collect();
+    assert!(layer_cmds
+        .iter()
+        .any(|cmd| matches!(cmd, DrawCommand::Primitive(_))));
+}
+
+#[test]
+fn layer_exported_to_svg_with_blend_mode() {
+    let draw = Draw::new();
+    draw.layer()
+        .opacity(0.5)
+        .blend_mode(BlendMode::SoftLight)
+        .ellipse();
+    let svg = svg::to_string(&draw, vec2(100.0, 100.0));
+    assert!(svg.contains("mix-blend-mode:soft-light"));
+    assert!(svg.contains("opacity=\"0.5\""));
+}
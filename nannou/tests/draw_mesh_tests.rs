@@ -0,0 +1,44 @@
+use nannou::draw::mesh::vertex::{self, DEFAULT_NORMAL};
+use nannou::draw::primitive::mesh::Vertexless;
+use nannou::draw::Mesh;
+use nannou::glam::Vec3;
+
+fn vertex(x: f32) -> vertex::Vertex {
+    let color = vertex::DEFAULT_VERTEX_COLOR;
+    vertex::new(Vec3::new(x, 0.0, 0.0), color, vertex::default_tex_coords())
+}
+
+#[test]
+fn normals_line_up_with_vertices() {
+    let mut mesh = Mesh::default();
+    mesh.push_vertex(vertex(0.0));
+    let color = vertex::DEFAULT_VERTEX_COLOR;
+    let tex_coords = vertex::default_tex_coords();
+    mesh.push_vertex(vertex::with_normal(Vec3::X, color, tex_coords, Vec3::X));
+    mesh.extend_vertices_from_slices(&[Vec3::Y], &[color], &[tex_coords]);
+    assert_eq!(mesh.normals(), &[DEFAULT_NORMAL, Vec3::X, DEFAULT_NORMAL]);
+    assert_eq!(mesh.normals().len(), mesh.points().len());
+    mesh.clear();
+    assert!(mesh.normals().is_empty());
+}
+
+#[test]
+fn mesh_builder_supplies_normals() {
+    let mut mesh = Mesh::default();
+    let points = vec![
+        (Vec3::ZERO, Vec3::X),
+        (Vec3::X, Vec3::Y),
+        (Vec3::Y, Vec3::Z),
+    ];
+    Vertexless.points_with_normals(&mut mesh, points);
+    assert_eq!(mesh.normals(), &[Vec3::X, Vec3::Y, Vec3::Z]);
+    assert_eq!(mesh.indices(), &[0, 1, 2]);
+
+    let points = vec![(Vec3::ZERO, Vec3::NEG_Z), (Vec3::X, Vec3::NEG_Z)];
+    Vertexless.indexed_with_normals(&mut mesh, points, vec![0, 1, 1]);
+    assert_eq!(&mesh.normals()[3..], &[Vec3::NEG_Z, Vec3::NEG_Z]);
+
+    // Builders without normals give their vertices the default.
+    Vertexless.points(&mut mesh, vec![Vec3::ONE]);
+    assert_eq!(mesh.normals()[5], DEFAULT_NORMAL);
+}
@@ -0,0 +1,64 @@
+use nannou::draw::Draw;
+use nannou::geom::{pt2, vec2, Rect};
+
+const SIZE: [f32; 2] = [400.0, 400.0];
+
+#[test]
+fn pick_topmost_tagged_drawing() {
+    let draw = Draw::new();
+    draw.tag(1).rect().w_h(100.0, 100.0);
+    draw.tag(2).ellipse().x(50.0).radius(30.0);
+    draw.x(-150.0).tri();
+
+    let size = SIZE.into();
+    let pick = |x, y| draw.pick(pt2(x, y), size).map(|p| (p.index, p.tag));
+    assert_eq!(pick(0.0, 0.0), Some((0, Some(1))));
+    assert_eq!(pick(60.0, 0.0), Some((1, Some(2))));
+    assert_eq!(pick(70.0, 0.0), Some((1, Some(2))));
+    assert_eq!(pick(-150.0, 0.0), Some((2, None)));
+    assert_eq!(pick(150.0, 150.0), None);
+
+    let all: Vec<_> = draw.pick_all(pt2(40.0, 0.0), size);
+    assert_eq!(all.iter().map(|p| p.index).collect::<Vec<_>>(), vec![1, 0]);
+}
+
+#[test]
+fn pick_respects_scissor_clip_and_layers() {
+    let draw = Draw::new();
+    draw.tag(1).rect().w_h(200.0, 200.0);
+    let layer = draw.layer();
+    draw.tag(3).rect().x(80.0).w_h(40.0, 40.0);
+    layer.tag(2).ellipse().radius(100.0);
+    draw.tag(4)
+        .scissor(Rect::from_w_h(20.0, 20.0))
+        .ellipse()
+        .radius(50.0);
+    draw.tag(5)
+        .clip_inverted(|mask| {
+            mask.rect().w_h(180.0, 180.0);
+        })
+        .rect()
+        .w_h(200.0, 200.0);
+
+    let size = vec2(SIZE[0], SIZE[1]);
+    let tag = |x, y| draw.pick(pt2(x, y), size).and_then(|p| p.tag);
+    // The scissored ellipse is only picked within the scissor.
+    assert_eq!(tag(0.0, 0.0), Some(4));
+    // The layer is above the first rect but below the later drawings.
+    assert_eq!(tag(30.0, 30.0), Some(2));
+    assert_eq!(tag(80.0, 0.0), Some(3));
+    // The clipped rect is only picked outside of the inverted mask.
+    assert_eq!(tag(95.0, -95.0), Some(5));
+    assert_eq!(draw.pick(pt2(30.0, 30.0), size).map(|p| p.index), Some(1));
+}
+
+#[test]
+fn pick_text() {
+    let draw = Draw::new();
+    draw.tag(1).text("MMMM").font_size(48).w_h(200.0, 100.0);
+    let size = SIZE.into();
+    let pick = |x, y| draw.pick(pt2(x, y), size).map(|p| p.tag);
+    // Glyphs are picked by their tessellated quads.
+    assert_eq!(pick(20.0, 0.0), Some(Some(1)));
+    assert_eq!(pick(150.0, 150.0), None);
+}
@@ -0,0 +1,60 @@
+use nannou::draw::{RenderTarget, RenderTargetBuilder};
+use nannou::frame::Frame;
+use nannou::wgpu;
+
+// The usages that are always enabled for a target's resolved texture.
+fn required_usage() -> wgpu::TextureUsages {
+    wgpu::TextureUsages::RENDER_ATTACHMENT
+        | wgpu::TextureUsages::TEXTURE_BINDING
+        | wgpu::TextureUsages::COPY_SRC
+}
+
+#[test]
+fn builder_defaults() {
+    let builder = RenderTarget::builder();
+    let desc = builder.texture_descriptor([64, 32]);
+    assert_eq!(desc.format, Frame::TEXTURE_FORMAT);
+    assert_eq!(desc.sample_count, 1);
+    assert_eq!(desc.usage, required_usage());
+
+    let ms = builder.multisampled_texture_descriptor([64, 32]).unwrap();
+    assert_eq!(ms.sample_count, RenderTargetBuilder::DEFAULT_SAMPLE_COUNT);
+    assert_eq!(ms.sample_count, Frame::DEFAULT_MSAA_SAMPLES);
+    assert_eq!(ms.format, Frame::TEXTURE_FORMAT);
+    assert_eq!(ms.usage, wgpu::TextureUsages::RENDER_ATTACHMENT);
+}
+
+#[test]
+fn sample_count_is_normalized() {
+    for &count in &[0, 1] {
+        let builder = RenderTarget::builder().sample_count(count);
+        assert!(builder.multisampled_texture_descriptor([8, 8]).is_none());
+        assert_eq!(builder.texture_descriptor([8, 8]).sample_count, 1);
+    }
+    let builder = RenderTarget::builder().sample_count(8);
+    let ms = builder.multisampled_texture_descriptor([8, 8]).unwrap();
+    assert_eq!(ms.sample_count, 8);
+    assert_eq!(builder.texture_descriptor([8, 8]).sample_count, 1);
+}
+
+#[test]
+fn size_format_and_usage_propagate() {
+    let format = wgpu::TextureFormat::Rgba8UnormSrgb;
+    let builder = RenderTarget::builder()
+        .format(format)
+        .usage(wgpu::TextureUsages::COPY_DST);
+    let extent = wgpu::Extent3d {
+        width: 320,
+        height: 240,
+        depth_or_array_layers: 1,
+    };
+
+    let desc = builder.texture_descriptor([320, 240]);
+    assert_eq!(desc.size, extent);
+    assert_eq!(desc.format, format);
+    assert_eq!(desc.usage, required_usage() | wgpu::TextureUsages::COPY_DST);
+
+    let ms = builder.multisampled_texture_descriptor([320, 240]).unwrap();
+    assert_eq!(ms.size, extent);
+    assert_eq!(ms.format, format);
+}
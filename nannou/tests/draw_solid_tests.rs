@@ -0,0 +1,48 @@
+use nannou::draw::primitive::solid::Geometry;
+
+// Check that all indices are in range, that normals are unit length and that every triangle is
+// wound counter-clockwise when viewed from the side its vertex normals face.
+fn assert_valid(geometry: &Geometry) {
+    assert!(!geometry.indices.is_empty());
+    assert_eq!(geometry.indices.len() % 3, 0);
+    for v in &geometry.vertices {
+        assert!((v.normal.length() - 1.0).abs() < 1e-4);
+    }
+    for tri in geometry.indices.chunks(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| geometry.vertices[i as usize]);
+        let face_normal = (b.point - a.point).cross(c.point - a.point);
+        let vertex_normal = a.normal + b.normal + c.normal;
+        assert!(face_normal.dot(vertex_normal) > 0.0);
+    }
+}
+
+#[test]
+fn solid_geometry_is_valid() {
+    assert_valid(&Geometry::sphere(16, 8));
+    assert_valid(&Geometry::cylinder(16));
+    assert_valid(&Geometry::cone(16));
+    assert_valid(&Geometry::torus(0.5, 0.2, 16, 8));
+    assert_valid(&Geometry::plane(4, 3));
+}
+
+#[test]
+fn solid_geometry_fits_unit_bounds() {
+    for geometry in &[
+        Geometry::sphere(16, 8),
+        Geometry::cylinder(16),
+        Geometry::cone(16),
+        Geometry::plane(4, 3),
+    ] {
+        for v in &geometry.vertices {
+            assert!(v.point.abs().max_element() <= 0.5 + 1e-6);
+        }
+    }
+}
+
+#[test]
+fn solid_resolution_is_clamped() {
+    let sphere = Geometry::sphere(0, 0);
+    assert_eq!(sphere.vertices.len(), 4 * 3);
+    let plane = Geometry::plane(0, 0);
+    assert_eq!(plane.indices.len(), 6);
+}
@@ -0,0 +1,80 @@
+use nannou::color::{BLACK, RED, WHITE};
+use nannou::draw::{Draw, Gradient};
+use nannou::geom::{pt2, pt3, vec2};
+
+#[test]
+fn paths_are_written_in_local_space() {
+    let draw = Draw::new();
+    draw.background().color(WHITE);
+    draw.rect()
+        .x_y(10.0, 20.0)
+        .w_h(40.0, 20.0)
+        .color(RED)
+        .stroke(BLACK)
+        .stroke_weight(2.0)
+        .dashes(&[4.0, 2.0]);
+    let svg = draw.to_svg_string(vec2(200.0, 100.0));
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="100""#));
+    assert!(svg.contains(r##"<rect width="100%" height="100%" fill="#ffffff"/>"##));
+    // The fill and stroke share the geometry, placed via the transform from the centred, y-up
+    // coordinates of `Draw` to the top-left, y-down coordinates of SVG.
+    let d = r#"d="M-20 10L20 10L20 -10L-20 -10Z""#;
+    assert_eq!(svg.matches(d).count(), 2);
+    assert!(
+        svg.contains(r##"fill="#ff0000" fill-rule="evenodd" transform="matrix(1 0 0 -1 110 30)""##)
+    );
+    assert!(svg.contains(r#"stroke-width="2""#));
+    assert!(svg.contains(r#"stroke-dasharray="4 2" stroke-dashoffset="0""#));
+    // Exporting leaves the commands in place for rendering.
+    assert_eq!(draw.to_svg_string(vec2(200.0, 100.0)), svg);
+}
+
+#[test]
+fn gradients_and_scissors_are_defined() {
+    let draw = Draw::new();
+    let gradient = Gradient::linear(pt2(-50.0, 0.0), pt2(50.0, 0.0)).colors(vec![RED, BLACK]);
+    draw.scissor(nannou::geom::Rect::from_w_h(100.0, 50.0))
+        .ellipse()
+        .radius(50.0)
+        .gradient(gradient);
+    let svg = draw.to_svg_string(vec2(200.0, 100.0));
+    assert!(svg.contains(
+        r#"<clipPath id="clip1"><rect x="50" y="25" width="100" height="50"/></clipPath>"#
+    ));
+    assert!(svg.contains(r#"<linearGradient id="gradient2" gradientUnits="userSpaceOnUse" spreadMethod="pad" x1="-50" y1="0" x2="50" y2="0">"#));
+    assert!(svg.contains(r#"fill="url(#gradient2)""#));
+    assert!(svg.contains(r#"<g clip-path="url(#clip1)">"#));
+    assert!(svg.trim_end().ends_with("</g>\n</svg>"));
+}
+
+#[test]
+fn primitives_without_vector_paths_are_tessellated() {
+    let draw = Draw::new();
+    let points = [pt3(0.0, 0.0, 0.0), pt3(10.0, 0.0, 0.0), pt3(0.0, 10.0, 0.0)];
+    draw.mesh().points_colored(points.iter().map(|&p| (p, RED)));
+    let svg = draw.to_svg_string(vec2(100.0, 100.0));
+    // Written in the coordinates of the document, as a single flat shaded triangle.
+    assert!(svg.contains(r##"<path d="M50 50L60 50L50 40Z" fill="#ff0000"/>"##));
+
+    // Conic gradients have no SVG equivalent.
+    let draw = Draw::new();
+    let gradient = Gradient::conic(pt2(0.0, 0.0), 0.0).colors(vec![RED, BLACK]);
+    draw.rect().w_h(10.0, 10.0).gradient(gradient);
+    let svg = draw.to_svg_string(vec2(100.0, 100.0));
+    assert!(!svg.contains("Gradient"));
+    assert!(svg.matches("<path").count() >= 2);
+    assert!(!svg.contains("transform="));
+}
+
+#[test]
+fn strokes_use_the_start_cap() {
+    let draw = Draw::new();
+    draw.line()
+        .start(pt2(-10.0, 0.0))
+        .end(pt2(10.0, 0.0))
+        .start_cap_round()
+        .end_cap_square();
+    let svg = draw.to_svg_string(vec2(100.0, 100.0));
+    assert!(svg.contains(r#"stroke-linecap="round""#));
+    assert!(!svg.contains("square"));
+}
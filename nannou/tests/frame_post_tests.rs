@@ -0,0 +1,152 @@
+use nannou::frame::post::{Chain, Effect};
+use nannou::frame::Frame;
+use nannou::wgpu;
+
+#[test]
+fn params_are_declared_as_uniform_fields() {
+    let effect = Effect::new("// body")
+        .param("amount", 0.5)
+        .param("radius", 2.0);
+    let source = effect.shader_source();
+    let fields = source.find("    frame: u32;\n    amount: f32;\n    radius: f32;\n};");
+    assert!(fields.is_some(), "{}", source);
+    assert!(source.ends_with("// body"));
+
+    // Re-declaring a parameter replaces its value rather than adding a field.
+    let mut effect = effect.param("amount", 1.0);
+    assert_eq!(effect.get_param("amount"), Some(1.0));
+    assert_eq!(effect.shader_source(), source);
+    assert!(effect.set_param("radius", 4.0));
+    assert!(!effect.set_param("missing", 4.0));
+    assert_eq!(effect.get_param("radius"), Some(4.0));
+}
+
+#[test]
+#[should_panic]
+fn builtin_uniform_names_are_reserved() {
+    let _ = Effect::new("").param("time", 0.0);
+}
+
+#[test]
+fn chain_order() {
+    let mut chain = Chain::new().effect(Effect::blur(2.0));
+    chain.push(Effect::feedback(0.9));
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain.effects()[1].get_param("decay"), Some(0.9));
+    assert_eq!(chain.effects()[0].get_param("radius"), Some(2.0));
+}
+
+// Applies `chain` to a single-sampled source texture over two frames, as `Frame::post_process`
+// does for windows without MSAA, returning the red channel of the source and destination after
+// each frame.
+//
+// Returns `None` if no GPU adapter is available.
+fn apply_twice(chain: &Chain) -> Option<Vec<(f32, f32)>> {
+    let instance = wgpu::Instance::new(wgpu::Backends::PRIMARY);
+    let adapters = wgpu::AdapterMap::default();
+    let adapter = adapters.get_or_request(wgpu::RequestAdapterOptions::default(), &instance)?;
+    let device_queue_pair = adapter.get_or_request_device(wgpu::default_device_descriptor());
+    let (device, queue) = (device_queue_pair.device(), device_queue_pair.queue());
+
+    let size = [64, 2];
+    let new_texture = || {
+        wgpu::TextureBuilder::new()
+            .size(size)
+            .format(Frame::TEXTURE_FORMAT)
+            .usage(
+                wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_SRC
+                    | wgpu::TextureUsages::COPY_DST,
+            )
+            .build(device)
+    };
+    let (src, dst) = (new_texture(), new_texture());
+    let src_view = src.view().build();
+    let mut encoder = device.create_command_encoder(&Default::default());
+    let grey = wgpu::Color {
+        r: 0.25,
+        g: 0.25,
+        b: 0.25,
+        a: 1.0,
+    };
+    wgpu::clear_texture(&src_view, grey, &mut encoder);
+    queue.submit(Some(encoder.finish()));
+
+    let read_red = |texture: &wgpu::Texture| {
+        let bytes_per_row = size[0] * 8;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (bytes_per_row * size[1]) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: std::num::NonZeroU32::new(bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            texture.extent(),
+        );
+        queue.submit(Some(encoder.finish()));
+        let slice = buffer.slice(..);
+        let mapping = slice.map_async(wgpu::MapMode::Read);
+        device.poll(wgpu::Maintain::Wait);
+        async_std::task::block_on(mapping).expect("failed to map buffer");
+        let bytes = slice.get_mapped_range();
+        f16_to_f32(u16::from_ne_bytes([bytes[0], bytes[1]]))
+    };
+
+    let mut results = vec![];
+    for frame in 0..2 {
+        let mut encoder = device.create_command_encoder(&Default::default());
+        chain.encode(device, queue, &mut encoder, &src_view, &dst, frame);
+        queue.submit(Some(encoder.finish()));
+        results.push((read_red(&src), read_red(&dst)));
+    }
+    Some(results)
+}
+
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits >> 15 == 1 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let mantissa = (bits & 0x3ff) as f32 / 1024.0;
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-14),
+        _ => sign * (1.0 + mantissa) * 2f32.powi(exponent - 15),
+    }
+}
+
+#[test]
+fn effects_do_not_accumulate_within_the_source() {
+    let brighten = Effect::new(
+        "[[stage(fragment)]]
+fn main([[location(0)]] tex_coords: vec2<f32>) -> [[location(0)]] vec4<f32> {
+    let color = textureSample(src_texture, tex_sampler, tex_coords);
+    return vec4<f32>(color.rgb + vec3<f32>(uniforms.amount), 1.0);
+}",
+    )
+    .param("amount", 0.125);
+    // Both odd and even numbers of passes must end within the destination.
+    for n in 1..=3 {
+        let mut chain = Chain::new();
+        for _ in 0..n {
+            chain.push(brighten.clone());
+        }
+        let results = match apply_twice(&chain) {
+            Some(results) => results,
+            None => {
+                eprintln!("skipping post-processing test as no GPU adapter is available");
+                return;
+            }
+        };
+        let expected = 0.25 + 0.125 * n as f32;
+        assert_eq!(results, vec![(0.25, expected), (0.25, expected)]);
+    }
+}
@@ -0,0 +1,94 @@
+use nannou::geom::boolean::{FillRule, Shape, DEFAULT_TOLERANCE};
+use nannou::geom::{self, pt2, Rect};
+
+fn square(x: f32, y: f32, size: f32) -> Shape {
+    Shape::from_points(Rect::from_x_y_w_h(x, y, size, size).corners_iter())
+}
+
+fn approx_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+#[test]
+fn overlapping_squares() {
+    let a = square(0.0, 0.0, 2.0);
+    let b = square(1.0, 1.0, 2.0);
+    assert!(approx_eq(a.union(&b).area(), 7.0));
+    assert!(approx_eq(a.intersection(&b).area(), 1.0));
+    assert!(approx_eq(a.difference(&b).area(), 3.0));
+    assert!(approx_eq(a.xor(&b).area(), 6.0));
+    assert_eq!(a.union(&b).contours().len(), 1);
+    assert_eq!(a.xor(&b).contours().len(), 2);
+    assert!(a.intersection(&square(10.0, 0.0, 2.0)).is_empty());
+
+    let rect = a.intersection(&b).bounding_rect().unwrap();
+    assert!(approx_eq(rect.left(), 0.0) && approx_eq(rect.top(), 1.0));
+}
+
+#[test]
+fn holes_and_self_intersections() {
+    // A square with a hole, described by a path with two sub-paths.
+    let path = geom::path()
+        .begin(pt2(-2.0, -2.0))
+        .line_to(pt2(2.0, -2.0))
+        .line_to(pt2(2.0, 2.0))
+        .line_to(pt2(-2.0, 2.0))
+        .close()
+        .begin(pt2(-1.0, -1.0))
+        .line_to(pt2(1.0, -1.0))
+        .line_to(pt2(1.0, 1.0))
+        .line_to(pt2(-1.0, 1.0))
+        .close()
+        .build();
+    let frame = Shape::from_path(&path, DEFAULT_TOLERANCE);
+    let simplified = frame.simplify();
+    assert!(approx_eq(simplified.area(), 12.0));
+    assert_eq!(simplified.contours().len(), 2);
+    // Results may be converted back into paths.
+    let roundtrip = Shape::from_path(&simplified.to_path(), DEFAULT_TOLERANCE);
+    assert!(approx_eq(roundtrip.simplify().area(), 12.0));
+
+    // With the non-zero rule, the inner square winds the same way and so fills the hole.
+    assert!(approx_eq(
+        frame.clone().fill_rule(FillRule::NonZero).simplify().area(),
+        16.0
+    ));
+    // Filling the hole.
+    assert!(approx_eq(frame.union(&square(0.0, 0.0, 2.0)).area(), 16.0));
+    assert!(approx_eq(
+        frame.intersection(&square(0.0, 0.0, 2.0)).area(),
+        0.0
+    ));
+
+    // A self-intersecting bow-tie covers two triangles.
+    let bow_tie = Shape::from_points(vec![
+        pt2(-1.0, -1.0),
+        pt2(1.0, 1.0),
+        pt2(1.0, -1.0),
+        pt2(-1.0, 1.0),
+    ]);
+    assert!(approx_eq(bow_tie.area(), 0.0));
+    let simplified = bow_tie.simplify();
+    assert!(approx_eq(simplified.area(), 2.0));
+    assert!(approx_eq(
+        bow_tie.intersection(&square(0.0, 0.0, 2.0)).area(),
+        2.0
+    ));
+}
+
+#[test]
+fn precision_is_relative_to_the_bounds() {
+    // Detail well above the fixed point step of the combined bounds is preserved.
+    let large = square(0.0, 0.0, 10_000.0);
+    let small = square(1.0, 1.0, 0.01);
+    let diff = large.difference(&small);
+    assert_eq!(diff.contours().len(), 2);
+    assert!(((diff.area() - 1.0e8) / 1.0e8).abs() < 1e-6);
+    assert!((large.intersection(&small).area() - 1.0e-4).abs() < 1e-6);
+
+    // Detail far below the step is lost.
+    let huge = Shape::from_points(vec![pt2(-1e30, -1e30), pt2(1e30, -1e30), pt2(0.0, 1e30)]);
+    let unit = Shape::from_points(vec![pt2(0.0, 0.0), pt2(1.0, 0.0), pt2(0.0, 1.0)]);
+    assert_eq!(huge.difference(&unit).contours().len(), 1);
+    assert!(huge.intersection(&unit).is_empty());
+}
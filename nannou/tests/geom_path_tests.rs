@@ -0,0 +1,87 @@
+use nannou::geom::path::{self, DEFAULT_TOLERANCE};
+use nannou::geom::{pt2, vec2, Path, Vec2};
+
+fn approx_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+fn near(a: Vec2, b: Vec2) -> bool {
+    a.distance(b) < 1e-3
+}
+
+// A counter-clockwise 2x2 square centred on the origin.
+fn square() -> Path {
+    path::path()
+        .begin(pt2(-1.0, -1.0))
+        .line_to(pt2(1.0, -1.0))
+        .line_to(pt2(1.0, 1.0))
+        .line_to(pt2(-1.0, 1.0))
+        .close()
+        .build()
+}
+
+#[test]
+fn measure_and_sample() {
+    let square = square();
+    let measure = square.measure(DEFAULT_TOLERANCE);
+    assert!(approx_eq(measure.length(), 8.0));
+
+    let s = measure.sample(3.0).unwrap();
+    assert!(near(s.position, pt2(1.0, 0.0)));
+    assert!(near(s.tangent, vec2(0.0, 1.0)));
+    // Normals point outward for counter-clockwise paths.
+    assert!(near(s.normal, vec2(1.0, 0.0)));
+    assert!(approx_eq(s.angle(), std::f32::consts::FRAC_PI_2));
+
+    // The closing edge is included, and distances are clamped.
+    let s = measure.sample_normalized(0.9375).unwrap();
+    assert!(near(s.position, pt2(-1.0, -0.5)));
+    assert_eq!(measure.sample(100.0).unwrap().position, pt2(-1.0, -1.0));
+    assert!(Path::new().measure(DEFAULT_TOLERANCE).sample(0.0).is_none());
+
+    let samples: Vec<_> = measure.samples(5).map(|s| s.distance).collect();
+    assert_eq!(samples, [0.0, 2.0, 4.0, 6.0, 8.0]);
+
+    // Curves are measured within the tolerance.
+    let arc = path::path()
+        .begin(pt2(1.0, 0.0))
+        .quadratic_bezier_to(pt2(1.0, 1.0), pt2(0.0, 1.0))
+        .end()
+        .build();
+    let len = arc.measure(0.0001).length();
+    assert!(len > 2.0f32.sqrt() && len < 2.0);
+}
+
+#[test]
+fn resample_section_and_offset() {
+    let square = square();
+    let measure = square.measure(DEFAULT_TOLERANCE);
+
+    // Resampled paths retain their corners.
+    let resampled = measure.resample(0.5);
+    assert!(approx_eq(resampled.length(), 8.0));
+    assert_eq!(resampled.iter().count(), 1 + 15 + 1);
+
+    // Sections span corners.
+    let section = measure.section(1.0, 4.0);
+    assert!(approx_eq(section.length(), 3.0));
+    let section = section.measure(DEFAULT_TOLERANCE);
+    assert!(near(section.sample(0.0).unwrap().position, pt2(0.0, -1.0)));
+    assert!(near(section.sample(3.0).unwrap().position, pt2(1.0, 1.0)));
+
+    // Positive distances offset outward and negative distances offset inward.
+    assert!(approx_eq(square.offset(0.5).length(), 12.0));
+    assert!(approx_eq(square.offset(-0.5).length(), 4.0));
+    let corner = square.offset(0.5).measure(DEFAULT_TOLERANCE).sample(0.0);
+    assert!(near(corner.unwrap().position, pt2(-1.5, -1.5)));
+
+    // Open paths are offset without joining their ends.
+    let line = path::path()
+        .begin(pt2(0.0, 0.0))
+        .line_to(pt2(2.0, 0.0))
+        .end()
+        .build();
+    let offset = line.offset(1.0).measure(DEFAULT_TOLERANCE);
+    assert!(approx_eq(offset.length(), 2.0));
+    assert!(near(offset.sample(0.0).unwrap().position, pt2(0.0, -1.0)));
+}
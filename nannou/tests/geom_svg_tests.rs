@@ -0,0 +1,134 @@
+use nannou::color::{LinSrgba, Srgb};
+use nannou::geom::svg::{self, Document};
+use nannou::glam::{Mat4, Vec2, Vec3};
+use nannou::lyon::path::PathEvent;
+use nannou::lyon::tessellation::{FillRule, LineCap};
+
+fn endpoints(path: &nannou::geom::Path) -> Vec<(f32, f32)> {
+    path.iter()
+        .filter_map(|event| match event {
+            PathEvent::Begin { at } => Some((at.x, at.y)),
+            PathEvent::Line { to, .. } => Some((to.x, to.y)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn path_data() {
+    // Compact numbers, implicit line segments and relative commands.
+    let path = svg::parse_path_data("M10-5l5.5.5h-1V1e1zm1,1");
+    let points = endpoints(&path);
+    assert_eq!(
+        points,
+        vec![
+            (10.0, -5.0),
+            (15.5, -4.5),
+            (14.5, -4.5),
+            (14.5, 10.0),
+            (11.0, -4.0)
+        ],
+    );
+    let closes = path
+        .iter()
+        .filter(|e| matches!(e, PathEvent::End { close: true, .. }))
+        .count();
+    assert_eq!(closes, 1);
+
+    // Arc flags may be written without separators.
+    let path = svg::parse_path_data("M0 0a5 5 0 1110 0");
+    let last = path.iter().last().unwrap();
+    assert!(matches!(last, PathEvent::End { last, .. } if (last.x - 10.0).abs() < 1e-4));
+
+    // Data is read up until the first error.
+    let path = svg::parse_path_data("M0 0L1 1L2 x3 3");
+    assert_eq!(endpoints(&path), vec![(0.0, 0.0), (1.0, 1.0)]);
+}
+
+#[test]
+fn transforms() {
+    let t = svg::parse_transform("translate(10, 20) scale(2)");
+    assert_eq!(
+        t.transform_point3(Vec3::new(1.0, 1.0, 0.0)),
+        Vec3::new(12.0, 22.0, 0.0)
+    );
+    let t = svg::parse_transform("rotate(90 10 10)");
+    let p = t.transform_point3(Vec3::new(20.0, 10.0, 0.0));
+    assert!((p - Vec3::new(10.0, 20.0, 0.0)).length() < 1e-4);
+    let t = svg::parse_transform("matrix(1 0 0 1 5 6)");
+    assert_eq!(t, Mat4::from_translation(Vec3::new(5.0, 6.0, 0.0)));
+}
+
+#[test]
+fn colors() {
+    let orange = Srgb::new(1.0, 136.0 / 255.0, 0.0);
+    assert_eq!(svg::parse_color("#f80"), Some(orange));
+    assert_eq!(svg::parse_color("#FF8800"), Some(orange));
+    assert_eq!(svg::parse_color("rgb(255, 136, 0)"), Some(orange));
+    assert_eq!(
+        svg::parse_color("rgb(100%,0%,0%)"),
+        Some(Srgb::new(1.0, 0.0, 0.0))
+    );
+    assert_eq!(svg::parse_color("blurple"), None);
+    assert_eq!(
+        svg::parse_color("SteelBlue"),
+        Some(Srgb::new(70u8, 130, 180).into_format())
+    );
+    assert_eq!(svg::parse_color("#12"), None);
+}
+
+#[test]
+fn document_styles_and_structure() {
+    let doc = Document::parse(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="2in" height="100" viewBox="0 0 20 10">
+            <defs><rect id="hidden" width="5" height="5"/></defs>
+            <g fill="red" opacity="0.5" stroke-width="2" transform="translate(1 2)">
+                <rect id="a" width="4" height="3" stroke="blue" style="stroke-linecap:round"/>
+                <circle id="b" r="2" fill-rule="evenodd" style="fill: none; stroke: #000"
+                        stroke-dasharray="1 2 3"/>
+                <polygon id="c" points="0,0 1,0 1,1" visibility="hidden"/>
+                <g display="none"><rect width="1" height="1"/></g>
+            </g>
+            <line id="d" x1="0" y1="0" x2="5" y2="5" fill="url(#g)"/>
+        </svg>"##,
+    )
+    .unwrap();
+    assert_eq!(doc.size, Vec2::new(192.0, 100.0));
+    assert_eq!(doc.view_box.x.start, 0.0);
+    assert_eq!(doc.view_box.wh(), Vec2::new(20.0, 10.0));
+    let ids: Vec<_> = doc
+        .shapes
+        .iter()
+        .map(|s| s.id.as_deref().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["a", "b", "d"]);
+
+    let a = doc.shape("a").unwrap();
+    let fill = a.fill.unwrap();
+    assert_eq!(fill.color, LinSrgba::new(1.0, 0.0, 0.0, 0.5));
+    assert_eq!(fill.rule, FillRule::NonZero);
+    let stroke = a.stroke.as_ref().unwrap();
+    assert_eq!(stroke.color, LinSrgba::new(0.0, 0.0, 1.0, 0.5));
+    assert_eq!(stroke.options.line_width, 2.0);
+    assert_eq!(stroke.options.start_cap, LineCap::Round);
+    assert_eq!(
+        a.transform,
+        Mat4::from_translation(Vec3::new(1.0, 2.0, 0.0))
+    );
+    assert_eq!(
+        endpoints(&a.path),
+        vec![(0.0, 0.0), (4.0, 0.0), (4.0, 3.0), (0.0, 3.0), (0.0, 0.0)],
+    );
+
+    let b = doc.shape("b").unwrap();
+    assert!(b.fill.is_none());
+    assert_eq!(b.stroke.as_ref().unwrap().dash_array, vec![1.0, 2.0, 3.0]);
+
+    // Unsupported paint servers are treated as `none`, while the default fill is black.
+    let d = doc.shape("d").unwrap();
+    assert!(d.fill.is_none() && d.stroke.is_none());
+
+    // The view box is centred on the origin with y pointing up and scaled to fit the size.
+    let p = doc.transform().transform_point3(Vec3::new(20.0, 10.0, 0.0));
+    assert_eq!(p, Vec3::new(96.0, -48.0, 0.0));
+}
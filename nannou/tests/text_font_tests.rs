@@ -0,0 +1,76 @@
+use nannou::geom::Rect;
+use nannou::text::font::{Database, Family, Query, Style, Weight};
+use nannou::text::{self, font};
+
+fn assets_db() -> Database {
+    let mut db = Database::new();
+    db.load_fonts_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/fonts"));
+    db
+}
+
+#[test]
+fn database_matches_queries() {
+    let db = assets_db();
+    assert_eq!(db.faces().len(), 4);
+    assert_eq!(db.families(), ["Noto Sans"]);
+
+    let face = |query: Query| db.query(&query).map(|f| (f.weight, f.style));
+    let noto = || Query::new().family("noto sans");
+    assert_eq!(face(noto()), Some((Weight::NORMAL, Style::Normal)));
+    assert_eq!(
+        face(noto().bold().italic()),
+        Some((Weight::BOLD, Style::Italic))
+    );
+    assert_eq!(
+        face(noto().style(Style::Oblique)),
+        Some((Weight::NORMAL, Style::Italic))
+    );
+    // Heavier weights are preferred above 500, and lighter weights below 400.
+    assert_eq!(
+        face(noto().weight(Weight::SEMI_BOLD)),
+        Some((Weight::BOLD, Style::Normal))
+    );
+    assert_eq!(
+        face(noto().weight(Weight::LIGHT)),
+        Some((Weight::NORMAL, Style::Normal))
+    );
+    assert_eq!(
+        face(noto().weight(Weight::BLACK)),
+        Some((Weight::BOLD, Style::Normal))
+    );
+
+    // Families are tried in order.
+    let query = Query::new()
+        .family("Missing Sans")
+        .family(Family::SansSerif);
+    assert_eq!(db.query(&query).map(|f| &f.family[..]), Some("Noto Sans"));
+    assert!(db.query(&Query::new().family("Missing Sans")).is_none());
+    assert!(db.font(&Query::new().family(Family::Serif)).is_err());
+
+    // Loaded fonts may be shaped.
+    let font = db.font(&noto().bold()).unwrap();
+    assert!(font.data().is_some());
+}
+
+#[test]
+fn fallback_fonts_display_missing_characters() {
+    // Find an installed font with chess piece glyphs, skipping the test if there are none.
+    let db = Database::system();
+    let fallback = match db.font(&Query::new().family(Family::SansSerif)) {
+        Ok(f) if font::has_glyph(&f, '♞') => f,
+        _ => return,
+    };
+    let primary = font::default_notosans();
+    assert!(!font::has_glyph(&primary, '♞'));
+
+    let rect = Rect::from_w_h(400.0, 200.0);
+    let text = text::text("abc ♞♞ def")
+        .font(primary.clone())
+        .fallback_font(fallback.clone())
+        .build(rect);
+    let runs = text.runs();
+    let fonts: Vec<_> = runs.iter().map(|r| font::id(&r.font)).collect();
+    let (primary, fallback) = (font::id(&primary), font::id(&fallback));
+    assert_eq!(fonts, [primary, fallback, primary]);
+    assert_eq!(&text.text()[runs[1].byte_range.clone()], "♞♞ ");
+}
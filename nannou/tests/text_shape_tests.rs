@@ -0,0 +1,72 @@
+use nannou::geom::Rect;
+use nannou::text::{self, shape::Direction};
+
+fn font() -> text::Font {
+    text::font::default_notosans()
+}
+
+#[test]
+fn shaping_applies_kerning() {
+    let font = font();
+    let width = |s: &str| {
+        let glyphs = text::shape::shape(s, &font, 48, Direction::LeftToRight)
+            .expect("the default font's data should be available for shaping");
+        text::shape::advance_width(&glyphs)
+    };
+    assert!(width("AV") < width("A") + width("V"));
+
+    // Line widths are measured with the shaped advances.
+    let text = text::text("AV")
+        .font(font.clone())
+        .font_size(48)
+        .build(Rect::from_w_h(400.0, 200.0));
+    assert!((text.width() - width("AV")).abs() < 1e-3);
+}
+
+#[test]
+fn shaping_data_belongs_to_each_font() {
+    let font = font();
+    let shape = |f: &text::Font| text::shape::shape("AV", f, 48, Direction::LeftToRight);
+    assert!(shape(&font).is_some());
+    assert_eq!(font.data().map(|(_, index)| index), Some(0));
+
+    // Fonts sharing the same names do not share data.
+    let without_data = text::Font::from(font.rusttype().clone());
+    assert!(without_data.data().is_none());
+    assert!(shape(&without_data).is_none());
+    let invalid_data = text::Font::with_data(font.rusttype().clone(), vec![0u8; 64], 0);
+    assert!(invalid_data.data().is_none());
+    assert!(shape(&invalid_data).is_none());
+
+    // Unshaped fonts are still laid out glyph by glyph.
+    let glyphs = text::shape::glyphs("AV", &without_data, 48, Direction::LeftToRight);
+    assert_eq!(glyphs.len(), 2);
+}
+
+#[test]
+fn runs_are_ordered_visually() {
+    let rect = Rect::from_w_h(400.0, 200.0);
+    let text = text::text("abc אבג def")
+        .font(font())
+        .font_size(16)
+        .build(rect);
+    let runs = text.runs();
+    let run_texts: Vec<_> = runs
+        .iter()
+        .map(|r| &text.text()[r.byte_range.clone()])
+        .collect();
+    assert_eq!(run_texts, ["abc ", "אבג", " def"]);
+    assert_eq!(runs[1].direction, Direction::RightToLeft);
+    assert_eq!(runs[1].start_char, 4);
+    for w in runs.windows(2) {
+        assert!((w[0].rect.x.end - w[1].rect.x.start).abs() < 1e-3);
+    }
+
+    // The glyphs of right-to-left runs begin with the last character.
+    let chars: Vec<_> = text
+        .run_glyphs(&runs[1])
+        .into_iter()
+        .map(|(c, _, _)| c)
+        .collect();
+    assert_eq!(chars, [6, 5, 4]);
+}
@@ -0,0 +1,83 @@
+use nannou::geom::Rect;
+use nannou::text::{self, Span};
+
+fn font() -> text::Font {
+    text::font::default_notosans()
+}
+
+#[test]
+fn text_without_spans_has_a_run_per_line() {
+    let rect = Rect::from_w_h(400.0, 200.0);
+    let text = text::text("hello\nworld")
+        .font(font())
+        .font_size(16)
+        .build(rect);
+    let runs = text.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(text.line_font_sizes(), &[16, 16]);
+    assert_eq!(text.height_by_lines(), text::height_by_lines(2, 16, 0.0));
+    for ((run, info), line_rect) in runs.iter().zip(text.line_infos()).zip(text.line_rects()) {
+        assert_eq!(run.byte_range, info.byte_range());
+        assert_eq!(run.rect.x.start, line_rect.x.start);
+        assert!((run.rect.w() - info.width).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn spans_split_runs_and_grow_lines() {
+    let rect = Rect::from_w_h(400.0, 200.0);
+    let text = text::text("Temp: 23°C\nnext")
+        .font(font())
+        .font_size(12)
+        .span(Span::new(6..12).font_size(24).bold())
+        .build(rect);
+    let runs = text.runs();
+    assert_eq!(runs.len(), 3);
+    assert_eq!(&text.text()[runs[1].byte_range.clone()], "23°C");
+    assert_eq!(runs[1].font_size, 24);
+    assert!(runs[1].bold && !runs[0].bold);
+    assert_eq!(runs[1].start_char, 6);
+    assert_eq!(runs[1].rect.x.start, runs[0].rect.x.end);
+
+    // The first line is as tall as its largest span.
+    assert_eq!(text.line_font_sizes(), &[24, 12]);
+    assert_eq!(text.height_by_lines(), 36.0);
+    let line_rects: Vec<_> = text.line_rects().collect();
+    assert_eq!(line_rects[0].h(), 24.0);
+    assert_eq!(line_rects[1].h(), 12.0);
+    assert_eq!(line_rects[0].bottom(), line_rects[1].top());
+
+    // The line width accounts for the larger glyphs.
+    let width = runs[0].rect.w() + runs[1].rect.w();
+    assert!((text.line_infos()[0].width - width).abs() < 1e-3);
+}
+
+#[test]
+fn larger_spans_wrap_sooner() {
+    let rect = Rect::from_w_h(120.0, 200.0);
+    let s = "one two three four";
+    let plain = text::text(s).font(font()).font_size(12).build(rect);
+    let styled = text::text(s)
+        .font(font())
+        .font_size(12)
+        .span(Span::new(..).font_size(24))
+        .build(rect);
+    assert!(styled.num_lines() > plain.num_lines());
+    for info in styled.line_infos() {
+        assert!(info.width <= rect.w());
+    }
+}
+
+#[test]
+fn span_ranges_are_clamped_to_char_boundaries() {
+    let rect = Rect::from_w_h(400.0, 200.0);
+    // Byte 9 falls within the two byte '°'.
+    let text = text::text("Temp: 23°C")
+        .font(font())
+        .span(Span::new(9..100).italic())
+        .build(rect);
+    let runs = text.runs();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[1].byte_range, 8..11);
+    assert!(runs[1].italic);
+}
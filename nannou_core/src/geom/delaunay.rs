@@ -0,0 +1,504 @@
+//! Delaunay triangulation of a set of points.
+//!
+//! The triangulation is produced via a sweep-hull algorithm in `O(n log n)` time. Triangles are
+//! described by indices into the triangulated points, wound counter-clockwise, and connected by
+//! their *halfedges*. Halfedge `e` belongs to triangle `e / 3` and runs from vertex `e` to the
+//! following vertex of its triangle.
+//!
+//! Requires the `alloc` feature.
+//!
+//! ## License
+//!
+//! The sweep-hull algorithm, including its hull hash, pseudo-angles and edge legalization, is a
+//! port of mapbox's [delaunator](https://github.com/mapbox/delaunator), which is distributed under
+//! the following license:
+//!
+//! ```text
+//! ISC License
+//!
+//! Copyright (c) 2017, Mapbox
+//!
+//! Permission to use, copy, modify, and/or distribute this software for any purpose
+//! with or without fee is hereby granted, provided that the above copyright notice
+//! and this permission notice appear in all copies.
+//!
+//! THE SOFTWARE IS PROVIDED "AS IS" AND ISC DISCLAIMS ALL WARRANTIES WITH REGARD TO
+//! THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS.
+//! IN NO EVENT SHALL ISC BE LIABLE FOR ANY SPECIAL, DIRECT, INDIRECT, OR
+//! CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM LOSS OF USE, DATA
+//! OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//! ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! ```
+
+use crate::geom::{Point2, Tri};
+use crate::math::num_traits::Float;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Marks a halfedge on the convex hull, which has no opposite halfedge.
+pub const EMPTY: usize = usize::MAX;
+
+/// The Delaunay triangulation of a set of points.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Triangulation {
+    points: Vec<Point2>,
+    triangles: Vec<usize>,
+    halfedges: Vec<usize>,
+    hull: Vec<usize>,
+}
+
+// The state of the triangulation during the sweep.
+struct Sweep<'a> {
+    coords: &'a [[f64; 2]],
+    triangles: Vec<usize>,
+    halfedges: Vec<usize>,
+    hull_next: Vec<usize>,
+    hull_prev: Vec<usize>,
+    hull_tri: Vec<usize>,
+    hull_hash: Vec<usize>,
+    center: [f64; 2],
+    stack: Vec<usize>,
+}
+
+impl Triangulation {
+    /// Triangulate the given points.
+    ///
+    /// Points that coincide with a previous point are excluded from the triangulation. If all
+    /// points are collinear there are no triangles, and the hull contains every distinct point in
+    /// order along the line.
+    pub fn new(points: &[Point2]) -> Self {
+        let coords: Vec<[f64; 2]> = points.iter().map(|p| [p.x as f64, p.y as f64]).collect();
+        let (triangles, halfedges, hull) = triangulate_coords(&coords);
+        Triangulation {
+            points: points.to_vec(),
+            triangles,
+            halfedges,
+            hull,
+        }
+    }
+
+    /// The triangulated points.
+    pub fn points(&self) -> &[Point2] {
+        &self.points
+    }
+
+    /// The number of triangles.
+    pub fn len(&self) -> usize {
+        self.triangles.len() / 3
+    }
+
+    /// Whether or not the triangulation has no triangles.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// The indices of the vertices of each triangle, three per triangle.
+    ///
+    /// These may be used directly as the indices of a mesh whose vertices are the triangulated
+    /// points.
+    pub fn indices(&self) -> &[usize] {
+        &self.triangles
+    }
+
+    /// The index of the opposite halfedge of each halfedge, or `EMPTY` for halfedges on the hull.
+    pub fn halfedges(&self) -> &[usize] {
+        &self.halfedges
+    }
+
+    /// The indices of the points on the convex hull, in counter-clockwise order.
+    pub fn hull(&self) -> &[usize] {
+        &self.hull
+    }
+
+    /// The indices of the vertices of each triangle.
+    pub fn triangle_indices(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.triangles.chunks_exact(3).map(|t| [t[0], t[1], t[2]])
+    }
+
+    /// Each triangle of the triangulation.
+    pub fn triangles(&self) -> impl Iterator<Item = Tri<Point2>> + '_ {
+        self.triangle_indices()
+            .map(move |[a, b, c]| Tri([self.points[a], self.points[b], self.points[c]]))
+    }
+
+    /// The centre of the circumcircle of each triangle.
+    ///
+    /// These are the vertices of the Voronoi diagram of the points.
+    pub fn circumcenters(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.triangles().map(|Tri([a, b, c])| {
+            let [x, y] = circumcenter(
+                [a.x as f64, a.y as f64],
+                [b.x as f64, b.y as f64],
+                [c.x as f64, c.y as f64],
+            );
+            Point2::new(x as f32, y as f32)
+        })
+    }
+
+    /// The indices of the points connected to each point by an edge of the triangulation.
+    ///
+    /// Points excluded from the triangulation have no neighbours.
+    pub fn neighbours(&self) -> Vec<Vec<usize>> {
+        let mut neighbours = vec![vec![]; self.points.len()];
+        if self.triangles.is_empty() {
+            for w in self.hull.windows(2) {
+                neighbours[w[0]].push(w[1]);
+                neighbours[w[1]].push(w[0]);
+            }
+            return neighbours;
+        }
+        for (e, &a) in self.triangles.iter().enumerate() {
+            let b = self.triangles[next_halfedge(e)];
+            neighbours[a].push(b);
+            // Edges on the hull have no opposite halfedge to visit.
+            if self.halfedges[e] == EMPTY {
+                neighbours[b].push(a);
+            }
+        }
+        neighbours
+    }
+}
+
+/// Produce the Delaunay triangulation of the given points.
+///
+/// See `Triangulation::new`.
+pub fn triangulate(points: &[Point2]) -> Triangulation {
+    Triangulation::new(points)
+}
+
+/// The index of the halfedge following the given halfedge within its triangle.
+pub fn next_halfedge(e: usize) -> usize {
+    match e % 3 {
+        2 => e - 2,
+        _ => e + 1,
+    }
+}
+
+/// The index of the halfedge preceding the given halfedge within its triangle.
+pub fn prev_halfedge(e: usize) -> usize {
+    match e % 3 {
+        0 => e + 2,
+        _ => e - 1,
+    }
+}
+
+impl<'a> Sweep<'a> {
+    fn add_triangle(&mut self, i0: usize, i1: usize, i2: usize, a: usize, b: usize, c: usize) {
+        let t = self.triangles.len();
+        self.triangles.extend_from_slice(&[i0, i1, i2]);
+        self.halfedges.extend_from_slice(&[EMPTY; 3]);
+        self.link(t, a);
+        self.link(t + 1, b);
+        self.link(t + 2, c);
+    }
+
+    fn link(&mut self, a: usize, b: usize) {
+        self.halfedges[a] = b;
+        if b != EMPTY {
+            self.halfedges[b] = a;
+        }
+    }
+
+    fn hash_key(&self, p: [f64; 2]) -> usize {
+        let angle = pseudo_angle(p[0] - self.center[0], p[1] - self.center[1]);
+        let len = self.hull_hash.len();
+        (Float::floor(angle * len as f64) as usize) % len
+    }
+
+    // Restore the Delaunay condition by flipping edges, starting with the given halfedge, which
+    // must lie opposite the most recently inserted point.
+    fn legalize(&mut self, a: usize) {
+        self.stack.push(a);
+        while let Some(a) = self.stack.pop() {
+            let b = self.halfedges[a];
+            if b == EMPTY {
+                continue;
+            }
+
+            // Triangle `(u, v, p0)` shares edge `a` from `u` to `v` with triangle `(v, u, p1)`.
+            let (al, ar) = (next_halfedge(a), prev_halfedge(a));
+            let bl = prev_halfedge(b);
+            let p0 = self.triangles[ar];
+            let u = self.triangles[a];
+            let v = self.triangles[al];
+            let p1 = self.triangles[bl];
+            let c = self.coords;
+            if !in_circle(c[u], c[v], c[p0], c[p1]) {
+                continue;
+            }
+
+            // Flip the shared edge, producing triangles `(p1, v, p0)` and `(p0, u, p1)`.
+            self.triangles[a] = p1;
+            self.triangles[b] = p0;
+            let hbl = self.halfedges[bl];
+            let har = self.halfedges[ar];
+            self.link(a, hbl);
+            self.link(b, har);
+            self.link(ar, bl);
+
+            // Edges on the hull moved to a different halfedge.
+            if hbl == EMPTY {
+                self.hull_tri[p1] = a;
+            }
+            if har == EMPTY {
+                self.hull_tri[p0] = b;
+            }
+
+            self.stack.push(a);
+            self.stack.push(next_halfedge(b));
+        }
+    }
+}
+
+fn triangulate_coords(coords: &[[f64; 2]]) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    let n = coords.len();
+    if n == 0 {
+        return (vec![], vec![], vec![]);
+    }
+
+    // Seed the triangulation with the smallest circle near the centre of the points.
+    let (mut min, mut max) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+    for p in coords {
+        min = [min[0].min(p[0]), min[1].min(p[1])];
+        max = [max[0].max(p[0]), max[1].max(p[1])];
+    }
+    let mid = [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5];
+    let closest = |p: [f64; 2], skip: &[usize]| {
+        (0..n)
+            .filter(|i| !skip.contains(i) && dist2(p, coords[*i]) > 0.0)
+            .min_by(|&a, &b| cmp_f64(dist2(p, coords[a]), dist2(p, coords[b])))
+    };
+    let i0 = (0..n)
+        .min_by(|&a, &b| cmp_f64(dist2(mid, coords[a]), dist2(mid, coords[b])))
+        .unwrap();
+    let seed = closest(coords[i0], &[i0]).and_then(|i1| {
+        let (p0, p1) = (coords[i0], coords[i1]);
+        let i2 = (0..n)
+            .filter(|&i| i != i0 && i != i1)
+            .map(|i| (i, circumradius2(p0, p1, coords[i])))
+            .filter(|(_, r)| r.is_finite())
+            .min_by(|a, b| cmp_f64(a.1, b.1))?
+            .0;
+        Some((i1, i2))
+    });
+    let (mut i1, mut i2) = match seed {
+        Some(seed) => seed,
+        None => return (vec![], vec![], collinear_hull(coords)),
+    };
+    if orient(coords[i0], coords[i1], coords[i2]) < 0.0 {
+        core::mem::swap(&mut i1, &mut i2);
+    }
+    let center = circumcenter(coords[i0], coords[i1], coords[i2]);
+
+    // Sweep the remaining points in order of distance from the seed circle.
+    let mut ids: Vec<usize> = (0..n).collect();
+    let dists: Vec<f64> = coords.iter().map(|&p| dist2(center, p)).collect();
+    ids.sort_unstable_by(|&a, &b| cmp_f64(dists[a], dists[b]));
+
+    let hash_size = Float::ceil(Float::sqrt(n as f64)) as usize;
+    let max_triangles = 2 * n - 5;
+    let mut s = Sweep {
+        coords,
+        triangles: Vec::with_capacity(max_triangles * 3),
+        halfedges: Vec::with_capacity(max_triangles * 3),
+        hull_next: vec![0; n],
+        hull_prev: vec![0; n],
+        hull_tri: vec![0; n],
+        hull_hash: vec![EMPTY; hash_size],
+        center,
+        stack: vec![],
+    };
+
+    // The hull is a counter-clockwise cycle, where `hull_tri` refers to the halfedge of the edge
+    // from each hull point to the next.
+    let mut hull_start = i0;
+    s.hull_next[i0] = i1;
+    s.hull_prev[i2] = i1;
+    s.hull_next[i1] = i2;
+    s.hull_prev[i0] = i2;
+    s.hull_next[i2] = i0;
+    s.hull_prev[i1] = i0;
+    s.hull_tri[i0] = 0;
+    s.hull_tri[i1] = 1;
+    s.hull_tri[i2] = 2;
+    for &i in &[i0, i1, i2] {
+        let key = s.hash_key(coords[i]);
+        s.hull_hash[key] = i;
+    }
+    s.add_triangle(i0, i1, i2, EMPTY, EMPTY, EMPTY);
+
+    let mut prev: Option<[f64; 2]> = None;
+    for &i in &ids {
+        let p = coords[i];
+
+        // Skip points coinciding with the previous point or the seed triangle.
+        if prev.map(|q| dist2(p, q) <= f64::EPSILON).unwrap_or(false) {
+            continue;
+        }
+        prev = Some(p);
+        if i == i0 || i == i1 || i == i2 {
+            continue;
+        }
+
+        // Find the first edge of the hull visible from the point, starting near its angle.
+        let key = s.hash_key(p);
+        let mut start = 0;
+        for j in 0..hash_size {
+            start = s.hull_hash[(key + j) % hash_size];
+            if start != EMPTY && start != s.hull_next[start] {
+                break;
+            }
+        }
+        start = s.hull_prev[start];
+        let mut e = start;
+        loop {
+            let q = s.hull_next[e];
+            if orient(coords[e], coords[q], p) < 0.0 {
+                break;
+            }
+            e = q;
+            if e == start {
+                e = EMPTY;
+                break;
+            }
+        }
+        // The point lies on the hull, likely near a duplicate.
+        if e == EMPTY {
+            continue;
+        }
+
+        // Add the triangle between the point and the visible edge.
+        let t = s.triangles.len();
+        let q = s.hull_next[e];
+        s.add_triangle(e, i, q, EMPTY, EMPTY, s.hull_tri[e]);
+        s.hull_tri[e] = t;
+        s.hull_tri[i] = t + 1;
+        s.legalize(t + 2);
+
+        // Walk forward through the hull, adding triangles for each visible edge.
+        let mut n = q;
+        loop {
+            let q = s.hull_next[n];
+            if orient(coords[n], coords[q], p) >= 0.0 {
+                break;
+            }
+            let t = s.triangles.len();
+            s.add_triangle(n, i, q, s.hull_tri[i], EMPTY, s.hull_tri[n]);
+            s.hull_tri[i] = t + 1;
+            s.legalize(t + 2);
+            s.hull_next[n] = n;
+            n = q;
+        }
+
+        // Walk backward from the first visible edge.
+        if e == start {
+            loop {
+                let q = s.hull_prev[e];
+                if orient(coords[q], coords[e], p) >= 0.0 {
+                    break;
+                }
+                let t = s.triangles.len();
+                s.add_triangle(q, i, e, EMPTY, s.hull_tri[e], s.hull_tri[q]);
+                s.hull_tri[q] = t;
+                s.legalize(t + 2);
+                s.hull_next[e] = e;
+                e = q;
+            }
+        }
+
+        // Update the hull.
+        hull_start = e;
+        s.hull_prev[i] = e;
+        s.hull_next[e] = i;
+        s.hull_prev[n] = i;
+        s.hull_next[i] = n;
+        let key = s.hash_key(p);
+        s.hull_hash[key] = i;
+        let key = s.hash_key(coords[e]);
+        s.hull_hash[key] = e;
+    }
+
+    let mut hull = vec![hull_start];
+    let mut e = s.hull_next[hull_start];
+    while e != hull_start {
+        hull.push(e);
+        e = s.hull_next[e];
+    }
+    (s.triangles, s.halfedges, hull)
+}
+
+// The distinct points ordered along the line through all points.
+fn collinear_hull(coords: &[[f64; 2]]) -> Vec<usize> {
+    let origin = coords[0];
+    let far = coords
+        .iter()
+        .cloned()
+        .max_by(|&a, &b| cmp_f64(dist2(origin, a), dist2(origin, b)))
+        .unwrap();
+    let dir = [far[0] - origin[0], far[1] - origin[1]];
+    let project = |p: [f64; 2]| (p[0] - origin[0]) * dir[0] + (p[1] - origin[1]) * dir[1];
+    let mut ids: Vec<usize> = (0..coords.len()).collect();
+    ids.sort_by(|&a, &b| cmp_f64(project(coords[a]), project(coords[b])));
+    ids.dedup_by(|a, b| coords[*a] == coords[*b]);
+    ids
+}
+
+fn cmp_f64(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+fn dist2(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let (dx, dy) = (a[0] - b[0], a[1] - b[1]);
+    dx * dx + dy * dy
+}
+
+// Positive if `c` lies to the left of the line from `a` to `b`.
+fn orient(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+// Whether `d` lies within the circumcircle of the counter-clockwise triangle `abc`.
+fn in_circle(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> bool {
+    let (adx, ady) = (a[0] - d[0], a[1] - d[1]);
+    let (bdx, bdy) = (b[0] - d[0], b[1] - d[1]);
+    let (cdx, cdy) = (c[0] - d[0], c[1] - d[1]);
+    let ad = adx * adx + ady * ady;
+    let bd = bdx * bdx + bdy * bdy;
+    let cd = cdx * cdx + cdy * cdy;
+    let det =
+        ad * (bdx * cdy - cdx * bdy) - bd * (adx * cdy - cdx * ady) + cd * (adx * bdy - bdx * ady);
+    det > 0.0
+}
+
+// The offset from `a` to the circumcenter of `abc`, or `None` if the points are collinear.
+fn circumcenter_offset(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Option<[f64; 2]> {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let (ex, ey) = (c[0] - a[0], c[1] - a[1]);
+    let det = dx * ey - dy * ex;
+    if det == 0.0 {
+        return None;
+    }
+    let (bl, cl) = (dx * dx + dy * dy, ex * ex + ey * ey);
+    let d = 0.5 / det;
+    Some([(ey * bl - dy * cl) * d, (dx * cl - ex * bl) * d])
+}
+
+fn circumradius2(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    match circumcenter_offset(a, b, c) {
+        Some([x, y]) => x * x + y * y,
+        None => f64::INFINITY,
+    }
+}
+
+fn circumcenter(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> [f64; 2] {
+    let [x, y] = circumcenter_offset(a, b, c).unwrap_or([0.0; 2]);
+    [a[0] + x, a[1] + y]
+}
+
+// A value in `0..1` that increases monotonically with the angle of the given vector.
+fn pseudo_angle(dx: f64, dy: f64) -> f64 {
+    let p = dx / (Float::abs(dx) + Float::abs(dy));
+    let a = if dy > 0.0 { 3.0 - p } else { 1.0 + p };
+    a / 4.0
+}
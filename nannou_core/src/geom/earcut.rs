@@ -0,0 +1,593 @@
+//! Triangulation of concave polygons with holes via ear clipping.
+//!
+//! Unlike the fan produced by `polygon::triangles`, which is only correct for convex polygons,
+//! these functions handle concave polygons, holes, and to some extent degenerate and
+//! self-intersecting outlines.
+//!
+//! A polygon is described by a single slice of points, where the outer ring is followed by each of
+//! its holes. The index of the first point of each hole is given separately. Rings may be wound in
+//! either direction, and should not repeat their first point.
+//!
+//! Requires the `alloc` feature.
+//!
+//! ## License
+//!
+//! The implementation is a port of mapbox's [earcut](https://github.com/mapbox/earcut), which is
+//! distributed under the following license:
+//!
+//! ```text
+//! ISC License
+//!
+//! Copyright (c) 2016, Mapbox
+//!
+//! Permission to use, copy, modify, and/or distribute this software for any purpose
+//! with or without fee is hereby granted, provided that the above copyright notice
+//! and this permission notice appear in all copies.
+//!
+//! THE SOFTWARE IS PROVIDED "AS IS" AND ISC DISCLAIMS ALL WARRANTIES WITH REGARD TO
+//! THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS.
+//! IN NO EVENT SHALL ISC BE LIABLE FOR ANY SPECIAL, DIRECT, INDIRECT, OR
+//! CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM LOSS OF USE, DATA
+//! OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//! ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//! ```
+
+use crate::geom::{Tri, Vertex2d};
+use crate::math::num_traits::{cast, Float, NumCast};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+// A vertex of a ring, as a node within a circular doubly linked list.
+#[derive(Clone, Debug)]
+struct Node {
+    // The index of the vertex within the input points.
+    i: usize,
+    x: f64,
+    y: f64,
+    prev: usize,
+    next: usize,
+    // Whether the node is a lone point within a hole, which must be retained.
+    steiner: bool,
+}
+
+#[derive(Default)]
+struct Earcut {
+    nodes: Vec<Node>,
+    triangles: Vec<usize>,
+}
+
+/// Triangulate the polygon described by the given points and hole indices.
+///
+/// The points from each index within `holes` onwards describe a hole, with the points prior to the
+/// first hole describing the outer ring. Returns the indices of the points of each triangle, three
+/// per triangle, wound counter-clockwise.
+///
+/// The indices may be used directly with a mesh whose vertices are the given points, e.g. via
+/// `nannou_mesh::with_indices`.
+pub fn triangle_indices<V>(points: &[V], holes: &[usize]) -> Vec<usize>
+where
+    V: Vertex2d,
+    V::Scalar: NumCast,
+{
+    let coords: Vec<[f64; 2]> = points
+        .iter()
+        .map(|&v| {
+            let [x, y] = v.point2();
+            let x = cast(x).unwrap_or(f64::NAN);
+            let y = cast(y).unwrap_or(f64::NAN);
+            [x, y]
+        })
+        .collect();
+    let mut earcut = Earcut::default();
+    earcut.run(&coords, holes);
+    earcut.triangles
+}
+
+/// Triangulate the polygon described by the given points and hole indices, producing each
+/// triangle.
+///
+/// See `triangle_indices`.
+pub fn triangles<'a, V>(points: &'a [V], holes: &[usize]) -> impl Iterator<Item = Tri<V>> + 'a
+where
+    V: Vertex2d,
+    V::Scalar: NumCast,
+{
+    let indices = triangle_indices(points, holes);
+    (0..indices.len() / 3).map(move |t| {
+        let i = &indices[t * 3..t * 3 + 3];
+        Tri([points[i[0]], points[i[1]], points[i[2]]])
+    })
+}
+
+impl Earcut {
+    fn run(&mut self, coords: &[[f64; 2]], holes: &[usize]) {
+        let outer_end = holes.first().cloned().unwrap_or(coords.len());
+        let mut outer = match self.linked_list(coords, 0, outer_end, true) {
+            Some(node) => node,
+            None => return,
+        };
+        if self.next(outer) == self.prev(outer) {
+            return;
+        }
+        if !holes.is_empty() {
+            outer = self.eliminate_holes(coords, holes, outer);
+        }
+        self.earcut_linked(outer, 0);
+    }
+
+    fn next(&self, n: usize) -> usize {
+        self.nodes[n].next
+    }
+
+    fn prev(&self, n: usize) -> usize {
+        self.nodes[n].prev
+    }
+
+    fn xy(&self, n: usize) -> [f64; 2] {
+        [self.nodes[n].x, self.nodes[n].y]
+    }
+
+    fn equals(&self, a: usize, b: usize) -> bool {
+        self.xy(a) == self.xy(b)
+    }
+
+    fn area(&self, p: usize, q: usize, r: usize) -> f64 {
+        area(self.xy(p), self.xy(q), self.xy(r))
+    }
+
+    fn insert_node(&mut self, i: usize, [x, y]: [f64; 2], last: Option<usize>) -> usize {
+        let n = self.nodes.len();
+        let (prev, next) = match last {
+            None => (n, n),
+            Some(last) => (last, self.next(last)),
+        };
+        self.nodes.push(Node {
+            i,
+            x,
+            y,
+            prev,
+            next,
+            steiner: false,
+        });
+        if let Some(last) = last {
+            self.nodes[next].prev = n;
+            self.nodes[last].next = n;
+        }
+        n
+    }
+
+    fn remove_node(&mut self, n: usize) {
+        let (prev, next) = (self.prev(n), self.next(n));
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+    }
+
+    // Create a circular linked list from the given range of points, in the specified winding.
+    fn linked_list(
+        &mut self,
+        coords: &[[f64; 2]],
+        start: usize,
+        end: usize,
+        clockwise: bool,
+    ) -> Option<usize> {
+        let end = end.min(coords.len());
+        if start >= end {
+            return None;
+        }
+        let mut last = None;
+        if clockwise == (signed_area(&coords[start..end]) > 0.0) {
+            for (i, &p) in coords.iter().enumerate().take(end).skip(start) {
+                last = Some(self.insert_node(i, p, last));
+            }
+        } else {
+            for (i, &p) in coords.iter().enumerate().take(end).skip(start).rev() {
+                last = Some(self.insert_node(i, p, last));
+            }
+        }
+        if let Some(l) = last {
+            if self.equals(l, self.next(l)) {
+                self.remove_node(l);
+                last = Some(self.next(l));
+            }
+        }
+        last
+    }
+
+    // Remove duplicate and collinear points.
+    fn filter_points(&mut self, start: usize, end: Option<usize>) -> usize {
+        let mut end = end.unwrap_or(start);
+        let mut p = start;
+        loop {
+            let mut again = false;
+            let (prev, next) = (self.prev(p), self.next(p));
+            if !self.nodes[p].steiner && (self.equals(p, next) || self.area(prev, p, next) == 0.0) {
+                self.remove_node(p);
+                p = prev;
+                end = prev;
+                if p == self.next(p) {
+                    break;
+                }
+                again = true;
+            } else {
+                p = next;
+            }
+            if !again && p == end {
+                break;
+            }
+        }
+        end
+    }
+
+    // Clip ears from the polygon, with further passes to handle degenerate cases.
+    fn earcut_linked(&mut self, mut ear: usize, pass: u8) {
+        let mut stop = ear;
+        while self.prev(ear) != self.next(ear) {
+            let (prev, next) = (self.prev(ear), self.next(ear));
+            if self.is_ear(ear) {
+                let i = [self.nodes[prev].i, self.nodes[ear].i, self.nodes[next].i];
+                self.triangles.extend_from_slice(&i);
+                self.remove_node(ear);
+                ear = self.next(next);
+                stop = ear;
+                continue;
+            }
+            ear = next;
+            if ear == stop {
+                match pass {
+                    // Try again after removing duplicate points.
+                    0 => {
+                        let ear = self.filter_points(ear, None);
+                        self.earcut_linked(ear, 1);
+                    }
+                    // Try again after resolving small self-intersections.
+                    1 => {
+                        let filtered = self.filter_points(ear, None);
+                        let ear = self.cure_local_intersections(filtered);
+                        self.earcut_linked(ear, 2);
+                    }
+                    // Split the polygon in two and triangulate each.
+                    _ => self.split_earcut(ear),
+                }
+                break;
+            }
+        }
+    }
+
+    // Whether the given node forms a valid ear with its neighbours.
+    fn is_ear(&self, ear: usize) -> bool {
+        let (a, b, c) = (self.prev(ear), ear, self.next(ear));
+        if self.area(a, b, c) >= 0.0 {
+            // Reflex, so cannot be an ear.
+            return false;
+        }
+        let (pa, pb, pc) = (self.xy(a), self.xy(b), self.xy(c));
+        let mut p = self.next(c);
+        while p != a {
+            let pp = self.xy(p);
+            if point_in_triangle(pa, pb, pc, pp)
+                && pp != pa
+                && self.area(self.prev(p), p, self.next(p)) >= 0.0
+            {
+                return false;
+            }
+            p = self.next(p);
+        }
+        true
+    }
+
+    // Walk through the polygon and clip ears formed by small self-intersections.
+    fn cure_local_intersections(&mut self, mut start: usize) -> usize {
+        let mut p = start;
+        loop {
+            let a = self.prev(p);
+            let b = self.next(self.next(p));
+            if !self.equals(a, b)
+                && self.intersects(a, p, self.next(p), b)
+                && self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+            {
+                let i = [self.nodes[a].i, self.nodes[p].i, self.nodes[b].i];
+                self.triangles.extend_from_slice(&i);
+                let next = self.next(p);
+                self.remove_node(p);
+                self.remove_node(next);
+                p = b;
+                start = b;
+            }
+            p = self.next(p);
+            if p == start {
+                break;
+            }
+        }
+        self.filter_points(p, None)
+    }
+
+    // Find a valid diagonal dividing the polygon in two, and triangulate each separately.
+    fn split_earcut(&mut self, start: usize) {
+        let mut a = start;
+        loop {
+            let mut b = self.next(self.next(a));
+            while b != self.prev(a) {
+                if self.nodes[a].i != self.nodes[b].i && self.is_valid_diagonal(a, b) {
+                    let c = self.split_polygon(a, b);
+                    let next = self.next(a);
+                    let a = self.filter_points(a, Some(next));
+                    let next = self.next(c);
+                    let c = self.filter_points(c, Some(next));
+                    self.earcut_linked(a, 0);
+                    self.earcut_linked(c, 0);
+                    return;
+                }
+                b = self.next(b);
+            }
+            a = self.next(a);
+            if a == start {
+                break;
+            }
+        }
+    }
+
+    // Link each hole into the outer ring, producing a single ring.
+    fn eliminate_holes(&mut self, coords: &[[f64; 2]], holes: &[usize], mut outer: usize) -> usize {
+        let mut queue = Vec::with_capacity(holes.len());
+        for (h, &start) in holes.iter().enumerate() {
+            let end = holes.get(h + 1).cloned().unwrap_or(coords.len());
+            if let Some(list) = self.linked_list(coords, start, end, false) {
+                if list == self.next(list) {
+                    self.nodes[list].steiner = true;
+                }
+                queue.push(self.leftmost(list));
+            }
+        }
+        queue.sort_by(|&a, &b| {
+            let (a, b) = (self.xy(a), self.xy(b));
+            cmp_f64(a[0], b[0]).then(cmp_f64(a[1], b[1]))
+        });
+        for hole in queue {
+            outer = self.eliminate_hole(hole, outer);
+        }
+        outer
+    }
+
+    fn eliminate_hole(&mut self, hole: usize, outer: usize) -> usize {
+        let bridge = match self.find_hole_bridge(hole, outer) {
+            Some(bridge) => bridge,
+            None => return outer,
+        };
+        let bridge_reverse = self.split_polygon(bridge, hole);
+        let next = self.next(bridge_reverse);
+        self.filter_points(bridge_reverse, Some(next));
+        let next = self.next(bridge);
+        self.filter_points(bridge, Some(next))
+    }
+
+    // Find a point of the outer ring visible from the leftmost point of the hole.
+    fn find_hole_bridge(&self, hole: usize, outer: usize) -> Option<usize> {
+        let [hx, hy] = self.xy(hole);
+        let mut qx = f64::NEG_INFINITY;
+        let mut m = None;
+
+        // Find the segment intersected by a ray from the hole's leftmost point to the left. The
+        // segment's endpoint with the lesser x is a potential connection point.
+        let mut p = outer;
+        loop {
+            let ([px, py], [nx, ny]) = (self.xy(p), self.xy(self.next(p)));
+            if hy <= py && hy >= ny && ny != py {
+                let x = px + (hy - py) * (nx - px) / (ny - py);
+                if x <= hx && x > qx {
+                    qx = x;
+                    m = Some(if px < nx { p } else { self.next(p) });
+                    if x == hx {
+                        // The hole touches the outer segment.
+                        return m;
+                    }
+                }
+            }
+            p = self.next(p);
+            if p == outer {
+                break;
+            }
+        }
+        let mut m = m?;
+
+        // Look for points inside the triangle of the hole point, the intersection and the
+        // endpoint. If there are any, connect to the point with the minimum angle to the ray.
+        let stop = m;
+        let [mx, my] = self.xy(m);
+        let mut tan_min = f64::INFINITY;
+        let mut p = m;
+        loop {
+            let [px, py] = self.xy(p);
+            let (a, c) = if hy < my {
+                ([hx, hy], [qx, hy])
+            } else {
+                ([qx, hy], [hx, hy])
+            };
+            if hx >= px && px >= mx && hx != px && point_in_triangle(a, [mx, my], c, [px, py]) {
+                let tan = Float::abs(hy - py) / (hx - px);
+                let [m_x, _] = self.xy(m);
+                if self.locally_inside(p, hole)
+                    && (tan < tan_min
+                        || (tan == tan_min
+                            && (px > m_x || (px == m_x && self.sector_contains_sector(m, p)))))
+                {
+                    m = p;
+                    tan_min = tan;
+                }
+            }
+            p = self.next(p);
+            if p == stop {
+                break;
+            }
+        }
+        Some(m)
+    }
+
+    // Whether the sector of `m` contains the sector of `p`.
+    fn sector_contains_sector(&self, m: usize, p: usize) -> bool {
+        self.area(self.prev(m), m, self.prev(p)) < 0.0
+            && self.area(self.next(p), m, self.next(m)) < 0.0
+    }
+
+    fn leftmost(&self, start: usize) -> usize {
+        let mut p = start;
+        let mut leftmost = start;
+        loop {
+            let ([px, py], [lx, ly]) = (self.xy(p), self.xy(leftmost));
+            if px < lx || (px == lx && py < ly) {
+                leftmost = p;
+            }
+            p = self.next(p);
+            if p == start {
+                break;
+            }
+        }
+        leftmost
+    }
+
+    // Whether a diagonal between the given nodes lies within the polygon without intersecting it.
+    fn is_valid_diagonal(&self, a: usize, b: usize) -> bool {
+        let (ai, bi) = (self.nodes[a].i, self.nodes[b].i);
+        if self.nodes[self.next(a)].i == bi
+            || self.nodes[self.prev(a)].i == bi
+            || self.intersects_polygon(a, b)
+        {
+            return false;
+        }
+        let visible = self.locally_inside(a, b)
+            && self.locally_inside(b, a)
+            && self.middle_inside(a, b)
+            // Does not create opposite-facing sectors.
+            && (self.area(self.prev(a), a, self.prev(b)) != 0.0
+                || self.area(a, self.prev(b), b) != 0.0);
+        // A zero-length diagonal between coincident convex vertices.
+        let zero_length = self.equals(a, b)
+            && self.area(self.prev(a), a, self.next(a)) > 0.0
+            && self.area(self.prev(b), b, self.next(b)) > 0.0;
+        ai != bi && (visible || zero_length)
+    }
+
+    fn intersects(&self, p1: usize, q1: usize, p2: usize, q2: usize) -> bool {
+        let (p1, q1, p2, q2) = (self.xy(p1), self.xy(q1), self.xy(p2), self.xy(q2));
+        let o1 = sign(area(p1, q1, p2));
+        let o2 = sign(area(p1, q1, q2));
+        let o3 = sign(area(p2, q2, p1));
+        let o4 = sign(area(p2, q2, q1));
+        (o1 != o2 && o3 != o4)
+            || (o1 == 0 && on_segment(p1, p2, q1))
+            || (o2 == 0 && on_segment(p1, q2, q1))
+            || (o3 == 0 && on_segment(p2, p1, q2))
+            || (o4 == 0 && on_segment(p2, q1, q2))
+    }
+
+    fn intersects_polygon(&self, a: usize, b: usize) -> bool {
+        let (ai, bi) = (self.nodes[a].i, self.nodes[b].i);
+        let mut p = a;
+        loop {
+            let next = self.next(p);
+            let (pi, ni) = (self.nodes[p].i, self.nodes[next].i);
+            if pi != ai && ni != ai && pi != bi && ni != bi && self.intersects(p, next, a, b) {
+                return true;
+            }
+            p = next;
+            if p == a {
+                return false;
+            }
+        }
+    }
+
+    // Whether the diagonal from `a` to `b` lies within the polygon in the vicinity of `a`.
+    fn locally_inside(&self, a: usize, b: usize) -> bool {
+        let (prev, next) = (self.prev(a), self.next(a));
+        if self.area(prev, a, next) < 0.0 {
+            self.area(a, b, next) >= 0.0 && self.area(a, prev, b) >= 0.0
+        } else {
+            self.area(a, b, prev) < 0.0 || self.area(a, next, b) < 0.0
+        }
+    }
+
+    // Whether the middle of the diagonal from `a` to `b` lies within the polygon.
+    fn middle_inside(&self, a: usize, b: usize) -> bool {
+        let ([ax, ay], [bx, by]) = (self.xy(a), self.xy(b));
+        let (px, py) = ((ax + bx) / 2.0, (ay + by) / 2.0);
+        let mut inside = false;
+        let mut p = a;
+        loop {
+            let ([x, y], [nx, ny]) = (self.xy(p), self.xy(self.next(p)));
+            if ((y > py) != (ny > py)) && ny != y && px < (nx - x) * (py - y) / (ny - y) + x {
+                inside = !inside;
+            }
+            p = self.next(p);
+            if p == a {
+                return inside;
+            }
+        }
+    }
+
+    // Link `a` to `b` with a bridge, splitting the ring in two if they belong to the same ring or
+    // merging two rings into one otherwise. Returns the duplicate of `b`.
+    fn split_polygon(&mut self, a: usize, b: usize) -> usize {
+        let a2 = self.nodes.len();
+        let b2 = a2 + 1;
+        let (an, bp) = (self.next(a), self.prev(b));
+        let mut node_a2 = self.nodes[a].clone();
+        let mut node_b2 = self.nodes[b].clone();
+        node_a2.steiner = false;
+        node_b2.steiner = false;
+        node_a2.next = an;
+        node_a2.prev = b2;
+        node_b2.next = a2;
+        node_b2.prev = bp;
+        self.nodes.push(node_a2);
+        self.nodes.push(node_b2);
+        self.nodes[a].next = b;
+        self.nodes[b].prev = a;
+        self.nodes[an].prev = a2;
+        self.nodes[bp].next = b2;
+        b2
+    }
+}
+
+// Twice the signed area of the given ring, positive for counter-clockwise rings.
+fn signed_area(ring: &[[f64; 2]]) -> f64 {
+    let mut sum = 0.0;
+    let mut j = ring.len() - 1;
+    for i in 0..ring.len() {
+        sum += (ring[j][0] - ring[i][0]) * (ring[i][1] + ring[j][1]);
+        j = i;
+    }
+    sum
+}
+
+// Negative if `r` lies to the left of the line from `p` to `q`.
+fn area(p: [f64; 2], q: [f64; 2], r: [f64; 2]) -> f64 {
+    (q[1] - p[1]) * (r[0] - q[0]) - (q[0] - p[0]) * (r[1] - q[1])
+}
+
+fn point_in_triangle(a: [f64; 2], b: [f64; 2], c: [f64; 2], p: [f64; 2]) -> bool {
+    (c[0] - p[0]) * (a[1] - p[1]) >= (a[0] - p[0]) * (c[1] - p[1])
+        && (a[0] - p[0]) * (b[1] - p[1]) >= (b[0] - p[0]) * (a[1] - p[1])
+        && (b[0] - p[0]) * (c[1] - p[1]) >= (c[0] - p[0]) * (b[1] - p[1])
+}
+
+// Whether `q` lies within the bounds of the segment from `p` to `r`, given they are collinear.
+fn on_segment(p: [f64; 2], q: [f64; 2], r: [f64; 2]) -> bool {
+    q[0] <= p[0].max(r[0])
+        && q[0] >= p[0].min(r[0])
+        && q[1] <= p[1].max(r[1])
+        && q[1] >= p[1].min(r[1])
+}
+
+fn sign(x: f64) -> i8 {
+    if x > 0.0 {
+        1
+    } else if x < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+fn cmp_f64(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
@@ -10,6 +10,10 @@
 //! - A function for finding the centroid.
 
 pub mod cuboid;
+#[cfg(feature = "alloc")]
+pub mod delaunay;
+#[cfg(feature = "alloc")]
+pub mod earcut;
 pub mod ellipse;
 pub mod point;
 pub mod polygon;
@@ -17,9 +21,13 @@ pub mod quad;
 pub mod range;
 pub mod rect;
 pub mod scalar;
+#[cfg(feature = "alloc")]
+pub mod spatial;
 pub mod tri;
 pub mod vector;
 pub mod vertex;
+#[cfg(feature = "alloc")]
+pub mod voronoi;
 
 pub use self::cuboid::Cuboid;
 pub use self::ellipse::Ellipse;
@@ -33,6 +41,8 @@ pub use self::tri::Tri;
 #[allow(deprecated)]
 pub use self::vector::{Vector2, Vector3, Vector4};
 pub use self::vertex::{Vertex, Vertex2d, Vertex3d};
+#[cfg(feature = "alloc")]
+pub use self::voronoi::Voronoi;
 pub use glam::{
     dvec2, dvec3, dvec4, ivec2, ivec3, ivec4, vec2, vec3, vec4, DVec2, DVec3, DVec4, IVec2, IVec3,
     IVec4, Vec2, Vec3, Vec4,
@@ -30,6 +30,9 @@ where
 
     /// Triangulate the polygon given as a list of `Point`s describing its sides.
     ///
+    /// The triangles form a fan from the first point, and are only correct for convex polygons.
+    /// See the `earcut` module for concave polygons and polygons with holes.
+    ///
     /// Returns `None` if the polygon's iterator yields less than two points.
     pub fn triangles(self) -> Option<Triangles<I>> {
         triangles(self.points)
@@ -81,6 +84,9 @@ where
 
 /// Triangulate the polygon given as a list of `Point`s describing its sides.
 ///
+/// The triangles form a fan from the first point, and are only correct for convex polygons. See
+/// the `earcut` module for concave polygons and polygons with holes.
+///
 /// Returns `None` if the given iterator yields less than two points.
 pub fn triangles<I>(points: I) -> Option<Triangles<I::IntoIter>>
 where
@@ -103,6 +109,9 @@ where
 }
 
 /// An iterator yielding indices into a polygon's vertices required to triangulate the polygon.
+///
+/// The indices describe a fan from the first vertex, which is only correct for convex polygons.
+/// See `earcut::triangle_indices` for concave polygons and polygons with holes.
 pub fn triangle_indices(n_points: usize) -> TriangleIndices {
     let index = 0;
     TriangleIndices { index, n_points }
@@ -0,0 +1,242 @@
+//! A uniform grid of 2D or 3D points, hashing each point to the cell in which it lies.
+
+use super::{box_contains, Entries, Key, Nearest, Point};
+use crate::math::num_traits::Float;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// The coordinates of a cell. Unused axes are zero.
+type Cell = [i32; 3];
+
+/// A uniform grid of values keyed on `Point2`s or `Point3`s.
+///
+/// Only occupied cells are stored, so the grid is unbounded. Inserting, removing and moving
+/// points is cheap, making the grid well suited to points that move every frame. Queries are most
+/// efficient when their radius is similar to the cell size.
+#[derive(Clone, Debug)]
+pub struct GridHash<P, T> {
+    cell_size: f32,
+    entries: Entries<P, T>,
+    cells: BTreeMap<Cell, Vec<Key>>,
+}
+
+impl<P, T> GridHash<P, T>
+where
+    P: Point,
+{
+    /// An empty grid with the given cell size.
+    ///
+    /// **Panics** if the cell size is not greater than zero.
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "cell size must be greater than zero");
+        GridHash {
+            cell_size,
+            entries: Entries::new(),
+            cells: BTreeMap::new(),
+        }
+    }
+
+    /// The size of each cell along every axis.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The number of values within the grid.
+    pub fn len(&self) -> usize {
+        self.entries.len
+    }
+
+    /// Whether or not the grid contains any values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Insert a value at the given point, returning the key with which it may be retrieved.
+    pub fn insert(&mut self, point: P, value: T) -> Key {
+        let key = self.entries.insert(point, value);
+        let cell = self.cell(point);
+        self.cells.entry(cell).or_default().push(key);
+        key
+    }
+
+    /// Remove the value with the given key, returning its point and value.
+    pub fn remove(&mut self, key: Key) -> Option<(P, T)> {
+        let (point, value) = self.entries.remove(key)?;
+        let cell = self.cell(point);
+        self.remove_from_cell(cell, key);
+        Some((point, value))
+    }
+
+    /// Move the value with the given key to the given point.
+    ///
+    /// Returns `false` if there is no value with the given key.
+    pub fn set_point(&mut self, key: Key, point: P) -> bool {
+        let old = match self.entries.slots.get_mut(key.index()) {
+            Some(Some((p, _))) => core::mem::replace(p, point),
+            _ => return false,
+        };
+        let (from, to) = (self.cell(old), self.cell(point));
+        if from != to {
+            self.remove_from_cell(from, key);
+            self.cells.entry(to).or_default().push(key);
+        }
+        true
+    }
+
+    /// The point and value with the given key.
+    pub fn get(&self, key: Key) -> Option<(P, &T)> {
+        self.entries.get(key)
+    }
+
+    /// A mutable reference to the value with the given key.
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        self.entries.get_mut(key)
+    }
+
+    /// An iterator yielding the key, point and value of every value within the grid.
+    pub fn iter(&self) -> impl Iterator<Item = (Key, P, &T)> {
+        self.entries.iter()
+    }
+
+    /// Remove all values from the grid.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cells.clear();
+    }
+
+    /// The keys of all values whose points lie within the given bounds.
+    pub fn within_bounds(&self, bounds: &P::Bounds) -> Vec<Key> {
+        let (min, max) = (P::bounds_min(bounds), P::bounds_max(bounds));
+        let mut keys = vec![];
+        self.visit(self.cell(min), self.cell(max), |key, p| {
+            if box_contains(min, max, p) {
+                keys.push(key);
+            }
+        });
+        keys
+    }
+
+    /// The keys of all values whose points lie within the given distance of the given point.
+    pub fn within_radius(&self, point: P, radius: f32) -> Vec<Key> {
+        let bounds = point.bounds_around(radius);
+        let (min, max) = (P::bounds_min(&bounds), P::bounds_max(&bounds));
+        let radius_squared = radius * radius;
+        let mut keys = vec![];
+        self.visit(self.cell(min), self.cell(max), |key, p| {
+            if p.distance_squared(point) <= radius_squared {
+                keys.push(key);
+            }
+        });
+        keys
+    }
+
+    /// The keys of the `k` values nearest to the given point, ordered from nearest to furthest.
+    ///
+    /// Searches rings of cells of increasing size around the point until no nearer values may
+    /// remain, falling back to checking every value once the rings outgrow the occupied cells.
+    pub fn nearest(&self, point: P, k: usize) -> Vec<Key> {
+        let mut nearest = Nearest::new(k);
+        let centre = self.cell(point);
+        let mut ring: i32 = 0;
+        loop {
+            let cells_in_ring = (2 * ring as usize + 1).pow(P::DIMENSIONS as u32);
+            if k >= self.len() || cells_in_ring > self.cells.len() {
+                nearest = Nearest::new(k);
+                for (key, p, _) in self.entries.iter() {
+                    nearest.push(key, p.distance_squared(point));
+                }
+                break;
+            }
+            let min = self.offset(centre, -ring);
+            let max = self.offset(centre, ring);
+            for_each_cell(min, max, |cell| {
+                let on_ring = (0..P::DIMENSIONS).any(|a| (cell[a] - centre[a]).abs() == ring);
+                if !on_ring {
+                    return;
+                }
+                for &key in self.cells.get(&cell).into_iter().flatten() {
+                    nearest.push(key, self.entries.point(key).distance_squared(point));
+                }
+            });
+            // Points beyond the ring are at least `ring` cells away along some axis.
+            let reach = ring as f32 * self.cell_size;
+            if nearest.worst() <= reach * reach {
+                break;
+            }
+            ring += 1;
+        }
+        nearest.into_keys()
+    }
+
+    // The cell in which the point lies.
+    fn cell(&self, point: P) -> Cell {
+        let mut cell = [0; 3];
+        for (a, c) in cell.iter_mut().enumerate().take(P::DIMENSIONS) {
+            *c = Float::floor(point.axis(a) / self.cell_size) as i32;
+        }
+        cell
+    }
+
+    // The cell offset from the given cell by the given number of cells along every used axis.
+    fn offset(&self, cell: Cell, n: i32) -> Cell {
+        let mut cell = cell;
+        for c in cell.iter_mut().take(P::DIMENSIONS) {
+            *c = c.saturating_add(n);
+        }
+        cell
+    }
+
+    fn remove_from_cell(&mut self, cell: Cell, key: Key) {
+        if let Some(keys) = self.cells.get_mut(&cell) {
+            if let Some(ix) = keys.iter().position(|&k| k == key) {
+                keys.swap_remove(ix);
+            }
+            if keys.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    // Visit the points of all cells between the given cells inclusive.
+    fn visit<F>(&self, min: Cell, max: Cell, mut f: F)
+    where
+        F: FnMut(Key, P),
+    {
+        let count = (0..3).fold(1u64, |n, a| {
+            n.saturating_mul((max[a] as i64 - min[a] as i64 + 1) as u64)
+        });
+        let mut visit_cell = |keys: &Vec<Key>| {
+            for &key in keys {
+                f(key, self.entries.point(key));
+            }
+        };
+        // Check every occupied cell rather than every cell in range if there are fewer.
+        if count > self.cells.len() as u64 {
+            let in_range = |c: &Cell| (0..3).all(|a| min[a] <= c[a] && c[a] <= max[a]);
+            for (_, keys) in self.cells.iter().filter(|(c, _)| in_range(c)) {
+                visit_cell(keys);
+            }
+        } else {
+            for_each_cell(min, max, |cell| {
+                if let Some(keys) = self.cells.get(&cell) {
+                    visit_cell(keys);
+                }
+            });
+        }
+    }
+}
+
+// Call `f` with every cell between the given cells inclusive.
+fn for_each_cell<F>(min: Cell, max: Cell, mut f: F)
+where
+    F: FnMut(Cell),
+{
+    for x in min[0]..=max[0] {
+        for y in min[1]..=max[1] {
+            for z in min[2]..=max[2] {
+                f([x, y, z]);
+            }
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! A k-d tree of 2D or 3D points.
+
+use super::{box_contains, Entries, Key, Nearest, Point};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// A k-d tree of values keyed on `Point2`s or `Point3`s.
+///
+/// Each node splits space along one axis, cycling through the axes with depth. Trees collected
+/// via `FromIterator` are balanced, while points inserted individually are placed at the leaves
+/// and may unbalance the tree. Removed points are marked as such and the tree is rebuilt once
+/// they make up the majority of its nodes.
+#[derive(Clone, Debug)]
+pub struct KdTree<P, T> {
+    entries: Entries<P, T>,
+    nodes: Vec<Node<P>>,
+    // The index of the node of each entry slot.
+    node_of: Vec<usize>,
+    root: Option<usize>,
+    removed: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node<P> {
+    point: P,
+    key: Key,
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+    removed: bool,
+}
+
+impl<P, T> KdTree<P, T>
+where
+    P: Point,
+{
+    /// An empty k-d tree.
+    pub fn new() -> Self {
+        KdTree {
+            entries: Entries::new(),
+            nodes: Vec::new(),
+            node_of: Vec::new(),
+            root: None,
+            removed: 0,
+        }
+    }
+
+    /// The number of values within the tree.
+    pub fn len(&self) -> usize {
+        self.entries.len
+    }
+
+    /// Whether or not the tree contains any values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Insert a value at the given point, returning the key with which it may be retrieved.
+    pub fn insert(&mut self, point: P, value: T) -> Key {
+        let key = self.entries.insert(point, value);
+        let ix = self.nodes.len();
+        let axis = match self.root {
+            None => {
+                self.root = Some(ix);
+                0
+            }
+            Some(mut parent) => loop {
+                let node = &mut self.nodes[parent];
+                let child = if point.axis(node.axis) < node.point.axis(node.axis) {
+                    &mut node.left
+                } else {
+                    &mut node.right
+                };
+                match *child {
+                    Some(c) => parent = c,
+                    None => {
+                        *child = Some(ix);
+                        break (node.axis + 1) % P::DIMENSIONS;
+                    }
+                }
+            },
+        };
+        self.nodes.push(Node::new(point, key, axis));
+        self.set_node_of(key, ix);
+        key
+    }
+
+    /// Remove the value with the given key, returning its point and value.
+    pub fn remove(&mut self, key: Key) -> Option<(P, T)> {
+        let entry = self.entries.remove(key)?;
+        self.nodes[self.node_of[key.index()]].removed = true;
+        self.removed += 1;
+        if self.removed * 2 > self.nodes.len() {
+            self.rebuild();
+        }
+        Some(entry)
+    }
+
+    /// The point and value with the given key.
+    pub fn get(&self, key: Key) -> Option<(P, &T)> {
+        self.entries.get(key)
+    }
+
+    /// A mutable reference to the value with the given key.
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        self.entries.get_mut(key)
+    }
+
+    /// An iterator yielding the key, point and value of every value within the tree.
+    pub fn iter(&self) -> impl Iterator<Item = (Key, P, &T)> {
+        self.entries.iter()
+    }
+
+    /// Remove all values from the tree.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.nodes.clear();
+        self.node_of.clear();
+        self.root = None;
+        self.removed = 0;
+    }
+
+    /// Rebuild the tree so that it is balanced, discarding the nodes of removed values.
+    ///
+    /// Useful after inserting many points individually.
+    pub fn rebuild(&mut self) {
+        let mut items: Vec<_> = self.entries.iter().map(|(k, p, _)| (k, p)).collect();
+        self.nodes.clear();
+        self.removed = 0;
+        self.root = self.build(&mut items, 0);
+    }
+
+    /// The keys of all values whose points lie within the given bounds.
+    pub fn within_bounds(&self, bounds: &P::Bounds) -> Vec<Key> {
+        let (min, max) = (P::bounds_min(bounds), P::bounds_max(bounds));
+        let mut keys = vec![];
+        self.visit(
+            |axis, pivot| (min.axis(axis) <= pivot, max.axis(axis) >= pivot),
+            |key, p| {
+                if box_contains(min, max, p) {
+                    keys.push(key);
+                }
+            },
+        );
+        keys
+    }
+
+    /// The keys of all values whose points lie within the given distance of the given point.
+    pub fn within_radius(&self, point: P, radius: f32) -> Vec<Key> {
+        let radius_squared = radius * radius;
+        let mut keys = vec![];
+        self.visit(
+            |axis, pivot| {
+                let v = point.axis(axis);
+                (v - radius <= pivot, v + radius >= pivot)
+            },
+            |key, p| {
+                if p.distance_squared(point) <= radius_squared {
+                    keys.push(key);
+                }
+            },
+        );
+        keys
+    }
+
+    /// The keys of the `k` values nearest to the given point, ordered from nearest to furthest.
+    pub fn nearest(&self, point: P, k: usize) -> Vec<Key> {
+        let mut nearest = Nearest::new(k);
+        // Each node is paired with a lower bound on the distance to the points beneath it.
+        let mut stack: Vec<_> = self.root.map(|r| (r, 0.0)).into_iter().collect();
+        while let Some((ix, distance_squared)) = stack.pop() {
+            if distance_squared > nearest.worst() {
+                continue;
+            }
+            let node = &self.nodes[ix];
+            if !node.removed {
+                nearest.push(node.key, node.point.distance_squared(point));
+            }
+            let d = point.axis(node.axis) - node.point.axis(node.axis);
+            let (near, far) = if d < 0.0 {
+                (node.left, node.right)
+            } else {
+                (node.right, node.left)
+            };
+            // Push the near side last so that it is searched first.
+            if let Some(far) = far {
+                stack.push((far, distance_squared.max(d * d)));
+            }
+            if let Some(near) = near {
+                stack.push((near, distance_squared));
+            }
+        }
+        nearest.into_keys()
+    }
+
+    // Build a balanced subtree of the given items, returning the index of its root.
+    fn build(&mut self, items: &mut [(Key, P)], depth: usize) -> Option<usize> {
+        if items.is_empty() {
+            return None;
+        }
+        let axis = depth % P::DIMENSIONS;
+        let mid = items.len() / 2;
+        items.select_nth_unstable_by(mid, |a, b| a.1.axis(axis).total_cmp(&b.1.axis(axis)));
+        let (key, point) = items[mid];
+        let ix = self.nodes.len();
+        self.nodes.push(Node::new(point, key, axis));
+        self.set_node_of(key, ix);
+        let (left, right) = items.split_at_mut(mid);
+        self.nodes[ix].left = self.build(left, depth + 1);
+        self.nodes[ix].right = self.build(&mut right[1..], depth + 1);
+        Some(ix)
+    }
+
+    fn set_node_of(&mut self, key: Key, ix: usize) {
+        let slot = key.index();
+        if self.node_of.len() <= slot {
+            self.node_of.resize(slot + 1, 0);
+        }
+        self.node_of[slot] = ix;
+    }
+
+    // Visit the points of all nodes, descending to the (left, right) sides given by `sides`.
+    fn visit<S, F>(&self, sides: S, mut f: F)
+    where
+        S: Fn(usize, f32) -> (bool, bool),
+        F: FnMut(Key, P),
+    {
+        let mut stack: Vec<_> = self.root.into_iter().collect();
+        while let Some(ix) = stack.pop() {
+            let node = &self.nodes[ix];
+            if !node.removed {
+                f(node.key, node.point);
+            }
+            let (left, right) = sides(node.axis, node.point.axis(node.axis));
+            if let (true, Some(l)) = (left, node.left) {
+                stack.push(l);
+            }
+            if let (true, Some(r)) = (right, node.right) {
+                stack.push(r);
+            }
+        }
+    }
+}
+
+impl<P> Node<P> {
+    fn new(point: P, key: Key, axis: usize) -> Self {
+        Node {
+            point,
+            key,
+            axis,
+            left: None,
+            right: None,
+            removed: false,
+        }
+    }
+}
+
+impl<P, T> Default for KdTree<P, T>
+where
+    P: Point,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P, T> FromIterator<(P, T)> for KdTree<P, T>
+where
+    P: Point,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (P, T)>,
+    {
+        let mut tree = Self::new();
+        for (point, value) in iter {
+            tree.entries.insert(point, value);
+        }
+        tree.rebuild();
+        tree
+    }
+}
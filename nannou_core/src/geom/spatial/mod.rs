@@ -0,0 +1,286 @@
+//! Spatial indexing structures for efficiently finding points by location.
+//!
+//! Each container stores values keyed on their position, and supports:
+//!
+//! - Insertion and removal of values, identified by the **Key** returned upon insertion.
+//! - Range queries for values within a `Rect` (2D) or `Cuboid` (3D).
+//! - Radius queries for values within some distance of a point.
+//! - k-nearest-neighbour queries.
+//!
+//! The containers suit different workloads:
+//!
+//! - **QuadTree**: 2D points within known bounds, adapting to uneven distributions.
+//! - **KdTree**: 2D or 3D points, built in bulk via `FromIterator` and queried many times.
+//! - **GridHash**: 2D or 3D points that move every frame, e.g. particles or flocking agents, where
+//!   queries are within a radius similar to the cell size.
+//!
+//! Requires the `alloc` feature.
+
+use crate::geom::{Cuboid, Point2, Point3, Range, Rect};
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+pub mod grid;
+pub mod kdtree;
+pub mod quadtree;
+
+pub use self::grid::GridHash;
+pub use self::kdtree::KdTree;
+pub use self::quadtree::QuadTree;
+
+/// Points that may be used to key the values of a spatial container, i.e. `Point2` and `Point3`.
+pub trait Point: Copy + PartialEq {
+    /// The axis-aligned bounds used for range queries, i.e. `Rect` or `Cuboid`.
+    type Bounds: Copy;
+    /// The number of dimensions of the point.
+    const DIMENSIONS: usize;
+    /// The value of the point along the given axis.
+    fn axis(self, axis: usize) -> f32;
+    /// The squared distance to the other point.
+    fn distance_squared(self, other: Self) -> f32;
+    /// The corner of the bounds with the least value along every axis.
+    fn bounds_min(bounds: &Self::Bounds) -> Self;
+    /// The corner of the bounds with the greatest value along every axis.
+    fn bounds_max(bounds: &Self::Bounds) -> Self;
+    /// The bounds of the square or cube centred on the point with the given half size.
+    fn bounds_around(self, radius: f32) -> Self::Bounds;
+}
+
+/// Identifies a value within a spatial container.
+///
+/// Keys of removed values may be reused by later insertions.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Key(usize);
+
+// Storage for the points and values of a container.
+#[derive(Clone, Debug)]
+struct Entries<P, T> {
+    slots: Vec<Option<(P, T)>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+// A candidate for a nearest-neighbour query, ordered by distance.
+#[derive(Copy, Clone, Debug)]
+struct Candidate {
+    distance_squared: f32,
+    key: Key,
+}
+
+// The `k` nearest candidates found so far.
+struct Nearest {
+    k: usize,
+    heap: BinaryHeap<Candidate>,
+}
+
+impl Point for Point2 {
+    type Bounds = Rect;
+    const DIMENSIONS: usize = 2;
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+    fn distance_squared(self, other: Self) -> f32 {
+        Point2::distance_squared(self, other)
+    }
+    fn bounds_min(bounds: &Rect) -> Self {
+        Point2::new(bounds.x.absolute().start, bounds.y.absolute().start)
+    }
+    fn bounds_max(bounds: &Rect) -> Self {
+        Point2::new(bounds.x.absolute().end, bounds.y.absolute().end)
+    }
+    fn bounds_around(self, radius: f32) -> Rect {
+        Rect::from_xy_wh(self, Point2::splat(radius * 2.0))
+    }
+}
+
+impl Point for Point3 {
+    type Bounds = Cuboid;
+    const DIMENSIONS: usize = 3;
+    fn axis(self, axis: usize) -> f32 {
+        self[axis]
+    }
+    fn distance_squared(self, other: Self) -> f32 {
+        Point3::distance_squared(self, other)
+    }
+    fn bounds_min(bounds: &Cuboid) -> Self {
+        let (x, y, z) = (
+            bounds.x.absolute(),
+            bounds.y.absolute(),
+            bounds.z.absolute(),
+        );
+        Point3::new(x.start, y.start, z.start)
+    }
+    fn bounds_max(bounds: &Cuboid) -> Self {
+        let (x, y, z) = (
+            bounds.x.absolute(),
+            bounds.y.absolute(),
+            bounds.z.absolute(),
+        );
+        Point3::new(x.end, y.end, z.end)
+    }
+    fn bounds_around(self, radius: f32) -> Cuboid {
+        let range = |v: f32| Range::new(v - radius, v + radius);
+        Cuboid {
+            x: range(self.x),
+            y: range(self.y),
+            z: range(self.z),
+        }
+    }
+}
+
+impl Key {
+    /// The index of the key, unique among the values of its container.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl<P, T> Entries<P, T>
+where
+    P: Copy,
+{
+    fn new() -> Self {
+        Entries {
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn insert(&mut self, point: P, value: T) -> Key {
+        self.len += 1;
+        match self.free.pop() {
+            Some(ix) => {
+                self.slots[ix] = Some((point, value));
+                Key(ix)
+            }
+            None => {
+                self.slots.push(Some((point, value)));
+                Key(self.slots.len() - 1)
+            }
+        }
+    }
+
+    fn remove(&mut self, key: Key) -> Option<(P, T)> {
+        let entry = self.slots.get_mut(key.0)?.take()?;
+        self.free.push(key.0);
+        self.len -= 1;
+        Some(entry)
+    }
+
+    fn get(&self, key: Key) -> Option<(P, &T)> {
+        match self.slots.get(key.0) {
+            Some(Some((p, t))) => Some((*p, t)),
+            _ => None,
+        }
+    }
+
+    fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        match self.slots.get_mut(key.0) {
+            Some(Some((_, t))) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn point(&self, key: Key) -> P {
+        self.get(key).expect("no entry for key").0
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (Key, P, &T)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| e.as_ref().map(|(p, t)| (Key(i), *p, t)))
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+        self.free.clear();
+        self.len = 0;
+    }
+}
+
+impl Nearest {
+    fn new(k: usize) -> Self {
+        Nearest {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    fn push(&mut self, key: Key, distance_squared: f32) {
+        if self.k == 0 {
+            return;
+        }
+        if self.heap.len() < self.k || distance_squared < self.worst() {
+            self.heap.push(Candidate {
+                distance_squared,
+                key,
+            });
+            if self.heap.len() > self.k {
+                self.heap.pop();
+            }
+        }
+    }
+
+    // The greatest distance that may still yield a candidate.
+    fn worst(&self) -> f32 {
+        match self.heap.peek() {
+            _ if self.k == 0 => f32::NEG_INFINITY,
+            Some(c) if self.heap.len() == self.k => c.distance_squared,
+            _ => f32::INFINITY,
+        }
+    }
+
+    fn into_keys(self) -> Vec<Key> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|c| c.key)
+            .collect()
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance_squared
+            .total_cmp(&other.distance_squared)
+            .then(self.key.cmp(&other.key))
+    }
+}
+
+// Whether the point lies within the box described by the given corners.
+fn box_contains<P: Point>(min: P, max: P, p: P) -> bool {
+    (0..P::DIMENSIONS).all(|a| min.axis(a) <= p.axis(a) && p.axis(a) <= max.axis(a))
+}
+
+// Whether the boxes described by the given corners overlap.
+fn boxes_overlap<P: Point>(min_a: P, max_a: P, min_b: P, max_b: P) -> bool {
+    (0..P::DIMENSIONS).all(|a| min_a.axis(a) <= max_b.axis(a) && min_b.axis(a) <= max_a.axis(a))
+}
+
+// The squared distance from the point to the nearest point within the box.
+fn box_distance_squared<P: Point>(min: P, max: P, p: P) -> f32 {
+    (0..P::DIMENSIONS)
+        .map(|a| {
+            let v = p.axis(a);
+            let d = (min.axis(a) - v).max(v - max.axis(a)).max(0.0);
+            d * d
+        })
+        .sum()
+}
@@ -0,0 +1,282 @@
+//! A region quadtree of 2D points within some bounds.
+
+use super::{box_contains, box_distance_squared, boxes_overlap, Entries, Key, Nearest, Point};
+use crate::geom::{Point2, Rect};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The default maximum number of points stored within a node before it is subdivided.
+pub const DEFAULT_NODE_CAPACITY: usize = 8;
+/// The maximum depth of the tree, limiting subdivision of many coincident points.
+pub const MAX_DEPTH: usize = 16;
+
+/// A quadtree of values keyed on 2D points.
+///
+/// Nodes are subdivided into quadrants as they fill, adapting the tree to the distribution of
+/// points within its bounds. Points outside of the bounds are stored separately and are always
+/// checked by queries, so the bounds should cover the majority of points.
+#[derive(Clone, Debug)]
+pub struct QuadTree<T> {
+    entries: Entries<Point2, T>,
+    nodes: Vec<Node>,
+    outside: Vec<Key>,
+    node_capacity: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    rect: Rect,
+    keys: Vec<Key>,
+    children: Option<[usize; 4]>,
+    depth: usize,
+}
+
+impl<T> QuadTree<T> {
+    /// An empty quadtree covering the given bounds.
+    pub fn new(bounds: Rect) -> Self {
+        QuadTree {
+            entries: Entries::new(),
+            nodes: vec![Node::new(bounds.absolute(), 0)],
+            outside: Vec::new(),
+            node_capacity: DEFAULT_NODE_CAPACITY,
+        }
+    }
+
+    /// Specify the maximum number of points stored within a node before it is subdivided.
+    ///
+    /// Only affects subsequent subdivisions. The default is `DEFAULT_NODE_CAPACITY`.
+    pub fn node_capacity(mut self, capacity: usize) -> Self {
+        self.node_capacity = capacity.max(1);
+        self
+    }
+
+    /// The bounds covered by the tree.
+    pub fn bounds(&self) -> Rect {
+        self.nodes[0].rect
+    }
+
+    /// The number of values within the tree.
+    pub fn len(&self) -> usize {
+        self.entries.len
+    }
+
+    /// Whether or not the tree contains any values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Insert a value at the given point, returning the key with which it may be retrieved.
+    pub fn insert(&mut self, point: Point2, value: T) -> Key {
+        let key = self.entries.insert(point, value);
+        match self.leaf(point) {
+            None => self.outside.push(key),
+            Some(leaf) => {
+                self.nodes[leaf].keys.push(key);
+                self.subdivide(leaf);
+            }
+        }
+        key
+    }
+
+    /// Remove the value with the given key, returning its point and value.
+    pub fn remove(&mut self, key: Key) -> Option<(Point2, T)> {
+        let (point, value) = self.entries.remove(key)?;
+        let keys = match self.leaf(point) {
+            None => &mut self.outside,
+            Some(leaf) => &mut self.nodes[leaf].keys,
+        };
+        if let Some(ix) = keys.iter().position(|&k| k == key) {
+            keys.swap_remove(ix);
+        }
+        Some((point, value))
+    }
+
+    /// The point and value with the given key.
+    pub fn get(&self, key: Key) -> Option<(Point2, &T)> {
+        self.entries.get(key)
+    }
+
+    /// A mutable reference to the value with the given key.
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        self.entries.get_mut(key)
+    }
+
+    /// An iterator yielding the key, point and value of every value within the tree.
+    pub fn iter(&self) -> impl Iterator<Item = (Key, Point2, &T)> {
+        self.entries.iter()
+    }
+
+    /// Remove all values from the tree, retaining its bounds.
+    pub fn clear(&mut self) {
+        let bounds = self.bounds();
+        self.entries.clear();
+        self.nodes.clear();
+        self.nodes.push(Node::new(bounds, 0));
+        self.outside.clear();
+    }
+
+    /// The rectangles of the leaf nodes of the tree, useful for visualising its structure.
+    pub fn leaf_rects(&self) -> impl Iterator<Item = Rect> + '_ {
+        self.nodes
+            .iter()
+            .filter(|n| n.children.is_none())
+            .map(|n| n.rect)
+    }
+
+    /// The keys of all values whose points lie within the given bounds.
+    pub fn within_bounds(&self, bounds: &Rect) -> Vec<Key> {
+        let (min, max) = (Point2::bounds_min(bounds), Point2::bounds_max(bounds));
+        let mut keys = vec![];
+        self.visit(
+            |rect| boxes_overlap(min, max, rect_min(rect), rect_max(rect)),
+            |key, p| {
+                if box_contains(min, max, p) {
+                    keys.push(key);
+                }
+            },
+        );
+        keys
+    }
+
+    /// The keys of all values whose points lie within the given distance of the given point.
+    pub fn within_radius(&self, point: Point2, radius: f32) -> Vec<Key> {
+        let radius_squared = radius * radius;
+        let mut keys = vec![];
+        self.visit(
+            |rect| box_distance_squared(rect_min(rect), rect_max(rect), point) <= radius_squared,
+            |key, p| {
+                if p.distance_squared(point) <= radius_squared {
+                    keys.push(key);
+                }
+            },
+        );
+        keys
+    }
+
+    /// The keys of the `k` values nearest to the given point, ordered from nearest to furthest.
+    pub fn nearest(&self, point: Point2, k: usize) -> Vec<Key> {
+        let mut nearest = Nearest::new(k);
+        for &key in &self.outside {
+            nearest.push(key, self.entries.point(key).distance_squared(point));
+        }
+        let mut stack = vec![(0, 0.0)];
+        while let Some((ix, distance_squared)) = stack.pop() {
+            if distance_squared > nearest.worst() {
+                continue;
+            }
+            let node = &self.nodes[ix];
+            for &key in &node.keys {
+                nearest.push(key, self.entries.point(key).distance_squared(point));
+            }
+            if let Some(children) = node.children {
+                // Push the nearest quadrant last so that it is searched first.
+                let mut children = children.map(|c| {
+                    let rect = &self.nodes[c].rect;
+                    (
+                        c,
+                        box_distance_squared(rect_min(rect), rect_max(rect), point),
+                    )
+                });
+                children.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+                stack.extend_from_slice(&children);
+            }
+        }
+        nearest.into_keys()
+    }
+
+    // The index of the leaf node containing the point, or `None` if it lies outside of the tree.
+    fn leaf(&self, point: Point2) -> Option<usize> {
+        let rect = &self.nodes[0].rect;
+        if !box_contains(rect_min(rect), rect_max(rect), point) {
+            return None;
+        }
+        let mut ix = 0;
+        while let Some(children) = self.nodes[ix].children {
+            ix = children[quadrant(&self.nodes[ix].rect, point)];
+        }
+        Some(ix)
+    }
+
+    // Subdivide the given leaf node if it has exceeded its capacity.
+    fn subdivide(&mut self, ix: usize) {
+        let node = &self.nodes[ix];
+        if node.keys.len() <= self.node_capacity || node.depth >= MAX_DEPTH {
+            return;
+        }
+        let (rect, depth) = (node.rect, node.depth + 1);
+        let (c, min, max) = (rect.xy(), rect_min(&rect), rect_max(&rect));
+        let first = self.nodes.len();
+        let quadrants = [
+            Rect::from_corners(min, c),
+            Rect::from_corners(Point2::new(c.x, min.y), Point2::new(max.x, c.y)),
+            Rect::from_corners(Point2::new(min.x, c.y), Point2::new(c.x, max.y)),
+            Rect::from_corners(c, max),
+        ];
+        for &q in &quadrants {
+            self.nodes.push(Node::new(q, depth));
+        }
+        let keys = core::mem::take(&mut self.nodes[ix].keys);
+        for key in keys {
+            let q = quadrant(&rect, self.entries.point(key));
+            self.nodes[first + q].keys.push(key);
+        }
+        self.nodes[ix].children = Some([first, first + 1, first + 2, first + 3]);
+        for q in 0..4 {
+            self.subdivide(first + q);
+        }
+    }
+
+    // Visit the points within all nodes accepted by `accept`, along with points outside the tree.
+    fn visit<A, F>(&self, accept: A, mut f: F)
+    where
+        A: Fn(&Rect) -> bool,
+        F: FnMut(Key, Point2),
+    {
+        for &key in &self.outside {
+            f(key, self.entries.point(key));
+        }
+        let mut stack = vec![0];
+        while let Some(ix) = stack.pop() {
+            let node = &self.nodes[ix];
+            if !accept(&node.rect) {
+                continue;
+            }
+            for &key in &node.keys {
+                f(key, self.entries.point(key));
+            }
+            if let Some(children) = node.children {
+                stack.extend_from_slice(&children);
+            }
+        }
+    }
+}
+
+impl Node {
+    fn new(rect: Rect, depth: usize) -> Self {
+        Node {
+            rect,
+            keys: vec![],
+            children: None,
+            depth,
+        }
+    }
+}
+
+// The index of the quadrant of the rect in which the point lies.
+fn quadrant(rect: &Rect, point: Point2) -> usize {
+    let c = rect.xy();
+    match (point.x >= c.x, point.y >= c.y) {
+        (false, false) => 0,
+        (true, false) => 1,
+        (false, true) => 2,
+        (true, true) => 3,
+    }
+}
+
+fn rect_min(rect: &Rect) -> Point2 {
+    Point2::bounds_min(rect)
+}
+
+fn rect_max(rect: &Rect) -> Point2 {
+    Point2::bounds_max(rect)
+}
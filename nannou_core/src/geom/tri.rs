@@ -83,7 +83,7 @@ impl<V> Tri<V> {
         Tri([map(a), map(b), map(c)])
     }
 
-    /// Returns `true` if the given 2D vertex is contained within the 2D `Tri`.
+    /// Returns `true` if the given 2D vertex is contained within the 2D `Tri` or on its edges.
     ///
     /// # Example
     ///
@@ -115,11 +115,14 @@ impl<V> Tri<V> {
             (ax - cx) * (by - cy) - (bx - cx) * (ay - cy)
         }
 
-        let b1 = sign(v, a, b) < V::Scalar::zero();
-        let b2 = sign(v, b, c) < V::Scalar::zero();
-        let b3 = sign(v, c, a) < V::Scalar::zero();
+        // Points on an edge are contained regardless of the winding of the triangle, ensuring
+        // that points on the shared edge of adjacent triangles are contained by either.
+        let zero = V::Scalar::zero();
+        let (s1, s2, s3) = (sign(v, a, b), sign(v, b, c), sign(v, c, a));
+        let neg = s1 < zero || s2 < zero || s3 < zero;
+        let pos = s1 > zero || s2 > zero || s3 > zero;
 
-        (b1 == b2) && (b2 == b3)
+        !(neg && pos)
     }
 
     /// The bounding `Rect` of the triangle.
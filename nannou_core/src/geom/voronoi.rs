@@ -0,0 +1,160 @@
+//! Voronoi diagrams of a set of points, with cells clipped to a bounding `Rect`.
+//!
+//! The diagram is derived from the Delaunay triangulation of the points, where each cell is the
+//! intersection of the half-planes closer to its site than to each of its neighbours.
+//!
+//! Requires the `alloc` feature.
+
+use crate::geom::delaunay::Triangulation;
+use crate::geom::{Point2, Polygon, Rect};
+use crate::math::num_traits::Float;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Cloned;
+use core::slice;
+
+/// The Voronoi diagram of a set of points within some bounds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Voronoi {
+    triangulation: Triangulation,
+    neighbours: Vec<Vec<usize>>,
+    cells: Vec<Vec<Point2>>,
+    bounds: Rect,
+}
+
+impl Voronoi {
+    /// Produce the Voronoi diagram of the given points, with cells clipped to the given bounds.
+    pub fn new(points: &[Point2], bounds: Rect) -> Self {
+        Self::from_triangulation(Triangulation::new(points), bounds)
+    }
+
+    /// Produce the Voronoi diagram of the points of the given triangulation, with cells clipped
+    /// to the given bounds.
+    pub fn from_triangulation(triangulation: Triangulation, bounds: Rect) -> Self {
+        let points = triangulation.points();
+        let neighbours = triangulation.neighbours();
+        let rect = vec![
+            bounds.bottom_left(),
+            bounds.bottom_right(),
+            bounds.top_right(),
+            bounds.top_left(),
+        ];
+        let cells = neighbours
+            .iter()
+            .enumerate()
+            .map(|(i, ns)| {
+                // Points excluded from the triangulation, i.e. duplicates, have no cell.
+                if ns.is_empty() && triangulation.hull().len() > 1 {
+                    return vec![];
+                }
+                ns.iter().fold(rect.clone(), |cell, &n| {
+                    clip_to_bisector(&cell, points[i], points[n])
+                })
+            })
+            .collect();
+        Voronoi {
+            triangulation,
+            neighbours,
+            cells,
+            bounds,
+        }
+    }
+
+    /// The Delaunay triangulation from which the diagram was derived.
+    pub fn triangulation(&self) -> &Triangulation {
+        &self.triangulation
+    }
+
+    /// The sites of the diagram.
+    pub fn points(&self) -> &[Point2] {
+        self.triangulation.points()
+    }
+
+    /// The bounds to which cells are clipped.
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// The vertices of the cell of each site, in counter-clockwise order.
+    ///
+    /// Cells lying entirely outside of the bounds and the cells of duplicate sites are empty.
+    pub fn cells(&self) -> &[Vec<Point2>] {
+        &self.cells
+    }
+
+    /// The cell of the site at the given index as a **Polygon**.
+    pub fn polygon(&self, index: usize) -> Polygon<Cloned<slice::Iter<'_, Point2>>> {
+        Polygon::new(self.cells[index].iter().cloned())
+    }
+
+    /// The cell of each site as a **Polygon**.
+    pub fn polygons(&self) -> impl Iterator<Item = Polygon<Cloned<slice::Iter<'_, Point2>>>> {
+        self.cells.iter().map(|c| Polygon::new(c.iter().cloned()))
+    }
+
+    /// The indices of the sites whose cells neighbour the cell of the site at the given index.
+    ///
+    /// Neighbours are determined prior to clipping, so cells may neighbour one another outside of
+    /// the bounds.
+    pub fn neighbours(&self, index: usize) -> &[usize] {
+        &self.neighbours[index]
+    }
+
+    /// The centroid of the cell of each site.
+    ///
+    /// Empty cells yield their site.
+    pub fn centroids(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.cells
+            .iter()
+            .zip(self.points())
+            .map(|(cell, &site)| centroid(cell).unwrap_or(site))
+    }
+}
+
+/// Relax the given points via Lloyd's algorithm, moving each point to the centroid of its Voronoi
+/// cell within the given bounds for the given number of iterations.
+///
+/// Points tend toward an even distribution throughout the bounds.
+pub fn relax(points: &mut [Point2], bounds: Rect, iterations: usize) {
+    for _ in 0..iterations {
+        let voronoi = Voronoi::new(points, bounds);
+        for (p, centroid) in points.iter_mut().zip(voronoi.centroids()) {
+            *p = centroid;
+        }
+    }
+}
+
+// Clip the given convex polygon to the half-plane closer to `site` than to `other`.
+fn clip_to_bisector(polygon: &[Point2], site: Point2, other: Point2) -> Vec<Point2> {
+    let mid = (site + other) * 0.5;
+    let normal = other - site;
+    let side = |p: Point2| (p - mid).dot(normal);
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let (sa, sb) = (side(a), side(b));
+        if sa <= 0.0 {
+            clipped.push(a);
+        }
+        if (sa < 0.0 && sb > 0.0) || (sa > 0.0 && sb < 0.0) {
+            clipped.push(a.lerp(b, sa / (sa - sb)));
+        }
+    }
+    clipped
+}
+
+// The area-weighted centroid of the given polygon, or `None` if it has no area.
+fn centroid(polygon: &[Point2]) -> Option<Point2> {
+    let mut area = 0.0;
+    let mut sum = Point2::ZERO;
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let cross = a.perp_dot(b);
+        area += cross;
+        sum += (a + b) * cross;
+    }
+    if Float::abs(area) <= f32::EPSILON {
+        return None;
+    }
+    Some(sum / (3.0 * area))
+}
@@ -27,11 +27,17 @@
 //!   to disable default features (i.e. `default-features = false`) and enable the `libm` feature.
 //! - `libm`: provides some core math support in the case that `std` is not enabled. This feature
 //!   must be enabled if `std` is disabled.
+//! - `alloc`: enables items requiring heap allocation in the absence of `std`, e.g. the
+//!   `geom::delaunay`, `geom::earcut`, `geom::spatial` and `geom::voronoi` modules. Enabled by
+//!   `std`.
 //! - `serde`: enables the associated serde serialization/deserialization features in `glam`,
 //!   `palette` and `rand`.
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod color;
 pub mod geom;
 pub mod math;
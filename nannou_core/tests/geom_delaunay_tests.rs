@@ -0,0 +1,118 @@
+use nannou_core::geom::delaunay::{self, Triangulation, EMPTY};
+use nannou_core::geom::voronoi::{self, Voronoi};
+use nannou_core::geom::{pt2, Point2, Rect};
+
+// A deterministic scattering of points within the given rect.
+fn scatter(n: usize, rect: Rect) -> Vec<Point2> {
+    let mut state = 0x2545_f491_u32;
+    let mut rand = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as f32 / u32::MAX as f32
+    };
+    (0..n)
+        .map(|_| pt2(rect.x.lerp(rand()), rect.y.lerp(rand())))
+        .collect()
+}
+
+fn area(polygon: &[Point2]) -> f32 {
+    let n = polygon.len();
+    (0..n)
+        .map(|i| polygon[i].perp_dot(polygon[(i + 1) % n]))
+        .sum::<f32>()
+        * 0.5
+}
+
+fn assert_delaunay(t: &Triangulation) {
+    let points = t.points();
+    let halfedges = t.halfedges();
+    for (e, &opposite) in halfedges.iter().enumerate() {
+        if opposite != EMPTY {
+            assert_eq!(halfedges[opposite], e);
+        }
+    }
+    for tri in t.triangles() {
+        let [a, b, c] = tri.0;
+        assert!(
+            (b - a).perp_dot(c - a) > 0.0,
+            "triangles are counter-clockwise"
+        );
+    }
+    for (tri, center) in t.triangles().zip(t.circumcenters()) {
+        let r = center.distance(tri.0[0]);
+        for p in points {
+            assert!(
+                center.distance(*p) > r - r * 1e-3,
+                "circumcircles are empty"
+            );
+        }
+    }
+    let hull = t.hull();
+    let n_unique = points.len();
+    assert_eq!(t.len(), 2 * n_unique - 2 - hull.len());
+    assert_eq!(
+        halfedges.iter().filter(|&&e| e == EMPTY).count(),
+        hull.len()
+    );
+}
+
+#[test]
+fn triangulation() {
+    let rect = Rect::from_w_h(100.0, 100.0);
+    let t = delaunay::triangulate(&scatter(500, rect));
+    assert_delaunay(&t);
+    assert_eq!(t.indices().len(), t.len() * 3);
+
+    // Cocircular points on a grid.
+    let grid: Vec<_> = (0..10)
+        .flat_map(|x| (0..10).map(move |y| pt2(x as f32, y as f32)))
+        .collect();
+    let t = Triangulation::new(&grid);
+    assert_eq!(t.len(), 2 * 9 * 9);
+    assert_eq!(t.hull().len(), 36);
+
+    // Each point neighbours its adjacent grid points.
+    let neighbours = t.neighbours();
+    assert!(neighbours.iter().all(|ns| ns.len() >= 2));
+    assert!(neighbours[0].contains(&1) && neighbours[0].contains(&10));
+
+    // Collinear points have no triangles.
+    let line = [pt2(2.0, 2.0), pt2(0.0, 0.0), pt2(1.0, 1.0), pt2(1.0, 1.0)];
+    let t = Triangulation::new(&line);
+    assert!(t.is_empty());
+    assert_eq!(t.hull(), [0, 2, 1]);
+    assert!(Triangulation::new(&[]).is_empty());
+}
+
+#[test]
+fn voronoi_cells() {
+    let rect = Rect::from_w_h(100.0, 100.0);
+    let points = scatter(200, rect);
+    let v = Voronoi::new(&points, rect);
+    let total: f32 = v.cells().iter().map(|c| area(c)).sum();
+    assert!((total - rect.w() * rect.h()).abs() < 1.0);
+    for (i, polygon) in v.polygons().enumerate() {
+        assert!(polygon.contains(&points[i]).is_some());
+        assert!(!v.neighbours(i).is_empty());
+    }
+
+    // Collinear sites divide the bounds into strips.
+    let sites = [pt2(-25.0, 0.0), pt2(0.0, 0.0), pt2(25.0, 0.0)];
+    let v = Voronoi::new(&sites, rect);
+    let areas: Vec<_> = v.cells().iter().map(|c| area(c)).collect();
+    assert!((areas[1] - 2500.0).abs() < 1e-2);
+    assert!((areas[0] - 3750.0).abs() < 1e-2);
+
+    // Relaxation evens out the area of each cell.
+    let spread = |points: &[Point2]| {
+        let v = Voronoi::new(points, rect);
+        let areas = v.cells().iter().map(|c| area(c));
+        let (min, max) = areas.fold((f32::MAX, f32::MIN), |(a, b), x| (a.min(x), b.max(x)));
+        max - min
+    };
+    let mut relaxed = points.clone();
+    voronoi::relax(&mut relaxed, rect, 10);
+    assert!(spread(&relaxed) < spread(&points) * 0.5);
+    assert!(relaxed.iter().all(|&p| rect.contains(p)));
+}
@@ -0,0 +1,70 @@
+use nannou_core::geom::{earcut, pt2, Point2, Tri};
+
+fn area(tri: &Tri<Point2>) -> f32 {
+    let [a, b, c] = tri.0;
+    (b - a).perp_dot(c - a) * 0.5
+}
+
+#[test]
+fn concave_polygon() {
+    // A comb with three teeth, wound clockwise.
+    let points = [
+        pt2(0.0, 0.0),
+        pt2(0.0, 3.0),
+        pt2(1.0, 3.0),
+        pt2(1.0, 1.0),
+        pt2(2.0, 1.0),
+        pt2(2.0, 3.0),
+        pt2(3.0, 3.0),
+        pt2(3.0, 1.0),
+        pt2(4.0, 1.0),
+        pt2(4.0, 3.0),
+        pt2(5.0, 3.0),
+        pt2(5.0, 0.0),
+    ];
+    let tris: Vec<_> = earcut::triangles(&points, &[]).collect();
+    assert_eq!(tris.len(), points.len() - 2);
+    assert!(tris.iter().all(|t| area(t) > 0.0));
+    let total: f32 = tris.iter().map(area).sum();
+    assert!((total - 11.0).abs() < 1e-4);
+
+    // The indices describe the same triangles, e.g. for use with a mesh of the points.
+    let indices = earcut::triangle_indices(&points, &[]);
+    let index_tris: Vec<_> = indices
+        .chunks(3)
+        .map(|c| Tri([points[c[0]], points[c[1]], points[c[2]]]))
+        .collect();
+    assert_eq!(index_tris, tris);
+}
+
+#[test]
+fn polygon_with_holes() {
+    // A 4x4 square with two 1x1 holes.
+    let points = [
+        pt2(0.0, 0.0),
+        pt2(4.0, 0.0),
+        pt2(4.0, 4.0),
+        pt2(0.0, 4.0),
+        pt2(1.0, 1.0),
+        pt2(2.0, 1.0),
+        pt2(2.0, 2.0),
+        pt2(1.0, 2.0),
+        pt2(2.5, 2.5),
+        pt2(3.5, 2.5),
+        pt2(3.5, 3.5),
+        pt2(2.5, 3.5),
+    ];
+    let tris: Vec<_> = earcut::triangles(&points, &[4, 8]).collect();
+    assert_eq!(tris.len(), points.len() + 2 * 2 - 2);
+    assert!(tris.iter().all(|t| area(t) > 0.0));
+    let total: f32 = tris.iter().map(area).sum();
+    assert!((total - 14.0).abs() < 1e-4);
+
+    // No triangle covers the centre of a hole.
+    assert!(tris.iter().all(|t| !t.contains(&pt2(1.5, 1.5))));
+
+    // Degenerate input produces no triangles.
+    assert!(earcut::triangle_indices(&points[..2], &[]).is_empty());
+    let line = [pt2(0.0, 0.0), pt2(1.0, 1.0), pt2(2.0, 2.0)];
+    assert!(earcut::triangle_indices(&line, &[]).is_empty());
+}
@@ -0,0 +1,168 @@
+use nannou_core::geom::spatial::{GridHash, KdTree, Key, QuadTree};
+use nannou_core::geom::{pt2, pt3, Cuboid, Point2, Point3, Rect};
+
+// Deterministic pseudo-random points within [-100, 100), including some duplicates.
+fn points2(count: usize) -> Vec<Point2> {
+    let mut state = 1u32;
+    let mut next = move || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (state >> 8) as f32 / (1u32 << 24) as f32 * 200.0 - 100.0
+    };
+    let mut points: Vec<_> = (0..count).map(|_| pt2(next(), next())).collect();
+    points.extend_from_within(..count / 10);
+    points
+}
+
+fn points3(count: usize) -> Vec<Point3> {
+    points2(count * 2)
+        .chunks(2)
+        .map(|c| pt3(c[0].x, c[0].y, c[1].x))
+        .collect()
+}
+
+fn indices(keys: Vec<Key>) -> Vec<usize> {
+    let mut indices: Vec<_> = keys.into_iter().map(Key::index).collect();
+    indices.sort_unstable();
+    indices
+}
+
+// Compare the distances of the nearest points, as keys of equidistant points are ambiguous.
+fn distances<P: Copy>(keys: Vec<Key>, points: &[P], d: impl Fn(P) -> f32) -> Vec<f32> {
+    keys.into_iter().map(|k| d(points[k.index()])).collect()
+}
+
+fn brute_nearest<P: Copy>(points: &[P], k: usize, d: impl Fn(P) -> f32) -> Vec<f32> {
+    let mut ds: Vec<_> = points.iter().map(|&p| d(p)).collect();
+    ds.sort_by(f32::total_cmp);
+    ds.truncate(k);
+    ds
+}
+
+#[test]
+fn queries_match_brute_force_2d() {
+    let points = points2(500);
+    // Some points lie outside of the quadtree's bounds.
+    let mut quadtree = QuadTree::new(Rect::from_w_h(160.0, 160.0)).node_capacity(4);
+    let mut grid = GridHash::new(10.0);
+    for (i, &p) in points.iter().enumerate() {
+        assert_eq!(quadtree.insert(p, i).index(), i);
+        assert_eq!(grid.insert(p, i).index(), i);
+    }
+    let kdtree: KdTree<_, _> = points.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+    assert_eq!(kdtree.len(), points.len());
+    assert!(quadtree.leaf_rects().count() > 1);
+
+    let query = Rect::from_corners(pt2(-30.0, 90.0), pt2(45.0, -10.0));
+    let expected: Vec<_> = (0..points.len())
+        .filter(|&i| query.contains(points[i]))
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(indices(quadtree.within_bounds(&query)), expected);
+    assert_eq!(indices(kdtree.within_bounds(&query)), expected);
+    assert_eq!(indices(grid.within_bounds(&query)), expected);
+
+    for &centre in &[pt2(0.0, 0.0), pt2(95.0, -95.0), pt2(300.0, 0.0), points[7]] {
+        let d = |p: Point2| p.distance_squared(centre);
+        let expected: Vec<_> = (0..points.len())
+            .filter(|&i| d(points[i]) <= 25.0 * 25.0)
+            .collect();
+        assert_eq!(indices(quadtree.within_radius(centre, 25.0)), expected);
+        assert_eq!(indices(kdtree.within_radius(centre, 25.0)), expected);
+        assert_eq!(indices(grid.within_radius(centre, 25.0)), expected);
+
+        for &k in &[0, 1, 5, 40, points.len() + 1] {
+            let expected = brute_nearest(&points, k, d);
+            assert_eq!(distances(quadtree.nearest(centre, k), &points, d), expected);
+            assert_eq!(distances(kdtree.nearest(centre, k), &points, d), expected);
+            assert_eq!(distances(grid.nearest(centre, k), &points, d), expected);
+        }
+    }
+}
+
+#[test]
+fn queries_match_brute_force_3d() {
+    let points = points3(400);
+    let mut kdtree = KdTree::new();
+    let mut grid = GridHash::new(15.0);
+    for (i, &p) in points.iter().enumerate() {
+        kdtree.insert(p, i);
+        grid.insert(p, i);
+    }
+
+    let query = Cuboid::from_xyz_whd(pt3(10.0, -20.0, 5.0), pt3(80.0, 60.0, 100.0));
+    let expected: Vec<_> = (0..points.len())
+        .filter(|&i| query.contains(points[i]))
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(indices(kdtree.within_bounds(&query)), expected);
+    assert_eq!(indices(grid.within_bounds(&query)), expected);
+
+    let centre = pt3(-40.0, 30.0, 0.0);
+    let d = |p: Point3| p.distance_squared(centre);
+    let expected: Vec<_> = (0..points.len())
+        .filter(|&i| d(points[i]) <= 40.0 * 40.0)
+        .collect();
+    assert_eq!(indices(kdtree.within_radius(centre, 40.0)), expected);
+    assert_eq!(indices(grid.within_radius(centre, 40.0)), expected);
+    for &k in &[1, 10, 100] {
+        let expected = brute_nearest(&points, k, d);
+        assert_eq!(distances(kdtree.nearest(centre, k), &points, d), expected);
+        assert_eq!(distances(grid.nearest(centre, k), &points, d), expected);
+    }
+}
+
+#[test]
+fn remove_and_move() {
+    let points = points2(200);
+    let mut quadtree = QuadTree::new(Rect::from_w_h(200.0, 200.0));
+    let mut kdtree = KdTree::new();
+    let mut grid = GridHash::new(8.0);
+    let mut keys = vec![];
+    for (i, &p) in points.iter().enumerate() {
+        keys.push(quadtree.insert(p, i));
+        kdtree.insert(p, i);
+        grid.insert(p, i);
+    }
+
+    // Remove every other point, rebuilding the k-d tree along the way.
+    let centre = pt2(10.0, 10.0);
+    let mut remaining = vec![];
+    for (i, &key) in keys.iter().enumerate() {
+        if i % 2 == 0 {
+            assert!(quadtree.remove(key).is_some());
+            assert!(quadtree.remove(key).is_none());
+            assert_eq!(kdtree.remove(key), grid.remove(key));
+        } else {
+            remaining.push(i);
+        }
+    }
+    assert_eq!(quadtree.len(), remaining.len());
+    assert_eq!(kdtree.len(), remaining.len());
+    assert_eq!(grid.len(), remaining.len());
+    let all = Rect::from_w_h(1000.0, 1000.0);
+    assert_eq!(quadtree.within_bounds(&all).len(), remaining.len());
+    assert_eq!(kdtree.within_bounds(&all).len(), remaining.len());
+    let near: Vec<_> = quadtree.within_radius(centre, 30.0);
+    assert_eq!(
+        indices(near.clone()),
+        indices(kdtree.within_radius(centre, 30.0))
+    );
+    assert_eq!(indices(near), indices(grid.within_radius(centre, 30.0)));
+
+    // Keys of removed values are reused.
+    let key = grid.insert(pt2(500.0, 500.0), 0);
+    assert!(key.index() < points.len());
+    assert_eq!(grid.nearest(pt2(400.0, 400.0), 1), [key]);
+
+    // Moving a point updates its cell.
+    assert!(grid.set_point(key, pt2(-500.0, 0.0)));
+    assert_eq!(grid.get(key).unwrap().0, pt2(-500.0, 0.0));
+    assert_eq!(grid.within_radius(pt2(-500.0, 0.0), 1.0), [key]);
+    assert!(grid.within_radius(pt2(500.0, 500.0), 1.0).is_empty());
+    *grid.get_mut(key).unwrap() = 42;
+    assert_eq!(grid.get(key).unwrap().1, &42);
+
+    grid.clear();
+    assert!(grid.is_empty());
+    assert!(grid.nearest(centre, 3).is_empty());
+}
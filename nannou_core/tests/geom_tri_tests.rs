@@ -0,0 +1,57 @@
+use nannou_core::geom::Tri;
+
+// A right triangle along with the same triangle with the opposite winding.
+fn tris() -> [Tri<[f32; 2]>; 2] {
+    let (a, b, c) = ([0.0, 0.0], [4.0, 0.0], [0.0, 4.0]);
+    [Tri([a, b, c]), Tri([a, c, b])]
+}
+
+#[test]
+fn contains_points_within() {
+    for tri in &tris() {
+        assert!(tri.contains(&[1.0, 1.0]));
+        assert!(tri.contains(&[0.1, 3.8]));
+    }
+}
+
+#[test]
+fn contains_points_on_edges() {
+    for tri in &tris() {
+        assert!(tri.contains(&[2.0, 0.0]));
+        assert!(tri.contains(&[0.0, 2.0]));
+        assert!(tri.contains(&[2.0, 2.0]));
+        assert!(tri.contains(&[1.0, 3.0]));
+    }
+}
+
+#[test]
+fn contains_vertices() {
+    for tri in &tris() {
+        for v in &tri.0 {
+            assert!(tri.contains(v));
+        }
+    }
+}
+
+#[test]
+fn excludes_points_just_outside() {
+    let e = 1.0e-3;
+    for tri in &tris() {
+        assert!(!tri.contains(&[2.0, -e]));
+        assert!(!tri.contains(&[-e, 2.0]));
+        assert!(!tri.contains(&[2.0 + e, 2.0 + e]));
+        assert!(!tri.contains(&[-e, -e]));
+        assert!(!tri.contains(&[4.0 + e, 0.0]));
+        assert!(!tri.contains(&[0.0, 4.0 + e]));
+    }
+}
+
+#[test]
+fn shared_edge_is_contained_by_both_triangles() {
+    let (a, b, c, d) = ([0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]);
+    let (t1, t2) = (Tri([a, b, c]), Tri([a, c, d]));
+    for &p in &[[1.0, 1.0], [2.0, 2.0], [3.5, 3.5]] {
+        assert!(t1.contains(&p));
+        assert!(t2.contains(&p));
+    }
+}